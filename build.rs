@@ -0,0 +1,61 @@
+//! Generates the lookup tables used by the high evaluator
+//!
+//! The tables are derived by `src/poker/tables/generate.rs`, which is shared with the crate as
+//! `poker::tables::generate`, and written out as static arrays that `src/poker/tables/mod.rs`
+//! includes.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+#[allow(dead_code)]
+#[path = "src/poker/tables/generate.rs"]
+mod generate;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/poker/tables/generate.rs");
+    if env::var_os("CARGO_FEATURE_POKER").is_none() {
+        return;
+    }
+
+    let tables = generate::generate(&generate::Rules::standard());
+    assert_eq!(
+        tables.classes, 7462,
+        "Generated the wrong number of hand classes"
+    );
+
+    let mut source = String::new();
+    write_table(
+        &mut source,
+        "The class of every flush, indexed by the bits of its five values",
+        "FLUSHES",
+        &tables.flushes,
+    );
+    write_table(
+        &mut source,
+        "The class of every straight and high card hand, indexed by the bits of its five values",
+        "UNIQUE5",
+        &tables.unique5,
+    );
+    write_table(
+        &mut source,
+        "The class of every hand with a repeated value, indexed by `perfect_hash`",
+        "HASH_VALUES",
+        &tables.hash_values,
+    );
+
+    let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("tables.rs"), source).expect("Failed to write tables");
+}
+
+fn write_table(source: &mut String, doc: &str, name: &str, table: &[u16]) {
+    writeln!(source, "/// {doc}").unwrap();
+    writeln!(
+        source,
+        "pub static {name}: [u16; {}] = {table:?};",
+        table.len()
+    )
+    .unwrap();
+}
@@ -0,0 +1,94 @@
+use std::cmp::Ordering;
+
+use num_traits::FromPrimitive;
+
+use super::{Card, Value};
+
+/// Whether the ace is ranked above the king or below the 2
+///
+/// `Value` orders the ace high by default. Lowball games such as A-5 lowball and Badugi rank the
+/// ace as the lowest value instead, which can be expressed by ordering with `AceOrdering::Low`.
+///
+/// Jokers are always ranked above every other value.
+///
+/// ```rust
+/// use playing_cards::core::{AceOrdering, Card, Value};
+///
+/// let mut hand = Card::vec_from_str("5dAs3cKh").unwrap();
+///
+/// hand.sort_by(|a, b| AceOrdering::Low.cmp_cards(a, b));
+/// assert_eq!(hand, Card::vec_from_str("As3c5dKh").unwrap());
+///
+/// hand.sort_by(|a, b| AceOrdering::High.cmp_cards(a, b));
+/// assert_eq!(hand, Card::vec_from_str("3c5dKhAs").unwrap());
+///
+/// assert_eq!(AceOrdering::Low.index(Value::Ace), 0);
+/// assert_eq!(AceOrdering::Low.value(1), Some(Value::Two));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum AceOrdering {
+    /// The ace ranks above the king, as in `Value`'s own ordering
+    #[default]
+    High,
+    /// The ace ranks below the 2
+    Low,
+}
+
+impl AceOrdering {
+    /// Returns the position of a value under this ordering, starting from 0 for the lowest value
+    ///
+    /// With the ace high, this is the numerical representation of the value. With the ace low,
+    /// the ace is 0, the 2 is 1, and so on up to the king at 12. Jokers are always 13.
+    pub fn index(&self, value: Value) -> u8 {
+        match (self, value) {
+            (_, Value::Joker) => value as u8,
+            (Self::High, _) => value as u8,
+            (Self::Low, _) => (value as u8 + 1) % 13,
+        }
+    }
+
+    /// Returns the value at a position under this ordering, the reverse of `index()`
+    pub fn value(&self, index: u8) -> Option<Value> {
+        match (self, index) {
+            (Self::Low, 0..=12) => Value::from_u8((index + 12) % 13),
+            _ => Value::from_u8(index),
+        }
+    }
+
+    /// Compares two values under this ordering
+    pub fn cmp_values(&self, a: Value, b: Value) -> Ordering {
+        self.index(a).cmp(&self.index(b))
+    }
+
+    /// Compares two cards by their values under this ordering, with suits breaking any ties
+    ///
+    /// Suits are ordered by their numerical representation, so this is a total ordering over
+    /// distinct cards.
+    pub fn cmp_cards(&self, a: &Card, b: &Card) -> Ordering {
+        self.cmp_values(a.value, b.value)
+            .then((a.suit as u8).cmp(&(b.suit as u8)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn index_round_trip() {
+        for ordering in [AceOrdering::High, AceOrdering::Low] {
+            for value in Value::iter().chain([Value::Joker]) {
+                assert_eq!(ordering.value(ordering.index(value)), Some(value));
+            }
+            assert_eq!(ordering.value(14), None);
+        }
+
+        let mut low_values: Vec<Value> = Value::iter().collect();
+        low_values.sort_by(|a, b| AceOrdering::Low.cmp_values(*a, *b));
+        assert_eq!(low_values.first(), Some(&Value::Ace));
+        assert_eq!(low_values[1], Value::Two);
+        assert_eq!(low_values.last(), Some(&Value::King));
+    }
+}
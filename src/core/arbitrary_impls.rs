@@ -0,0 +1,61 @@
+//! Implementations of `arbitrary::Arbitrary` for fuzzing
+//!
+//! Like the `proptest` strategies, only cards from a standard 52-card deck are generated and
+//! collections never contain the same card twice.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::{Card, CardSet, Hand};
+
+impl<'a> Arbitrary<'a> for Card {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Card::try_from(u.int_in_range(0..=51u8)?).unwrap())
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl<'a> Arbitrary<'a> for CardSet {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let bits = u64::arbitrary(u)? & CardSet::full().bits();
+        Ok(CardSet::from_bits(bits).unwrap())
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u64::size_hint(depth)
+    }
+}
+
+/// Hands are generated with between 0 and 7 cards
+impl<'a> Arbitrary<'a> for Hand {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut remaining: Vec<Card> = CardSet::full().iter().collect();
+        let len = u.int_in_range(0..=7)?;
+
+        let cards = (0..len)
+            .map(|_| Ok(remaining.swap_remove(u.choose_index(remaining.len())?)))
+            .collect::<Result<Vec<Card>>>()?;
+
+        Ok(Hand::new(cards).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_valid_hands() {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut u = Unstructured::new(&data);
+
+        for _ in 0..20 {
+            let hand = Hand::arbitrary(&mut u).unwrap();
+            assert!(hand.len() <= 7);
+            assert!(hand.iter().all(|card| !card.is_joker()));
+        }
+        assert!(!Card::arbitrary(&mut u).unwrap().is_joker());
+    }
+}
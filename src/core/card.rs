@@ -1,16 +1,25 @@
 use num_traits::FromPrimitive;
+use std::ops::RangeBounds;
 use std::str::FromStr;
+use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
+use super::ParseCardError;
+
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// An enum representation of the rank of a card
 ///
-/// Each value corresponds to the rank strength.
+/// Each value corresponds to the rank strength. `Joker` is not part of a standard deck and sorts
+/// above every other value; see `Card::is_joker()` for how jokers are represented as cards. It is
+/// skipped by `Value::iter()` and `Value::range()`, which only yield the 13 standard values.
 #[allow(missing_docs)]
 #[derive(
     Debug, Clone, Copy, FromPrimitive, ToPrimitive, EnumIter, Eq, PartialEq, Ord, PartialOrd, Hash,
 )]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "char", try_from = "char"))]
 pub enum Value {
     Two = 0,
     Three = 1,
@@ -25,6 +34,8 @@ pub enum Value {
     Queen = 10,
     King = 11,
     Ace = 12,
+    #[strum(disabled)]
+    Joker = 13,
 }
 
 impl Value {
@@ -48,6 +59,7 @@ impl Value {
             Self::Queen => 'Q',
             Self::King => 'K',
             Self::Ace => 'A',
+            Self::Joker => 'X',
         }
     }
 
@@ -69,6 +81,7 @@ impl Value {
             'Q' => Some(Self::Queen),
             'K' => Some(Self::King),
             'A' => Some(Self::Ace),
+            'X' => Some(Self::Joker),
             _ => None,
         }
     }
@@ -92,6 +105,7 @@ impl Value {
             Self::Queen => "Queen".to_string(),
             Self::King => "King".to_string(),
             Self::Ace => "Ace".to_string(),
+            Self::Joker => "Joker".to_string(),
         }
     }
 
@@ -113,16 +127,108 @@ impl Value {
             Self::Queen => 31,
             Self::King => 37,
             Self::Ace => 41,
+            Self::Joker => 43,
+        }
+    }
+
+    /// Returns whether the value is a broadway card (10 through Ace)
+    pub fn is_broadway(&self) -> bool {
+        (Self::Ten..=Self::Ace).contains(self)
+    }
+
+    /// Returns whether the value is a face card (Jack, Queen, or King)
+    pub fn is_face(&self) -> bool {
+        (Self::Jack..=Self::King).contains(self)
+    }
+
+    /// Returns the number of values that lie strictly between this value and the other
+    ///
+    /// The ace is treated as high, so connected values (e.g. 9 and 8) have a gap of 0 and the
+    /// gap between a King and a 9 is 3. Identical values also have a gap of 0.
+    ///
+    /// ```rust
+    /// use playing_cards::core::Value;
+    ///
+    /// assert_eq!(Value::Jack.gap_to(Value::Nine), 1);
+    /// assert_eq!(Value::Two.gap_to(Value::Ace), 11);
+    /// assert_eq!(Value::Five.gap_to(Value::Five), 0);
+    /// ```
+    pub fn gap_to(&self, other: Value) -> u8 {
+        (*self as u8).abs_diff(other as u8).saturating_sub(1)
+    }
+
+    /// Returns the next highest value, or `None` for the ace
+    ///
+    /// Jokers have no neighbouring values, so `None` is also returned for them.
+    pub fn next(&self) -> Option<Value> {
+        match self {
+            Self::Ace | Self::Joker => None,
+            _ => Value::from_u8(*self as u8 + 1),
+        }
+    }
+
+    /// Returns the next lowest value, or `None` for the 2
+    ///
+    /// Jokers have no neighbouring values, so `None` is also returned for them.
+    pub fn prev(&self) -> Option<Value> {
+        match self {
+            Self::Two | Self::Joker => None,
+            _ => Value::from_u8(*self as u8 - 1),
+        }
+    }
+
+    /// Returns the next highest value, wrapping from the ace around to the 2
+    ///
+    /// Jokers are returned unchanged.
+    pub fn next_wrapping(&self) -> Value {
+        match self {
+            Self::Ace => Self::Two,
+            Self::Joker => Self::Joker,
+            _ => self.next().unwrap(),
+        }
+    }
+
+    /// Returns the next lowest value, wrapping from the 2 around to the ace
+    ///
+    /// This is useful for ace-low straights, where the ace sits below the 2. Jokers are returned
+    /// unchanged.
+    pub fn prev_wrapping(&self) -> Value {
+        match self {
+            Self::Two => Self::Ace,
+            Self::Joker => Self::Joker,
+            _ => self.prev().unwrap(),
         }
     }
+
+    /// Returns an iterator over the standard values within a range, from lowest to highest
+    ///
+    /// `Joker` is never returned, even by an unbounded range.
+    ///
+    /// ```rust
+    /// use playing_cards::core::Value;
+    ///
+    /// let broadway: Vec<Value> = Value::range(Value::Ten..=Value::Ace).collect();
+    /// assert_eq!(broadway.len(), 5);
+    ///
+    /// assert_eq!(Value::range(..Value::Five).count(), 3);
+    /// assert_eq!(Value::range(Value::Six..Value::Six).count(), 0);
+    /// assert_eq!(Value::range(..).last(), Some(Value::Ace));
+    /// ```
+    pub fn range<R: RangeBounds<Value>>(range: R) -> impl Iterator<Item = Value> {
+        Value::iter().filter(move |value| range.contains(value))
+    }
 }
 
+/// Converts the numerical representation of a standard value back into a value
+///
+/// Only the 13 values of a standard deck (0-12) are accepted; `Joker` is not converted from an
+/// integer.
 impl TryFrom<i32> for Value {
     type Error = i32;
     fn try_from(s: i32) -> Result<Self, Self::Error> {
         match Value::from_i32(s) {
-            Some(val) => Ok(val),
-            None => Err(s),
+            Some(val) if val != Value::Joker => Ok(val),
+            _ => Err(s),
         }
     }
 }
@@ -137,6 +243,26 @@ impl TryFrom<char> for Value {
     }
 }
 
+impl FromStr for Value {
+    type Err = ParseCardError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => {
+                Value::from_char(c).ok_or_else(|| ParseCardError::InvalidValue(s.to_string()))
+            }
+            _ => Err(ParseCardError::InvalidValue(s.to_string())),
+        }
+    }
+}
+
+impl TryFrom<&str> for Value {
+    type Error = ParseCardError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Value::from_str(s)
+    }
+}
+
 impl From<Value> for char {
     fn from(value: Value) -> Self {
         value.get_char()
@@ -155,6 +281,8 @@ impl std::fmt::Display for Value {
 /// Numerical value is just for distinction and each suit has equal strength.
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, FromPrimitive, ToPrimitive, EnumIter, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "char", try_from = "char"))]
 pub enum Suit {
     Heart = 0,
     Club = 1,
@@ -163,6 +291,34 @@ pub enum Suit {
 }
 
 impl Suit {
+    /// Returns whether the suit is red (hearts or diamonds)
+    pub fn is_red(&self) -> bool {
+        matches!(self, Self::Heart | Self::Diamond)
+    }
+
+    /// Returns whether the suit is black (clubs or spades)
+    pub fn is_black(&self) -> bool {
+        !self.is_red()
+    }
+
+    /// Returns the next suit in numerical order, wrapping from spades back around to hearts
+    ///
+    /// ```rust
+    /// use playing_cards::core::Suit;
+    ///
+    /// assert_eq!(Suit::Heart.next(), Suit::Club);
+    /// assert_eq!(Suit::Spade.next(), Suit::Heart);
+    /// assert_eq!(Suit::Heart.prev(), Suit::Spade);
+    /// ```
+    pub fn next(&self) -> Suit {
+        Suit::from_u8((*self as u8 + 1) % 4).unwrap()
+    }
+
+    /// Returns the previous suit in numerical order, wrapping from hearts back around to spades
+    pub fn prev(&self) -> Suit {
+        Suit::from_u8((*self as u8 + 3) % 4).unwrap()
+    }
+
     /// Gets the associated character for the Suit
     ///
     /// This is typically used to parse a Suit into a string format for users like printing
@@ -212,6 +368,26 @@ impl TryFrom<char> for Suit {
     }
 }
 
+impl FromStr for Suit {
+    type Err = ParseCardError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => {
+                Suit::from_char(c).ok_or_else(|| ParseCardError::InvalidSuit(s.to_string()))
+            }
+            _ => Err(ParseCardError::InvalidSuit(s.to_string())),
+        }
+    }
+}
+
+impl TryFrom<&str> for Suit {
+    type Error = ParseCardError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Suit::from_str(s)
+    }
+}
+
 impl From<Suit> for char {
     fn from(value: Suit) -> Self {
         value.get_char()
@@ -231,9 +407,13 @@ impl std::fmt::Display for Suit {
 }
 
 /// A structural representation of a playing card
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Hash)]
-#[serde(try_from = "String")]
-#[serde(into = "String")]
+///
+/// With the `serde` feature enabled, cards are serialized in their compact form (e.g. "As", "Td"),
+/// while values and suits are serialized as their single characters. Deserialization accepts
+/// anything that `Card::from_str()` does, including suit symbols.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String"))]
 pub struct Card {
     /// The Value of the Card
     pub value: Value,
@@ -242,6 +422,97 @@ pub struct Card {
 }
 
 impl Card {
+    /// The bit that is set within `calculate_bit_pattern()` for jokers
+    ///
+    /// This sits just above the 13 value bits of a standard Cactus-Kev bit pattern, so evaluators
+    /// that mask out those value bits will ignore it. Evaluators that do not support jokers can
+    /// detect them by checking for this bit.
+    pub const JOKER_BIT: u32 = 1 << 29;
+
+    /// Creates a new card
+    ///
+    /// This is a `const fn`, so cards can be created at compile time. Constants for every card in
+    /// a standard deck can also be found in the `cards` module.
+    ///
+    /// ```rust
+    /// use playing_cards::core::{cards::ACE_OF_SPADES, Card, Suit, Value};
+    ///
+    /// const ACE: Card = Card::new(Value::Ace, Suit::Spade);
+    ///
+    /// assert_eq!(ACE, ACE_OF_SPADES);
+    /// ```
+    pub const fn new(value: Value, suit: Suit) -> Self {
+        let suit = match (value, suit) {
+            (Value::Joker, Suit::Heart | Suit::Diamond) => Suit::Heart,
+            (Value::Joker, Suit::Club | Suit::Spade) => Suit::Spade,
+            _ => suit,
+        };
+
+        Self { value, suit }
+    }
+
+    /// Returns whether the card is a joker
+    ///
+    /// Jokers are cards with a `Value` of `Joker`. The suit of a joker only determines its color:
+    /// hearts and diamonds are red jokers, while clubs and spades are black jokers. `Card::new()`
+    /// and parsing normalize the suit of a joker to hearts or spades, so "Xd" is the same card as
+    /// "Xh". The `cards` module provides these as `RED_JOKER` and `BLACK_JOKER`.
+    ///
+    /// The evaluators in the poker module do not support jokers and will return an error if they
+    /// are given any.
+    ///
+    /// ```rust
+    /// use playing_cards::core::{cards, Card};
+    ///
+    /// let joker: Card = "Xd".parse().unwrap();
+    ///
+    /// assert!(joker.is_joker());
+    /// assert_eq!(joker, cards::RED_JOKER);
+    /// assert_ne!(joker.calculate_bit_pattern() & Card::JOKER_BIT, 0);
+    /// assert_eq!(cards::ACE_OF_SPADES.calculate_bit_pattern() & Card::JOKER_BIT, 0);
+    /// ```
+    pub const fn is_joker(&self) -> bool {
+        matches!(self.value, Value::Joker)
+    }
+
+    /// Returns whether the card is red (hearts or diamonds)
+    pub fn is_red(&self) -> bool {
+        self.suit.is_red()
+    }
+
+    /// Returns whether the card is black (clubs or spades)
+    pub fn is_black(&self) -> bool {
+        self.suit.is_black()
+    }
+
+    /// Returns whether the card has the same suit as the other
+    pub fn is_suited_with(&self, other: &Card) -> bool {
+        self.suit == other.suit
+    }
+
+    /// Returns whether the card has the same value as the other
+    pub fn is_paired_with(&self, other: &Card) -> bool {
+        self.value == other.value
+    }
+
+    /// Returns whether the card's value is adjacent to the other's (e.g. a 9 and an 8)
+    ///
+    /// The ace is treated as high, so an ace is only connected to a king.
+    ///
+    /// ```rust
+    /// use playing_cards::core::Card;
+    ///
+    /// let hand = Card::vec_from_str("9s8s").unwrap();
+    ///
+    /// assert!(hand[0].is_connected_to(&hand[1]));
+    /// assert!(hand[0].is_suited_with(&hand[1]));
+    /// assert!(!hand[0].is_paired_with(&hand[1]));
+    /// assert_eq!(hand[0].value.gap_to(hand[1].value), 0);
+    /// ```
+    pub fn is_connected_to(&self, other: &Card) -> bool {
+        (self.value as u8).abs_diff(other.value as u8) == 1
+    }
+
     /// Takes in a string and returns back a vector of Cards
     ///
     /// This can be used to quickly static hands that can be evaluated for testing.
@@ -261,7 +532,8 @@ impl Card {
 
     /// Turns card into integer
     ///
-    /// This is typically used for when traversing the lookup table.
+    /// This is typically used for when traversing the lookup table. For a compact, checked
+    /// representation, convert the card into a `u8` instead.
     pub fn to_int(&self) -> i32 {
         ((self.value as i32) * 4) + (self.suit as i32) + 1
     }
@@ -270,64 +542,81 @@ impl Card {
     ///
     /// This can be useful for building custom hand evaluators. For poker-related hand evaluators,
     /// please see the poker module.
-    pub fn calculate_bit_pattern(&self) -> u32 {
-        let mut bit_pattern: u32 = 0;
-        bit_pattern |= 1 << (16 + self.value as u32);
-        bit_pattern |= 1
-            << (12
-                + match self.suit {
-                    Suit::Heart => 1,
-                    Suit::Club => 3,
-                    Suit::Diamond => 2,
-                    Suit::Spade => 0,
-                });
-        bit_pattern |= (self.value as u32) << 8;
-        bit_pattern |= self.value.get_cactus_kev_prime() as u32;
-
-        bit_pattern
+    ///
+    /// Jokers are given the value bit above the ace (`Card::JOKER_BIT`) and the next prime after
+    /// the ace's prime.
+    ///
+    /// Every bit pattern is precomputed at compile time, so this is a single table lookup.
+    pub const fn calculate_bit_pattern(&self) -> u32 {
+        BIT_PATTERNS[self.value as usize * 4 + self.suit as usize]
     }
 }
 
+/// The Cactus-Kev bit patterns of every card (including jokers), indexed by `value * 4 + suit`
+const BIT_PATTERNS: [u32; 56] = {
+    const PRIMES: [u32; 14] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43];
+    // indexed by the numerical representation of the suit: hearts, clubs, diamonds, and spades
+    const SUIT_BITS: [u32; 4] = [1, 3, 2, 0];
+
+    let mut patterns = [0; 56];
+    let mut i = 0;
+    while i < patterns.len() {
+        let value = i as u32 / 4;
+        patterns[i] =
+            1 << (16 + value) | 1 << (12 + SUIT_BITS[i % 4]) | value << 8 | PRIMES[value as usize];
+        i += 1;
+    }
+
+    patterns
+};
+
+/// Converts the 1-based integer representation of a card back into a card
+///
+/// Only the 52 cards of a standard deck (1-52) have an integer representation. Jokers can be
+/// created with `Card::new()` or from their `u8` encoding.
+///
+/// # Panics
+///
+/// Panics if the integer does not represent a standard card. Prefer `Card::try_from()` with a `u8`, which
+/// validates its input.
 impl From<i32> for Card {
     fn from(s: i32) -> Card {
-        Card {
-            value: Value::try_from((s - 1) / 4).unwrap(),
-            suit: Suit::try_from((s - 1) % 4).unwrap(),
-        }
+        Card::new(
+            Value::try_from((s - 1) / 4).unwrap(),
+            Suit::try_from((s - 1) % 4).unwrap(),
+        )
     }
 }
 
-impl TryFrom<String> for Card {
-    type Error = String;
-    fn try_from(s: String) -> Result<Self, Self::Error> {
-        if s.chars().count() != 2 {
-            return Err(format!(
-                r#"Card string "{}" is not exactly a length of 2"#,
-                s
-            ));
+impl TryFrom<&str> for Card {
+    type Error = ParseCardError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let chars: Vec<char> = s.chars().collect();
+
+        if let [glyph] = chars[..] {
+            if let Some(card) = Card::from_glyph(glyph) {
+                return Ok(card);
+            }
         }
 
-        let mut chars = s.chars();
+        let (value, suit) = match chars[..] {
+            [value, suit] => (Value::try_from(value), suit),
+            ['1', '0', suit] => (Ok(Value::Ten), suit),
+            _ => return Err(ParseCardError::InvalidLength(s.to_string())),
+        };
 
-        let value = Value::try_from(chars.next().unwrap());
-        if value.is_err() {
-            return Err(format!(
-                r#"Card value "{}" was not a valid character"#,
-                value.unwrap_err()
-            ));
-        }
-        let value = value.unwrap();
-
-        let suit = Suit::try_from(chars.next().unwrap());
-        if suit.is_err() {
-            return Err(format!(
-                r#"Card suit "{}" was not a valid character"#,
-                suit.unwrap_err()
-            ));
-        }
-        let suit = suit.unwrap();
+        let value = value.map_err(|c| ParseCardError::InvalidValue(c.to_string()))?;
 
-        Ok(Card { value, suit })
+        let suit = Suit::try_from(suit).map_err(|c| ParseCardError::InvalidSuit(c.to_string()))?;
+
+        Ok(Card::new(value, suit))
+    }
+}
+
+impl TryFrom<String> for Card {
+    type Error = ParseCardError;
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Card::try_from(s.as_str())
     }
 }
 
@@ -338,9 +627,20 @@ impl From<Card> for String {
 }
 
 impl FromStr for Card {
-    type Err = String;
+    type Err = ParseCardError;
     fn from_str(s: &'_ str) -> Result<Self, Self::Err> {
-        Self::try_from(s.to_string())
+        Self::try_from(s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Card {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!(
+            "{}{}",
+            self.value.get_char(),
+            self.suit.get_char()
+        ))
     }
 }
 
@@ -350,6 +650,71 @@ impl From<Card> for i32 {
     }
 }
 
+/// Converts a card into its compact `u8` encoding
+///
+/// The 52 cards of a standard deck are encoded within 0-51 as `value * 4 + suit`, using the
+/// numerical representations of `Value` and `Suit`. This is the same order as the `cards::ALL`
+/// array. The red joker is encoded as 52 and the black joker as 53.
+///
+/// This encoding is stable, making it suitable for packing cards densely within network messages
+/// and lookup tables.
+///
+/// ```rust
+/// use playing_cards::core::{cards, Card};
+///
+/// assert_eq!(u8::from(cards::TWO_OF_HEARTS), 0);
+/// assert_eq!(u8::from(cards::ACE_OF_SPADES), 51);
+/// assert_eq!(Card::try_from(51u8), Ok(cards::ACE_OF_SPADES));
+/// assert_eq!(Card::try_from(54u8), Err(54));
+/// ```
+impl From<Card> for u8 {
+    fn from(card: Card) -> Self {
+        match (card.value, card.suit) {
+            (Value::Joker, Suit::Heart | Suit::Diamond) => 52,
+            (Value::Joker, Suit::Club | Suit::Spade) => 53,
+            (value, suit) => value as u8 * 4 + suit as u8,
+        }
+    }
+}
+
+/// Converts a compact `u8` encoding back into a card
+///
+/// See the conversion from `Card` into `u8` for details on the encoding. Returns the given
+/// integer back as an error if it is not within 0-53.
+impl TryFrom<u8> for Card {
+    type Error = u8;
+    fn try_from(n: u8) -> Result<Self, Self::Error> {
+        match n {
+            0..=51 => Ok(Card {
+                value: Value::try_from(n as i32 / 4).unwrap(),
+                suit: Suit::try_from(n as i32 % 4).unwrap(),
+            }),
+            52 => Ok(Card::new(Value::Joker, Suit::Heart)),
+            53 => Ok(Card::new(Value::Joker, Suit::Spade)),
+            _ => Err(n),
+        }
+    }
+}
+
+/// Samples a uniformly random card from a standard 52-card deck
+///
+/// Jokers are never sampled. This requires the `rand` feature.
+///
+/// ```rust
+/// use playing_cards::core::Card;
+/// use rand::Rng;
+///
+/// let card: Card = rand::thread_rng().gen();
+///
+/// assert!(!card.is_joker());
+/// ```
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Card> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Card {
+        Card::try_from(rng.gen_range(0..52u8)).unwrap()
+    }
+}
+
 impl std::fmt::Display for Card {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{}{}", self.value, self.suit)
@@ -398,6 +763,129 @@ mod tests {
         let _ = Card::from(val);
     }
 
+    #[test]
+    fn predicates() {
+        let broadway: Vec<Value> = Value::iter().filter(Value::is_broadway).collect();
+        assert_eq!(
+            broadway,
+            vec![
+                Value::Ten,
+                Value::Jack,
+                Value::Queen,
+                Value::King,
+                Value::Ace
+            ]
+        );
+        assert_eq!(Value::iter().filter(Value::is_face).count(), 3);
+
+        let red_cards: Vec<Card> = Card::vec_from_str("AhKdQcJs")
+            .unwrap()
+            .into_iter()
+            .filter(Card::is_red)
+            .collect();
+        assert_eq!(red_cards, Card::vec_from_str("AhKd").unwrap());
+
+        let hand = Card::vec_from_str("AsKdAd").unwrap();
+        assert!(hand[0].is_connected_to(&hand[1]));
+        assert!(!hand[0].is_connected_to(&hand[2]));
+        assert!(hand[0].is_paired_with(&hand[2]));
+        assert!(hand[1].is_suited_with(&hand[2]));
+        assert!(hand[0].is_black());
+    }
+
+    #[test]
+    fn precomputed_bit_patterns() {
+        for value in Value::iter() {
+            for suit in Suit::iter() {
+                let suit_bit = match suit {
+                    Suit::Heart => 1,
+                    Suit::Club => 3,
+                    Suit::Diamond => 2,
+                    Suit::Spade => 0,
+                };
+                let expected = 1 << (16 + value as u32)
+                    | 1 << (12 + suit_bit)
+                    | (value as u32) << 8
+                    | value.get_cactus_kev_prime() as u32;
+
+                assert_eq!(Card::new(value, suit).calculate_bit_pattern(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn successors_and_predecessors() {
+        let values: Vec<Value> = std::iter::successors(Some(Value::Two), Value::next).collect();
+        assert_eq!(values, Value::range(..=Value::Ace).collect::<Vec<_>>());
+        assert_eq!(values.len(), 13);
+
+        let values: Vec<Value> = std::iter::successors(Some(Value::Ace), Value::prev).collect();
+        assert_eq!(values.len(), 13);
+        assert_eq!(values.last(), Some(&Value::Two));
+
+        assert_eq!(Value::Ace.next_wrapping(), Value::Two);
+        assert_eq!(Value::Two.prev_wrapping(), Value::Ace);
+        assert_eq!(Value::Nine.next_wrapping(), Value::Ten);
+        assert_eq!(Value::Joker.next(), None);
+        assert_eq!(Value::Joker.prev(), None);
+
+        for suit in Suit::iter() {
+            assert_eq!(suit.next().prev(), suit);
+            assert_ne!(suit.next(), suit);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn uniform_random_cards() {
+        use rand::Rng;
+        use rand_core::SeedableRng;
+
+        let mut rng = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(1337);
+        let mut counts = [0; 52];
+        for _ in 0..52_000 {
+            let card: Card = rng.gen();
+            counts[u8::from(card) as usize] += 1;
+        }
+
+        assert!(counts.iter().all(|&count| (800..1200).contains(&count)));
+    }
+
+    #[test]
+    fn compact_u8_round_trip() {
+        for n in 0..=53u8 {
+            let card = Card::try_from(n).unwrap();
+            assert_eq!(u8::from(card), n);
+
+            if n < 52 {
+                assert_eq!(card, Card::from(n as i32 + 1));
+            }
+        }
+
+        for n in 54..=u8::MAX {
+            assert_eq!(Card::try_from(n), Err(n));
+        }
+
+        assert_eq!(u8::from(Card::new(Value::Joker, Suit::Diamond)), 52);
+    }
+
+    #[test]
+    fn jokers() {
+        assert_eq!(Value::iter().count(), 13);
+        assert_eq!(Value::iter().next_back(), Some(Value::Ace));
+        assert!(Value::range(Value::Ten..).all(|value| value != Value::Joker));
+
+        for (s, joker) in [("Xh", "Xd"), ("Xs", "Xc")] {
+            let (a, b) = (Card::from_str(s).unwrap(), Card::from_str(joker).unwrap());
+            assert_eq!(a, b);
+            assert_eq!(u8::from(a), u8::from(b));
+        }
+        assert_eq!(Card::new(Value::Joker, Suit::Diamond).suit, Suit::Heart);
+        assert_eq!(Card::new(Value::Joker, Suit::Club).suit, Suit::Spade);
+        assert_eq!(Value::try_from(13), Err(13));
+        assert_ne!(Card::from_str("Xh"), Card::from_str("Xs"));
+    }
+
     #[test]
     fn conversion() {
         for card_str in ["AH", "ah"] {
@@ -417,18 +905,76 @@ mod tests {
     #[test]
     fn conversion_error() {
         assert_eq!(
-            Card::from_str("xh").unwrap_err(),
-            r#"Card value "x" was not a valid character"#
+            Card::from_str("zh").unwrap_err().to_string(),
+            r#"Card value "z" was not a valid character"#
         );
 
         assert_eq!(
-            Card::from_str("Ky").unwrap_err(),
+            Card::from_str("Ky").unwrap_err().to_string(),
             r#"Card suit "y" was not a valid character"#,
         );
 
         assert_eq!(
-            Card::from_str("abc").unwrap_err(),
+            Card::from_str("abc").unwrap_err().to_string(),
             r#"Card string "abc" is not exactly a length of 2"#,
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_compact_strings() {
+        let hand = Card::vec_from_str("AsTd2h").unwrap();
+
+        let json = serde_json::to_string(&hand).unwrap();
+        assert_eq!(json, r#"["As","Td","2h"]"#);
+        assert_eq!(serde_json::from_str::<Vec<Card>>(&json).unwrap(), hand);
+
+        assert_eq!(serde_json::to_string(&Value::King).unwrap(), r#""K""#);
+        assert_eq!(serde_json::to_string(&Suit::Club).unwrap(), r#""c""#);
+        assert_eq!(
+            serde_json::from_str::<Value>(r#""q""#).unwrap(),
+            Value::Queen
+        );
+        assert_eq!(
+            serde_json::from_str::<Suit>(r#""♦""#).unwrap(),
+            Suit::Diamond
+        );
+        assert_eq!(
+            serde_json::from_str::<Card>(r#""K♥""#).unwrap(),
+            Card::new(Value::King, Suit::Heart)
+        );
+
+        assert!(serde_json::from_str::<Card>(r#""Kx""#).is_err());
+        assert!(serde_json::from_str::<Value>(r#""1""#).is_err());
+    }
+
+    #[test]
+    fn parse_generic() {
+        assert_eq!(
+            "As".parse::<Card>(),
+            Ok(Card {
+                value: Value::Ace,
+                suit: Suit::Spade
+            })
+        );
+        assert_eq!("t".parse::<Value>(), Ok(Value::Ten));
+        assert_eq!(Suit::try_from("♦"), Ok(Suit::Diamond));
+
+        assert_eq!(
+            "1".parse::<Value>(),
+            Err(ParseCardError::InvalidValue("1".to_string()))
+        );
+        assert_eq!(
+            "10".parse::<Value>(),
+            Err(ParseCardError::InvalidValue("10".to_string()))
+        );
+        assert_eq!(
+            "".parse::<Suit>(),
+            Err(ParseCardError::InvalidSuit("".to_string()))
+        );
+        assert_eq!(
+            Card::try_from("Kx"),
+            Err(ParseCardError::InvalidSuit("x".to_string()))
+        );
+    }
 }
@@ -0,0 +1,381 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use super::{Card, CardDeck, CardDeckError, CardSet, ShuffleGenerator, Suit, Value};
+
+/// The makeup of a deck created by `CardDeckBuilder`
+///
+/// This describes the cards that the deck started with, regardless of how many cards have since
+/// been dealt.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DeckComposition {
+    decks: usize,
+    values: Vec<Value>,
+    jokers: usize,
+}
+
+impl DeckComposition {
+    /// Returns the number of decks that were combined
+    pub fn decks(&self) -> usize {
+        self.decks
+    }
+
+    /// Returns the values that each deck contains, excluding jokers, from lowest to highest
+    pub fn values(&self) -> &[Value] {
+        &self.values
+    }
+
+    /// Returns the number of jokers within each deck
+    pub fn jokers(&self) -> usize {
+        self.jokers
+    }
+
+    /// Returns the total number of cards
+    pub fn len(&self) -> usize {
+        self.decks * (self.values.len() * 4 + self.jokers)
+    }
+
+    /// Returns whether the composition contains no cards
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the set of distinct cards within the composition
+    pub fn card_set(&self) -> CardSet {
+        if self.decks == 0 {
+            return CardSet::new();
+        }
+
+        let mut set: CardSet = Suit::iter()
+            .flat_map(|suit| self.values.iter().map(move |value| Card::new(*value, suit)))
+            .collect();
+        if self.jokers > 0 {
+            set.insert(Card::new(Value::Joker, Suit::Heart));
+        }
+        if self.jokers > 1 {
+            set.insert(Card::new(Value::Joker, Suit::Spade));
+        }
+        set
+    }
+
+    /// Returns the number of copies of a card
+    ///
+    /// Jokers alternate between red and black, starting with red, and use the same suits as
+    /// `cards::RED_JOKER` and `cards::BLACK_JOKER`.
+    pub fn copies_of(&self, card: Card) -> usize {
+        let per_deck = if card.is_joker() {
+            match card.suit {
+                Suit::Heart => self.jokers.div_ceil(2),
+                Suit::Spade => self.jokers / 2,
+                Suit::Club | Suit::Diamond => 0,
+            }
+        } else if self.values.contains(&card.value) {
+            1
+        } else {
+            0
+        };
+
+        per_deck * self.decks
+    }
+}
+
+impl Default for DeckComposition {
+    fn default() -> Self {
+        Self {
+            decks: 1,
+            values: Value::iter().collect(),
+            jokers: 0,
+        }
+    }
+}
+
+/// A builder for decks other than a single standard 52-card deck
+///
+/// Multi-deck shoes, stripped decks, and decks with jokers can all be built. The resulting
+/// `CardDeck` records what it was built from in `composition()`.
+///
+/// ```rust
+/// use playing_cards::core::{CardDeckBuilder, Value};
+///
+/// // a 6 deck shoe for blackjack
+/// let shoe = CardDeckBuilder::new().decks(6).build().unwrap();
+/// assert_eq!(shoe.composition().unwrap().len(), 312);
+///
+/// // a 32-card piquet deck, which only contains the 7s through the aces
+/// let piquet = CardDeckBuilder::new().lowest_value(Value::Seven).build().unwrap();
+/// assert_eq!(piquet.count(), 32);
+///
+/// // a 54-card deck with a red and a black joker
+/// let deck = CardDeckBuilder::new().jokers(2).build().unwrap();
+/// assert_eq!(deck.filter(|card| card.is_joker()).count(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CardDeckBuilder {
+    composition: DeckComposition,
+    seed: Option<[u8; 32]>,
+    shuffle: bool,
+    generator: ShuffleGenerator,
+    order: Option<Vec<Card>>,
+}
+
+impl CardDeckBuilder {
+    /// Creates a builder for a single standard 52-card deck
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a builder for a 48-card Pinochle deck
+    ///
+    /// A Pinochle deck contains two copies of each card from the 9s through the aces.
+    ///
+    /// ```rust
+    /// use playing_cards::core::{Card, CardDeckBuilder};
+    ///
+    /// let mut deck = CardDeckBuilder::pinochle().build().unwrap();
+    /// let ace = "As".parse::<Card>().unwrap();
+    ///
+    /// deck.remove_cards(&[ace, ace]).unwrap();
+    /// assert_eq!(deck.len(), 46);
+    /// assert!(!deck.contains(&ace));
+    /// ```
+    pub fn pinochle() -> Self {
+        Self::new().decks(2).lowest_value(Value::Nine)
+    }
+
+    /// Creates a builder for a 24-card Euchre deck, which contains the 9s through the aces
+    pub fn euchre() -> Self {
+        Self::new().lowest_value(Value::Nine)
+    }
+
+    /// Creates a builder for a 32-card Euchre deck, which contains the 7s through the aces
+    pub fn euchre_32() -> Self {
+        Self::new().lowest_value(Value::Seven)
+    }
+
+    /// Sets the number of decks to combine into a shoe
+    pub fn decks(mut self, decks: usize) -> Self {
+        self.composition.decks = decks;
+        self
+    }
+
+    /// Removes every value below the given value from each deck
+    ///
+    /// For example, a lowest value of 7 gives a 32-card deck and a lowest value of 6 gives a
+    /// 36-card deck.
+    pub fn lowest_value(mut self, lowest: Value) -> Self {
+        self.composition.values.retain(|value| *value >= lowest);
+        self
+    }
+
+    /// Removes the given values from each deck
+    ///
+    /// For example, removing the 8s, 9s, and 10s gives a 40-card Spanish or Italian deck.
+    pub fn strip_values(mut self, values: &[Value]) -> Self {
+        self.composition
+            .values
+            .retain(|value| !values.contains(value));
+        self
+    }
+
+    /// Sets the number of jokers to add to each deck
+    ///
+    /// Jokers alternate between red and black, starting with red.
+    pub fn jokers(mut self, jokers: usize) -> Self {
+        self.composition.jokers = jokers;
+        self
+    }
+
+    /// Shuffles the deck once it is built
+    ///
+    /// If no seed is provided, a seed will be sampled from entropy.
+    pub fn shuffled(self, seed: Option<[u8; 32]>) -> Self {
+        self.shuffled_with_generator(ShuffleGenerator::default(), seed)
+    }
+
+    /// Shuffles the deck with the given generator once it is built
+    ///
+    /// See `CardDeck::shuffle_with_generator()`. If no seed is provided, a seed will be sampled
+    /// from entropy.
+    pub fn shuffled_with_generator(
+        mut self,
+        generator: ShuffleGenerator,
+        seed: Option<[u8; 32]>,
+    ) -> Self {
+        self.shuffle = true;
+        self.generator = generator;
+        self.seed = seed;
+        self
+    }
+
+    /// Deals the cards in exactly the given order, with the first card given being dealt first
+    ///
+    /// When the deck is built, the cards must match the composition exactly, meaning each card
+    /// must appear as many times as `DeckComposition::copies_of()` returns.
+    pub fn stacked(mut self, cards: Vec<Card>) -> Self {
+        self.order = Some(cards);
+        self
+    }
+
+    /// Builds the deck
+    ///
+    /// Unless the deck is shuffled or stacked, the cards are dealt in the reverse order of a
+    /// standard deck, with any jokers dealt first. An error will return if shuffling fails or if
+    /// the stacked cards do not match the composition.
+    pub fn build(mut self) -> Result<CardDeck, CardDeckError> {
+        let cards = match self.order.take() {
+            Some(order) => self.check_stacked_order(order)?,
+            None => self.unshuffled_cards(),
+        };
+
+        let mut deck = CardDeck::new_custom_deck(cards, None)?;
+        if self.shuffle {
+            deck.shuffle_with_generator(self.generator, self.seed)?;
+        }
+        deck.composition = Some(self.composition);
+
+        Ok(deck)
+    }
+
+    fn check_stacked_order(&self, mut order: Vec<Card>) -> Result<Vec<Card>, CardDeckError> {
+        for (i, card) in order.iter().enumerate() {
+            if order[..=i].iter().filter(|c| *c == card).count() > self.composition.copies_of(*card)
+            {
+                return Err(CardDeckError::CardAlreadyInDeck(*card));
+            }
+        }
+
+        if order.len() < self.composition.len() {
+            let missing = self
+                .unshuffled_cards()
+                .into_iter()
+                .find(|card| {
+                    order.iter().filter(|c| *c == card).count() < self.composition.copies_of(*card)
+                })
+                .expect("a card should be missing from a short deck");
+            return Err(CardDeckError::MissingCard(missing));
+        }
+
+        // the top of the deck is the end of the vector
+        order.reverse();
+        Ok(order)
+    }
+
+    fn unshuffled_cards(&self) -> Vec<Card> {
+        let mut cards = Vec::with_capacity(self.composition.len());
+        for _ in 0..self.composition.decks {
+            for suit in Suit::iter() {
+                for value in &self.composition.values {
+                    cards.push(Card::new(*value, suit));
+                }
+            }
+
+            for i in 0..self.composition.jokers {
+                let suit = if i % 2 == 0 { Suit::Heart } else { Suit::Spade };
+                cards.push(Card::new(Value::Joker, suit));
+            }
+        }
+
+        cards
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stripped_decks() {
+        let deck = CardDeckBuilder::new()
+            .lowest_value(Value::Six)
+            .build()
+            .unwrap();
+        assert_eq!(deck.composition().unwrap().len(), 36);
+        assert!(deck.clone().all(|card| card.value >= Value::Six));
+        assert_eq!(deck.count(), 36);
+
+        let deck = CardDeckBuilder::new()
+            .strip_values(&[Value::Eight, Value::Nine, Value::Ten])
+            .build()
+            .unwrap();
+        assert_eq!(deck.count(), 40);
+    }
+
+    #[test]
+    fn shoes_with_jokers() {
+        let deck = CardDeckBuilder::new()
+            .decks(2)
+            .jokers(3)
+            .shuffled(Some([7; 32]))
+            .build()
+            .unwrap();
+        let composition = deck.composition().unwrap().clone();
+
+        let cards: Vec<Card> = deck.collect();
+        assert_eq!(cards.len(), 110);
+        assert_eq!(composition.len(), 110);
+
+        for card in [
+            Card::new(Value::Ace, Suit::Spade),
+            Card::new(Value::Joker, Suit::Heart),
+            Card::new(Value::Joker, Suit::Spade),
+        ] {
+            assert_eq!(
+                cards.iter().filter(|c| **c == card).count(),
+                composition.copies_of(card)
+            );
+        }
+        assert_eq!(
+            composition.copies_of(Card::new(Value::Joker, Suit::Heart)),
+            4
+        );
+    }
+
+    #[test]
+    fn pinochle_and_euchre() {
+        let nine = Card::new(Value::Nine, Suit::Heart);
+
+        let composition = CardDeckBuilder::pinochle().composition;
+        assert_eq!(composition.len(), 48);
+        assert_eq!(composition.copies_of(nine), 2);
+        assert_eq!(
+            composition.copies_of(Card::new(Value::Eight, Suit::Heart)),
+            0
+        );
+
+        let mut deck = CardDeckBuilder::pinochle()
+            .shuffled(Some([1; 32]))
+            .build()
+            .unwrap();
+        deck.remove_cards(&[nine, nine]).unwrap();
+        deck.return_to_top(&[nine, nine]).unwrap();
+        assert!(deck.return_to_top(&[nine]).is_err());
+
+        assert_eq!(CardDeckBuilder::euchre().build().unwrap().len(), 24);
+        assert_eq!(CardDeckBuilder::euchre_32().build().unwrap().len(), 32);
+    }
+
+    #[test]
+    fn stacked_shoes() {
+        let builder = CardDeckBuilder::new().decks(2).lowest_value(Value::King);
+        let mut order = builder.unshuffled_cards();
+        order.rotate_left(3);
+
+        let deck = builder.clone().stacked(order.clone()).build().unwrap();
+        assert_eq!(deck.collect::<Vec<_>>(), order);
+
+        let king = Card::new(Value::King, Suit::Club);
+        order[0] = king;
+        assert!(matches!(
+            builder.clone().stacked(order.clone()).build(),
+            Err(CardDeckError::CardAlreadyInDeck(card)) if card == king
+        ));
+
+        order.remove(0);
+        assert!(matches!(
+            builder.stacked(order).build(),
+            Err(CardDeckError::MissingCard(_))
+        ));
+    }
+}
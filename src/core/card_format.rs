@@ -0,0 +1,255 @@
+use super::{Card, Suit, Value};
+
+/// The style used to format a card
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum CardStyle {
+    /// A value character followed by a suit letter (e.g. "As")
+    Ascii,
+    /// A value character followed by a Unicode suit symbol (e.g. "A♠")
+    ///
+    /// This is the same format that `Display` uses.
+    #[default]
+    Symbols,
+    /// A single character from the Unicode "Playing Cards" block (e.g. "🂡")
+    Glyph,
+}
+
+/// Options for formatting a card
+///
+/// ```rust
+/// use playing_cards::core::{Card, CardFormat, CardStyle};
+///
+/// let card: Card = "Ts".parse().unwrap();
+///
+/// assert_eq!(card.format(CardFormat::default()).to_string(), "T♠");
+/// assert_eq!(card.format(CardFormat::new(CardStyle::Ascii)).to_string(), "Ts");
+/// assert_eq!(card.format(CardFormat::new(CardStyle::Glyph)).to_string(), "🂪");
+///
+/// let long_ten = CardFormat {
+///     style: CardStyle::Symbols,
+///     long_ten: true,
+/// };
+/// assert_eq!(card.format(long_ten).to_string(), "10♠");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub struct CardFormat {
+    /// The style of the card
+    pub style: CardStyle,
+    /// Whether tens are written as "10" instead of "T"
+    ///
+    /// This has no effect when formatting glyphs.
+    pub long_ten: bool,
+}
+
+impl CardFormat {
+    /// Creates formatting options for the given style
+    pub fn new(style: CardStyle) -> Self {
+        Self {
+            style,
+            long_ten: false,
+        }
+    }
+}
+
+/// A card paired with its formatting options
+///
+/// This is returned by `Card::format()` and implements `Display`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct FormattedCard {
+    card: Card,
+    options: CardFormat,
+}
+
+impl std::fmt::Display for FormattedCard {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let Card { value, suit } = self.card;
+        if self.options.style == CardStyle::Glyph {
+            return write!(f, "{}", self.card.to_glyph());
+        }
+
+        if self.options.long_ten && value == Value::Ten {
+            write!(f, "10")?;
+        } else {
+            write!(f, "{}", value.get_char())?;
+        }
+
+        match self.options.style {
+            CardStyle::Ascii => write!(f, "{}", suit.get_char()),
+            _ => write!(f, "{}", suit),
+        }
+    }
+}
+
+impl Card {
+    /// Formats the card using the given options
+    pub fn format(&self, options: CardFormat) -> FormattedCard {
+        FormattedCard {
+            card: *self,
+            options,
+        }
+    }
+
+    /// Returns the character from the Unicode "Playing Cards" block representing the card
+    ///
+    /// Red jokers are represented by U+1F0BF and black jokers by U+1F0CF.
+    pub fn to_glyph(&self) -> char {
+        if self.is_joker() {
+            return match self.suit {
+                Suit::Heart | Suit::Diamond => '\u{1F0BF}',
+                Suit::Club | Suit::Spade => '\u{1F0CF}',
+            };
+        }
+
+        let base = suit_glyph_base(self.suit);
+        // the glyphs are ordered ace first, with a knight between the jack and the queen
+        let offset = match self.value {
+            Value::Ace => 1,
+            Value::Queen | Value::King => self.value as u32 + 3,
+            _ => self.value as u32 + 2,
+        };
+
+        char::from_u32(base + offset).unwrap()
+    }
+
+    /// Parses a character from the Unicode "Playing Cards" block, returning the corresponding
+    /// card if valid
+    ///
+    /// Returns `None` for characters that do not represent a card within a standard deck (e.g.
+    /// the knights or the card backs).
+    pub fn from_glyph(c: char) -> Option<Card> {
+        match c {
+            '\u{1F0BF}' => return Some(Card::new(Value::Joker, Suit::Heart)),
+            '\u{1F0CF}' => return Some(Card::new(Value::Joker, Suit::Spade)),
+            _ => (),
+        }
+
+        let code = c as u32;
+        let suit = [Suit::Spade, Suit::Heart, Suit::Diamond, Suit::Club]
+            .into_iter()
+            .find(|&suit| (suit_glyph_base(suit)..suit_glyph_base(suit) + 0x10).contains(&code))?;
+
+        let value = match code - suit_glyph_base(suit) {
+            1 => Value::Ace,
+            offset @ 2..=11 => Value::try_from(offset as i32 - 2).ok()?,
+            offset @ 13..=14 => Value::try_from(offset as i32 - 3).ok()?,
+            _ => return None,
+        };
+
+        Some(Card::new(value, suit))
+    }
+}
+
+/// A wrapper for displaying a collection of cards
+///
+/// Each card is formatted with the given `CardFormat` (the same format as `Card`'s `Display` by
+/// default) and separated by a single space unless another separator is given.
+///
+/// ```rust
+/// use playing_cards::core::{Card, CardFormat, CardStyle, DisplayCards};
+///
+/// let cards = Card::vec_from_str("AsKd7h7c2s").unwrap();
+///
+/// assert_eq!(DisplayCards::new(&cards).to_string(), "A♠ K♦ 7♥ 7♣ 2♠");
+/// assert_eq!(
+///     DisplayCards::new(&cards)
+///         .format(CardFormat::new(CardStyle::Ascii))
+///         .to_string(),
+///     "As Kd 7h 7c 2s"
+/// );
+/// assert_eq!(
+///     DisplayCards::new(&cards[..3]).separator(", ").to_string(),
+///     "A♠, K♦, 7♥"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayCards<'a> {
+    cards: &'a [Card],
+    separator: &'a str,
+    options: CardFormat,
+}
+
+impl<'a> DisplayCards<'a> {
+    /// Wraps a collection of cards for display
+    pub fn new(cards: &'a [Card]) -> Self {
+        Self {
+            cards,
+            separator: " ",
+            options: CardFormat::default(),
+        }
+    }
+
+    /// Sets the separator that is placed between each card
+    pub fn separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets the options that each card is formatted with
+    pub fn format(mut self, options: CardFormat) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+impl std::fmt::Display for DisplayCards<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (i, card) in self.cards.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", self.separator)?;
+            }
+            write!(f, "{}", card.format(self.options))?;
+        }
+        Ok(())
+    }
+}
+
+fn suit_glyph_base(suit: Suit) -> u32 {
+    match suit {
+        Suit::Spade => 0x1F0A0,
+        Suit::Heart => 0x1F0B0,
+        Suit::Diamond => 0x1F0C0,
+        Suit::Club => 0x1F0D0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cards::*;
+
+    #[test]
+    fn glyph_round_trip() {
+        for card in ALL {
+            let glyph = card.to_glyph();
+            assert_eq!(Card::from_glyph(glyph), Some(card));
+            assert_eq!(glyph.to_string().parse::<Card>(), Ok(card));
+        }
+
+        assert_eq!(ACE_OF_SPADES.to_glyph(), '🂡');
+        assert_eq!(KING_OF_CLUBS.to_glyph(), '🃞');
+        assert_eq!(Card::from_glyph('🂬'), None); // knight of spades
+        assert_eq!(Card::from_glyph('🂠'), None); // card back
+        assert_eq!(Card::from_glyph('A'), None);
+
+        for joker in [RED_JOKER, BLACK_JOKER] {
+            assert_eq!(Card::from_glyph(joker.to_glyph()), Some(joker));
+        }
+    }
+
+    #[test]
+    fn formatted_strings_parse() {
+        for card in ALL {
+            for style in [CardStyle::Ascii, CardStyle::Symbols, CardStyle::Glyph] {
+                for long_ten in [false, true] {
+                    let formatted = card.format(CardFormat { style, long_ten }).to_string();
+                    assert_eq!(
+                        formatted.parse::<Card>(),
+                        Ok(card),
+                        "Failed on {}",
+                        formatted
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Support for the `cards!` and `hand!` macros
+//!
+//! The functions within this module are `const fn`s so that card strings can be parsed at compile
+//! time. Any invalid card string will cause the build to fail rather than panicking at runtime.
+
+use super::{Card, Suit, Value};
+
+/// Creates an array of cards from a string literal at compile time
+///
+/// Cards are made up of a value character followed by a suit character (e.g. "As", "Td", "7♥").
+/// "10" is also accepted as a value. Cards may be separated by whitespace or commas, but this is
+/// not required.
+///
+/// ```rust
+/// use playing_cards::{cards, core::Card};
+///
+/// const BOARD: [Card; 3] = cards!("As Kd 7h");
+///
+/// assert_eq!(BOARD.to_vec(), Card::vec_from_str("AsKd7h").unwrap());
+/// assert_eq!(cards!("10♠, J♠"), cards!("TsJs"));
+/// ```
+///
+/// Invalid card strings are reported as build errors:
+///
+/// ```compile_fail
+/// use playing_cards::cards;
+///
+/// let cards = cards!("As Kx");
+/// ```
+#[macro_export]
+macro_rules! cards {
+    ($cards:expr) => {{
+        const CARD_COUNT: usize = $crate::core::card_macros::count_cards($cards);
+        const CARDS: [$crate::core::Card; CARD_COUNT] =
+            $crate::core::card_macros::parse_cards($cards);
+        CARDS
+    }};
+}
+
+/// Creates a vector of cards from a string literal, parsing it at compile time
+///
+/// This accepts the same strings as `cards!` and is a replacement for
+/// `Card::vec_from_str(...).unwrap()`.
+///
+/// ```rust
+/// use playing_cards::{core::Card, hand};
+///
+/// let cards: Vec<Card> = hand!("5h 5s 2d Td Ks 5d Ac");
+///
+/// assert_eq!(cards, Card::vec_from_str("5h5s2dTdKs5dAc").unwrap());
+/// ```
+#[macro_export]
+macro_rules! hand {
+    ($cards:expr) => {
+        $crate::cards!($cards).to_vec()
+    };
+}
+
+/// Counts the number of cards within a card string
+///
+/// # Panics
+///
+/// Panics if the card string is invalid. When called within a constant, this fails the build.
+pub const fn count_cards(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut count = 0;
+    let mut i = skip_separators(bytes, 0);
+    while i < bytes.len() {
+        let (_, next) = parse_card(bytes, i);
+        count += 1;
+        i = skip_separators(bytes, next);
+    }
+
+    count
+}
+
+/// Parses a card string containing exactly `N` cards
+///
+/// # Panics
+///
+/// Panics if the card string is invalid or does not contain exactly `N` cards. When called within
+/// a constant, this fails the build.
+pub const fn parse_cards<const N: usize>(s: &str) -> [Card; N] {
+    let bytes = s.as_bytes();
+    let mut cards = [Card::new(Value::Two, Suit::Heart); N];
+    let mut count = 0;
+    let mut i = skip_separators(bytes, 0);
+    while i < bytes.len() {
+        if count == N {
+            panic!("Card string contains more cards than expected");
+        }
+        let (card, next) = parse_card(bytes, i);
+        cards[count] = card;
+        count += 1;
+        i = skip_separators(bytes, next);
+    }
+
+    if count != N {
+        panic!("Card string contains fewer cards than expected");
+    }
+
+    cards
+}
+
+const fn skip_separators(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() && (bytes[i].is_ascii_whitespace() || bytes[i] == b',') {
+        i += 1;
+    }
+
+    i
+}
+
+const fn parse_card(bytes: &[u8], i: usize) -> (Card, usize) {
+    let (value, i) = match bytes[i] {
+        b'2' => (Value::Two, i + 1),
+        b'3' => (Value::Three, i + 1),
+        b'4' => (Value::Four, i + 1),
+        b'5' => (Value::Five, i + 1),
+        b'6' => (Value::Six, i + 1),
+        b'7' => (Value::Seven, i + 1),
+        b'8' => (Value::Eight, i + 1),
+        b'9' => (Value::Nine, i + 1),
+        b'1' if i + 1 < bytes.len() && bytes[i + 1] == b'0' => (Value::Ten, i + 2),
+        b'T' | b't' => (Value::Ten, i + 1),
+        b'J' | b'j' => (Value::Jack, i + 1),
+        b'Q' | b'q' => (Value::Queen, i + 1),
+        b'K' | b'k' => (Value::King, i + 1),
+        b'A' | b'a' => (Value::Ace, i + 1),
+        b'X' | b'x' => (Value::Joker, i + 1),
+        _ => panic!("Card string contains an invalid card value"),
+    };
+
+    if i >= bytes.len() {
+        panic!("Card string is missing a card suit");
+    }
+
+    // the suit symbols are all encoded as E2 99 XX in UTF-8
+    let (suit, i) = match bytes[i] {
+        b'H' | b'h' => (Suit::Heart, i + 1),
+        b'C' | b'c' => (Suit::Club, i + 1),
+        b'D' | b'd' => (Suit::Diamond, i + 1),
+        b'S' | b's' => (Suit::Spade, i + 1),
+        0xE2 if i + 2 < bytes.len() && bytes[i + 1] == 0x99 => match bytes[i + 2] {
+            0xA5 => (Suit::Heart, i + 3),
+            0xA3 => (Suit::Club, i + 3),
+            0xA6 => (Suit::Diamond, i + 3),
+            0xA0 => (Suit::Spade, i + 3),
+            _ => panic!("Card string contains an invalid card suit"),
+        },
+        _ => panic!("Card string contains an invalid card suit"),
+    };
+
+    (Card::new(value, suit), i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cards::*;
+
+    #[test]
+    fn parse_formats() {
+        assert_eq!(cards!(""), []);
+        assert_eq!(
+            cards!(" As,kd 10h "),
+            [ACE_OF_SPADES, KING_OF_DIAMONDS, TEN_OF_HEARTS]
+        );
+        assert_eq!(cards!("2♥3♣4♦5♠"), cards!("2h3c4d5s"));
+        assert_eq!(hand!("AsKs"), vec![ACE_OF_SPADES, KING_OF_SPADES]);
+        assert_eq!(cards!("Xh Xs"), [RED_JOKER, BLACK_JOKER]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_value_at_runtime() {
+        count_cards("Zs");
+    }
+
+    #[test]
+    #[should_panic]
+    fn missing_suit_at_runtime() {
+        count_cards("AsK");
+    }
+}
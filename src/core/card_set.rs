@@ -0,0 +1,298 @@
+use std::ops::{BitAnd, BitOr, Sub};
+
+use super::Card;
+
+/// The number of bits used by a `CardSet`, one for every card including the jokers of each suit
+const CARD_BITS: u32 = 54;
+
+/// A set of cards backed by a 64-bit bitset
+///
+/// Each card is assigned its own bit, so every set operation is a handful of bitwise operations
+/// without any allocation. For the 52 cards of a standard deck, the bit of a card matches its
+/// compact `u8` encoding. Jokers are tracked by color, using the same bits as their `u8` encoding,
+/// so a set holds at most one red and one black joker.
+///
+/// Iterating over a set yields cards in ascending order of their `u8` encoding (by value and then
+/// by suit).
+///
+/// ```rust
+/// use playing_cards::core::{Card, CardSet};
+///
+/// let hand: CardSet = Card::vec_from_str("AsKs").unwrap().into();
+/// let board: CardSet = Card::vec_from_str("KsQsJs").unwrap().into();
+///
+/// assert_eq!(hand.len(), 2);
+/// assert_eq!(hand.intersection(board).len(), 1);
+/// assert_eq!(hand.union(board).len(), 4);
+///
+/// let dead_cards = hand | board;
+/// let live_cards = CardSet::full() - dead_cards;
+/// assert_eq!(live_cards.len(), 48);
+/// assert!(!live_cards.contains("As".parse().unwrap()));
+/// ```
+#[derive(Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CardSet(u64);
+
+impl CardSet {
+    /// Creates an empty set
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Creates a set containing all 52 cards of a standard deck
+    pub const fn full() -> Self {
+        Self((1 << 52) - 1)
+    }
+
+    /// Creates a set from its raw bits, returning `None` if any bit does not represent a card
+    pub const fn from_bits(bits: u64) -> Option<Self> {
+        if bits >> CARD_BITS == 0 {
+            Some(Self(bits))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the raw bits of the set
+    pub const fn bits(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the number of cards within the set
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns whether the set contains no cards
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Adds a card to the set, returning whether the card was newly inserted
+    pub fn insert(&mut self, card: Card) -> bool {
+        let bit = Self::bit(card);
+        let inserted = self.0 & bit == 0;
+        self.0 |= bit;
+        inserted
+    }
+
+    /// Removes a card from the set, returning whether the card was present
+    pub fn remove(&mut self, card: Card) -> bool {
+        let bit = Self::bit(card);
+        let removed = self.0 & bit != 0;
+        self.0 &= !bit;
+        removed
+    }
+
+    /// Returns whether the set contains the card
+    pub fn contains(&self, card: Card) -> bool {
+        self.0 & Self::bit(card) != 0
+    }
+
+    /// Returns the cards that are in either set
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns the cards that are in both sets
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Returns the cards that are in this set but not in the other
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Returns whether every card within this set is also in the other
+    pub const fn is_subset(&self, other: &Self) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// Returns whether the sets have no cards in common
+    pub const fn is_disjoint(&self, other: &Self) -> bool {
+        self.0 & other.0 == 0
+    }
+
+    /// Returns an iterator over the cards within the set
+    pub fn iter(&self) -> CardSetIter {
+        CardSetIter(self.0)
+    }
+
+    fn bit(card: Card) -> u64 {
+        1 << u8::from(card)
+    }
+}
+
+/// An iterator over the cards within a `CardSet`
+///
+/// This is created by `CardSet::iter()`.
+#[derive(Debug, Clone)]
+pub struct CardSetIter(u64);
+
+impl Iterator for CardSetIter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let index = self.0.trailing_zeros();
+        self.0 &= self.0 - 1;
+
+        Card::try_from(index as u8).ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for CardSetIter {}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = CardSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &CardSet {
+    type Item = Card;
+    type IntoIter = CardSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a> FromIterator<&'a Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = &'a Card>>(iter: I) -> Self {
+        iter.into_iter().copied().collect()
+    }
+}
+
+impl Extend<Card> for CardSet {
+    fn extend<I: IntoIterator<Item = Card>>(&mut self, iter: I) {
+        for card in iter {
+            self.insert(card);
+        }
+    }
+}
+
+impl From<&[Card]> for CardSet {
+    fn from(cards: &[Card]) -> Self {
+        cards.iter().collect()
+    }
+}
+
+impl From<Vec<Card>> for CardSet {
+    fn from(cards: Vec<Card>) -> Self {
+        cards.iter().collect()
+    }
+}
+
+impl From<CardSet> for Vec<Card> {
+    fn from(set: CardSet) -> Self {
+        set.iter().collect()
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl Sub for CardSet {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl std::fmt::Debug for CardSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{cards::*, Suit, Value};
+
+    #[test]
+    fn insert_remove_contains() {
+        let mut set = CardSet::new();
+
+        assert!(set.insert(ACE_OF_SPADES));
+        assert!(!set.insert(ACE_OF_SPADES));
+        assert!(set.insert(RED_JOKER));
+        assert!(!set.insert(Card {
+            value: Value::Joker,
+            suit: Suit::Diamond
+        }));
+        assert!(set.insert(BLACK_JOKER));
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(ACE_OF_SPADES));
+        assert!(!set.contains(KING_OF_SPADES));
+
+        assert!(set.remove(ACE_OF_SPADES));
+        assert!(!set.remove(ACE_OF_SPADES));
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [RED_JOKER, BLACK_JOKER]);
+        assert_eq!(CardSet::from_bits(set.bits()), Some(set));
+        assert_eq!(CardSet::from_bits(1 << 60), None);
+    }
+
+    #[test]
+    fn iterates_in_encoding_order() {
+        let full = CardSet::full();
+
+        assert_eq!(full.iter().len(), 52);
+        assert_eq!(full.iter().collect::<Vec<_>>(), ALL.to_vec());
+
+        let cards = vec![KING_OF_CLUBS, TWO_OF_HEARTS, KING_OF_CLUBS];
+        let set = CardSet::from(cards);
+        assert_eq!(Vec::from(set), vec![TWO_OF_HEARTS, KING_OF_CLUBS]);
+        assert_eq!(
+            format!("{:?}", set),
+            "{Card { value: Two, suit: Heart }, Card { value: King, suit: Club }}"
+        );
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: CardSet = [ACE_OF_SPADES, KING_OF_SPADES].into_iter().collect();
+        let b: CardSet = [KING_OF_SPADES, QUEEN_OF_SPADES].into_iter().collect();
+
+        assert_eq!(a | b, a.union(b));
+        assert_eq!((a & b).iter().collect::<Vec<_>>(), vec![KING_OF_SPADES]);
+        assert_eq!((a - b).iter().collect::<Vec<_>>(), vec![ACE_OF_SPADES]);
+        assert!((a & b).is_subset(&a));
+        assert!((a - b).is_disjoint(&b));
+        assert!(CardSet::new().is_empty());
+    }
+}
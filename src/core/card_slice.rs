@@ -0,0 +1,80 @@
+use std::cmp::Reverse;
+
+use super::{AceOrdering, Card};
+
+/// Sorts cards from the highest value to the lowest, with the ace placed according to `ordering`
+///
+/// The sort is stable, so cards of the same value keep their relative order.
+///
+/// ```rust
+/// use playing_cards::core::{sort_by_value, AceOrdering, Card};
+///
+/// let mut cards = Card::vec_from_str("5dAs7hKc").unwrap();
+///
+/// sort_by_value(&mut cards, AceOrdering::High);
+/// assert_eq!(cards, Card::vec_from_str("AsKc7h5d").unwrap());
+///
+/// sort_by_value(&mut cards, AceOrdering::Low);
+/// assert_eq!(cards, Card::vec_from_str("Kc7h5dAs").unwrap());
+/// ```
+pub fn sort_by_value(cards: &mut [Card], ordering: AceOrdering) {
+    cards.sort_by_key(|card| Reverse(ordering.index(card.value)));
+}
+
+/// Sorts cards by suit, and then from the highest value to the lowest within each suit
+///
+/// Suits are ordered by their numerical representation, and the ace is placed according to
+/// `ordering`.
+///
+/// ```rust
+/// use playing_cards::core::{sort_by_suit, AceOrdering, Card};
+///
+/// let mut cards = Card::vec_from_str("5sAh7sKh").unwrap();
+/// sort_by_suit(&mut cards, AceOrdering::High);
+///
+/// assert_eq!(cards, Card::vec_from_str("AhKh7s5s").unwrap());
+/// ```
+pub fn sort_by_suit(cards: &mut [Card], ordering: AceOrdering) {
+    cards.sort_by_key(|card| (card.suit as u8, Reverse(ordering.index(card.value))));
+}
+
+/// Counts how many times each value appears within the cards
+///
+/// The counts are indexed by the numerical representation of `Value`, so jokers are counted at
+/// the last index.
+///
+/// ```rust
+/// use playing_cards::core::{value_histogram, Card, Value};
+///
+/// let counts = value_histogram(&Card::vec_from_str("5h5s2dTd5d").unwrap());
+///
+/// assert_eq!(counts[Value::Five as usize], 3);
+/// assert_eq!(counts[Value::Ace as usize], 0);
+/// ```
+pub fn value_histogram(cards: &[Card]) -> [usize; 14] {
+    let mut counts = [0; 14];
+    for card in cards {
+        counts[card.value as usize] += 1;
+    }
+    counts
+}
+
+/// Counts how many times each suit appears within the cards
+///
+/// The counts are indexed by the numerical representation of `Suit`. Jokers are not counted.
+///
+/// ```rust
+/// use playing_cards::core::{suit_histogram, Card, Suit};
+///
+/// let counts = suit_histogram(&Card::vec_from_str("5h5s2dTd5d").unwrap());
+///
+/// assert_eq!(counts[Suit::Diamond as usize], 3);
+/// assert_eq!(counts.iter().max(), Some(&3));
+/// ```
+pub fn suit_histogram(cards: &[Card]) -> [usize; 4] {
+    let mut counts = [0; 4];
+    for card in cards.iter().filter(|card| !card.is_joker()) {
+        counts[card.suit as usize] += 1;
+    }
+    counts
+}
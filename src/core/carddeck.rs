@@ -1,16 +1,26 @@
 use getrandom;
+use num_traits::FromPrimitive;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use std::iter::Rev;
 use thiserror::Error;
 
 extern crate rand;
 
 use rand::seq::SliceRandom;
-use rand_core::SeedableRng;
-use rand_xoshiro::Xoshiro256PlusPlus;
+use rand::Rng;
+use rand_core::{RngCore, SeedableRng};
+use rand_xoshiro::{Xoshiro256PlusPlus, Xoshiro256StarStar};
+#[cfg(feature = "seed-phrase")]
+use sha2::{Digest, Sha256};
 
 use strum::IntoEnumIterator;
 
-use super::{Card, Suit, Value};
+use super::{
+    suit_histogram, value_histogram, Card, CardDeckBuilder, CardSet, DeckComposition, DeckDiff,
+    DeckEvent, DisplayCards, HoldemDeal, PhysicalShuffle, Suit, Value,
+};
 
 /// Error type for `CardDeck`
 #[non_exhaustive]
@@ -22,13 +32,164 @@ pub enum CardDeckError {
     /// deck.
     #[error("Error occured when trying to sample entropy: {0}")]
     EntropyError(#[from] getrandom::Error),
+    /// A card that was expected to be within the deck could not be found
+    #[error("Card {0} is not in the deck")]
+    CardNotInDeck(Card),
+    /// The deck was cut at a position past the number of cards remaining
+    #[error("Cannot cut the deck at position {0}")]
+    InvalidCutPosition(usize),
+    /// A card being returned to the deck is already present within the deck
+    #[error("Card {0} is already in the deck")]
+    CardAlreadyInDeck(Card),
+    /// A card that the deck should contain is missing
+    #[error("Card {0} is missing from the deck")]
+    MissingCard(Card),
+    /// A card that was expected to be within the muck could not be found
+    #[error("Card {0} is not in the muck")]
+    CardNotInMuck(Card),
+}
+
+/// What happens when a deck does not have enough cards remaining for a deal
+///
+/// The policy is configured on each `CardDeck` with `set_exhaustion_policy()` and is used by
+/// `deal_cards()` and `draw_cards()`.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DeckExhaustionPolicy {
+    /// No cards are dealt and `None` is returned
+    #[default]
+    Fail,
+    /// The muck is reshuffled underneath the remaining cards before dealing
+    ///
+    /// The seed of the reshuffle is derived from the most recent seed in `seed_history()`, so a
+    /// seeded deck deals the same cards every time, and the derived seed is recorded like any
+    /// other. If the history has no seeds, one is sampled from entropy instead. If there are not
+    /// enough cards even with the muck, no cards are dealt and `None` is returned.
+    ReshuffleMuck,
+    /// Every remaining card is dealt, even if there are fewer cards than requested
+    DealShort,
+}
+
+/// A seedable pseudorandom generator that a `CardDeck` can be shuffled with
+///
+/// This is recorded alongside the seed of every shuffle in `CardDeck::seed_history()`, so that
+/// the shuffle can be reproduced with `CardDeck::shuffle_with_generator()`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ShuffleGenerator {
+    /// Xoshiro256PlusPlus, which is used by `CardDeck::shuffle()`
+    #[default]
+    Xoshiro256PlusPlus,
+    /// Xoshiro256StarStar
+    Xoshiro256StarStar,
+    /// The cryptographically secure ChaCha20, which requires the `chacha` feature
+    #[cfg(feature = "chacha")]
+    ChaCha20,
+}
+
+impl ShuffleGenerator {
+    fn rng(self, seed: [u8; 32]) -> Box<dyn RngCore> {
+        match self {
+            ShuffleGenerator::Xoshiro256PlusPlus => {
+                Box::new(Xoshiro256PlusPlus::from_seed(CardDeck::expand_seed(seed)))
+            }
+            ShuffleGenerator::Xoshiro256StarStar => {
+                Box::new(Xoshiro256StarStar::from_seed(CardDeck::expand_seed(seed)))
+            }
+            #[cfg(feature = "chacha")]
+            ShuffleGenerator::ChaCha20 => Box::new(rand_chacha::ChaCha20Rng::from_seed(seed)),
+        }
+    }
+
+    /// Derives a new seed from `seed`, using output that does not overlap with what a shuffle
+    /// seeded with `seed` would consume
+    fn derive_seed(self, seed: [u8; 32]) -> [u8; 32] {
+        let mut derived = [0; 32];
+        match self {
+            ShuffleGenerator::Xoshiro256PlusPlus => {
+                let mut rng = Xoshiro256PlusPlus::from_seed(CardDeck::expand_seed(seed));
+                rng.long_jump();
+                rng.fill_bytes(&mut derived);
+            }
+            ShuffleGenerator::Xoshiro256StarStar => {
+                let mut rng = Xoshiro256StarStar::from_seed(CardDeck::expand_seed(seed));
+                rng.long_jump();
+                rng.fill_bytes(&mut derived);
+            }
+            #[cfg(feature = "chacha")]
+            ShuffleGenerator::ChaCha20 => {
+                let mut rng = rand_chacha::ChaCha20Rng::from_seed(seed);
+                rng.set_stream(1);
+                rng.fill_bytes(&mut derived);
+            }
+        }
+        derived
+    }
+}
+
+/// A pseudorandom generator that can be used with `CardDeck::shuffle_with_seed()`
+///
+/// Each implementation names the `ShuffleGenerator` that is recorded within
+/// `CardDeck::seed_history()` when it is used.
+pub trait ShuffleRng: RngCore + SeedableRng<Seed = [u8; 32]> {
+    /// The generator as recorded within the seed history
+    const GENERATOR: ShuffleGenerator;
+}
+
+impl ShuffleRng for Xoshiro256PlusPlus {
+    const GENERATOR: ShuffleGenerator = ShuffleGenerator::Xoshiro256PlusPlus;
+}
+
+impl ShuffleRng for Xoshiro256StarStar {
+    const GENERATOR: ShuffleGenerator = ShuffleGenerator::Xoshiro256StarStar;
+}
+
+#[cfg(feature = "chacha")]
+impl ShuffleRng for rand_chacha::ChaCha20Rng {
+    const GENERATOR: ShuffleGenerator = ShuffleGenerator::ChaCha20;
+}
+
+/// A use of randomness on a `CardDeck`, as recorded in `CardDeck::seed_history()`
+///
+/// The seed is `None` if a generator provided by the caller was used, in which case the use
+/// cannot be reproduced from the history alone. Unless a `ShuffleGenerator` is recorded, the seed
+/// was used with Xoshiro256PlusPlus.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SeedUse {
+    /// The deck was shuffled uniformly
+    Shuffle {
+        /// The seed that the deck was shuffled with
+        seed: Option<[u8; 32]>,
+        /// The generator that the deck was shuffled with, or `None` if it was provided by the
+        /// caller
+        generator: Option<ShuffleGenerator>,
+    },
+    /// The deck was shuffled with a physical shuffle
+    PhysicalShuffle {
+        /// The seed that the deck was shuffled with
+        seed: Option<[u8; 32]>,
+        /// The kind of shuffle
+        mode: PhysicalShuffle,
+        /// The number of passes of the shuffle
+        passes: usize,
+    },
+    /// The deck was cut at a random position
+    Cut([u8; 32]),
+    /// The muck was shuffled before being placed underneath the deck
+    MuckReshuffle(Option<[u8; 32]>),
+    /// The muck was inserted into the deck at random positions
+    MuckReinsert(Option<[u8; 32]>),
 }
 
 /// A deck of cards
 ///
-/// This deck will contain 52 distinct cards upon initialization. To ensure uniform randomness,
-/// the Xoshiro256PlusPlus pseudorandom generator is used when the deck is shuffled and every time
-/// when the muck is reshuffled back in.
+/// This deck will contain 52 distinct cards upon initialization. Other decks, such as shoes and
+/// stripped decks, can be created with `CardDeckBuilder`. To ensure uniform randomness,
+/// the Xoshiro256PlusPlus pseudorandom generator is used by default when the deck is shuffled and
+/// every time when the muck is reshuffled back in. Other generators can be used with
+/// `shuffle_with_seed()` and `shuffle_with_rng()`.
 ///
 /// Example
 /// ```rust
@@ -41,11 +202,120 @@ pub enum CardDeckError {
 ///
 /// println!("{:?}", hand.unwrap()); // Two random cards from the deck
 /// ```
-#[derive(Debug, Clone)]
+///
+/// With the `serde` feature enabled, the complete state of the deck can be serialized. This
+/// includes the remaining cards in dealing order, the muck, the seed of the last shuffle, and the
+/// composition. Since the pseudorandom generator is reseeded every time that cards are shuffled,
+/// no other random state is kept between shuffles, so a deserialized deck deals exactly the same
+/// cards as the original. This allows a hand to be checkpointed and resumed later.
+///
+/// ```rust
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use playing_cards::core::CardDeck;
+///
+/// let mut deck = CardDeck::new(Some([3; 32])).unwrap();
+/// deck.deal_cards(4, false);
+///
+/// let checkpoint = serde_json::to_string(&deck).unwrap();
+/// let mut resumed: CardDeck = serde_json::from_str(&checkpoint).unwrap();
+///
+/// assert_eq!(resumed.deal_cards(5, false), deck.deal_cards(5, false));
+/// # }
+/// ```
+///
+/// Both `Display` and `Debug` summarize the deck, including how many of each value and suit
+/// remain, to make logs and test failures easier to read. `Debug` also lists the remaining cards
+/// in the order that they will be dealt.
+///
+/// ```rust
+/// use playing_cards::core::{Card, CardDeck};
+///
+/// let mut deck = CardDeck::new_custom_deck(Card::vec_from_str("2c5hAsAd").unwrap(), None).unwrap();
+/// deck.burn();
+///
+/// assert_eq!(
+///     deck.to_string(),
+///     "3 remaining [A:1 5:1 2:1] [♥1 ♣1 ♠1], 1 mucked, unshuffled"
+/// );
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CardDeck {
     deck: Vec<Card>,
     seed: Option<[u8; 32]>,
     muck: Vec<Card>,
+    burned: Vec<Card>,
+    pub(super) composition: Option<DeckComposition>,
+    events: Option<Vec<DeckEvent>>,
+    exhaustion_policy: DeckExhaustionPolicy,
+    seed_history: Vec<SeedUse>,
+}
+
+impl CardDeck {
+    fn write_summary(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let values = value_histogram(&self.deck);
+        let values: Vec<String> = (0..values.len())
+            .rev()
+            .filter(|i| values[*i] > 0)
+            .map(|i| {
+                let value = Value::from_usize(i).expect("histogram index should be a value");
+                format!("{}:{}", value, values[i])
+            })
+            .collect();
+
+        let suits = suit_histogram(&self.deck);
+        let suits: Vec<String> = Suit::iter()
+            .filter(|suit| suits[*suit as usize] > 0)
+            .map(|suit| format!("{}{}", suit, suits[suit as usize]))
+            .collect();
+
+        write!(
+            f,
+            "{} remaining [{}] [{}], {} mucked",
+            self.deck.len(),
+            values.join(" "),
+            suits.join(" "),
+            self.muck.len()
+        )
+    }
+
+    fn seed_hex(&self) -> Option<String> {
+        self.seed
+            .map(|seed| seed.iter().map(|b| format!("{:02x}", b)).collect())
+    }
+}
+
+impl std::fmt::Display for CardDeck {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.write_summary(f)?;
+        match self.seed_hex() {
+            Some(seed) => write!(f, ", seed {}", seed),
+            None => write!(f, ", unshuffled"),
+        }
+    }
+}
+
+impl std::fmt::Debug for CardDeck {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        struct Summary<'a>(&'a CardDeck);
+
+        impl std::fmt::Debug for Summary<'_> {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                self.0.write_summary(f)
+            }
+        }
+
+        let cards: Vec<Card> = self.iter().copied().collect();
+        f.debug_struct("CardDeck")
+            .field("summary", &Summary(self))
+            .field("seed", &self.seed_hex())
+            .field("cards", &DisplayCards::new(&cards).to_string())
+            .field("muck", &DisplayCards::new(&self.muck).to_string())
+            .field("composition", &self.composition)
+            .field("exhaustion_policy", &self.exhaustion_policy)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for CardDeck {
@@ -122,6 +392,37 @@ impl CardDeck {
         Ok(deck)
     }
 
+    /// Creates a new shuffled CardDeck from a passphrase
+    ///
+    /// The seed is derived from the passphrase with `seed_from_phrase()`, so the same passphrase
+    /// always produces the same deck. This allows reproducible decks to be shared between people
+    /// as a short string instead of a 32-byte array. Passphrases that can be guessed produce
+    /// decks that can be predicted, so this should not be used when the order of the cards must
+    /// be kept secret. This requires the `seed-phrase` feature.
+    ///
+    /// ```rust
+    /// use playing_cards::core::CardDeck;
+    ///
+    /// let mut d1 = CardDeck::new_with_seed_phrase("quick brown fox").unwrap();
+    /// let mut d2 = CardDeck::new_with_seed_phrase("quick brown fox").unwrap();
+    ///
+    /// assert_eq!(d1.deal_cards(5, false), d2.deal_cards(5, false));
+    /// ```
+    #[cfg(feature = "seed-phrase")]
+    pub fn new_with_seed_phrase(phrase: &str) -> Result<Self, CardDeckError> {
+        Self::new(Some(Self::seed_from_phrase(phrase)))
+    }
+
+    /// Derives a 256-bit seed from a passphrase
+    ///
+    /// The seed is the SHA-256 digest of the UTF-8 bytes of the passphrase, with no salt or
+    /// normalization applied. The seed can be used with any method that accepts a seed. This
+    /// requires the `seed-phrase` feature.
+    #[cfg(feature = "seed-phrase")]
+    pub fn seed_from_phrase(phrase: &str) -> [u8; 32] {
+        Sha256::digest(phrase.as_bytes()).into()
+    }
+
     /// Creates a new CardDeck with provided `cards`
     ///
     /// Useful if a standard 52-card deck does not fulfill your needs.
@@ -136,6 +437,11 @@ impl CardDeck {
             deck: cards,
             seed,
             muck: Vec::new(),
+            burned: Vec::new(),
+            composition: None,
+            events: None,
+            exhaustion_policy: DeckExhaustionPolicy::default(),
+            seed_history: Vec::new(),
         };
 
         if seed.is_some() {
@@ -145,6 +451,28 @@ impl CardDeck {
         Ok(deck)
     }
 
+    /// Creates a standard 52-card deck that deals the cards in exactly the given order
+    ///
+    /// The first card given will be the first card dealt. This is useful for scripting exact
+    /// deals in tests and tutorials. The cards must form a complete deck with no duplicates,
+    /// otherwise a `MissingCard` or `CardAlreadyInDeck` error is returned. Other decks can be
+    /// stacked with `CardDeckBuilder::stacked()`.
+    ///
+    /// ```rust
+    /// use playing_cards::core::{cards, CardDeck};
+    ///
+    /// let mut order = cards::ALL.to_vec();
+    /// order.swap(0, 51);
+    ///
+    /// let mut deck = CardDeck::from_cards(order.clone()).unwrap();
+    /// assert_eq!(deck.deal_cards(2, false), Some(order[..2].to_vec()));
+    ///
+    /// assert!(CardDeck::from_cards(order[..51].to_vec()).is_err());
+    /// ```
+    pub fn from_cards(cards: Vec<Card>) -> Result<Self, CardDeckError> {
+        CardDeckBuilder::new().stacked(cards).build()
+    }
+
     fn create_unshuffled_deck() -> Self {
         let mut d: Vec<Card> = Vec::with_capacity(52);
 
@@ -158,34 +486,256 @@ impl CardDeck {
             deck: d,
             seed: None,
             muck: Vec::new(),
+            burned: Vec::new(),
+            composition: Some(DeckComposition::default()),
+            events: None,
+            exhaustion_policy: DeckExhaustionPolicy::default(),
+            seed_history: Vec::new(),
         }
     }
 
     /// Shuffles the deck
     ///
     /// An optional seed can be provided if the deck should be shuffled with a specific seed. If no
-    /// seed is provided, then system entropy is sampled for a random seed.
+    /// seed is provided, then system entropy is sampled for a random seed. Seeds are mixed before
+    /// they are used, so structured seeds such as `[1; 32]` still give uniform shuffles.
     pub fn shuffle(&mut self, seed: Option<[u8; 32]>) -> Result<(), CardDeckError> {
-        self.seed = Some(Self::shuffle_cards(&mut self.deck, seed)?);
+        self.shuffle_with_seed::<Xoshiro256PlusPlus>(seed)
+    }
+
+    /// Shuffles the deck with a specific seedable pseudorandom generator
+    ///
+    /// This behaves like `shuffle()`, but `R` is used instead of Xoshiro256PlusPlus. With the
+    /// `chacha` feature enabled, the cryptographically secure `ChaCha20Rng` can be used, which
+    /// should be preferred whenever the order of the cards must not be predictable from previous
+    /// outputs, such as for real-money games.
+    ///
+    /// ```rust
+    /// use playing_cards::core::CardDeck;
+    /// use rand_xoshiro::Xoshiro256StarStar;
+    ///
+    /// let mut deck: CardDeck = Default::default();
+    /// deck.shuffle_with_seed::<Xoshiro256StarStar>(Some([7; 32])).unwrap();
+    /// ```
+    pub fn shuffle_with_seed<R: ShuffleRng>(
+        &mut self,
+        seed: Option<[u8; 32]>,
+    ) -> Result<(), CardDeckError> {
+        self.shuffle_with_generator(R::GENERATOR, seed)
+    }
+
+    /// Shuffles the deck with the given generator
+    ///
+    /// This behaves like `shuffle_with_seed()`, but the generator is chosen at runtime, which
+    /// allows a shuffle to be replayed from `seed_history()`.
+    ///
+    /// ```rust
+    /// use playing_cards::core::{CardDeck, SeedUse, ShuffleGenerator};
+    ///
+    /// let mut deck: CardDeck = Default::default();
+    /// deck.shuffle_with_generator(ShuffleGenerator::Xoshiro256StarStar, None)
+    ///     .unwrap();
+    ///
+    /// let mut replay: CardDeck = Default::default();
+    /// if let SeedUse::Shuffle {
+    ///     seed,
+    ///     generator: Some(generator),
+    /// } = deck.seed_history()[0]
+    /// {
+    ///     replay.shuffle_with_generator(generator, seed).unwrap();
+    /// }
+    ///
+    /// assert_eq!(replay.deal_cards(52, false), deck.deal_cards(52, false));
+    /// ```
+    pub fn shuffle_with_generator(
+        &mut self,
+        generator: ShuffleGenerator,
+        seed: Option<[u8; 32]>,
+    ) -> Result<(), CardDeckError> {
+        let seed_used = Self::seed_or_entropy(seed)?;
+        self.deck.shuffle(&mut *generator.rng(seed_used));
+        self.seed = Some(seed_used);
+        self.seed_history.push(SeedUse::Shuffle {
+            seed: Some(seed_used),
+            generator: Some(generator),
+        });
+        self.record(|| DeckEvent::Shuffled {
+            seed: Some(seed_used),
+        });
         Ok(())
     }
 
-    fn shuffle_cards(
-        cards: &mut Vec<Card>,
+    /// Shuffles the deck with the provided random number generator
+    ///
+    /// Any generator can be used, including a `Box<dyn RngCore>` or an already seeded generator
+    /// that is shared between multiple decks. Since the seed of `rng` is not known, the seed of
+    /// the deck is cleared.
+    ///
+    /// ```rust
+    /// use playing_cards::core::CardDeck;
+    /// use rand_core::{RngCore, SeedableRng};
+    /// use rand_xoshiro::Xoshiro256PlusPlus;
+    ///
+    /// let mut rng: Box<dyn RngCore> = Box::new(Xoshiro256PlusPlus::seed_from_u64(42));
+    ///
+    /// let mut deck: CardDeck = Default::default();
+    /// deck.shuffle_with_rng(&mut rng);
+    /// ```
+    pub fn shuffle_with_rng<R: RngCore + ?Sized>(&mut self, rng: &mut R) {
+        self.deck.shuffle(rng);
+        self.seed = None;
+        self.seed_history.push(SeedUse::Shuffle {
+            seed: None,
+            generator: None,
+        });
+        self.record(|| DeckEvent::Shuffled { seed: None });
+    }
+
+    /// Shuffles the deck with `passes` passes of a physical shuffle
+    ///
+    /// See `PhysicalShuffle` for the models of each shuffle. Like `shuffle()`, an optional seed
+    /// can be provided. If no seed is provided, a seed will be sampled from entropy.
+    pub fn physical_shuffle(
+        &mut self,
+        shuffle: PhysicalShuffle,
+        passes: usize,
         seed: Option<[u8; 32]>,
-    ) -> Result<[u8; 32], CardDeckError> {
-        let mut rng;
-        let mut seed_used;
+    ) -> Result<(), CardDeckError> {
+        let seed_used = Self::seed_or_entropy(seed)?;
+        self.apply_physical_shuffle(
+            shuffle,
+            passes,
+            &mut Xoshiro256PlusPlus::from_seed(Self::expand_seed(seed_used)),
+        );
+        self.seed = Some(seed_used);
+        self.seed_history.push(SeedUse::PhysicalShuffle {
+            seed: Some(seed_used),
+            mode: shuffle,
+            passes,
+        });
+        self.record(|| DeckEvent::PhysicallyShuffled {
+            shuffle,
+            passes,
+            seed: Some(seed_used),
+        });
+
+        Ok(())
+    }
+
+    /// Shuffles the deck with `passes` passes of a physical shuffle using the provided random
+    /// number generator
+    ///
+    /// Since the seed of `rng` is not known, the seed of the deck is cleared.
+    pub fn physical_shuffle_with_rng<R: RngCore + ?Sized>(
+        &mut self,
+        shuffle: PhysicalShuffle,
+        passes: usize,
+        rng: &mut R,
+    ) {
+        self.apply_physical_shuffle(shuffle, passes, rng);
+        self.seed = None;
+        self.seed_history.push(SeedUse::PhysicalShuffle {
+            seed: None,
+            mode: shuffle,
+            passes,
+        });
+        self.record(|| DeckEvent::PhysicallyShuffled {
+            shuffle,
+            passes,
+            seed: None,
+        });
+    }
+
+    fn apply_physical_shuffle<R: RngCore + ?Sized>(
+        &mut self,
+        shuffle: PhysicalShuffle,
+        passes: usize,
+        rng: &mut R,
+    ) {
+        for _ in 0..passes {
+            shuffle.apply(&mut self.deck, rng);
+        }
+    }
+
+    /// Mixes every 64-bit word of a seed with SplitMix64 before it is used to seed a Xoshiro
+    /// generator
+    ///
+    /// Xoshiro generators produce strongly correlated output when their state is sparse, so
+    /// structured seeds such as `[1; 32]` or a counter in the first bytes would otherwise lead to
+    /// biased shuffles. The mix is a bijection, so distinct seeds still give distinct states.
+    pub(crate) fn expand_seed(seed: [u8; 32]) -> [u8; 32] {
+        let mut expanded = [0; 32];
+        for (i, (word, out)) in seed
+            .chunks_exact(8)
+            .zip(expanded.chunks_exact_mut(8))
+            .enumerate()
+        {
+            let word = u64::from_le_bytes(word.try_into().unwrap());
+            let mut z = word.wrapping_add((i as u64 + 1).wrapping_mul(0x9e37_79b9_7f4a_7c15));
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            out.copy_from_slice(&(z ^ (z >> 31)).to_le_bytes());
+        }
+        expanded
+    }
+
+    pub(crate) fn seed_or_entropy(seed: Option<[u8; 32]>) -> Result<[u8; 32], CardDeckError> {
         match seed {
-            Some(seed) => seed_used = seed,
+            Some(seed) => Ok(seed),
             None => {
-                seed_used = [0u8; 32];
-                getrandom::getrandom(&mut seed_used)?;
+                let mut seed = [0u8; 32];
+                getrandom::getrandom(&mut seed)?;
+                Ok(seed)
             }
         }
-        rng = Xoshiro256PlusPlus::from_seed(seed_used);
-        cards.shuffle(&mut rng);
-        Ok(seed_used)
+    }
+
+    /// Cuts the deck, moving the top `position` cards underneath the rest of the deck
+    ///
+    /// The card that was `position` cards from the top becomes the top card. Cutting at 0 or at
+    /// the number of remaining cards leaves the deck unchanged. An `InvalidCutPosition` error is
+    /// returned if `position` is greater than the number of remaining cards.
+    ///
+    /// ```rust
+    /// use playing_cards::core::CardDeck;
+    ///
+    /// let mut deck = CardDeck::new(Some([1; 32])).unwrap();
+    /// let before: Vec<_> = deck.peek(52).into_iter().copied().collect();
+    ///
+    /// deck.cut(20).unwrap();
+    /// let after: Vec<_> = deck.peek(52).into_iter().copied().collect();
+    ///
+    /// assert_eq!(after[..32], before[20..]);
+    /// assert_eq!(after[32..], before[..20]);
+    /// ```
+    pub fn cut(&mut self, position: usize) -> Result<(), CardDeckError> {
+        if position > self.deck.len() {
+            return Err(CardDeckError::InvalidCutPosition(position));
+        }
+
+        // the top of the deck is at the end of the vector
+        self.deck.rotate_right(position);
+        self.record(|| DeckEvent::Cut(position));
+        Ok(())
+    }
+
+    /// Cuts the deck at a random position, returning the position that was used
+    ///
+    /// The position is never at either end of the deck, so at least one card is moved whenever
+    /// the deck has two or more cards. Like `shuffle()`, an optional seed can be provided to make
+    /// the cut deterministic. If no seed is provided, a seed will be sampled from entropy.
+    pub fn cut_random(&mut self, seed: Option<[u8; 32]>) -> Result<usize, CardDeckError> {
+        if self.deck.len() < 2 {
+            return Ok(0);
+        }
+
+        let seed_used = Self::seed_or_entropy(seed)?;
+        let mut rng = Xoshiro256PlusPlus::from_seed(Self::expand_seed(seed_used));
+        let position = rng.gen_range(1..self.deck.len());
+        self.cut(position)?;
+        self.seed_history.push(SeedUse::Cut(seed_used));
+
+        Ok(position)
     }
 
     /// Gets the Xoshiro256PlusPlus seed of the CardDeck
@@ -197,6 +747,268 @@ impl CardDeck {
         self.seed
     }
 
+    /// Gets every use of randomness on the deck, in the order that they occurred
+    ///
+    /// Unlike `get_seed()`, which only remembers the most recent shuffle, this contains the seeds
+    /// of every shuffle, random cut, and reshuffle or reinsertion of the muck. Together with the
+    /// initial order of the deck and the actions taken, this allows a completed hand to be
+    /// reproduced and audited.
+    ///
+    /// ```rust
+    /// use playing_cards::core::{CardDeck, SeedUse, ShuffleGenerator};
+    ///
+    /// let mut deck = CardDeck::new(Some([1; 32])).unwrap();
+    /// let folded = deck.deal_cards(2, false).unwrap();
+    /// deck.muck_cards(folded);
+    /// deck.reshuffle_muck(Some([2; 32])).unwrap();
+    ///
+    /// assert_eq!(
+    ///     deck.seed_history(),
+    ///     [
+    ///         SeedUse::Shuffle {
+    ///             seed: Some([1; 32]),
+    ///             generator: Some(ShuffleGenerator::Xoshiro256PlusPlus),
+    ///         },
+    ///         SeedUse::MuckReshuffle(Some([2; 32])),
+    ///     ]
+    /// );
+    /// ```
+    pub fn seed_history(&self) -> &[SeedUse] {
+        &self.seed_history
+    }
+
+    /// Gets the policy used when there are not enough cards remaining for a deal
+    pub fn exhaustion_policy(&self) -> DeckExhaustionPolicy {
+        self.exhaustion_policy
+    }
+
+    /// Sets the policy used when there are not enough cards remaining for a deal
+    pub fn set_exhaustion_policy(&mut self, policy: DeckExhaustionPolicy) {
+        self.exhaustion_policy = policy;
+    }
+
+    /// Starts recording every action performed on the deck
+    ///
+    /// Shuffles, cuts, deals, burns, and changes to the muck are recorded in order as a
+    /// `DeckEvent`. Only actions performed after the log is enabled are recorded. Enabling the
+    /// log when it is already enabled has no effect.
+    pub fn enable_event_log(&mut self) {
+        self.events.get_or_insert_with(Vec::new);
+    }
+
+    /// Gets the events recorded so far, or `None` if the event log is not enabled
+    pub fn events(&self) -> Option<&[DeckEvent]> {
+        self.events.as_deref()
+    }
+
+    /// Takes the events recorded so far, leaving the event log enabled but empty
+    ///
+    /// This is useful for producing a separate log for each hand. Returns an empty vector if the
+    /// event log is not enabled.
+    pub fn take_events(&mut self) -> Vec<DeckEvent> {
+        self.events.as_mut().map(std::mem::take).unwrap_or_default()
+    }
+
+    fn record(&mut self, event: impl FnOnce() -> DeckEvent) {
+        if let Some(events) = self.events.as_mut() {
+            events.push(event());
+        }
+    }
+
+    /// Returns the number of cards remaining in the deck, excluding the muck
+    pub fn len(&self) -> usize {
+        self.deck.len()
+    }
+
+    /// Returns whether there are no cards remaining in the deck, excluding the muck
+    pub fn is_empty(&self) -> bool {
+        self.deck.is_empty()
+    }
+
+    /// Returns the number of cards that are available to be dealt
+    ///
+    /// If `include_muck` is set, the cards in the muck are also counted, since they can be
+    /// reshuffled back into the deck.
+    ///
+    /// ```rust
+    /// use playing_cards::core::CardDeck;
+    ///
+    /// let mut deck: CardDeck = Default::default();
+    /// let hand = deck.deal_cards(5, false).unwrap();
+    /// deck.muck_cards(hand);
+    ///
+    /// assert_eq!(deck.len(), 47);
+    /// assert_eq!(deck.remaining(false), 47);
+    /// assert_eq!(deck.remaining(true), 52);
+    /// ```
+    pub fn remaining(&self, include_muck: bool) -> usize {
+        if include_muck {
+            self.deck.len() + self.muck.len()
+        } else {
+            self.deck.len()
+        }
+    }
+
+    /// Iterates over the cards remaining in the deck without dealing them
+    ///
+    /// The cards are yielded in the order that they would be dealt. The muck is not included.
+    ///
+    /// ```rust
+    /// use playing_cards::core::CardDeck;
+    ///
+    /// let mut deck = CardDeck::new(Some([1; 32])).unwrap();
+    /// let order: Vec<_> = deck.iter().copied().collect();
+    ///
+    /// assert_eq!(order.len(), 52);
+    /// assert_eq!(deck.next(), Some(order[0]));
+    /// ```
+    pub fn iter(&self) -> Rev<std::slice::Iter<'_, Card>> {
+        self.deck.iter().rev()
+    }
+
+    /// Iterates over the cards in the muck, in the order that they were mucked
+    pub fn muck_iter(&self) -> std::slice::Iter<'_, Card> {
+        self.muck.iter()
+    }
+
+    /// Returns whether the card is remaining in the deck, excluding the muck
+    pub fn contains(&self, card: &Card) -> bool {
+        self.deck.contains(card)
+    }
+
+    /// Compares the cards in the deck against the composition it was created with
+    ///
+    /// Every card from the composition is placed into exactly one set of the `DeckDiff`: still
+    /// remaining in the deck, burned, otherwise mucked, or dealt. Cards that were removed with
+    /// `remove_cards()` or one of the strip methods are counted as dealt. Returns `None` for decks
+    /// created with `new_custom_deck()`, since they have no composition.
+    ///
+    /// ```rust
+    /// use playing_cards::core::CardDeck;
+    ///
+    /// let mut deck = CardDeck::new(Some([1; 32])).unwrap();
+    /// let burned = deck.burn().unwrap();
+    /// let hand = deck.deal_cards(2, false).unwrap();
+    ///
+    /// let diff = deck.diff().unwrap();
+    /// assert!(diff.burned.contains(burned));
+    /// assert!(diff.dealt.contains(hand[0]));
+    /// assert_eq!(diff.remaining.len(), 49);
+    /// ```
+    pub fn diff(&self) -> Option<DeckDiff> {
+        let original = self.composition.as_ref()?.card_set();
+        let remaining = CardSet::from(self.deck.as_slice());
+        let burned = CardSet::from(self.burned.as_slice()) - remaining;
+        let mucked = CardSet::from(self.muck.as_slice()) - remaining - burned;
+
+        Some(DeckDiff {
+            dealt: original - remaining - burned - mucked,
+            remaining,
+            burned,
+            mucked,
+        })
+    }
+
+    /// Gets the composition that the deck was created with
+    ///
+    /// This is `None` for decks created from a custom set of cards with `new_custom_deck()`.
+    pub fn composition(&self) -> Option<&DeckComposition> {
+        self.composition.as_ref()
+    }
+
+    /// Removes specific cards from the deck, such as known hole cards or exposed cards
+    ///
+    /// One copy of each given card is removed, so a card can be given more than once to remove
+    /// multiple copies from a shoe. If any card cannot be found within the deck, a
+    /// `CardNotInDeck` error is returned and the deck is left unchanged. The muck is not searched.
+    ///
+    /// ```rust
+    /// use playing_cards::core::{Card, CardDeck, CardDeckError};
+    ///
+    /// let mut deck = CardDeck::new(Some([1; 32])).unwrap();
+    /// let dead_cards = Card::vec_from_str("AsKs").unwrap();
+    ///
+    /// deck.remove_cards(&dead_cards).unwrap();
+    /// assert_eq!(deck.peek(52).len(), 50);
+    ///
+    /// assert!(matches!(
+    ///     deck.remove_cards(&dead_cards),
+    ///     Err(CardDeckError::CardNotInDeck(_))
+    /// ));
+    /// ```
+    pub fn remove_cards(&mut self, cards: &[Card]) -> Result<(), CardDeckError> {
+        let mut remaining = self.deck.clone();
+        for card in cards {
+            let position = remaining
+                .iter()
+                .rposition(|c| c == card)
+                .ok_or(CardDeckError::CardNotInDeck(*card))?;
+            remaining.remove(position);
+        }
+
+        self.deck = remaining;
+        self.record(|| DeckEvent::Removed(cards.to_vec()));
+        Ok(())
+    }
+
+    /// Returns cards to the top of the deck, such as after a misdeal
+    ///
+    /// The first card given becomes the top card, so the cards will be dealt again in the order
+    /// given. A `CardAlreadyInDeck` error is returned and the deck is left unchanged if returning
+    /// the cards would leave more copies of a card in the deck than the deck was created with.
+    /// Decks created with `new_custom_deck()` allow a single copy of each card.
+    ///
+    /// ```rust
+    /// use playing_cards::core::{CardDeck, CardDeckError};
+    ///
+    /// let mut deck = CardDeck::new(Some([1; 32])).unwrap();
+    /// let exposed = deck.deal_cards(2, false).unwrap();
+    ///
+    /// deck.return_to_top(&exposed).unwrap();
+    /// assert_eq!(deck.deal_cards(2, false), Some(exposed.clone()));
+    ///
+    /// deck.return_to_top(&exposed[..1]).unwrap();
+    /// assert!(matches!(
+    ///     deck.return_to_top(&exposed[..1]),
+    ///     Err(CardDeckError::CardAlreadyInDeck(_))
+    /// ));
+    /// ```
+    pub fn return_to_top(&mut self, cards: &[Card]) -> Result<(), CardDeckError> {
+        self.check_can_return(cards)?;
+        self.deck.extend(cards.iter().rev());
+        self.record(|| DeckEvent::Returned(cards.to_vec()));
+        Ok(())
+    }
+
+    /// Returns cards to the bottom of the deck
+    ///
+    /// The cards are placed underneath the remaining cards in the order given, so the first card
+    /// given will be dealt before the rest of the returned cards. The same validation as
+    /// `return_to_top()` is performed.
+    pub fn return_to_bottom(&mut self, cards: &[Card]) -> Result<(), CardDeckError> {
+        self.check_can_return(cards)?;
+        self.deck.splice(0..0, cards.iter().rev().copied());
+        self.record(|| DeckEvent::Returned(cards.to_vec()));
+        Ok(())
+    }
+
+    fn check_can_return(&self, cards: &[Card]) -> Result<(), CardDeckError> {
+        for (i, card) in cards.iter().enumerate() {
+            let allowed = self
+                .composition
+                .as_ref()
+                .map_or(1, |composition| composition.copies_of(*card));
+            let in_deck = self.deck.iter().filter(|c| *c == card).count();
+            let returned = cards[..=i].iter().filter(|c| *c == card).count();
+
+            if in_deck + returned > allowed {
+                return Err(CardDeckError::CardAlreadyInDeck(*card));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Searches the deck and removes cards within provided set of cards
     ///
     /// Returns back a list of cards that were removed from the deck. Duplicates can be present in
@@ -210,6 +1022,7 @@ impl CardDeck {
             .collect();
 
         self.deck.retain(|card| !cards_to_remove.contains(card));
+        self.record(|| DeckEvent::Removed(removed_cards.clone()));
         removed_cards
     }
 
@@ -227,6 +1040,7 @@ impl CardDeck {
 
         self.deck
             .retain(|card| !ranks_to_remove.contains(&card.value));
+        self.record(|| DeckEvent::Removed(removed_cards.clone()));
 
         removed_cards
     }
@@ -245,6 +1059,7 @@ impl CardDeck {
 
         self.deck
             .retain(|card| !suits_to_remove.contains(&card.suit));
+        self.record(|| DeckEvent::Removed(removed_cards.clone()));
 
         removed_cards
     }
@@ -253,25 +1068,99 @@ impl CardDeck {
     ///
     /// This is primarily important if reshuffling the muck can occur.
     pub fn muck_cards(&mut self, mut cards: Vec<Card>) {
+        self.record(|| DeckEvent::Mucked(cards.clone()));
         self.muck.append(&mut cards);
     }
 
+    /// Returns the number of cards in the muck
+    pub fn muck_len(&self) -> usize {
+        self.muck.len()
+    }
+
+    /// Removes every card from the muck, returning the cards in the order that they were mucked
+    ///
+    /// The cards are no longer tracked by the deck, so they will not be reshuffled back in.
+    pub fn clear_muck(&mut self) -> Vec<Card> {
+        let cards = std::mem::take(&mut self.muck);
+        self.burned.clear();
+        self.record(|| DeckEvent::MuckCleared(cards.clone()));
+        cards
+    }
+
+    /// Takes a specific card out of the muck and back into play
+    ///
+    /// If there are multiple copies of the card in the muck, the most recently mucked copy is
+    /// taken. A `CardNotInMuck` error is returned if the card is not in the muck. The card can
+    /// then be given to a player or returned to the deck with `return_to_top()`.
+    ///
+    /// ```rust
+    /// use playing_cards::core::CardDeck;
+    ///
+    /// let mut deck = CardDeck::new(Some([1; 32])).unwrap();
+    /// let folded = deck.deal_cards(2, false).unwrap();
+    /// deck.muck_cards(folded.clone());
+    ///
+    /// assert_eq!(deck.take_from_muck(&folded[1]).unwrap(), folded[1]);
+    /// assert_eq!(deck.muck_len(), 1);
+    /// assert!(deck.take_from_muck(&folded[1]).is_err());
+    /// ```
+    pub fn take_from_muck(&mut self, card: &Card) -> Result<Card, CardDeckError> {
+        let position = self
+            .muck
+            .iter()
+            .rposition(|c| c == card)
+            .ok_or(CardDeckError::CardNotInMuck(*card))?;
+        let card = self.muck.remove(position);
+        if let Some(position) = self.burned.iter().rposition(|c| *c == card) {
+            self.burned.remove(position);
+        }
+        self.record(|| DeckEvent::TakenFromMuck(card));
+
+        Ok(card)
+    }
+
+    /// Deals the top card of the deck face down directly into the muck
+    ///
+    /// Returns the card that was burned, or `None` if the deck is empty.
+    pub fn burn(&mut self) -> Option<Card> {
+        let card = self.deck.pop()?;
+        self.muck.push(card);
+        self.burned.push(card);
+        self.record(|| DeckEvent::Burned(card));
+
+        Some(card)
+    }
+
     /// Checks to see if there are enough cards in the deck to deal
     ///
     /// Returns true if there are enough cards, false otherwise.
     pub fn check_deal_cards(&self, cards_to_deal: usize, include_muck: bool) -> bool {
-        let mut total_cards = self.deck.len();
-        if include_muck {
-            total_cards = self.muck.len();
-        }
-        total_cards >= cards_to_deal
+        self.remaining(include_muck) >= cards_to_deal
     }
 
     /// Deals `n` cards out from the CardDeck
     ///
-    /// If there is not enough cards remaining in the deck, it will reshuffle the mucked card back
-    /// into the deck and redeal them out. If there are no more cards left, this method will return
-    /// None. The method also returns
+    /// If there are not enough cards remaining in the deck, the deck's `DeckExhaustionPolicy`
+    /// decides whether `None` is returned, the muck is reshuffled back into the deck, or fewer
+    /// cards are dealt. Setting `include_muck` reshuffles the muck back in when needed, as if the
+    /// policy were `ReshuffleMuck` for this deal.
+    ///
+    /// ```rust
+    /// use playing_cards::core::{CardDeck, DeckExhaustionPolicy};
+    ///
+    /// let mut deck: CardDeck = Default::default();
+    /// let folded = deck.deal_cards(50, false).unwrap();
+    /// deck.muck_cards(folded);
+    ///
+    /// assert_eq!(deck.deal_cards(3, false), None);
+    ///
+    /// deck.set_exhaustion_policy(DeckExhaustionPolicy::DealShort);
+    /// assert_eq!(deck.clone().deal_cards(3, false).unwrap().len(), 2);
+    ///
+    /// deck.set_exhaustion_policy(DeckExhaustionPolicy::ReshuffleMuck);
+    /// assert_eq!(deck.deal_cards(3, false).unwrap().len(), 3);
+    /// assert_eq!(deck.len(), 49);
+    /// ```
     ///
     /// Examples
     /// ```rust
@@ -312,37 +1201,207 @@ impl CardDeck {
     /// unreachable!();
     /// ```
     pub fn deal_cards(&mut self, cards_to_deal: usize, include_muck: bool) -> Option<Vec<Card>> {
-        if !self.check_deal_cards(cards_to_deal, include_muck) {
+        let deal_short = self.exhaustion_policy == DeckExhaustionPolicy::DealShort;
+        let reshuffle =
+            include_muck || self.exhaustion_policy == DeckExhaustionPolicy::ReshuffleMuck;
+
+        if self.deck.len() < cards_to_deal && reshuffle && !self.muck.is_empty() {
+            if !deal_short && !self.check_deal_cards(cards_to_deal, true) {
+                return None;
+            }
+            self.reshuffle_muck(self.next_seed()).ok()?;
+        }
+
+        if !deal_short && !self.check_deal_cards(cards_to_deal, false) {
+            return None;
+        }
+
+        Some(self.deal_up_to(cards_to_deal))
+    }
+
+    /// Deals up to `n` cards out from the CardDeck
+    ///
+    /// Unlike `deal_cards()`, this never fails. If there are fewer than `n` cards remaining in the
+    /// deck, every remaining card is dealt, so the number of cards dealt is the length of the
+    /// returned vector. The muck is never reshuffled back in.
+    ///
+    /// ```rust
+    /// use playing_cards::core::CardDeck;
+    ///
+    /// let mut deck: CardDeck = Default::default();
+    /// deck.deal_cards(50, false);
+    ///
+    /// assert_eq!(deck.deal_up_to(5).len(), 2);
+    /// assert!(deck.deal_up_to(5).is_empty());
+    /// ```
+    pub fn deal_up_to(&mut self, n: usize) -> Vec<Card> {
+        self.by_ref().take(n).collect()
+    }
+
+    /// Deals `n` uniformly random cards from anywhere in the deck
+    ///
+    /// This performs a partial Fisher-Yates shuffle, so only `n` random numbers are needed rather
+    /// than shuffling the whole deck. This is much faster in Monte Carlo simulations that only
+    /// need a few cards from each deck. The order of the remaining cards is changed, so the deck
+    /// should be shuffled before dealing from the top again. Returns `None` without dealing any
+    /// cards if there are fewer than `n` cards remaining.
+    ///
+    /// ```rust
+    /// use playing_cards::core::CardDeck;
+    /// use rand_core::SeedableRng;
+    /// use rand_xoshiro::Xoshiro256PlusPlus;
+    ///
+    /// let mut rng = Xoshiro256PlusPlus::seed_from_u64(1);
+    /// let full_deck = CardDeck::default();
+    ///
+    /// for _ in 0..1000 {
+    ///     let mut deck = full_deck.clone();
+    ///     let board = deck.sample(5, &mut rng).unwrap();
+    ///     assert_eq!(deck.len(), 47);
+    /// }
+    /// ```
+    pub fn sample<R: RngCore + ?Sized>(&mut self, n: usize, rng: &mut R) -> Option<Vec<Card>> {
+        if self.deck.len() < n {
+            return None;
+        }
+
+        let mut cards = Vec::with_capacity(n);
+        for _ in 0..n {
+            let card = self.deck.swap_remove(rng.gen_range(0..self.deck.len()));
+            self.record(|| DeckEvent::Dealt(card));
+            cards.push(card);
+        }
+
+        Some(cards)
+    }
+
+    /// Deals `cards_each` cards to each of `num_players` players, one card at a time
+    ///
+    /// Cards are dealt around the table like a real dealer would, so the first player receives
+    /// the 1st, `num_players + 1`th, ... cards off the top of the deck. Returns `None` without
+    /// dealing any cards if there are not enough cards remaining in the deck.
+    ///
+    /// ```rust
+    /// use playing_cards::core::CardDeck;
+    ///
+    /// let mut deck = CardDeck::new(Some([1; 32])).unwrap();
+    /// let top: Vec<_> = deck.peek(6).into_iter().copied().collect();
+    ///
+    /// let hands = deck.deal_to_players(3, 2).unwrap();
+    ///
+    /// assert_eq!(hands[0], vec![top[0], top[3]]);
+    /// assert_eq!(hands[2], vec![top[2], top[5]]);
+    /// ```
+    pub fn deal_to_players(
+        &mut self,
+        num_players: usize,
+        cards_each: usize,
+    ) -> Option<Vec<Vec<Card>>> {
+        if !self.check_deal_cards(num_players * cards_each, false) {
             return None;
         }
-        let mut cards_dealt: Vec<Card> = Vec::new();
-        for _ in 0..cards_to_deal {
-            if let Some(s) = self.next() {
-                cards_dealt.push(s);
+
+        let mut hands = vec![Vec::with_capacity(cards_each); num_players];
+        for _ in 0..cards_each {
+            for hand in hands.iter_mut() {
+                hand.push(self.next()?);
             }
         }
 
-        Some(cards_dealt)
+        Some(hands)
+    }
+
+    /// Deals a complete hand of Texas Hold'em to `num_players` players
+    ///
+    /// The hole cards are dealt one at a time around the table, and a card is burned into the
+    /// muck before each of the flop, turn, and river. Returns `None` without dealing any cards if
+    /// there are not enough cards remaining in the deck.
+    ///
+    /// ```rust
+    /// use playing_cards::core::CardDeck;
+    ///
+    /// let mut deck = CardDeck::new(Some([1; 32])).unwrap();
+    /// let top: Vec<_> = deck.peek(8).into_iter().copied().collect();
+    ///
+    /// let deal = deck.deal_holdem(2).unwrap();
+    ///
+    /// assert_eq!(deal.hole_cards[0], [top[0], top[2]]);
+    /// assert_eq!(deal.burns[0], top[4]);
+    /// assert_eq!(deal.flop, [top[5], top[6], top[7]]);
+    /// assert_eq!(deck.muck_len(), 3);
+    /// ```
+    pub fn deal_holdem(&mut self, num_players: usize) -> Option<HoldemDeal> {
+        if !self.check_deal_cards(num_players * 2 + 8, false) {
+            return None;
+        }
+
+        let hole_cards = self
+            .deal_to_players(num_players, 2)?
+            .into_iter()
+            .map(|hand| [hand[0], hand[1]])
+            .collect();
+
+        let first_burn = self.burn()?;
+        let flop = [self.next()?, self.next()?, self.next()?];
+        let second_burn = self.burn()?;
+        let turn = self.next()?;
+        let third_burn = self.burn()?;
+        let river = self.next()?;
+
+        Some(HoldemDeal {
+            hole_cards,
+            flop,
+            turn,
+            river,
+            burns: [first_burn, second_burn, third_burn],
+        })
+    }
+
+    /// Looks at the next `n` cards that would be dealt without removing them from the deck
+    ///
+    /// The cards are returned in the order that they would be dealt. If there are fewer than `n`
+    /// cards remaining in the deck, all remaining cards are returned. The muck is never included.
+    ///
+    /// ```rust
+    /// use playing_cards::core::CardDeck;
+    ///
+    /// let mut deck = CardDeck::new(Some([1; 32])).unwrap();
+    ///
+    /// let upcoming: Vec<_> = deck.peek(3).into_iter().copied().collect();
+    /// assert_eq!(deck.deal_cards(3, false), Some(upcoming));
+    ///
+    /// assert_eq!(deck.peek(100).len(), 49);
+    /// ```
+    pub fn peek(&self, n: usize) -> Vec<&Card> {
+        self.iter().take(n).collect()
     }
 
     /// Draws `n` cards out from the CardDeck
     ///
     /// The definition of drawing in this case means to discard and replace cards. This function
     /// can take any number of discard cards with the help of `muck_cards()` and then simply
-    /// invokes `deal_cards()` to deal `n` cards out of the deck.
+    /// invokes `deal_cards()` to deal `n` cards out of the deck. The discarded cards can be dealt
+    /// again if the muck is reshuffled, following the deck's `DeckExhaustionPolicy`.
     pub fn draw_cards(
         &mut self,
         cards_to_deal: usize,
         discard_cards: Option<Vec<Card>>,
         include_muck: bool,
     ) -> Option<Vec<Card>> {
-        if !self.check_deal_cards(
-            cards_to_deal
-                - discard_cards
-                    .clone()
-                    .map_or(0, |v| if include_muck { v.len() } else { 0 }),
-            include_muck,
-        ) {
+        let include_muck =
+            include_muck || self.exhaustion_policy == DeckExhaustionPolicy::ReshuffleMuck;
+        if self.exhaustion_policy != DeckExhaustionPolicy::DealShort
+            && !self.check_deal_cards(
+                cards_to_deal.saturating_sub(discard_cards.as_ref().map_or(0, |v| {
+                    if include_muck {
+                        v.len()
+                    } else {
+                        0
+                    }
+                })),
+                include_muck,
+            )
+        {
             return None;
         }
         if let Some(c) = discard_cards {
@@ -359,21 +1418,132 @@ impl CardDeck {
     /// Similar to `shuffle()`, this function takes in an optional seed if a specific seed is
     /// desired. If no seed is provided, a seed will be sampled from entropy.
     pub fn reshuffle_muck(&mut self, seed: Option<[u8; 32]>) -> Result<(), CardDeckError> {
-        Self::shuffle_cards(&mut self.muck, seed)?;
+        let seed_used = Self::seed_or_entropy(seed)?;
+        self.muck
+            .shuffle(&mut Xoshiro256PlusPlus::from_seed(Self::expand_seed(
+                seed_used,
+            )));
+        self.place_muck_under_deck();
+        self.seed_history
+            .push(SeedUse::MuckReshuffle(Some(seed_used)));
+        self.record(|| DeckEvent::MuckReshuffled {
+            seed: Some(seed_used),
+        });
 
-        self.muck.append(&mut self.deck);
-        self.deck = self.muck.to_owned();
-        self.muck = Vec::new();
+        Ok(())
+    }
+
+    /// Reshuffles the muck with the provided random number generator and inserts those cards into
+    /// the deck
+    ///
+    /// The muck will be placed behind the remaining cards in the deck.
+    pub fn reshuffle_muck_with_rng<R: RngCore + ?Sized>(&mut self, rng: &mut R) {
+        self.muck.shuffle(rng);
+        self.place_muck_under_deck();
+        self.seed_history.push(SeedUse::MuckReshuffle(None));
+        self.record(|| DeckEvent::MuckReshuffled { seed: None });
+    }
+
+    /// Inserts each card in the muck into the deck at a uniformly random position
+    ///
+    /// This simulates a continuous shuffling machine, where cards are returned to the machine
+    /// after every round rather than being set aside until the deck is reshuffled. Like
+    /// `shuffle()`, an optional seed can be provided. If no seed is provided, a seed will be
+    /// sampled from entropy.
+    ///
+    /// ```rust
+    /// use playing_cards::core::CardDeck;
+    ///
+    /// let mut deck = CardDeck::new(Some([1; 32])).unwrap();
+    /// let round = deck.deal_cards(10, false).unwrap();
+    /// deck.muck_cards(round);
+    ///
+    /// deck.reinsert_muck(Some([2; 32])).unwrap();
+    /// assert_eq!(deck.len(), 52);
+    /// assert_eq!(deck.muck_len(), 0);
+    /// ```
+    pub fn reinsert_muck(&mut self, seed: Option<[u8; 32]>) -> Result<(), CardDeckError> {
+        let seed_used = Self::seed_or_entropy(seed)?;
+        self.insert_muck_randomly(&mut Xoshiro256PlusPlus::from_seed(Self::expand_seed(
+            seed_used,
+        )));
+        self.seed_history
+            .push(SeedUse::MuckReinsert(Some(seed_used)));
+        self.record(|| DeckEvent::MuckReinserted {
+            seed: Some(seed_used),
+        });
 
         Ok(())
     }
+
+    /// Inserts each card in the muck into the deck at a random position using the provided random
+    /// number generator
+    pub fn reinsert_muck_with_rng<R: RngCore + ?Sized>(&mut self, rng: &mut R) {
+        self.insert_muck_randomly(rng);
+        self.seed_history.push(SeedUse::MuckReinsert(None));
+        self.record(|| DeckEvent::MuckReinserted { seed: None });
+    }
+
+    fn insert_muck_randomly<R: RngCore + ?Sized>(&mut self, rng: &mut R) {
+        self.burned.clear();
+        for card in std::mem::take(&mut self.muck) {
+            let position = rng.gen_range(0..=self.deck.len());
+            self.deck.insert(position, card);
+        }
+    }
+
+    /// Derives a seed for an automatic reshuffle of the muck from the most recent seed in the
+    /// seed history, returning `None` if no seed has been recorded
+    ///
+    /// The seed is derived with the same generator that the most recent seed was used with.
+    fn next_seed(&self) -> Option<[u8; 32]> {
+        let (last, generator) =
+            self.seed_history
+                .iter()
+                .rev()
+                .find_map(|seed_use| match *seed_use {
+                    SeedUse::Shuffle { seed, generator } => seed.zip(generator),
+                    SeedUse::PhysicalShuffle { seed, .. }
+                    | SeedUse::MuckReshuffle(seed)
+                    | SeedUse::MuckReinsert(seed) => {
+                        seed.map(|seed| (seed, ShuffleGenerator::Xoshiro256PlusPlus))
+                    }
+                    SeedUse::Cut(seed) => Some((seed, ShuffleGenerator::Xoshiro256PlusPlus)),
+                })?;
+
+        Some(generator.derive_seed(last))
+    }
+
+    fn place_muck_under_deck(&mut self) {
+        self.burned.clear();
+        self.muck.append(&mut self.deck);
+        self.deck = std::mem::take(&mut self.muck);
+    }
 }
 
 impl Iterator for CardDeck {
     type Item = Card;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.deck.pop()
+        let card = self.deck.pop()?;
+        self.record(|| DeckEvent::Dealt(card));
+
+        Some(card)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.deck.len(), Some(self.deck.len()))
+    }
+}
+
+impl ExactSizeIterator for CardDeck {}
+
+impl<'a> IntoIterator for &'a CardDeck {
+    type Item = &'a Card;
+    type IntoIter = Rev<std::slice::Iter<'a, Card>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
@@ -383,6 +1553,7 @@ mod tests {
     use super::*;
     use rayon::prelude::*;
     use std::iter::Iterator;
+    use std::str::FromStr;
 
     #[test]
     fn test_deck_same_seed() {
@@ -417,6 +1588,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_get_seed() {
         let mut expected_seed = Vec::from(233_i32.to_le_bytes());
         expected_seed.extend_from_slice(&[0u8; 28]);
@@ -499,6 +1671,409 @@ mod tests {
         }
     }
 
+    #[test]
+    fn deal_to_too_many_players() {
+        let mut deck: CardDeck = Default::default();
+
+        assert_eq!(deck.deal_to_players(9, 6), None);
+        assert_eq!(deck.peek(52).len(), 52);
+
+        let hands = deck.deal_to_players(4, 13).unwrap();
+        assert!(hands.iter().all(|hand| hand.len() == 13));
+        assert_eq!(deck.next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_resumes_mid_hand() {
+        let mut deck = CardDeckBuilder::new()
+            .decks(2)
+            .jokers(2)
+            .shuffled(Some([9; 32]))
+            .build()
+            .unwrap();
+        deck.deal_cards(10, false);
+        let mucked = deck.deal_cards(3, false).unwrap();
+        deck.muck_cards(mucked);
+
+        let json = serde_json::to_string(&deck).unwrap();
+        let mut resumed: CardDeck = serde_json::from_str(&json).unwrap();
+        assert_eq!(resumed.composition(), deck.composition());
+
+        resumed.reshuffle_muck(Some([4; 32])).unwrap();
+        deck.reshuffle_muck(Some([4; 32])).unwrap();
+        assert_eq!(resumed.collect::<Vec<_>>(), deck.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn cut_positions() {
+        let mut deck: CardDeck = Default::default();
+        let top = *deck.peek(1)[0];
+
+        deck.cut(0).unwrap();
+        deck.cut(52).unwrap();
+        assert_eq!(*deck.peek(1)[0], top);
+        assert!(matches!(
+            deck.cut(53),
+            Err(CardDeckError::InvalidCutPosition(53))
+        ));
+
+        let position = deck.cut_random(Some([5; 32])).unwrap();
+        assert!((1..52).contains(&position));
+        assert_eq!(*deck.peek(53)[52 - position], top);
+        assert_eq!(deck.clone().cut_random(Some([5; 32])).unwrap(), position);
+    }
+
+    #[test]
+    #[cfg(feature = "chacha")]
+    fn shuffle_with_chacha() {
+        use super::super::ChaCha20Rng;
+
+        let mut d1: CardDeck = Default::default();
+        let mut d2: CardDeck = Default::default();
+        d1.shuffle_with_seed::<ChaCha20Rng>(Some([11; 32])).unwrap();
+        d2.shuffle_with_rng(&mut ChaCha20Rng::from_seed([11; 32]));
+        assert_eq!(d1.deck, d2.deck);
+
+        let mut d3: CardDeck = Default::default();
+        d3.shuffle(Some([11; 32])).unwrap();
+        assert_ne!(d1.deck, d3.deck);
+    }
+
+    #[test]
+    fn inspecting_without_dealing() {
+        let mut deck = CardDeck::new_custom_deck(Vec::new(), None).unwrap();
+        assert!(deck.is_empty());
+        assert_eq!(deck.size_hint(), (0, Some(0)));
+
+        deck = Default::default();
+        let top = deck.next().unwrap();
+        assert!(!deck.contains(&top));
+        assert!(deck.contains(deck.peek(1)[0]));
+        assert_eq!(deck.len(), 51);
+
+        deck.muck_cards(vec![top]);
+        assert!(!deck.contains(&top));
+        assert_eq!(deck.remaining(true), 52);
+        assert_eq!(deck.muck_iter().collect::<Vec<_>>(), [&top]);
+
+        let in_order: Vec<&Card> = (&deck).into_iter().collect();
+        assert_eq!(in_order, deck.peek(51));
+        assert_eq!(deck.iter().len(), 51);
+    }
+
+    #[test]
+    fn returning_cards() {
+        let mut deck = CardDeckBuilder::new().decks(2).build().unwrap();
+        let ace = Card::from_str("As").unwrap();
+        deck.remove_cards(&[ace, ace]).unwrap();
+
+        assert!(matches!(
+            deck.return_to_bottom(&[ace, ace, ace]),
+            Err(CardDeckError::CardAlreadyInDeck(card)) if card == ace
+        ));
+        assert_eq!(deck.len(), 102);
+
+        let bottom = Card::vec_from_str("AsKs").unwrap();
+        deck.remove_cards(&bottom[1..]).unwrap();
+        deck.return_to_bottom(&bottom).unwrap();
+        assert_eq!(
+            deck.iter().rev().take(2).collect::<Vec<_>>(),
+            [&bottom[1], &bottom[0]]
+        );
+
+        deck.return_to_top(&[ace]).unwrap();
+        assert_eq!(deck.next(), Some(ace));
+    }
+
+    #[test]
+    fn event_log() {
+        let mut deck: CardDeck = Default::default();
+        deck.next();
+        assert_eq!(deck.events(), None);
+        assert!(deck.take_events().is_empty());
+
+        deck.enable_event_log();
+        deck.cut(3).unwrap();
+        let hands = deck.deal_to_players(2, 1).unwrap();
+        deck.muck_cards(hands[0].clone());
+        deck.reshuffle_muck(Some([2; 32])).unwrap();
+
+        assert_eq!(
+            deck.take_events(),
+            [
+                DeckEvent::Cut(3),
+                DeckEvent::Dealt(hands[0][0]),
+                DeckEvent::Dealt(hands[1][0]),
+                DeckEvent::Mucked(hands[0].clone()),
+                DeckEvent::MuckReshuffled {
+                    seed: Some([2; 32])
+                },
+            ]
+        );
+
+        deck.physical_shuffle(PhysicalShuffle::Overhand, 2, Some([3; 32]))
+            .unwrap();
+        assert_eq!(deck.events().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn managing_the_muck() {
+        let mut deck: CardDeck = Default::default();
+        let burned = deck.burn().unwrap();
+        let folded = deck.deal_cards(3, false).unwrap();
+        deck.muck_cards(folded.clone());
+        assert_eq!(deck.muck_len(), 4);
+
+        deck.enable_event_log();
+        deck.take_from_muck(&burned).unwrap();
+        assert!(matches!(
+            deck.take_from_muck(&burned),
+            Err(CardDeckError::CardNotInMuck(card)) if card == burned
+        ));
+
+        assert_eq!(deck.clear_muck(), folded);
+        assert_eq!(deck.muck_iter().count(), 0);
+        assert_eq!(
+            deck.take_events(),
+            [
+                DeckEvent::TakenFromMuck(burned),
+                DeckEvent::MuckCleared(folded)
+            ]
+        );
+    }
+
+    #[test]
+    fn exhaustion_policies() {
+        let mut deck: CardDeck = Default::default();
+        assert_eq!(deck.exhaustion_policy(), DeckExhaustionPolicy::Fail);
+        deck.deal_cards(51, false).unwrap();
+
+        assert_eq!(deck.deal_cards(2, true), None);
+        deck.muck_cards(Card::vec_from_str("As").unwrap());
+        assert_eq!(deck.clone().deal_cards(2, true).unwrap().len(), 2);
+
+        deck.set_exhaustion_policy(DeckExhaustionPolicy::ReshuffleMuck);
+        assert_eq!(deck.deal_cards(3, false), None);
+        assert_eq!(deck.len(), 1);
+
+        deck.set_exhaustion_policy(DeckExhaustionPolicy::DealShort);
+        assert_eq!(deck.deal_cards(3, true).unwrap().len(), 2);
+        assert_eq!(deck.deal_cards(3, true), Some(Vec::new()));
+    }
+
+    #[test]
+    fn automatic_reshuffles_are_reproducible() {
+        let deal = || {
+            let mut deck = CardDeck::new(Some([7; 32])).unwrap();
+            deck.set_exhaustion_policy(DeckExhaustionPolicy::ReshuffleMuck);
+            let mut dealt = Vec::new();
+            for _ in 0..3 {
+                let cards = deck.deal_cards(40, false).unwrap();
+                dealt.extend_from_slice(&cards);
+                deck.muck_cards(cards);
+            }
+            (dealt, deck.seed_history().to_vec())
+        };
+
+        let (dealt, history) = deal();
+        assert_eq!(deal(), (dealt, history.clone()));
+        assert_eq!(history.len(), 3);
+        assert!(matches!(history[1], SeedUse::MuckReshuffle(Some(seed)) if seed != [7; 32]));
+        assert_ne!(history[1], history[2]);
+    }
+
+    #[test]
+    fn replaying_seed_history() {
+        let mut deck: CardDeck = Default::default();
+        deck.shuffle(None).unwrap();
+        deck.shuffle_with_seed::<Xoshiro256StarStar>(None).unwrap();
+        #[cfg(feature = "chacha")]
+        deck.shuffle_with_seed::<rand_chacha::ChaCha20Rng>(None)
+            .unwrap();
+        deck.physical_shuffle(PhysicalShuffle::Riffle, 3, None)
+            .unwrap();
+        deck.physical_shuffle(PhysicalShuffle::Strip, 1, None)
+            .unwrap();
+        deck.cut_random(None).unwrap();
+        let first = deck.deal_cards(10, false).unwrap();
+        deck.muck_cards(first.clone());
+        deck.reshuffle_muck(None).unwrap();
+        let second = deck.deal_cards(10, false).unwrap();
+        deck.muck_cards(second.clone());
+        deck.reinsert_muck(None).unwrap();
+
+        let mut replay: CardDeck = Default::default();
+        for seed_use in deck.seed_history().to_vec() {
+            match seed_use {
+                SeedUse::Shuffle {
+                    seed,
+                    generator: Some(generator),
+                } => replay.shuffle_with_generator(generator, seed).unwrap(),
+                SeedUse::PhysicalShuffle { seed, mode, passes } => {
+                    replay.physical_shuffle(mode, passes, seed).unwrap()
+                }
+                SeedUse::Cut(seed) => {
+                    replay.cut_random(Some(seed)).unwrap();
+                }
+                SeedUse::MuckReshuffle(seed) => {
+                    assert_eq!(replay.deal_cards(10, false).unwrap(), first);
+                    replay.muck_cards(first.clone());
+                    replay.reshuffle_muck(seed).unwrap();
+                }
+                SeedUse::MuckReinsert(seed) => {
+                    assert_eq!(replay.deal_cards(10, false).unwrap(), second);
+                    replay.muck_cards(second.clone());
+                    replay.reinsert_muck(seed).unwrap();
+                }
+                SeedUse::Shuffle {
+                    generator: None, ..
+                } => unreachable!(),
+            }
+        }
+
+        assert_eq!(replay.seed_history(), deck.seed_history());
+        assert_eq!(replay.deal_cards(52, false), deck.deal_cards(52, false));
+    }
+
+    #[test]
+    fn shuffles_record_their_generator() {
+        let mut deck: CardDeck = Default::default();
+        deck.shuffle_with_seed::<Xoshiro256StarStar>(Some([1; 32]))
+            .unwrap();
+        deck.physical_shuffle(PhysicalShuffle::Riffle, 3, Some([1; 32]))
+            .unwrap();
+        deck.shuffle_with_rng(&mut Xoshiro256PlusPlus::from_seed([1; 32]));
+
+        assert_eq!(
+            deck.seed_history(),
+            [
+                SeedUse::Shuffle {
+                    seed: Some([1; 32]),
+                    generator: Some(ShuffleGenerator::Xoshiro256StarStar),
+                },
+                SeedUse::PhysicalShuffle {
+                    seed: Some([1; 32]),
+                    mode: PhysicalShuffle::Riffle,
+                    passes: 3,
+                },
+                SeedUse::Shuffle {
+                    seed: None,
+                    generator: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "seed-phrase")]
+    fn seed_phrases() {
+        // SHA-256 of "abc"
+        assert_eq!(
+            CardDeck::seed_from_phrase("abc")[..4],
+            [0xba, 0x78, 0x16, 0xbf]
+        );
+
+        let mut d1 = CardDeck::new_with_seed_phrase("abc").unwrap();
+        let mut d2 = CardDeck::new(Some(CardDeck::seed_from_phrase("abc"))).unwrap();
+        are_decks_equal(&mut d1, &mut d2);
+
+        let d3 = CardDeck::new_with_seed_phrase("abd").unwrap();
+        assert_ne!(d3.deck, CardDeck::new_with_seed_phrase("abc").unwrap().deck);
+    }
+
+    #[test]
+    fn diff_after_a_hand() {
+        let mut deck = CardDeckBuilder::new().jokers(1).build().unwrap();
+        deck.remove_cards(&Card::vec_from_str("As").unwrap())
+            .unwrap();
+        let burned = deck.burn().unwrap();
+        let folded = deck.deal_cards(2, false).unwrap();
+        deck.muck_cards(folded.clone());
+        deck.deal_cards(2, false).unwrap();
+
+        let diff = deck.diff().unwrap();
+        assert_eq!(diff.burned, CardSet::from(vec![burned]));
+        assert_eq!(diff.mucked, CardSet::from(folded.clone()));
+        assert_eq!(diff.dealt.len(), 3);
+        assert_eq!(diff.remaining.len(), 47);
+
+        deck.take_from_muck(&burned).unwrap();
+        assert!(deck.diff().unwrap().dealt.contains(burned));
+        assert_eq!(
+            CardDeck::new_custom_deck(folded, None).unwrap().diff(),
+            None
+        );
+    }
+
+    #[test]
+    fn holdem_needs_enough_cards() {
+        let mut deck: CardDeck = Default::default();
+        assert_eq!(deck.deal_holdem(23), None);
+        assert_eq!(deck.len(), 52);
+
+        let deal = deck.deal_holdem(22).unwrap();
+        assert!(deck.is_empty());
+        assert_eq!(deal.hole_cards.len(), 22);
+        assert_eq!(deal.board()[3], deal.turn);
+        assert_eq!(deck.muck_iter().copied().collect::<Vec<_>>(), deal.burns);
+    }
+
+    #[test]
+    fn summaries() {
+        let mut deck = CardDeck::new(Some([1; 32])).unwrap();
+        deck.strip_suits(&HashSet::from([Suit::Club, Suit::Diamond]));
+        deck.strip_ranks(&HashSet::from_iter(Value::range(..Value::Queen)));
+
+        assert_eq!(
+            deck.to_string(),
+            format!(
+                "6 remaining [A:2 K:2 Q:2] [♥3 ♠3], 0 mucked, seed {}",
+                "01".repeat(32)
+            )
+        );
+
+        let debug = format!("{:?}", deck);
+        assert!(debug.starts_with("CardDeck { summary: 6 remaining"));
+        assert!(debug.contains(&DisplayCards::new(&deck.clone().collect::<Vec<_>>()).to_string()));
+    }
+
+    #[test]
+    fn sampling_is_uniform() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(2114);
+        let mut counts = [0; 52];
+
+        for _ in 0..52_000 {
+            let mut deck: CardDeck = Default::default();
+            for card in deck.sample(2, &mut rng).unwrap() {
+                counts[u8::from(card) as usize] += 1;
+            }
+            assert_eq!(deck.len(), 50);
+        }
+
+        // each card is expected 2000 times, with a standard deviation of about 44
+        assert!(counts.iter().all(|count| (1800..2200).contains(count)));
+
+        let mut deck = CardDeck::new_custom_deck(Card::vec_from_str("As").unwrap(), None).unwrap();
+        assert_eq!(deck.sample(2, &mut rng), None);
+        assert_eq!(deck.len(), 1);
+    }
+
+    #[test]
+    fn remove_cards_is_atomic() {
+        let mut deck: CardDeck = Default::default();
+        let ace = Card::from_str("As").unwrap();
+
+        assert!(matches!(
+            deck.remove_cards(&[ace, ace]),
+            Err(CardDeckError::CardNotInDeck(card)) if card == ace
+        ));
+        assert!(deck.peek(52).contains(&&ace));
+
+        deck.remove_cards(&[ace]).unwrap();
+        assert!(!deck.peek(52).contains(&&ace));
+    }
+
     // This test relies on random entropy seeding. By the very nature of random numbers and normal
     // curves, there will be a subset of runs that will fail since the actual percentage lands
     // outside if the bounds of the expected percentage (+/- 0.2%).
@@ -531,7 +2106,7 @@ mod tests {
 
     fn are_2kings_adjacent(deck: &mut CardDeck) -> bool {
         let mut was_previous_king = false;
-        while let Some(c) = deck.next() {
+        for c in deck.by_ref() {
             if c.value == Value::King {
                 if was_previous_king {
                     return true;
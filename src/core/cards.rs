@@ -0,0 +1,259 @@
+//! Named constants for every card in a standard 52-card deck, along with the jokers
+//!
+//! These can be used to build test fixtures and lookup tables at compile time without parsing any
+//! strings.
+//!
+//! ```rust
+//! use playing_cards::core::{cards::*, Card};
+//!
+//! const BROADWAY: [Card; 5] = [
+//!     ACE_OF_SPADES,
+//!     KING_OF_SPADES,
+//!     QUEEN_OF_SPADES,
+//!     JACK_OF_SPADES,
+//!     TEN_OF_SPADES,
+//! ];
+//!
+//! assert_eq!(BROADWAY.to_vec(), Card::vec_from_str("AsKsQsJsTs").unwrap());
+//! ```
+
+use super::{Card, Suit, Value};
+
+/// The 2 of hearts
+pub const TWO_OF_HEARTS: Card = Card::new(Value::Two, Suit::Heart);
+
+/// The 2 of clubs
+pub const TWO_OF_CLUBS: Card = Card::new(Value::Two, Suit::Club);
+
+/// The 2 of diamonds
+pub const TWO_OF_DIAMONDS: Card = Card::new(Value::Two, Suit::Diamond);
+
+/// The 2 of spades
+pub const TWO_OF_SPADES: Card = Card::new(Value::Two, Suit::Spade);
+
+/// The 3 of hearts
+pub const THREE_OF_HEARTS: Card = Card::new(Value::Three, Suit::Heart);
+
+/// The 3 of clubs
+pub const THREE_OF_CLUBS: Card = Card::new(Value::Three, Suit::Club);
+
+/// The 3 of diamonds
+pub const THREE_OF_DIAMONDS: Card = Card::new(Value::Three, Suit::Diamond);
+
+/// The 3 of spades
+pub const THREE_OF_SPADES: Card = Card::new(Value::Three, Suit::Spade);
+
+/// The 4 of hearts
+pub const FOUR_OF_HEARTS: Card = Card::new(Value::Four, Suit::Heart);
+
+/// The 4 of clubs
+pub const FOUR_OF_CLUBS: Card = Card::new(Value::Four, Suit::Club);
+
+/// The 4 of diamonds
+pub const FOUR_OF_DIAMONDS: Card = Card::new(Value::Four, Suit::Diamond);
+
+/// The 4 of spades
+pub const FOUR_OF_SPADES: Card = Card::new(Value::Four, Suit::Spade);
+
+/// The 5 of hearts
+pub const FIVE_OF_HEARTS: Card = Card::new(Value::Five, Suit::Heart);
+
+/// The 5 of clubs
+pub const FIVE_OF_CLUBS: Card = Card::new(Value::Five, Suit::Club);
+
+/// The 5 of diamonds
+pub const FIVE_OF_DIAMONDS: Card = Card::new(Value::Five, Suit::Diamond);
+
+/// The 5 of spades
+pub const FIVE_OF_SPADES: Card = Card::new(Value::Five, Suit::Spade);
+
+/// The 6 of hearts
+pub const SIX_OF_HEARTS: Card = Card::new(Value::Six, Suit::Heart);
+
+/// The 6 of clubs
+pub const SIX_OF_CLUBS: Card = Card::new(Value::Six, Suit::Club);
+
+/// The 6 of diamonds
+pub const SIX_OF_DIAMONDS: Card = Card::new(Value::Six, Suit::Diamond);
+
+/// The 6 of spades
+pub const SIX_OF_SPADES: Card = Card::new(Value::Six, Suit::Spade);
+
+/// The 7 of hearts
+pub const SEVEN_OF_HEARTS: Card = Card::new(Value::Seven, Suit::Heart);
+
+/// The 7 of clubs
+pub const SEVEN_OF_CLUBS: Card = Card::new(Value::Seven, Suit::Club);
+
+/// The 7 of diamonds
+pub const SEVEN_OF_DIAMONDS: Card = Card::new(Value::Seven, Suit::Diamond);
+
+/// The 7 of spades
+pub const SEVEN_OF_SPADES: Card = Card::new(Value::Seven, Suit::Spade);
+
+/// The 8 of hearts
+pub const EIGHT_OF_HEARTS: Card = Card::new(Value::Eight, Suit::Heart);
+
+/// The 8 of clubs
+pub const EIGHT_OF_CLUBS: Card = Card::new(Value::Eight, Suit::Club);
+
+/// The 8 of diamonds
+pub const EIGHT_OF_DIAMONDS: Card = Card::new(Value::Eight, Suit::Diamond);
+
+/// The 8 of spades
+pub const EIGHT_OF_SPADES: Card = Card::new(Value::Eight, Suit::Spade);
+
+/// The 9 of hearts
+pub const NINE_OF_HEARTS: Card = Card::new(Value::Nine, Suit::Heart);
+
+/// The 9 of clubs
+pub const NINE_OF_CLUBS: Card = Card::new(Value::Nine, Suit::Club);
+
+/// The 9 of diamonds
+pub const NINE_OF_DIAMONDS: Card = Card::new(Value::Nine, Suit::Diamond);
+
+/// The 9 of spades
+pub const NINE_OF_SPADES: Card = Card::new(Value::Nine, Suit::Spade);
+
+/// The 10 of hearts
+pub const TEN_OF_HEARTS: Card = Card::new(Value::Ten, Suit::Heart);
+
+/// The 10 of clubs
+pub const TEN_OF_CLUBS: Card = Card::new(Value::Ten, Suit::Club);
+
+/// The 10 of diamonds
+pub const TEN_OF_DIAMONDS: Card = Card::new(Value::Ten, Suit::Diamond);
+
+/// The 10 of spades
+pub const TEN_OF_SPADES: Card = Card::new(Value::Ten, Suit::Spade);
+
+/// The Jack of hearts
+pub const JACK_OF_HEARTS: Card = Card::new(Value::Jack, Suit::Heart);
+
+/// The Jack of clubs
+pub const JACK_OF_CLUBS: Card = Card::new(Value::Jack, Suit::Club);
+
+/// The Jack of diamonds
+pub const JACK_OF_DIAMONDS: Card = Card::new(Value::Jack, Suit::Diamond);
+
+/// The Jack of spades
+pub const JACK_OF_SPADES: Card = Card::new(Value::Jack, Suit::Spade);
+
+/// The Queen of hearts
+pub const QUEEN_OF_HEARTS: Card = Card::new(Value::Queen, Suit::Heart);
+
+/// The Queen of clubs
+pub const QUEEN_OF_CLUBS: Card = Card::new(Value::Queen, Suit::Club);
+
+/// The Queen of diamonds
+pub const QUEEN_OF_DIAMONDS: Card = Card::new(Value::Queen, Suit::Diamond);
+
+/// The Queen of spades
+pub const QUEEN_OF_SPADES: Card = Card::new(Value::Queen, Suit::Spade);
+
+/// The King of hearts
+pub const KING_OF_HEARTS: Card = Card::new(Value::King, Suit::Heart);
+
+/// The King of clubs
+pub const KING_OF_CLUBS: Card = Card::new(Value::King, Suit::Club);
+
+/// The King of diamonds
+pub const KING_OF_DIAMONDS: Card = Card::new(Value::King, Suit::Diamond);
+
+/// The King of spades
+pub const KING_OF_SPADES: Card = Card::new(Value::King, Suit::Spade);
+
+/// The Ace of hearts
+pub const ACE_OF_HEARTS: Card = Card::new(Value::Ace, Suit::Heart);
+
+/// The Ace of clubs
+pub const ACE_OF_CLUBS: Card = Card::new(Value::Ace, Suit::Club);
+
+/// The Ace of diamonds
+pub const ACE_OF_DIAMONDS: Card = Card::new(Value::Ace, Suit::Diamond);
+
+/// The Ace of spades
+pub const ACE_OF_SPADES: Card = Card::new(Value::Ace, Suit::Spade);
+
+/// The red joker
+pub const RED_JOKER: Card = Card::new(Value::Joker, Suit::Heart);
+
+/// The black joker
+pub const BLACK_JOKER: Card = Card::new(Value::Joker, Suit::Spade);
+
+/// Every card in a standard 52-card deck, ordered by value and then by suit
+///
+/// The jokers are not included.
+///
+/// The order matches the compact `u8` encoding of a card, i.e. `ALL[i]` is
+/// `Card::try_from(i as u8)`.
+pub const ALL: [Card; 52] = [
+    TWO_OF_HEARTS,
+    TWO_OF_CLUBS,
+    TWO_OF_DIAMONDS,
+    TWO_OF_SPADES,
+    THREE_OF_HEARTS,
+    THREE_OF_CLUBS,
+    THREE_OF_DIAMONDS,
+    THREE_OF_SPADES,
+    FOUR_OF_HEARTS,
+    FOUR_OF_CLUBS,
+    FOUR_OF_DIAMONDS,
+    FOUR_OF_SPADES,
+    FIVE_OF_HEARTS,
+    FIVE_OF_CLUBS,
+    FIVE_OF_DIAMONDS,
+    FIVE_OF_SPADES,
+    SIX_OF_HEARTS,
+    SIX_OF_CLUBS,
+    SIX_OF_DIAMONDS,
+    SIX_OF_SPADES,
+    SEVEN_OF_HEARTS,
+    SEVEN_OF_CLUBS,
+    SEVEN_OF_DIAMONDS,
+    SEVEN_OF_SPADES,
+    EIGHT_OF_HEARTS,
+    EIGHT_OF_CLUBS,
+    EIGHT_OF_DIAMONDS,
+    EIGHT_OF_SPADES,
+    NINE_OF_HEARTS,
+    NINE_OF_CLUBS,
+    NINE_OF_DIAMONDS,
+    NINE_OF_SPADES,
+    TEN_OF_HEARTS,
+    TEN_OF_CLUBS,
+    TEN_OF_DIAMONDS,
+    TEN_OF_SPADES,
+    JACK_OF_HEARTS,
+    JACK_OF_CLUBS,
+    JACK_OF_DIAMONDS,
+    JACK_OF_SPADES,
+    QUEEN_OF_HEARTS,
+    QUEEN_OF_CLUBS,
+    QUEEN_OF_DIAMONDS,
+    QUEEN_OF_SPADES,
+    KING_OF_HEARTS,
+    KING_OF_CLUBS,
+    KING_OF_DIAMONDS,
+    KING_OF_SPADES,
+    ACE_OF_HEARTS,
+    ACE_OF_CLUBS,
+    ACE_OF_DIAMONDS,
+    ACE_OF_SPADES,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_matches_int_conversion() {
+        for (i, card) in ALL.iter().enumerate() {
+            assert_eq!(*card, Card::from(i as i32 + 1));
+            assert_eq!(Ok(*card), Card::try_from(i as u8));
+        }
+
+        assert_eq!(ACE_OF_SPADES.to_string(), "A♠");
+        assert_eq!(TWO_OF_HEARTS.to_string(), "2♥");
+    }
+}
@@ -0,0 +1,112 @@
+use rand_core::{RngCore, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+use super::{Card, CardDeck, CardDeckBuilder, CardDeckError};
+
+/// A simulation of a continuous shuffling machine
+///
+/// Instead of dealing through a shoe and reshuffling once a cut card is reached, the cards from
+/// each round are returned to the machine and inserted at random positions. This makes the
+/// remaining cards independent of the cards that have been seen, which can be compared against a
+/// `Shoe` for card counting studies.
+///
+/// The seed for each reinsertion is drawn from a generator that is seeded once, so every
+/// reinsertion is recorded in the deck's `CardDeck::seed_history()`.
+///
+/// ```rust
+/// use playing_cards::core::{CardDeckBuilder, ContinuousShuffler};
+///
+/// let mut csm = ContinuousShuffler::new(CardDeckBuilder::new().decks(4), Some([1; 32])).unwrap();
+///
+/// for _ in 0..100 {
+///     let round = csm.deal_cards(12).unwrap();
+///     csm.end_round(round);
+/// }
+/// assert_eq!(csm.deck().len(), 208);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ContinuousShuffler {
+    deck: CardDeck,
+    rng: Xoshiro256PlusPlus,
+}
+
+impl ContinuousShuffler {
+    /// Builds and shuffles the cards that will be loaded into the machine
+    ///
+    /// Like `CardDeck::shuffle()`, an optional seed can be provided. If no seed is provided, a
+    /// seed will be sampled from entropy. An error will return if building or shuffling the deck
+    /// fails.
+    pub fn new(builder: CardDeckBuilder, seed: Option<[u8; 32]>) -> Result<Self, CardDeckError> {
+        let seed = CardDeck::seed_or_entropy(seed)?;
+        let deck = builder.shuffled(Some(seed)).build()?;
+
+        // jumping ahead keeps the reinsertions independent of the initial shuffle
+        let mut rng = Xoshiro256PlusPlus::from_seed(CardDeck::expand_seed(seed));
+        rng.jump();
+
+        Ok(Self { deck, rng })
+    }
+
+    /// Deals a single card, or `None` if the machine is empty
+    pub fn deal(&mut self) -> Option<Card> {
+        self.deck.next()
+    }
+
+    /// Deals `n` cards, or `None` without dealing any cards if there are not enough cards left
+    pub fn deal_cards(&mut self, n: usize) -> Option<Vec<Card>> {
+        self.deck.deal_cards(n, false)
+    }
+
+    /// Returns the cards from a finished round to the machine
+    ///
+    /// The cards, along with any cards mucked directly on the deck, are inserted at random
+    /// positions.
+    pub fn end_round(&mut self, cards: Vec<Card>) {
+        let mut seed = [0u8; 32];
+        self.rng.fill_bytes(&mut seed);
+
+        self.deck.muck_cards(cards);
+        self.deck
+            .reinsert_muck(Some(seed))
+            .expect("reinserting with a seed should never sample entropy");
+    }
+
+    /// Gets the cards that are loaded in the machine
+    pub fn deck(&self) -> &CardDeck {
+        &self.deck
+    }
+
+    /// Gets the cards that are loaded in the machine mutably, such as to burn or muck cards
+    pub fn deck_mut(&mut self) -> &mut CardDeck {
+        &mut self.deck
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::SeedUse;
+
+    #[test]
+    fn rounds_are_reproducible() {
+        let play = || {
+            let mut csm = ContinuousShuffler::new(CardDeckBuilder::new(), Some([3; 32])).unwrap();
+            let mut seen = Vec::new();
+            for _ in 0..20 {
+                let round = csm.deal_cards(20).unwrap();
+                seen.extend_from_slice(&round);
+                csm.end_round(round);
+            }
+            (seen, csm)
+        };
+
+        let (seen, csm) = play();
+        assert_eq!(seen, play().0);
+        assert_eq!(csm.deck().len(), 52);
+        assert_eq!(csm.deck().seed_history().len(), 21);
+        assert!(matches!(
+            csm.deck().seed_history()[1],
+            SeedUse::MuckReinsert(Some(_))
+        ));
+    }
+}
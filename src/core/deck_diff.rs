@@ -0,0 +1,18 @@
+use super::CardSet;
+
+/// Where the cards of a deck have gone, as returned by `CardDeck::diff()`
+///
+/// The sets never overlap, so each card is in exactly one of them. Decks with multiple copies of
+/// a card, such as shoes, only count whether any copy of a card is in each place, with remaining
+/// copies taking priority over burned copies, then mucked copies, then dealt copies.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct DeckDiff {
+    /// Cards that are still in the deck
+    pub remaining: CardSet,
+    /// Cards that were burned into the muck
+    pub burned: CardSet,
+    /// Cards in the muck that were not burned
+    pub mucked: CardSet,
+    /// Cards that were dealt or removed and are in neither the deck nor the muck
+    pub dealt: CardSet,
+}
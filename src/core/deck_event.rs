@@ -0,0 +1,77 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{Card, PhysicalShuffle};
+
+/// An action that was performed on a `CardDeck`
+///
+/// Events are only recorded once the event log is enabled with `CardDeck::enable_event_log()`.
+/// They are recorded in the order that they occurred, which allows an auditable log of a hand to
+/// be produced.
+///
+/// ```rust
+/// use playing_cards::core::{CardDeck, DeckEvent};
+///
+/// let mut deck: CardDeck = Default::default();
+/// deck.enable_event_log();
+///
+/// deck.shuffle(Some([1; 32])).unwrap();
+/// let burned = deck.burn().unwrap();
+/// let dealt = deck.deal_cards(1, false).unwrap();
+///
+/// assert_eq!(
+///     deck.events().unwrap(),
+///     [
+///         DeckEvent::Shuffled { seed: Some([1; 32]) },
+///         DeckEvent::Burned(burned),
+///         DeckEvent::Dealt(dealt[0]),
+///     ]
+/// );
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DeckEvent {
+    /// The deck was shuffled
+    ///
+    /// The seed is `None` if the deck was shuffled with a generator provided by the caller.
+    Shuffled {
+        /// The seed that the deck was shuffled with
+        seed: Option<[u8; 32]>,
+    },
+    /// The deck was shuffled with a physical shuffle
+    PhysicallyShuffled {
+        /// The kind of shuffle
+        shuffle: PhysicalShuffle,
+        /// The number of passes of the shuffle
+        passes: usize,
+        /// The seed that the deck was shuffled with
+        seed: Option<[u8; 32]>,
+    },
+    /// The deck was cut at a position
+    Cut(usize),
+    /// A card was dealt from the deck
+    Dealt(Card),
+    /// A card was dealt from the deck directly into the muck
+    Burned(Card),
+    /// Cards were placed into the muck
+    Mucked(Vec<Card>),
+    /// A card was taken out of the muck and back into play
+    TakenFromMuck(Card),
+    /// Every card in the muck was removed
+    MuckCleared(Vec<Card>),
+    /// The muck was shuffled and placed underneath the deck
+    MuckReshuffled {
+        /// The seed that the muck was shuffled with
+        seed: Option<[u8; 32]>,
+    },
+    /// The muck was inserted into the deck at random positions
+    MuckReinserted {
+        /// The seed that the positions were chosen with
+        seed: Option<[u8; 32]>,
+    },
+    /// Cards were removed from the deck without being dealt
+    Removed(Vec<Card>),
+    /// Cards were returned to the deck
+    Returned(Vec<Card>),
+}
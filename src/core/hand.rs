@@ -0,0 +1,222 @@
+use std::ops::Deref;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use super::{
+    sort_by_suit, sort_by_value, AceOrdering, Card, CardSet, DisplayCards, ParseCardError,
+};
+
+/// Error type for `Hand`
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Error)]
+pub enum HandError {
+    /// The same card was given more than once
+    #[error("Card {0} appears more than once in the hand")]
+    DuplicateCard(Card),
+    /// One of the cards within a hand string could not be parsed
+    #[error(transparent)]
+    InvalidCard(#[from] ParseCardError),
+}
+
+/// A validated collection of distinct cards
+///
+/// A `Hand` can only be constructed from cards that are all distinct, so any function that
+/// receives one does not need to check for duplicates again. The cards keep the order that they
+/// were given in, while `sorted_by_value()` and `sorted_by_suit()` provide sorted views.
+///
+/// A hand dereferences to a slice of cards, so it can be passed to any of the evaluators directly.
+///
+/// ```rust
+/// use playing_cards::core::{Card, Hand, HandError};
+///
+/// let hand: Hand = "5h 5s 2d Td Ks 5d Ac".parse().unwrap();
+///
+/// assert_eq!(hand.len(), 7);
+/// assert_eq!(Card::vec_from_str("AcKsTd5h5s5d2d").unwrap(), hand.sorted_by_value());
+///
+/// let duplicate = Hand::new(Card::vec_from_str("AsKsAs").unwrap());
+/// assert_eq!(duplicate, Err(HandError::DuplicateCard("As".parse().unwrap())));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Hand {
+    cards: Vec<Card>,
+    set: CardSet,
+}
+
+impl Hand {
+    /// Creates a hand, returning an error if any card appears more than once
+    pub fn new(cards: Vec<Card>) -> Result<Self, HandError> {
+        let mut set = CardSet::new();
+        for &card in &cards {
+            if !set.insert(card) {
+                return Err(HandError::DuplicateCard(card));
+            }
+        }
+
+        Ok(Self { cards, set })
+    }
+
+    /// Returns the cards of the hand in the order that they were given
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Returns the cards of the hand as a `CardSet`
+    pub fn card_set(&self) -> CardSet {
+        self.set
+    }
+
+    /// Returns whether the hand contains the card
+    pub fn contains(&self, card: Card) -> bool {
+        self.set.contains(card)
+    }
+
+    /// Returns the cards sorted from the highest value to the lowest
+    ///
+    /// Cards of the same value keep the order that they were given in.
+    pub fn sorted_by_value(&self) -> Vec<Card> {
+        let mut cards = self.cards.clone();
+        sort_by_value(&mut cards, AceOrdering::High);
+        cards
+    }
+
+    /// Returns the cards grouped by suit, with each suit sorted from the highest value to the
+    /// lowest
+    ///
+    /// Suits are ordered by their numerical representation.
+    pub fn sorted_by_suit(&self) -> Vec<Card> {
+        let mut cards = self.cards.clone();
+        sort_by_suit(&mut cards, AceOrdering::High);
+        cards
+    }
+
+    /// Unwraps the hand, returning its cards
+    pub fn into_inner(self) -> Vec<Card> {
+        self.cards
+    }
+}
+
+impl Deref for Hand {
+    type Target = [Card];
+    fn deref(&self) -> &Self::Target {
+        &self.cards
+    }
+}
+
+impl TryFrom<Vec<Card>> for Hand {
+    type Error = HandError;
+    fn try_from(cards: Vec<Card>) -> Result<Self, Self::Error> {
+        Hand::new(cards)
+    }
+}
+
+impl TryFrom<&[Card]> for Hand {
+    type Error = HandError;
+    fn try_from(cards: &[Card]) -> Result<Self, Self::Error> {
+        Hand::new(cards.to_vec())
+    }
+}
+
+impl From<Hand> for Vec<Card> {
+    fn from(hand: Hand) -> Self {
+        hand.cards
+    }
+}
+
+/// Parses a string of cards, which may be separated by whitespace or commas
+///
+/// Each card is either a value followed by a suit, such as "As", "10h", or "K♥", or a single
+/// playing card glyph such as "🂡".
+impl FromStr for Hand {
+    type Err = HandError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars: Vec<char> = s
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != ',')
+            .collect();
+
+        let mut cards = Vec::new();
+        let mut rest = &chars[..];
+        while !rest.is_empty() {
+            let len = match rest {
+                [glyph, ..] if Card::from_glyph(*glyph).is_some() => 1,
+                ['1', '0', ..] => 3,
+                _ => 2,
+            };
+            let (token, tail) = rest.split_at(len.min(rest.len()));
+            cards.push(token.iter().collect::<String>().parse()?);
+            rest = tail;
+        }
+
+        Hand::new(cards)
+    }
+}
+
+impl IntoIterator for Hand {
+    type Item = Card;
+    type IntoIter = std::vec::IntoIter<Card>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.cards.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Hand {
+    type Item = &'a Card;
+    type IntoIter = std::slice::Iter<'a, Card>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.cards.iter()
+    }
+}
+
+impl std::fmt::Display for Hand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", DisplayCards::new(&self.cards).separator(""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_duplicates() {
+        assert_eq!(
+            "As Kd As".parse::<Hand>(),
+            Err(HandError::DuplicateCard("As".parse().unwrap()))
+        );
+        assert_eq!(
+            "As Kx".parse::<Hand>(),
+            Err(HandError::InvalidCard(ParseCardError::InvalidSuit(
+                "x".to_string()
+            )))
+        );
+        assert!("".parse::<Hand>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn parses_tens_and_glyphs() {
+        let expected: Hand = "Th Js As Kh".parse().unwrap();
+
+        assert_eq!("10h, Js 🂡 K♥".parse::<Hand>(), Ok(expected.clone()));
+        assert_eq!("10hJs🂡K♥".parse::<Hand>(), Ok(expected));
+        assert!("As 10".parse::<Hand>().is_err());
+    }
+
+    #[test]
+    fn sorted_views() {
+        let hand: Hand = "2s,Ah,Ks,2h,Ts".parse().unwrap();
+
+        assert_eq!(hand.to_string(), "2♠A♥K♠2♥T♠");
+        assert_eq!(
+            Hand::new(hand.sorted_by_value()).unwrap().to_string(),
+            "A♥K♠T♠2♠2♥"
+        );
+        assert_eq!(
+            Hand::new(hand.sorted_by_suit()).unwrap().to_string(),
+            "A♥2♥K♠T♠2♠"
+        );
+        assert!(hand.contains("Ks".parse().unwrap()));
+        assert_eq!(hand.card_set().len(), 5);
+    }
+}
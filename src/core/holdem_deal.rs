@@ -0,0 +1,33 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::Card;
+
+/// The cards of a complete Texas Hold'em hand, as dealt by `CardDeck::deal_holdem()`
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HoldemDeal {
+    /// The two hole cards of each player, in seating order starting left of the dealer
+    pub hole_cards: Vec<[Card; 2]>,
+    /// The flop
+    pub flop: [Card; 3],
+    /// The turn
+    pub turn: Card,
+    /// The river
+    pub river: Card,
+    /// The cards burned before the flop, turn, and river
+    pub burns: [Card; 3],
+}
+
+impl HoldemDeal {
+    /// Returns the five community cards
+    pub fn board(&self) -> [Card; 5] {
+        [
+            self.flop[0],
+            self.flop[1],
+            self.flop[2],
+            self.turn,
+            self.river,
+        ]
+    }
+}
@@ -3,5 +3,75 @@
 mod carddeck;
 pub use carddeck::*;
 
+/// The ChaCha20 cryptographically secure pseudorandom generator, for use with
+/// `CardDeck::shuffle_with_seed()`
+///
+/// This requires the `chacha` feature.
+#[cfg(feature = "chacha")]
+pub use rand_chacha::ChaCha20Rng;
+
+mod deck_diff;
+pub use deck_diff::DeckDiff;
+
+mod deck_event;
+pub use deck_event::DeckEvent;
+
+mod physical_shuffle;
+pub use physical_shuffle::PhysicalShuffle;
+
+mod shared_card_deck;
+pub use shared_card_deck::SharedCardDeck;
+
+mod card_deck_builder;
+pub use card_deck_builder::{CardDeckBuilder, DeckComposition};
+
+mod holdem_deal;
+pub use holdem_deal::HoldemDeal;
+
+mod shoe;
+pub use shoe::Shoe;
+
+mod continuous_shuffler;
+pub use continuous_shuffler::ContinuousShuffler;
+
 mod card;
 pub use card::*;
+
+mod ace_ordering;
+pub use ace_ordering::AceOrdering;
+
+mod card_format;
+pub use card_format::{CardFormat, CardStyle, DisplayCards, FormattedCard};
+
+mod card_slice;
+pub use card_slice::{sort_by_suit, sort_by_value, suit_histogram, value_histogram};
+
+mod card_set;
+pub use card_set::{CardSet, CardSetIter};
+
+mod hand;
+pub use hand::{Hand, HandError};
+
+pub mod cards;
+
+pub mod render;
+
+pub mod shuffle_stats;
+
+#[cfg(feature = "provably-fair")]
+pub mod provably_fair;
+
+#[cfg(feature = "proptest")]
+pub mod strategies;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+
+#[doc(hidden)]
+pub mod card_macros;
+
+mod suit_isomorphism;
+pub use suit_isomorphism::{canonicalize_suits, SuitPermutation};
+
+mod parse_card_error;
+pub use parse_card_error::ParseCardError;
@@ -0,0 +1,14 @@
+/// An error that is returned when a string could not be parsed into a `Card`, `Value`, or `Suit`
+#[non_exhaustive]
+#[derive(Debug, Clone, Eq, PartialEq, thiserror::Error)]
+pub enum ParseCardError {
+    /// The card string was not made up of exactly two characters
+    #[error(r#"Card string "{0}" is not exactly a length of 2"#)]
+    InvalidLength(String),
+    /// The value of the card was not a valid character
+    #[error(r#"Card value "{0}" was not a valid character"#)]
+    InvalidValue(String),
+    /// The suit of the card was not a valid character
+    #[error(r#"Card suit "{0}" was not a valid character"#)]
+    InvalidSuit(String),
+}
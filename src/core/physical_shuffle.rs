@@ -0,0 +1,167 @@
+use rand::seq::index::sample;
+use rand::Rng;
+use rand_core::RngCore;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::Card;
+
+/// A model of a shuffle that is performed by hand
+///
+/// Unlike `CardDeck::shuffle()`, which produces a uniformly random permutation, a single pass of
+/// a physical shuffle only partially randomizes the cards. These models can be used to study how
+/// many passes of a real shuffle are needed before a deck is well mixed. For example, seven GSR
+/// riffle shuffles are famously needed to randomize a 52-card deck.
+///
+/// ```rust
+/// use playing_cards::core::{CardDeck, PhysicalShuffle};
+///
+/// let mut deck: CardDeck = Default::default();
+/// deck.physical_shuffle(PhysicalShuffle::Riffle, 7, Some([1; 32])).unwrap();
+///
+/// assert_eq!(deck.count(), 52);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PhysicalShuffle {
+    /// A riffle shuffle using the Gilbert–Shannon–Reeds model
+    ///
+    /// The deck is cut into two packets, with the size of the packets following a binomial
+    /// distribution. The packets are then riffled together, where each card is dropped from a
+    /// packet with a probability proportional to the number of cards remaining in that packet.
+    Riffle,
+    /// An overhand shuffle
+    ///
+    /// Small packets are repeatedly slid off the top of the deck into the other hand, which
+    /// reverses the order of the packets while the order within each packet is kept. Each gap
+    /// between two cards begins a new packet with a probability of 1/4.
+    Overhand,
+    /// A strip shuffle
+    ///
+    /// The deck is cut into between 3 and 6 strips at uniformly random positions and the strips
+    /// are stacked in reverse order, while the order within each strip is kept.
+    Strip,
+}
+
+impl PhysicalShuffle {
+    /// Performs a single pass of the shuffle on the cards
+    ///
+    /// The last card in the slice is treated as the top of the deck, which matches `CardDeck`.
+    pub fn apply<R: RngCore + ?Sized>(self, cards: &mut [Card], rng: &mut R) {
+        if cards.len() < 2 {
+            return;
+        }
+
+        match self {
+            PhysicalShuffle::Riffle => riffle(cards, rng),
+            PhysicalShuffle::Overhand => {
+                let cuts: Vec<usize> = (1..cards.len()).filter(|_| rng.gen_ratio(1, 4)).collect();
+                reverse_packets(cards, &cuts);
+            }
+            PhysicalShuffle::Strip => {
+                let strips = rng.gen_range(3..=6).min(cards.len());
+                let mut cuts: Vec<usize> = sample(rng, cards.len() - 1, strips - 1)
+                    .into_iter()
+                    .map(|i| i + 1)
+                    .collect();
+                cuts.sort_unstable();
+                reverse_packets(cards, &cuts);
+            }
+        }
+    }
+}
+
+fn riffle<R: RngCore + ?Sized>(cards: &mut [Card], rng: &mut R) {
+    let cut = (0..cards.len()).filter(|_| rng.gen::<bool>()).count();
+    let (bottom, top) = cards.split_at(cut);
+    let (mut i, mut j) = (0, 0);
+
+    // cards are dropped from the bottom of each packet
+    let mut riffled = Vec::with_capacity(cards.len());
+    while i < bottom.len() || j < top.len() {
+        let bottom_left = (bottom.len() - i) as u32;
+        let top_left = (top.len() - j) as u32;
+
+        if rng.gen_ratio(bottom_left, bottom_left + top_left) {
+            riffled.push(bottom[i]);
+            i += 1;
+        } else {
+            riffled.push(top[j]);
+            j += 1;
+        }
+    }
+
+    cards.copy_from_slice(&riffled);
+}
+
+/// Reverses the order of the packets, where `cuts` are the sorted indices that each start a new
+/// packet
+fn reverse_packets(cards: &mut [Card], cuts: &[usize]) {
+    let mut bounds = Vec::with_capacity(cuts.len() + 2);
+    bounds.push(0);
+    bounds.extend_from_slice(cuts);
+    bounds.push(cards.len());
+
+    let reordered: Vec<Card> = bounds
+        .windows(2)
+        .rev()
+        .flat_map(|packet| cards[packet[0]..packet[1]].iter().copied())
+        .collect();
+
+    cards.copy_from_slice(&reordered);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{cards::ALL, CardSet};
+    use rand_core::SeedableRng;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+
+    #[test]
+    fn shuffles_keep_every_card() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(2093);
+
+        for shuffle in [
+            PhysicalShuffle::Riffle,
+            PhysicalShuffle::Overhand,
+            PhysicalShuffle::Strip,
+        ] {
+            let mut cards = ALL.to_vec();
+            shuffle.apply(&mut cards, &mut rng);
+
+            assert_eq!(CardSet::from(cards.as_slice()), CardSet::full());
+            assert_ne!(cards, ALL.to_vec());
+
+            let mut few = ALL[..2].to_vec();
+            shuffle.apply(&mut few, &mut rng);
+            assert_eq!(CardSet::from(few.as_slice()).len(), 2);
+        }
+    }
+
+    #[test]
+    fn reversing_packets() {
+        let mut cards = ALL[..6].to_vec();
+        reverse_packets(&mut cards, &[2, 3]);
+
+        assert_eq!(cards, [ALL[3], ALL[4], ALL[5], ALL[2], ALL[0], ALL[1]]);
+    }
+
+    #[test]
+    fn riffle_keeps_packet_order() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(7);
+        let mut cards = ALL.to_vec();
+        PhysicalShuffle::Riffle.apply(&mut cards, &mut rng);
+
+        // a single riffle leaves at most two rising sequences
+        let mut positions = [0; 52];
+        for (i, card) in cards.iter().enumerate() {
+            positions[u8::from(*card) as usize] = i;
+        }
+        let breaks = positions
+            .windows(2)
+            .filter(|pair| pair[1] < pair[0])
+            .count();
+        assert!(breaks <= 1);
+    }
+}
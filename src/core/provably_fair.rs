@@ -0,0 +1,216 @@
+//! Provably fair shuffling with a commit-reveal scheme
+//!
+//! Before any cards are dealt, the dealer publishes a commitment, which is the SHA-256 digest of
+//! the 32-byte secret seed followed by a nonce. The nonce should be unique to each hand, such as
+//! a hand number or a value chosen by the players. Once the hand is over, the dealer reveals the
+//! seed, and anyone can check that it matches the commitment and that replaying the shuffle
+//! produces the cards that were dealt. Since the commitment was published before the deal, the
+//! dealer could not have picked the seed after seeing the players' actions.
+//!
+//! The deck is shuffled with the cryptographically secure ChaCha20 generator, seeded with
+//! `shuffle_seed()`. This mixes the nonce into the shuffle, so the same secret seed gives an
+//! unrelated deck for every hand.
+//!
+//! This module requires the `provably-fair` feature, which enables the `chacha` feature.
+//!
+//! ```rust
+//! use playing_cards::core::{provably_fair, CardDeckBuilder};
+//!
+//! // the dealer commits to a shuffle
+//! let shuffle = provably_fair::CommittedShuffle::new(b"hand #1").unwrap();
+//! let commitment = shuffle.commitment();
+//!
+//! // the hand is dealt
+//! let mut deck = shuffle.deck(CardDeckBuilder::new()).unwrap();
+//! let dealt = deck.deal_cards(9, false).unwrap();
+//!
+//! // after the hand, the seed is revealed and the deal is verified
+//! assert!(provably_fair::verify_deal(
+//!     &commitment,
+//!     &shuffle.seed(),
+//!     b"hand #1",
+//!     CardDeckBuilder::new(),
+//!     &dealt,
+//! ));
+//! ```
+
+use sha2::{Digest, Sha256};
+
+use super::{Card, CardDeck, CardDeckBuilder, CardDeckError, ShuffleGenerator};
+
+/// A shuffle seed that has been committed to
+///
+/// The seed must be kept secret until the hand is over. The nonce and commitment can be published
+/// before dealing. To avoid leaking the seed into logs, `Debug` omits it and the struct cannot be
+/// serialized.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct CommittedShuffle {
+    seed: [u8; 32],
+    nonce: Vec<u8>,
+}
+
+impl CommittedShuffle {
+    /// Commits to a seed sampled from entropy
+    ///
+    /// An error will return if sampling entropy fails.
+    pub fn new(nonce: &[u8]) -> Result<Self, CardDeckError> {
+        Ok(Self::with_seed(CardDeck::seed_or_entropy(None)?, nonce))
+    }
+
+    /// Commits to a specific seed
+    pub fn with_seed(seed: [u8; 32], nonce: &[u8]) -> Self {
+        Self {
+            seed,
+            nonce: nonce.to_vec(),
+        }
+    }
+
+    /// Returns the commitment to publish before dealing
+    pub fn commitment(&self) -> [u8; 32] {
+        commitment(&self.seed, &self.nonce)
+    }
+
+    /// Returns the seed, which should only be revealed once the hand is over
+    pub fn seed(&self) -> [u8; 32] {
+        self.seed
+    }
+
+    /// Returns the nonce
+    pub fn nonce(&self) -> &[u8] {
+        &self.nonce
+    }
+
+    /// Builds the deck from `builder` and shuffles it with the committed seed
+    ///
+    /// Any shuffle configured on the builder is replaced. An error will return if building or
+    /// shuffling the deck fails.
+    pub fn deck(&self, builder: CardDeckBuilder) -> Result<CardDeck, CardDeckError> {
+        shuffled_deck(&self.seed, &self.nonce, builder)
+    }
+}
+
+impl std::fmt::Debug for CommittedShuffle {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CommittedShuffle")
+            .field("nonce", &self.nonce)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Computes the commitment to a seed, which is the SHA-256 digest of the seed followed by the
+/// nonce
+pub fn commitment(seed: &[u8; 32], nonce: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(seed);
+    hasher.update(nonce);
+    hasher.finalize().into()
+}
+
+/// Computes the seed that the deck is shuffled with, which is the SHA-256 digest of a
+/// `"shuffle"` tag, the secret seed, and the nonce
+///
+/// The tag keeps the shuffle seed distinct from the published commitment, which is the digest of
+/// the same seed and nonce without it.
+pub fn shuffle_seed(seed: &[u8; 32], nonce: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"shuffle");
+    hasher.update(seed);
+    hasher.update(nonce);
+    hasher.finalize().into()
+}
+
+fn shuffled_deck(
+    seed: &[u8; 32],
+    nonce: &[u8],
+    builder: CardDeckBuilder,
+) -> Result<CardDeck, CardDeckError> {
+    builder
+        .shuffled_with_generator(ShuffleGenerator::ChaCha20, Some(shuffle_seed(seed, nonce)))
+        .build()
+}
+
+/// Checks that a revealed seed and nonce match a commitment
+pub fn verify_commitment(commitment: &[u8; 32], seed: &[u8; 32], nonce: &[u8]) -> bool {
+    self::commitment(seed, nonce) == *commitment
+}
+
+/// Checks that a revealed seed matches a commitment and produces the cards that were dealt
+///
+/// The deck is rebuilt from `builder` and shuffled like `CommittedShuffle::deck()`, and `dealt`
+/// must be the cards
+/// from the top of that deck in the order they were dealt, including any burned cards.
+pub fn verify_deal(
+    commitment: &[u8; 32],
+    seed: &[u8; 32],
+    nonce: &[u8],
+    builder: CardDeckBuilder,
+    dealt: &[Card],
+) -> bool {
+    if !verify_commitment(commitment, seed, nonce) {
+        return false;
+    }
+
+    match shuffled_deck(seed, nonce, builder) {
+        Ok(deck) => deck.len() >= dealt.len() && deck.iter().zip(dealt).all(|(a, b)| a == b),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::SeedUse;
+
+    #[test]
+    fn tampering_is_detected() {
+        let shuffle = CommittedShuffle::with_seed([8; 32], b"table 3, hand 17");
+        let commitment = shuffle.commitment();
+        let mut deck = shuffle.deck(CardDeckBuilder::new().decks(2)).unwrap();
+        let mut dealt = deck.deal_cards(20, false).unwrap();
+
+        let verify = |seed: &[u8; 32], nonce: &[u8], dealt: &[Card]| {
+            verify_deal(
+                &commitment,
+                seed,
+                nonce,
+                CardDeckBuilder::new().decks(2),
+                dealt,
+            )
+        };
+
+        assert!(verify(&[8; 32], b"table 3, hand 17", &dealt));
+        assert!(!verify(&[9; 32], b"table 3, hand 17", &dealt));
+        assert!(!verify(&[8; 32], b"table 3, hand 18", &dealt));
+
+        dealt.swap(0, 1);
+        assert!(!verify(&[8; 32], b"table 3, hand 17", &dealt));
+
+        let whole_deck: Vec<Card> = shuffle.deck(CardDeckBuilder::new()).unwrap().collect();
+        assert!(!verify(&[8; 32], b"table 3, hand 17", &whole_deck));
+    }
+
+    #[test]
+    fn shuffles_with_chacha_and_the_nonce() {
+        let shuffle = CommittedShuffle::with_seed([8; 32], b"hand 1");
+        let deck = shuffle.deck(CardDeckBuilder::new()).unwrap();
+
+        assert_eq!(
+            deck.seed_history(),
+            [SeedUse::Shuffle {
+                seed: Some(shuffle_seed(&[8; 32], b"hand 1")),
+                generator: Some(ShuffleGenerator::ChaCha20),
+            }]
+        );
+        assert_ne!(shuffle_seed(&[8; 32], b"hand 1"), shuffle.commitment());
+
+        let next_hand = CommittedShuffle::with_seed([8; 32], b"hand 2");
+        assert_ne!(
+            deck.collect::<Vec<_>>(),
+            next_hand
+                .deck(CardDeckBuilder::new())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+        assert!(!format!("{:?}", shuffle).contains("seed"));
+    }
+}
@@ -0,0 +1,118 @@
+//! Terminal rendering of cards
+//!
+//! Cards can be rendered either as compact strings colored with ANSI escape codes or as small
+//! ASCII-art boxes. Red cards (hearts and diamonds) are colored red, while black cards use the
+//! terminal's default color.
+//!
+//! ```rust
+//! use playing_cards::core::{render, Card};
+//!
+//! let hand = Card::vec_from_str("AsTh").unwrap();
+//!
+//! assert_eq!(render::ansi(&hand), "A♠ \x1b[31mT♥\x1b[0m");
+//! assert_eq!(
+//!     render::ascii_art(&hand, false),
+//!     [
+//!         "+-----+ +-----+",
+//!         "|A    | |10   |",
+//!         "|  ♠  | |  ♥  |",
+//!         "|    A| |   10|",
+//!         "+-----+ +-----+",
+//!     ]
+//!     .join("\n")
+//! );
+//! ```
+
+use super::{Card, Value};
+
+const RED: &str = "\x1b[31m";
+const RESET: &str = "\x1b[0m";
+
+/// Renders a single card as a compact string, colored red with ANSI escape codes if it is red
+pub fn ansi_card(card: &Card) -> String {
+    colorize(card, &card.to_string(), true)
+}
+
+/// Renders cards as compact strings separated by spaces, coloring the red cards with ANSI escape
+/// codes
+pub fn ansi(cards: &[Card]) -> String {
+    cards.iter().map(ansi_card).collect::<Vec<_>>().join(" ")
+}
+
+/// Renders cards as ASCII-art boxes placed side by side
+///
+/// Each card is 5 lines tall and 7 characters wide, with a space between each card. If `colored`
+/// is set, the contents of red cards are colored with ANSI escape codes. An empty string is
+/// returned if no cards are given.
+pub fn ascii_art(cards: &[Card], colored: bool) -> String {
+    if cards.is_empty() {
+        return String::new();
+    }
+
+    let boxes: Vec<[String; 5]> = cards.iter().map(|card| card_box(card, colored)).collect();
+
+    (0..5)
+        .map(|line| {
+            boxes
+                .iter()
+                .map(|card_box| card_box[line].as_str())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn card_box(card: &Card, colored: bool) -> [String; 5] {
+    let value = match card.value {
+        Value::Ten => "10".to_string(),
+        value => value.get_char().to_string(),
+    };
+    let center = if card.is_joker() {
+        "JOKER".to_string()
+    } else {
+        format!("  {}  ", card.suit)
+    };
+
+    let line = |contents: String| format!("|{}|", colorize(card, &contents, colored));
+
+    [
+        "+-----+".to_string(),
+        line(format!("{:<5}", value)),
+        line(center),
+        line(format!("{:>5}", value)),
+        "+-----+".to_string(),
+    ]
+}
+
+fn colorize(card: &Card, s: &str, colored: bool) -> String {
+    if colored && card.is_red() {
+        format!("{}{}{}", RED, s, RESET)
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cards::{BLACK_JOKER, RED_JOKER};
+
+    #[test]
+    fn jokers_and_colors() {
+        assert_eq!(
+            ascii_art(&[RED_JOKER], true),
+            [
+                "+-----+",
+                "|\x1b[31mX    \x1b[0m|",
+                "|\x1b[31mJOKER\x1b[0m|",
+                "|\x1b[31m    X\x1b[0m|",
+                "+-----+",
+            ]
+            .join("\n")
+        );
+        assert_eq!(ansi_card(&BLACK_JOKER), "X♠");
+        assert_eq!(ascii_art(&[], true), "");
+        assert_eq!(ansi(&[]), "");
+    }
+}
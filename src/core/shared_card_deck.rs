@@ -0,0 +1,172 @@
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use super::{Card, CardDeck, CardDeckError};
+
+/// A handle to a `CardDeck` that can be shared between threads
+///
+/// Cloning the handle does not clone the deck, so every clone deals from the same cards. Each
+/// method locks the deck for the duration of the call, so a deal never interleaves with another
+/// deal. For compound operations that must not be interrupted, such as burning a card and then
+/// dealing the flop, use `with()`.
+///
+/// ```rust
+/// use playing_cards::core::{CardDeck, SharedCardDeck};
+/// use std::thread;
+///
+/// let deck = SharedCardDeck::new(CardDeck::new(Some([1; 32])).unwrap());
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let deck = deck.clone();
+///         thread::spawn(move || deck.deal_cards(2, false).unwrap())
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap().len(), 2);
+/// }
+/// assert_eq!(deck.len(), 44);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SharedCardDeck {
+    deck: Arc<Mutex<CardDeck>>,
+}
+
+impl SharedCardDeck {
+    /// Creates a handle that shares the deck
+    pub fn new(deck: CardDeck) -> Self {
+        Self {
+            deck: Arc::new(Mutex::new(deck)),
+        }
+    }
+
+    /// Runs `f` with exclusive access to the deck
+    ///
+    /// No other handle can use the deck until `f` returns.
+    ///
+    /// ```rust
+    /// use playing_cards::core::{CardDeck, SharedCardDeck};
+    ///
+    /// let deck = SharedCardDeck::new(Default::default());
+    ///
+    /// let flop = deck.with(|deck| {
+    ///     deck.burn()?;
+    ///     deck.deal_cards(3, false)
+    /// });
+    /// assert_eq!(flop.unwrap().len(), 3);
+    /// ```
+    pub fn with<T>(&self, f: impl FnOnce(&mut CardDeck) -> T) -> T {
+        f(&mut self.lock())
+    }
+
+    /// Locks the deck
+    ///
+    /// If a thread panicked while holding the lock, the deck is still returned. Every method on
+    /// `CardDeck` leaves the deck in a valid state, so the deck remains usable.
+    fn lock(&self) -> MutexGuard<'_, CardDeck> {
+        self.deck.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Shuffles the deck
+    ///
+    /// See `CardDeck::shuffle()`.
+    pub fn shuffle(&self, seed: Option<[u8; 32]>) -> Result<(), CardDeckError> {
+        self.lock().shuffle(seed)
+    }
+
+    /// Deals `n` cards out from the deck
+    ///
+    /// See `CardDeck::deal_cards()`.
+    pub fn deal_cards(&self, cards_to_deal: usize, include_muck: bool) -> Option<Vec<Card>> {
+        self.lock().deal_cards(cards_to_deal, include_muck)
+    }
+
+    /// Deals up to `n` cards out from the deck
+    ///
+    /// See `CardDeck::deal_up_to()`.
+    pub fn deal_up_to(&self, n: usize) -> Vec<Card> {
+        self.lock().deal_up_to(n)
+    }
+
+    /// Deals `cards_each` cards to each of `num_players` players, one card at a time
+    ///
+    /// See `CardDeck::deal_to_players()`.
+    pub fn deal_to_players(&self, num_players: usize, cards_each: usize) -> Option<Vec<Vec<Card>>> {
+        self.lock().deal_to_players(num_players, cards_each)
+    }
+
+    /// Deals the top card of the deck face down directly into the muck
+    ///
+    /// See `CardDeck::burn()`.
+    pub fn burn(&self) -> Option<Card> {
+        self.lock().burn()
+    }
+
+    /// Adds the inputted cards into the muck
+    ///
+    /// See `CardDeck::muck_cards()`.
+    pub fn muck_cards(&self, cards: Vec<Card>) {
+        self.lock().muck_cards(cards)
+    }
+
+    /// Returns the number of cards remaining in the deck, excluding the muck
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns whether there are no cards remaining in the deck, excluding the muck
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Returns a copy of the deck in its current state
+    pub fn snapshot(&self) -> CardDeck {
+        self.lock().clone()
+    }
+}
+
+impl From<CardDeck> for SharedCardDeck {
+    fn from(deck: CardDeck) -> Self {
+        Self::new(deck)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::CardSet;
+    use std::thread;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn decks_are_send_and_sync() {
+        assert_send_sync::<CardDeck>();
+        assert_send_sync::<SharedCardDeck>();
+    }
+
+    #[test]
+    fn concurrent_deals_never_share_cards() {
+        let deck = SharedCardDeck::from(CardDeck::new(Some([4; 32])).unwrap());
+
+        let hands: Vec<Vec<Card>> = (0..8)
+            .map(|_| {
+                let deck = deck.clone();
+                thread::spawn(move || {
+                    let mut cards = Vec::new();
+                    for _ in 0..3 {
+                        cards.extend(deck.deal_cards(2, false).unwrap());
+                    }
+                    cards
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect();
+
+        let dealt: Vec<Card> = hands.concat();
+        assert_eq!(CardSet::from(dealt.as_slice()).len(), 48);
+        assert_eq!(deck.snapshot().len(), 4);
+    }
+}
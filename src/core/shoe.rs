@@ -0,0 +1,160 @@
+use super::{Card, CardDeck, CardDeckBuilder, CardDeckError};
+
+/// A dealing shoe with a cut card, as used in blackjack
+///
+/// The shoe is built from a `CardDeckBuilder` and shuffled. A cut card is placed `cut_card` cards
+/// from the top, and once it is reached, `cut_card_reached()` returns true to signal that the
+/// shoe should be reshuffled after the current round. Cards can still be dealt past the cut card
+/// so that the round can be finished.
+///
+/// ```rust
+/// use playing_cards::core::{CardDeckBuilder, Shoe};
+///
+/// // a 6 deck shoe with the cut card placed after 234 cards (75% penetration)
+/// let mut shoe = Shoe::new(CardDeckBuilder::new().decks(6), 234, Some([1; 32])).unwrap();
+///
+/// while !shoe.cut_card_reached() {
+///     shoe.deal_cards(10).unwrap();
+/// }
+/// assert_eq!(shoe.cards_dealt(), 240);
+/// assert!(shoe.penetration() > 0.75);
+///
+/// shoe.shuffle(None).unwrap();
+/// assert_eq!(shoe.cards_dealt(), 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Shoe {
+    builder: CardDeckBuilder,
+    deck: CardDeck,
+    size: usize,
+    cut_card: usize,
+}
+
+impl Shoe {
+    /// Builds and shuffles a shoe, with the cut card placed `cut_card` cards from the top
+    ///
+    /// An `InvalidCutPosition` error is returned if the cut card is placed past the bottom of the
+    /// shoe. An error will also return if building or shuffling the deck fails.
+    pub fn new(
+        builder: CardDeckBuilder,
+        cut_card: usize,
+        seed: Option<[u8; 32]>,
+    ) -> Result<Self, CardDeckError> {
+        let deck = builder.clone().shuffled(seed).build()?;
+        let size = deck.len();
+        if cut_card > size {
+            return Err(CardDeckError::InvalidCutPosition(cut_card));
+        }
+
+        Ok(Self {
+            builder,
+            deck,
+            size,
+            cut_card,
+        })
+    }
+
+    /// Collects every card and reshuffles the shoe, keeping the cut card at the same position
+    ///
+    /// Like `CardDeck::shuffle()`, an optional seed can be provided. If no seed is provided, a
+    /// seed will be sampled from entropy.
+    pub fn shuffle(&mut self, seed: Option<[u8; 32]>) -> Result<(), CardDeckError> {
+        self.deck = self.builder.clone().shuffled(seed).build()?;
+        Ok(())
+    }
+
+    /// Deals a single card, or `None` if the shoe is empty
+    pub fn deal(&mut self) -> Option<Card> {
+        self.deck.next()
+    }
+
+    /// Deals `n` cards, or `None` without dealing any cards if there are not enough cards left
+    pub fn deal_cards(&mut self, n: usize) -> Option<Vec<Card>> {
+        self.deck.deal_cards(n, false)
+    }
+
+    /// Returns whether the cut card has been reached and the shoe should be reshuffled
+    pub fn cut_card_reached(&self) -> bool {
+        self.cards_dealt() >= self.cut_card
+    }
+
+    /// Returns the number of cards that are dealt before the cut card is reached
+    pub fn cut_card(&self) -> usize {
+        self.cut_card
+    }
+
+    /// Moves the cut card to be `cut_card` cards from the top of the shoe
+    ///
+    /// An `InvalidCutPosition` error is returned if the cut card would be past the bottom of the
+    /// shoe.
+    pub fn set_cut_card(&mut self, cut_card: usize) -> Result<(), CardDeckError> {
+        if cut_card > self.size {
+            return Err(CardDeckError::InvalidCutPosition(cut_card));
+        }
+
+        self.cut_card = cut_card;
+        Ok(())
+    }
+
+    /// Returns the number of cards dealt since the shoe was last shuffled
+    pub fn cards_dealt(&self) -> usize {
+        self.size - self.deck.len()
+    }
+
+    /// Returns the number of cards remaining in the shoe
+    pub fn len(&self) -> usize {
+        self.deck.len()
+    }
+
+    /// Returns whether every card in the shoe has been dealt
+    pub fn is_empty(&self) -> bool {
+        self.deck.is_empty()
+    }
+
+    /// Returns the number of cards in the shoe when it is full
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the fraction of the shoe that has been dealt, from 0 to 1
+    pub fn penetration(&self) -> f64 {
+        if self.size == 0 {
+            return 0.0;
+        }
+
+        self.cards_dealt() as f64 / self.size as f64
+    }
+
+    /// Gets the cards remaining in the shoe
+    pub fn deck(&self) -> &CardDeck {
+        &self.deck
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cut_card_positions() {
+        let builder = CardDeckBuilder::new().decks(2);
+        assert!(matches!(
+            Shoe::new(builder.clone(), 105, None),
+            Err(CardDeckError::InvalidCutPosition(105))
+        ));
+
+        let mut shoe = Shoe::new(builder, 0, Some([2; 32])).unwrap();
+        assert!(shoe.cut_card_reached());
+
+        shoe.set_cut_card(104).unwrap();
+        assert!(shoe.set_cut_card(105).is_err());
+        assert_eq!(shoe.deal_cards(103).unwrap().len(), 103);
+        assert!(!shoe.cut_card_reached());
+
+        shoe.deal().unwrap();
+        assert!(shoe.cut_card_reached());
+        assert!(shoe.is_empty());
+        assert_eq!(shoe.deal(), None);
+        assert_eq!(shoe.penetration(), 1.0);
+    }
+}
@@ -0,0 +1,230 @@
+//! Statistics for measuring how well a shuffle randomizes a deck
+//!
+//! The statistics work on permutations, where `permutation[i]` is the original position of the
+//! card that is now at position `i`. `permutation()` converts a shuffled order of cards into a
+//! permutation. These can be used to validate custom random number generators and to study the
+//! physical shuffles in `PhysicalShuffle`.
+//!
+//! ```rust
+//! use playing_cards::core::{shuffle_stats, CardDeck, PhysicalShuffle};
+//!
+//! let original: Vec<_> = CardDeck::default().iter().copied().collect();
+//!
+//! let mut deck = CardDeck::default();
+//! deck.physical_shuffle(PhysicalShuffle::Riffle, 1, Some([1; 32])).unwrap();
+//! let shuffled: Vec<_> = deck.iter().copied().collect();
+//!
+//! let permutation = shuffle_stats::permutation(&original, &shuffled).unwrap();
+//!
+//! // a single riffle shuffle leaves at most two rising sequences
+//! assert!(shuffle_stats::rising_sequences(&permutation) <= 2);
+//! ```
+
+use super::Card;
+
+/// Finds the permutation that rearranges `original` into `shuffled`
+///
+/// If a card appears multiple times, the copies keep their relative order. Returns `None` if
+/// `shuffled` does not contain exactly the same cards as `original`.
+pub fn permutation(original: &[Card], shuffled: &[Card]) -> Option<Vec<usize>> {
+    if original.len() != shuffled.len() {
+        return None;
+    }
+
+    let mut used = vec![false; original.len()];
+    shuffled
+        .iter()
+        .map(|card| {
+            let position = original
+                .iter()
+                .enumerate()
+                .position(|(i, c)| c == card && !used[i])?;
+            used[position] = true;
+            Some(position)
+        })
+        .collect()
+}
+
+/// Counts the rising sequences of a permutation
+///
+/// A rising sequence is a maximal run of consecutive original positions that still appear in
+/// increasing order. An unshuffled deck has a single rising sequence, each riffle shuffle at most
+/// doubles the number of rising sequences, and a uniformly shuffled deck of `n` cards has about
+/// `(n + 1) / 2` on average.
+pub fn rising_sequences(permutation: &[usize]) -> usize {
+    if permutation.is_empty() {
+        return 0;
+    }
+
+    let mut positions = vec![0; permutation.len()];
+    for (i, original) in permutation.iter().enumerate() {
+        positions[*original] = i;
+    }
+
+    1 + positions
+        .windows(2)
+        .filter(|pair| pair[1] < pair[0])
+        .count()
+}
+
+/// Counts the pairs of cards that were adjacent before the shuffle and are still adjacent in the
+/// same order
+///
+/// A uniformly shuffled deck of `n` cards has `(n - 1) / n` such pairs on average, so values much
+/// larger than 1 suggest that clumps of cards are surviving the shuffle.
+pub fn adjacent_pairs(permutation: &[usize]) -> usize {
+    permutation
+        .windows(2)
+        .filter(|pair| pair[1] == pair[0] + 1)
+        .count()
+}
+
+/// A chi-square test of where each card ends up over many shuffles
+///
+/// For a uniform shuffle, every card is equally likely to end up at every position. After adding
+/// the permutations from many shuffles, `statistic()` measures how far the observed counts are
+/// from this. The statistic has `degrees_of_freedom()` degrees of freedom, so a uniform shuffle
+/// produces a statistic close to that number.
+///
+/// ```rust
+/// use playing_cards::core::{shuffle_stats::PositionalChiSquare, CardDeck};
+/// use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+///
+/// let original: Vec<_> = CardDeck::default().iter().copied().collect();
+/// let mut test = PositionalChiSquare::new(52);
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+///
+/// for _ in 0..1000 {
+///     let mut deck = CardDeck::default();
+///     deck.shuffle_with_rng(&mut rng);
+///     let shuffled: Vec<_> = deck.iter().copied().collect();
+///     test.add(&playing_cards::core::shuffle_stats::permutation(&original, &shuffled).unwrap());
+/// }
+///
+/// assert!(test.statistic() < 1.2 * test.degrees_of_freedom() as f64);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PositionalChiSquare {
+    size: usize,
+    counts: Vec<u64>,
+    trials: u64,
+}
+
+impl PositionalChiSquare {
+    /// Creates a test for permutations of `size` cards
+    pub fn new(size: usize) -> Self {
+        Self {
+            size,
+            counts: vec![0; size * size],
+            trials: 0,
+        }
+    }
+
+    /// Adds the permutation from a single shuffle
+    ///
+    /// # Panics
+    ///
+    /// Panics if the permutation is not of the size the test was created with.
+    pub fn add(&mut self, permutation: &[usize]) {
+        assert_eq!(
+            permutation.len(),
+            self.size,
+            "Permutation must contain {} cards",
+            self.size
+        );
+
+        for (position, original) in permutation.iter().enumerate() {
+            self.counts[original * self.size + position] += 1;
+        }
+        self.trials += 1;
+    }
+
+    /// Returns the number of permutations that have been added
+    pub fn trials(&self) -> u64 {
+        self.trials
+    }
+
+    /// Returns the chi-square statistic of the observed positions
+    ///
+    /// Returns 0 if no permutations have been added.
+    pub fn statistic(&self) -> f64 {
+        if self.trials == 0 {
+            return 0.0;
+        }
+
+        let expected = self.trials as f64 / self.size as f64;
+        self.counts
+            .iter()
+            .map(|count| (*count as f64 - expected).powi(2) / expected)
+            .sum()
+    }
+
+    /// Returns the degrees of freedom of the statistic
+    pub fn degrees_of_freedom(&self) -> usize {
+        self.size.saturating_sub(1).pow(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{cards::ALL, CardDeck, PhysicalShuffle};
+    use rand_core::{RngCore, SeedableRng};
+    use rand_xoshiro::Xoshiro256PlusPlus;
+
+    #[test]
+    fn unshuffled_and_shuffled_decks() {
+        let identity: Vec<usize> = (0..52).collect();
+        assert_eq!(rising_sequences(&identity), 1);
+        assert_eq!(adjacent_pairs(&identity), 51);
+
+        let reversed: Vec<usize> = (0..52).rev().collect();
+        assert_eq!(rising_sequences(&reversed), 52);
+        assert_eq!(adjacent_pairs(&reversed), 0);
+        assert_eq!(rising_sequences(&[]), 0);
+
+        assert_eq!(permutation(&ALL[..3], &ALL[1..4]), None);
+        assert_eq!(
+            permutation(&[ALL[0], ALL[0], ALL[1]], &[ALL[1], ALL[0], ALL[0]]),
+            Some(vec![2, 0, 1])
+        );
+    }
+
+    #[test]
+    fn overhand_shuffles_are_not_uniform() {
+        let original: Vec<Card> = CardDeck::default().iter().copied().collect();
+        let mut overhand = PositionalChiSquare::new(52);
+        let mut seeds = Xoshiro256PlusPlus::seed_from_u64(2108);
+
+        for _ in 0..256 {
+            let mut seed = [0; 32];
+            seeds.fill_bytes(&mut seed);
+            let mut deck = CardDeck::default();
+            deck.physical_shuffle(PhysicalShuffle::Overhand, 3, Some(seed))
+                .unwrap();
+            let shuffled: Vec<Card> = deck.iter().copied().collect();
+            overhand.add(&permutation(&original, &shuffled).unwrap());
+        }
+
+        assert_eq!(overhand.trials(), 256);
+        assert!(overhand.statistic() > 2.0 * overhand.degrees_of_freedom() as f64);
+    }
+
+    #[test]
+    fn seeded_shuffles_are_uniform() {
+        let original: Vec<Card> = CardDeck::default().iter().copied().collect();
+        let mut uniform = PositionalChiSquare::new(52);
+
+        // sparse seeds, such as a counter, must not bias the shuffle
+        for i in 0..2000u64 {
+            let mut seed = [0; 32];
+            seed[..8].copy_from_slice(&i.to_le_bytes());
+            let mut deck = CardDeck::default();
+            deck.shuffle(Some(seed)).unwrap();
+            let shuffled: Vec<Card> = deck.iter().copied().collect();
+            uniform.add(&permutation(&original, &shuffled).unwrap());
+        }
+
+        assert!(uniform.statistic() < 1.2 * uniform.degrees_of_freedom() as f64);
+    }
+}
@@ -0,0 +1,91 @@
+//! Strategies for property testing with `proptest`
+//!
+//! These generate cards from a standard 52-card deck, so jokers are never produced. Collections
+//! of cards never contain the same card twice, making them valid inputs for the evaluators. This
+//! module requires the `proptest` feature.
+//!
+//! ```rust
+//! use playing_cards::core::{strategies, Card};
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(cards in strategies::distinct_cards(7))| {
+//!     prop_assert_eq!(cards.len(), 7);
+//!     prop_assert_eq!(playing_cards::core::CardSet::from(cards).len(), 7);
+//! });
+//!
+//! proptest!(|(card in any::<Card>())| {
+//!     prop_assert_eq!(card.to_string().parse::<Card>(), Ok(card));
+//! });
+//! ```
+
+use proptest::prelude::*;
+use proptest::sample::{subsequence, SizeRange};
+
+use super::{cards::ALL, Card, CardSet, Hand};
+
+/// Generates any card from a standard 52-card deck
+pub fn card() -> impl Strategy<Value = Card> {
+    (0..52u8).prop_map(|n| Card::try_from(n).unwrap())
+}
+
+/// Generates distinct cards in a random order, with a length within the given range
+///
+/// # Panics
+///
+/// Panics if the range allows for more than 52 cards.
+pub fn distinct_cards(size: impl Into<SizeRange>) -> impl Strategy<Value = Vec<Card>> {
+    subsequence(ALL.to_vec(), size).prop_shuffle()
+}
+
+/// Generates a hand of distinct cards, with a length within the given range
+///
+/// # Panics
+///
+/// Panics if the range allows for more than 52 cards.
+pub fn hand(size: impl Into<SizeRange>) -> impl Strategy<Value = Hand> {
+    distinct_cards(size).prop_map(|cards| Hand::new(cards).unwrap())
+}
+
+/// Generates a set of cards, with a length within the given range
+///
+/// # Panics
+///
+/// Panics if the range allows for more than 52 cards.
+pub fn card_set(size: impl Into<SizeRange>) -> impl Strategy<Value = CardSet> {
+    subsequence(ALL.to_vec(), size).prop_map(CardSet::from)
+}
+
+impl Arbitrary for Card {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Card>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        card().boxed()
+    }
+}
+
+/// Hands are generated with between 0 and 7 cards by default
+impl Arbitrary for Hand {
+    type Parameters = SizeRange;
+    type Strategy = BoxedStrategy<Hand>;
+
+    fn arbitrary() -> Self::Strategy {
+        hand(0..=7).boxed()
+    }
+
+    fn arbitrary_with(size: Self::Parameters) -> Self::Strategy {
+        hand(size).boxed()
+    }
+}
+
+/// Sets may contain any number of cards from a standard 52-card deck
+impl Arbitrary for CardSet {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<CardSet>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<u64>()
+            .prop_map(|bits| CardSet::from_bits(bits & CardSet::full().bits()).unwrap())
+            .boxed()
+    }
+}
@@ -0,0 +1,173 @@
+use std::cmp::Reverse;
+
+use num_traits::FromPrimitive;
+
+use super::{Card, Suit};
+
+/// A relabelling of the four suits
+///
+/// This is returned by `canonicalize_suits()` to record how the suits of the original cards were
+/// relabelled, allowing results computed on the canonical cards to be mapped back.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SuitPermutation([Suit; 4]);
+
+impl SuitPermutation {
+    /// Returns the permutation that leaves every suit unchanged
+    pub fn identity() -> Self {
+        Self([Suit::Heart, Suit::Club, Suit::Diamond, Suit::Spade])
+    }
+
+    /// Returns the suit that the given suit is relabelled to
+    pub fn apply_suit(&self, suit: Suit) -> Suit {
+        self.0[suit as usize]
+    }
+
+    /// Relabels the suit of a card
+    ///
+    /// Jokers are returned unchanged, since their suit only determines their color.
+    pub fn apply(&self, card: Card) -> Card {
+        if card.is_joker() {
+            return card;
+        }
+
+        Card::new(card.value, self.apply_suit(card.suit))
+    }
+
+    /// Returns the permutation that undoes this one
+    pub fn inverse(&self) -> Self {
+        let mut inverse = Self::identity();
+        for (from, to) in self.0.iter().enumerate() {
+            inverse.0[*to as usize] = Suit::from_usize(from).unwrap();
+        }
+        inverse
+    }
+}
+
+impl Default for SuitPermutation {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Relabels the suits of groups of cards so that strategically identical holdings are identical
+///
+/// Suits have no strength of their own, so two holdings that only differ by a relabelling of the
+/// suits (e.g. A♥K♥ and A♠K♠) are strategically identical. This function maps every such
+/// holding to the same representative, which can be used as a key when caching or precomputing
+/// results.
+///
+/// The groups are typically the hole cards followed by the board, as the same cards can have a
+/// different meaning in each group. The order of the groups matters, but the order of the cards
+/// within each group does not: the canonical cards of each group are sorted from the highest
+/// value to the lowest. Suits are relabelled in numerical order (hearts first), with the suit
+/// holding the highest cards in the earliest group taking the first label.
+///
+/// The permutation that was applied is returned alongside the canonical cards. Jokers are left
+/// unchanged.
+///
+/// ```rust
+/// use playing_cards::core::{canonicalize_suits, Card};
+///
+/// let hand1 = Card::vec_from_str("AhKh").unwrap();
+/// let board1 = Card::vec_from_str("2h7dQc").unwrap();
+///
+/// let hand2 = Card::vec_from_str("KsAs").unwrap();
+/// let board2 = Card::vec_from_str("Qh2s7c").unwrap();
+///
+/// let (canonical1, _) = canonicalize_suits(&[&hand1, &board1]);
+/// let (canonical2, permutation) = canonicalize_suits(&[&hand2, &board2]);
+///
+/// assert_eq!(canonical1, canonical2);
+/// assert_eq!(permutation.inverse().apply(canonical2[0][0]), hand2[1]);
+/// ```
+pub fn canonicalize_suits(groups: &[&[Card]]) -> (Vec<Vec<Card>>, SuitPermutation) {
+    // each suit is described by the values that it holds within each group
+    let signature = |suit: Suit| -> Vec<u16> {
+        groups
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .filter(|card| card.suit == suit && !card.is_joker())
+                    .fold(0, |bits, card| bits | 1 << card.value as u16)
+            })
+            .collect()
+    };
+
+    let mut suits = SuitPermutation::identity().0;
+    suits.sort_by_cached_key(|suit| Reverse(signature(*suit)));
+
+    // suits with identical signatures are interchangeable, so ties can be broken arbitrarily
+    let mut permutation = SuitPermutation::identity();
+    for (label, suit) in suits.iter().enumerate() {
+        permutation.0[*suit as usize] = Suit::from_usize(label).unwrap();
+    }
+
+    let canonical_groups = groups
+        .iter()
+        .map(|group| {
+            let mut cards: Vec<Card> = group.iter().map(|card| permutation.apply(*card)).collect();
+            cards.sort_by_key(|card| Reverse((card.value, card.suit as u8)));
+            cards
+        })
+        .collect();
+
+    (canonical_groups, permutation)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::core::cards::ALL;
+
+    fn class_count(card_count: usize) -> usize {
+        fn fill(
+            start: usize,
+            hand: &mut Vec<Card>,
+            left: usize,
+            out: &mut HashSet<Vec<Vec<Card>>>,
+        ) {
+            if left == 0 {
+                out.insert(canonicalize_suits(&[hand]).0);
+                return;
+            }
+            for (i, card) in ALL.iter().enumerate().skip(start) {
+                hand.push(*card);
+                fill(i + 1, hand, left - 1, out);
+                hand.pop();
+            }
+        }
+
+        let mut classes = HashSet::new();
+        fill(0, &mut Vec::new(), card_count, &mut classes);
+        classes.len()
+    }
+
+    #[test]
+    fn starting_hand_classes() {
+        assert_eq!(class_count(2), 169);
+    }
+
+    #[test]
+    fn flop_classes() {
+        // the number of strategically distinct flops
+        assert_eq!(class_count(3), 1755);
+    }
+
+    #[test]
+    fn permutation_round_trip() {
+        let hand = Card::vec_from_str("9c8d").unwrap();
+        let board = Card::vec_from_str("Tc2s").unwrap();
+
+        let (canonical, permutation) = canonicalize_suits(&[&hand, &board]);
+        let restored: Vec<Card> = canonical[0]
+            .iter()
+            .map(|card| permutation.inverse().apply(*card))
+            .collect();
+
+        assert_eq!(restored, hand);
+        assert_eq!(canonical[1], Card::vec_from_str("Th2d").unwrap());
+    }
+}
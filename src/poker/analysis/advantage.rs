@@ -0,0 +1,348 @@
+use std::cmp::Ordering;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{known_cards, AnalysisError};
+use crate::core::{Card, CardSet};
+use crate::poker::boards::Boards;
+use crate::poker::evaluators::high_evaluator;
+use crate::poker::range::{Combo, Range};
+
+/// The equity of every combo of a range against another range on a board
+///
+/// The combos are kept from the lowest equity to the highest, and each equity is from 0 to 1, with
+/// ties counting as half. Combos that share a card with the board, or that block every combo of
+/// the other range, are left out.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EquityDistribution {
+    combos: Vec<(Combo, f64, f64)>,
+}
+
+impl EquityDistribution {
+    /// Returns the equity of a combo, or `None` if the combo is not in the distribution
+    pub fn equity(&self, combo: Combo) -> Option<f64> {
+        self.combos
+            .iter()
+            .find(|(other, _, _)| *other == combo)
+            .map(|(_, _, equity)| *equity)
+    }
+
+    /// Returns an iterator over the combos and their equities, from the lowest equity to the highest
+    pub fn iter(&self) -> impl Iterator<Item = (Combo, f64)> + '_ {
+        self.combos
+            .iter()
+            .map(|(combo, _, equity)| (*combo, *equity))
+    }
+
+    /// Returns the number of combos in the distribution
+    pub fn len(&self) -> usize {
+        self.combos.len()
+    }
+
+    /// Returns whether the distribution has no combos
+    pub fn is_empty(&self) -> bool {
+        self.combos.is_empty()
+    }
+
+    /// Returns the share of the range, by weight, whose equity is above `equity`
+    pub fn share_above(&self, equity: f64) -> f64 {
+        let total: f64 = self.combos.iter().map(|(_, weight, _)| weight).sum();
+        let above: f64 = self
+            .combos
+            .iter()
+            .filter(|(_, _, other)| *other > equity)
+            .map(|(_, weight, _)| weight)
+            .sum();
+
+        if total > 0.0 {
+            above / total
+        } else {
+            0.0
+        }
+    }
+}
+
+/// How two ranges compare on a board
+///
+/// The range advantage is how far the equity of the first range is from an even split, while the
+/// nut advantage compares how much of each range is very strong, which is what lets a player bet
+/// large. The two often differ: a range full of overpairs can have the range advantage on a low
+/// board while the range that can still hold sets has the nut advantage.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RangeAdvantage {
+    /// The equity of the first range against the second, from 0 to 1
+    pub equity: f64,
+    /// The equity of each combo of the first range against the second range
+    pub hero: EquityDistribution,
+    /// The equity of each combo of the second range against the first range
+    pub villain: EquityDistribution,
+}
+
+impl RangeAdvantage {
+    /// Returns the equity of the first range minus the equity of the second, from -1 to 1
+    pub fn range_advantage(&self) -> f64 {
+        2.0 * self.equity - 1.0
+    }
+
+    /// Returns the share of the first range with more than `equity` minus the same share of the
+    /// second range, from -1 to 1
+    pub fn nut_advantage(&self, equity: f64) -> f64 {
+        self.hero.share_above(equity) - self.villain.share_above(equity)
+    }
+}
+
+/// Calculates the exact equity of every combo of a range against another range on a board of 3 to
+/// 5 cards
+///
+/// Each combo's equity is counted over every runout of the board and every combo of the other range
+/// that does not share a card with it, weighted by the other combo's weight. The share of the range
+/// above an equity, such as how much of the range has more than 80%, is the usual way to compare
+/// ranges on a texture. An error will return if the board has the wrong number of cards, if any
+/// card on the board is given more than once or is a joker, or if no combo of the range can face a
+/// combo of the other range.
+pub fn equity_distribution(
+    range: &Range,
+    against: &Range,
+    board: &[Card],
+) -> Result<EquityDistribution, AnalysisError> {
+    distribution(range, against, board).map(|(distribution, _)| distribution)
+}
+
+/// Compares two ranges on a board of 3 to 5 cards, with the equity distribution of each range
+/// against the other
+///
+/// An error will return if the board has the wrong number of cards, if any card on the board is
+/// given more than once or is a joker, or if no combo of either range can face a combo of the
+/// other.
+///
+/// ```rust
+/// use playing_cards::{
+///     core::Card,
+///     poker::{analysis, range::Range},
+/// };
+///
+/// let raiser: Range = "AA, KK, AK".parse().unwrap();
+/// let caller: Range = "QQ-22, AQs-A9s".parse().unwrap();
+/// let flop = Card::vec_from_str("Kh7d2c").unwrap();
+///
+/// let advantage = analysis::range_advantage(&raiser, &caller, &flop).unwrap();
+///
+/// assert!(advantage.range_advantage() > 0.5);
+/// // the caller's sets are the only hands that beat the raiser's top pairs
+/// assert!(advantage.nut_advantage(0.8) > 0.0);
+/// assert!(advantage.villain.share_above(0.8) > 0.0);
+/// ```
+pub fn range_advantage(
+    hero: &Range,
+    villain: &Range,
+    board: &[Card],
+) -> Result<RangeAdvantage, AnalysisError> {
+    let (hero, equity) = distribution(hero, villain, board)?;
+    let (villain, _) = distribution(villain, &to_range(&hero), board)?;
+
+    Ok(RangeAdvantage {
+        equity,
+        hero,
+        villain,
+    })
+}
+
+/// Returns the range of the combos in a distribution, with their weights
+fn to_range(distribution: &EquityDistribution) -> Range {
+    let mut range = Range::new();
+    for (combo, weight, _) in &distribution.combos {
+        range.set_weight(*combo, *weight);
+    }
+
+    range
+}
+
+/// Returns the equity distribution of a range against another, along with the equity of the whole
+/// range
+fn distribution(
+    range: &Range,
+    against: &Range,
+    board: &[Card],
+) -> Result<(EquityDistribution, f64), AnalysisError> {
+    if !(3..=5).contains(&board.len()) {
+        return Err(AnalysisError::InvalidBoardSize(board.len()));
+    }
+    let known = known_cards(board)?;
+    let combos: Vec<(Combo, f64)> = range.without_dead(known).iter().collect();
+    let opponents: Vec<(Combo, f64)> = against.without_dead(known).iter().collect();
+
+    let mut results = vec![(0.0, 0.0); combos.len()];
+    Boards::new(board, 5, CardSet::new())
+        .expect("The board should already be checked")
+        .for_each(|runout| tally(runout, &combos, &opponents, against, &mut results));
+
+    let (mut won, mut faced) = (0.0, 0.0);
+    let mut equities = Vec::with_capacity(combos.len());
+    for ((combo, weight), (combo_won, combo_faced)) in combos.iter().zip(results) {
+        // subtracting the blocked combos can leave rounding errors where none were faced
+        if combo_faced > 1e-9 {
+            equities.push((*combo, *weight, (combo_won / combo_faced).clamp(0.0, 1.0)));
+            won += weight * combo_won;
+            faced += weight * combo_faced;
+        }
+    }
+    if equities.is_empty() {
+        return Err(AnalysisError::EmptyRange);
+    }
+    equities.sort_by(|a, b| a.2.total_cmp(&b.2));
+
+    Ok((EquityDistribution { combos: equities }, won / faced))
+}
+
+/// Adds the weight of the opponents each combo beats on a complete board, with ties counting as
+/// half, and the weight of every opponent it can face there
+fn tally(
+    board: &[Card],
+    combos: &[(Combo, f64)],
+    opponents: &[(Combo, f64)],
+    against: &Range,
+    results: &mut [(f64, f64)],
+) {
+    let on_board = CardSet::from(board);
+    let mut cards = [board[0]; 7];
+    cards[2..].copy_from_slice(board);
+    let mut rank = |combo: &Combo| {
+        let hole_cards = combo.cards();
+        if hole_cards.iter().any(|card| on_board.contains(*card)) {
+            return None;
+        }
+        cards[..2].copy_from_slice(&hole_cards);
+        high_evaluator::best_strength(&cards)
+    };
+
+    // the opponents sorted by rank, and the opponents that share each card
+    let mut ranked = Vec::with_capacity(opponents.len());
+    let mut sharing: Vec<Vec<(u32, f64)>> = vec![Vec::new(); 52];
+    for (combo, weight) in opponents {
+        if let Some(rank) = rank(combo) {
+            ranked.push((rank, *weight));
+            for card in combo.cards() {
+                sharing[u8::from(card) as usize].push((rank, *weight));
+            }
+        }
+    }
+    ranked.sort_unstable_by_key(|(rank, _)| *rank);
+    let mut below = Vec::with_capacity(ranked.len() + 1);
+    below.push(0.0);
+    for (_, weight) in &ranked {
+        below.push(below.last().copied().unwrap_or_default() + weight);
+    }
+
+    for ((combo, _), result) in combos.iter().zip(results.iter_mut()) {
+        let Some(rank) = rank(combo) else {
+            continue;
+        };
+        let lower = ranked.partition_point(|(other, _)| *other < rank);
+        let upper = ranked.partition_point(|(other, _)| *other <= rank);
+        let mut beaten = below[lower];
+        let mut tied = below[upper] - below[lower];
+        let mut faced = below[ranked.len()];
+
+        let [first, second] = combo.cards().map(|card| u8::from(card) as usize);
+        for (other, weight) in sharing[first].iter().chain(&sharing[second]) {
+            match other.cmp(&rank) {
+                Ordering::Less => beaten -= weight,
+                Ordering::Equal => tied -= weight,
+                Ordering::Greater => {}
+            }
+            faced -= weight;
+        }
+        // the same combo in the other range shares both cards, so it was taken out twice
+        let same = against.weight(*combo);
+        tied += same;
+        faced += same;
+
+        result.0 += beaten + tied / 2.0;
+        result.1 += faced;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hand;
+
+    #[test]
+    fn distributions() {
+        let combo = |s: &str| {
+            let cards = Card::vec_from_str(s).unwrap();
+            Combo::new(cards[0], cards[1]).unwrap()
+        };
+        let river = hand!("Qh7h2d3sKc");
+
+        // on the river each combo either beats, ties, or loses to each opponent
+        let hero: Range = "KK, JJ, KQ".parse().unwrap();
+        let villain: Range = "KQ, AA".parse().unwrap();
+        let distribution = equity_distribution(&hero, &villain, &river).unwrap();
+        assert_eq!(distribution.len(), 3 + 6 + 9);
+        assert_eq!(distribution.equity(combo("KsKd")), Some(1.0));
+        assert_eq!(distribution.equity(combo("JsJd")), Some(0.0));
+        // KsQs beats the 6 aces and ties the 4 KQ combos it does not block
+        assert_eq!(distribution.equity(combo("KsQs")), Some(0.8));
+        assert_eq!(distribution.share_above(0.5), 12.0 / 18.0);
+        assert_eq!(distribution.iter().last().unwrap().1, 1.0);
+
+        // hands that split the pot have half of the equity
+        let split = equity_distribution(&"AsKs".parse().unwrap(), &"AdKd".parse().unwrap(), &river);
+        assert_eq!(split.unwrap().equity(combo("AsKs")), Some(0.5));
+
+        let advantage = range_advantage(&hero, &villain, &river).unwrap();
+        assert_eq!(advantage.hero, distribution);
+        assert_eq!(advantage.villain.equity(combo("AsAd")), Some(6.0 / 18.0));
+        assert_eq!(advantage.nut_advantage(0.99), 3.0 / 18.0);
+
+        assert!(matches!(
+            equity_distribution(&"Kc2c".parse().unwrap(), &villain, &river),
+            Err(AnalysisError::EmptyRange)
+        ));
+        assert!(matches!(
+            range_advantage(&hero, &villain, &[]),
+            Err(AnalysisError::InvalidBoardSize(0))
+        ));
+    }
+
+    #[test]
+    fn range_equity_matches_every_matchup() {
+        let turn = hand!("Js7s2d3h");
+        let hero: Range = "AsKs, QQ".parse().unwrap();
+        let villain: Range = "JJ, AJ".parse().unwrap();
+
+        // the range equity weights every matchup between the two ranges equally
+        let (mut won, mut faced) = (0.0, 0.0);
+        let on_board = CardSet::from(turn.as_slice());
+        for (first, _) in hero.without_dead(on_board).iter() {
+            for (second, _) in villain.without_dead(on_board).iter() {
+                if first.cards().iter().any(|card| second.contains(*card)) {
+                    continue;
+                }
+                let dead = first.card_set() | second.card_set();
+                Boards::new(&turn, 5, dead).unwrap().for_each(|river| {
+                    let mut hand = first.cards().to_vec();
+                    hand.extend_from_slice(river);
+                    let mut other = second.cards().to_vec();
+                    other.extend_from_slice(river);
+                    won += match high_evaluator::best_strength(&hand)
+                        .cmp(&high_evaluator::best_strength(&other))
+                    {
+                        Ordering::Greater => 1.0,
+                        Ordering::Equal => 0.5,
+                        Ordering::Less => 0.0,
+                    };
+                    faced += 1.0;
+                });
+            }
+        }
+
+        let advantage = range_advantage(&hero, &villain, &turn).unwrap();
+        assert!((advantage.equity - won / faced).abs() < 1e-9);
+        assert!((advantage.range_advantage() - (2.0 * won / faced - 1.0)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,188 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{known_cards, value_mask, AnalysisError, STRAIGHTS};
+use crate::core::{suit_histogram, value_histogram, Card, Value};
+
+/// How the values on a board are paired
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Pairing {
+    /// Every card has a different value
+    Unpaired,
+    /// Two cards share a value
+    Paired,
+    /// Two pairs of cards share a value
+    TwoPaired,
+    /// Three cards share a value
+    Trips,
+    /// Three cards share a value and two other cards share another value
+    FullHouse,
+    /// Four cards share a value
+    Quads,
+}
+
+/// How the suits on a board are distributed, based on the most common suit
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SuitTexture {
+    /// No two cards share a suit
+    Rainbow,
+    /// At most two cards share a suit, so a flush cannot be made yet
+    TwoTone,
+    /// Three or more cards share a suit, but not every card does
+    FlushPossible,
+    /// Every card shares a suit
+    Monotone,
+}
+
+/// A description of the community cards that is independent of any player's hand
+///
+/// ```rust
+/// use playing_cards::{
+///     core::{Card, Value},
+///     poker::analysis::{BoardTexture, Pairing, SuitTexture},
+/// };
+///
+/// let flop = Card::vec_from_str("Jh9hTc").unwrap();
+/// let texture = BoardTexture::new(&flop).unwrap();
+///
+/// assert_eq!(texture.pairing, Pairing::Unpaired);
+/// assert_eq!(texture.suits, SuitTexture::TwoTone);
+/// assert_eq!(texture.connectedness, 3);
+/// assert_eq!(texture.high_card, Value::Jack);
+/// assert!(texture.straight_possible);
+/// assert!(!texture.flush_possible);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BoardTexture {
+    /// How the values on the board are paired
+    pub pairing: Pairing,
+    /// How the suits on the board are distributed
+    pub suits: SuitTexture,
+    /// The most cards on the board that fit within a single straight, from 1 to 5
+    pub connectedness: usize,
+    /// The highest value on the board
+    pub high_card: Value,
+    /// The number of cards that are a 10 or higher
+    pub broadway_cards: usize,
+    /// Whether a player can make a straight with the board
+    pub straight_possible: bool,
+    /// Whether a player can make a flush with the board
+    pub flush_possible: bool,
+}
+
+impl BoardTexture {
+    /// Describes a board of 3 to 5 cards
+    ///
+    /// An error will return if the board has the wrong number of cards, or if any card is given
+    /// more than once or is a joker.
+    pub fn new(board: &[Card]) -> Result<Self, AnalysisError> {
+        if !(3..=5).contains(&board.len()) {
+            return Err(AnalysisError::InvalidBoardSize(board.len()));
+        }
+        known_cards(board)?;
+
+        let mut value_counts: Vec<usize> = value_histogram(board)
+            .into_iter()
+            .filter(|count| *count > 0)
+            .collect();
+        value_counts.sort_unstable_by(|a, b| b.cmp(a));
+        let pairing = match value_counts.as_slice() {
+            [4, ..] => Pairing::Quads,
+            [3, 2, ..] => Pairing::FullHouse,
+            [3, ..] => Pairing::Trips,
+            [2, 2, ..] => Pairing::TwoPaired,
+            [2, ..] => Pairing::Paired,
+            _ => Pairing::Unpaired,
+        };
+
+        let most_suited = suit_histogram(board).into_iter().max().unwrap_or(0);
+        let suits = match most_suited {
+            1 => SuitTexture::Rainbow,
+            2 => SuitTexture::TwoTone,
+            n if n == board.len() => SuitTexture::Monotone,
+            _ => SuitTexture::FlushPossible,
+        };
+
+        let values = value_mask(board);
+        let connectedness = STRAIGHTS
+            .into_iter()
+            .map(|straight| (values & straight).count_ones() as usize)
+            .max()
+            .unwrap_or(0);
+
+        Ok(Self {
+            pairing,
+            suits,
+            connectedness,
+            high_card: board.iter().map(|card| card.value).max().unwrap(),
+            broadway_cards: board.iter().filter(|card| card.value >= Value::Ten).count(),
+            straight_possible: connectedness >= 3,
+            flush_possible: most_suited >= 3,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texture(board: &str) -> BoardTexture {
+        BoardTexture::new(&Card::vec_from_str(board).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn flops() {
+        let monotone = texture("AhKh2h");
+        assert_eq!(monotone.suits, SuitTexture::Monotone);
+        assert_eq!(monotone.connectedness, 2);
+        assert_eq!(monotone.broadway_cards, 2);
+        assert!(monotone.flush_possible && !monotone.straight_possible);
+
+        let wheel = texture("5c3d4s");
+        assert_eq!(wheel.suits, SuitTexture::Rainbow);
+        assert_eq!(wheel.high_card, Value::Five);
+        assert!(wheel.straight_possible);
+
+        let paired = texture("7c7d2c");
+        assert_eq!(paired.pairing, Pairing::Paired);
+        assert_eq!(paired.suits, SuitTexture::TwoTone);
+        assert_eq!(paired.connectedness, 1);
+        assert_eq!(texture("QcQdQh").pairing, Pairing::Trips);
+    }
+
+    #[test]
+    fn turns_and_rivers() {
+        let two_paired = texture("KsKd5c5h");
+        assert_eq!(two_paired.pairing, Pairing::TwoPaired);
+        assert_eq!(two_paired.suits, SuitTexture::Rainbow);
+
+        let full_house = texture("2c2d2h9s9c");
+        assert_eq!(full_house.pairing, Pairing::FullHouse);
+        assert_eq!(full_house.suits, SuitTexture::TwoTone);
+        assert_eq!(texture("8s8c8d8hAs").pairing, Pairing::Quads);
+
+        let broadway = texture("AsKdQcJhTs");
+        assert_eq!(broadway.connectedness, 5);
+        assert_eq!(broadway.broadway_cards, 5);
+
+        let flush = texture("2s7s9sJdKs");
+        assert_eq!(flush.suits, SuitTexture::FlushPossible);
+        assert!(flush.flush_possible);
+        assert_eq!(texture("2s7s9sJsKs").suits, SuitTexture::Monotone);
+    }
+
+    #[test]
+    fn invalid_boards() {
+        assert!(matches!(
+            BoardTexture::new(&Card::vec_from_str("AsKd").unwrap()),
+            Err(AnalysisError::InvalidBoardSize(2))
+        ));
+        assert!(matches!(
+            BoardTexture::new(&Card::vec_from_str("AsKdAs").unwrap()),
+            Err(AnalysisError::DuplicateCard(_))
+        ));
+    }
+}
@@ -0,0 +1,348 @@
+use std::cmp::Ordering;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{known_cards, AnalysisError};
+use crate::core::{Card, CardSet};
+use crate::poker::boards::Boards;
+use crate::poker::evaluators::high_evaluator;
+use crate::poker::range::Combo;
+
+/// The most rounds of k-means that `bucket_hands()` runs before settling on its buckets
+pub const MAX_KMEANS_ROUNDS: usize = 100;
+
+/// Which bucket every hand falls into on a board
+///
+/// Buckets are numbered from the weakest hands to the strongest by the expected strength of their
+/// centroids. Hands that share a card with the board are not in any bucket.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BucketMap {
+    board: Vec<Card>,
+    buckets: Vec<Option<usize>>,
+    centroids: Vec<Vec<f64>>,
+}
+
+impl BucketMap {
+    /// Returns the board that the hands were bucketed on
+    pub fn board(&self) -> &[Card] {
+        &self.board
+    }
+
+    /// Returns the bucket of a combo, or `None` if the combo shares a card with the board
+    pub fn bucket(&self, combo: Combo) -> Option<usize> {
+        self.buckets[combo.index()]
+    }
+
+    /// Returns the number of buckets
+    pub fn bucket_count(&self) -> usize {
+        self.centroids.len()
+    }
+
+    /// Returns the histogram at the center of each bucket
+    pub fn centroids(&self) -> &[Vec<f64>] {
+        &self.centroids
+    }
+
+    /// Returns an iterator over the combos in a bucket
+    pub fn combos(&self, bucket: usize) -> impl Iterator<Item = Combo> + '_ {
+        self.buckets
+            .iter()
+            .enumerate()
+            .filter(move |(_, b)| **b == Some(bucket))
+            .filter_map(|(index, _)| Combo::from_index(index))
+    }
+}
+
+/// Calculates the histogram of every hand's strength on the river against random hands, for every
+/// hand that does not share a card with a board of 3 to 5 cards
+///
+/// A hand's strength on a runout is the share of the other hands it beats, with ties counting as
+/// half, from 0 to 1. The strengths are split into `bins` equal bins, and each histogram holds the
+/// share of the runouts whose strength falls into each bin. Unlike the expected hand strength
+/// alone, the histogram tells a draw apart from a made hand of the same average strength. An error
+/// will return if the board has the wrong number of cards, if `bins` is 0, or if any card on the
+/// board is given more than once or is a joker.
+pub fn strength_histograms(
+    board: &[Card],
+    bins: usize,
+) -> Result<Vec<(Combo, Vec<f64>)>, AnalysisError> {
+    if !(3..=5).contains(&board.len()) {
+        return Err(AnalysisError::InvalidBoardSize(board.len()));
+    }
+    if bins == 0 {
+        return Err(AnalysisError::InvalidBucketCount(bins));
+    }
+    let known = known_cards(board)?;
+
+    let combos: Vec<Combo> = Combo::all()
+        .filter(|combo| combo.cards().iter().all(|card| !known.contains(*card)))
+        .collect();
+    let mut histograms = vec![vec![0.0; bins]; combos.len()];
+
+    let mut strengths = vec![None; combos.len()];
+    Boards::new(board, 5, CardSet::new())
+        .expect("The board should already be checked")
+        .for_each(|runout| {
+            river_strengths(runout, &combos, &mut strengths);
+            for (histogram, strength) in histograms.iter_mut().zip(&strengths) {
+                if let Some(strength) = strength {
+                    histogram[((strength * bins as f64) as usize).min(bins - 1)] += 1.0;
+                }
+            }
+        });
+
+    for histogram in &mut histograms {
+        let runouts: f64 = histogram.iter().sum();
+        histogram.iter_mut().for_each(|share| *share /= runouts);
+    }
+
+    Ok(combos.into_iter().zip(histograms).collect())
+}
+
+/// Clusters every hand on a board of 3 to 5 cards into `buckets` buckets of hands that play alike
+///
+/// Each hand is described by its `strength_histograms()` with `bins` bins, and the hands are
+/// clustered with k-means, measuring the distance between histograms with the earth mover's
+/// distance. The initial centroids are spread evenly through the hands sorted by their expected
+/// strength, so the buckets are always the same for the same board. Preflop, the 169 starting hands
+/// are already a lossless abstraction, so only boards of 3 to 5 cards are supported.
+///
+/// An error will return if the board has the wrong number of cards, if `buckets` is 0 or more than
+/// the number of hands, if `bins` is 0, or if any card on the board is given more than once or is a
+/// joker.
+///
+/// ```rust
+/// use playing_cards::{core::Card, poker::{analysis, range::Combo}};
+///
+/// let board = Card::vec_from_str("Qh7h2d3sKc").unwrap();
+/// let buckets = analysis::bucket_hands(&board, 8, 10).unwrap();
+///
+/// let combo = |s: &str| {
+///     let cards = Card::vec_from_str(s).unwrap();
+///     Combo::new(cards[0], cards[1]).unwrap()
+/// };
+///
+/// assert_eq!(buckets.bucket_count(), 8);
+/// assert_eq!(buckets.bucket(combo("KsKd")), Some(7));
+/// assert_eq!(buckets.bucket(combo("QsQd")), Some(7));
+/// assert_eq!(buckets.bucket(combo("8c4d")), Some(0));
+/// assert_eq!(buckets.bucket(combo("KhQs")), buckets.bucket(combo("KdQc")));
+/// assert_eq!(buckets.bucket(combo("Kc4d")), None);
+/// ```
+pub fn bucket_hands(
+    board: &[Card],
+    buckets: usize,
+    bins: usize,
+) -> Result<BucketMap, AnalysisError> {
+    let histograms = strength_histograms(board, bins)?;
+    if buckets == 0 || buckets > histograms.len() {
+        return Err(AnalysisError::InvalidBucketCount(buckets));
+    }
+
+    let points: Vec<&[f64]> = histograms.iter().map(|(_, h)| h.as_slice()).collect();
+    let (assignments, mut centroids) = kmeans(&points, buckets);
+
+    // number the buckets from the weakest centroid to the strongest
+    let mut order: Vec<usize> = (0..buckets).collect();
+    order.sort_by(|a, b| mean(&centroids[*a]).total_cmp(&mean(&centroids[*b])));
+    let mut labels = vec![0; buckets];
+    for (label, bucket) in order.iter().enumerate() {
+        labels[*bucket] = label;
+    }
+    centroids = order
+        .iter()
+        .map(|bucket| centroids[*bucket].clone())
+        .collect();
+
+    let mut map = vec![None; Combo::COUNT];
+    for ((combo, _), bucket) in histograms.iter().zip(assignments) {
+        map[combo.index()] = Some(labels[bucket]);
+    }
+
+    Ok(BucketMap {
+        board: board.to_vec(),
+        buckets: map,
+        centroids,
+    })
+}
+
+/// Sets the strength of every combo against every other combo on a complete board, or `None` for
+/// combos that share a card with the board
+fn river_strengths(board: &[Card], combos: &[Combo], strengths: &mut [Option<f64>]) {
+    let on_board = CardSet::from(board);
+    let mut cards = [board[0]; 7];
+    cards[2..].copy_from_slice(board);
+
+    let ranks: Vec<Option<u32>> = combos
+        .iter()
+        .map(|combo| {
+            let hole_cards = combo.cards();
+            if hole_cards.iter().any(|card| on_board.contains(*card)) {
+                return None;
+            }
+            cards[..2].copy_from_slice(&hole_cards);
+            high_evaluator::best_strength(&cards)
+        })
+        .collect();
+
+    let mut sorted: Vec<u32> = ranks.iter().flatten().copied().collect();
+    sorted.sort_unstable();
+
+    // the combos that share each card, to take out the opponents a hand blocks
+    let mut sharing: Vec<Vec<u32>> = vec![Vec::new(); 52];
+    for (combo, rank) in combos.iter().zip(&ranks) {
+        if let Some(rank) = rank {
+            for card in combo.cards() {
+                sharing[u8::from(card) as usize].push(*rank);
+            }
+        }
+    }
+
+    for ((combo, rank), strength) in combos.iter().zip(&ranks).zip(strengths.iter_mut()) {
+        *strength = rank.map(|rank| {
+            let mut beaten = sorted.partition_point(|other| *other < rank) as f64;
+            let mut tied = (sorted.partition_point(|other| *other <= rank) as f64) - beaten;
+            let mut opponents = sorted.len() as f64;
+
+            // the combos sharing either card, counting the combo itself once
+            let [first, second] = combo.cards().map(|card| u8::from(card) as usize);
+            for other in sharing[first].iter().chain(&sharing[second]) {
+                match other.cmp(&rank) {
+                    Ordering::Less => beaten -= 1.0,
+                    Ordering::Equal => tied -= 1.0,
+                    Ordering::Greater => {}
+                }
+                opponents -= 1.0;
+            }
+            tied += 1.0;
+            opponents += 1.0;
+
+            (beaten + tied / 2.0) / opponents
+        });
+    }
+}
+
+/// Clusters the points into `k` clusters, returning the cluster of each point and the centroids
+fn kmeans(points: &[&[f64]], k: usize) -> (Vec<usize>, Vec<Vec<f64>>) {
+    let mut by_strength: Vec<usize> = (0..points.len()).collect();
+    by_strength.sort_by(|a, b| mean(points[*a]).total_cmp(&mean(points[*b])));
+    by_strength.dedup_by(|a, b| points[*a] == points[*b]);
+    let spread = by_strength.len();
+    let mut centroids: Vec<Vec<f64>> = (0..k)
+        .map(|i| points[by_strength[(2 * i + 1) * spread / (2 * k)]].to_vec())
+        .collect();
+
+    let mut assignments = vec![usize::MAX; points.len()];
+    for _ in 0..MAX_KMEANS_ROUNDS {
+        let mut changed = false;
+        for (point, assignment) in points.iter().zip(assignments.iter_mut()) {
+            let nearest = (0..k)
+                .min_by(|a, b| {
+                    earth_movers_distance(point, &centroids[*a])
+                        .total_cmp(&earth_movers_distance(point, &centroids[*b]))
+                })
+                .expect("There is at least one cluster");
+            changed |= *assignment != nearest;
+            *assignment = nearest;
+        }
+        if !changed {
+            break;
+        }
+
+        // an empty cluster keeps its centroid
+        for (cluster, centroid) in centroids.iter_mut().enumerate() {
+            let members: Vec<&[f64]> = points
+                .iter()
+                .zip(&assignments)
+                .filter(|(_, assignment)| **assignment == cluster)
+                .map(|(point, _)| *point)
+                .collect();
+            if !members.is_empty() {
+                for (bin, value) in centroid.iter_mut().enumerate() {
+                    *value =
+                        members.iter().map(|point| point[bin]).sum::<f64>() / members.len() as f64;
+                }
+            }
+        }
+    }
+
+    (assignments, centroids)
+}
+
+/// Returns the earth mover's distance between two histograms with the same bins
+fn earth_movers_distance(a: &[f64], b: &[f64]) -> f64 {
+    let mut carried = 0.0;
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| {
+            carried += a - b;
+            carried.abs()
+        })
+        .sum()
+}
+
+/// Returns the expected bin of a histogram, scaled from 0 to 1
+fn mean(histogram: &[f64]) -> f64 {
+    histogram
+        .iter()
+        .enumerate()
+        .map(|(bin, share)| (bin as f64 + 0.5) * share)
+        .sum::<f64>()
+        / histogram.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histograms() {
+        let board = Card::vec_from_str("Qh7h2d3s").unwrap();
+        let histograms = strength_histograms(&board, 4).unwrap();
+        assert_eq!(histograms.len(), 1128);
+        for (_, histogram) in &histograms {
+            assert!((histogram.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        }
+
+        // the strengths of every hand on a river average out to one half
+        let river = Card::vec_from_str("Qh7h2d3sKc").unwrap();
+        let mut strengths = vec![None; histograms.len()];
+        let combos: Vec<Combo> = histograms.iter().map(|(combo, _)| *combo).collect();
+        river_strengths(&river, &combos, &mut strengths);
+        let live: Vec<f64> = strengths.into_iter().flatten().collect();
+        assert_eq!(live.len(), 1081);
+        assert!((live.iter().sum::<f64>() / live.len() as f64 - 0.5).abs() < 1e-9);
+
+        assert!(matches!(
+            strength_histograms(&board, 0),
+            Err(AnalysisError::InvalidBucketCount(0))
+        ));
+    }
+
+    #[test]
+    fn clusters() {
+        let board = Card::vec_from_str("Qh7h2d3s").unwrap();
+        let buckets = bucket_hands(&board, 5, 8).unwrap();
+        assert_eq!(buckets.board(), &board[..]);
+        assert_eq!(buckets.bucket_count(), 5);
+        assert_eq!(
+            (0..5).map(|b| buckets.combos(b).count()).sum::<usize>(),
+            1128
+        );
+
+        // the buckets are numbered from the weakest to the strongest
+        let means: Vec<f64> = buckets.centroids().iter().map(|c| mean(c)).collect();
+        assert!(means.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        assert_eq!(
+            earth_movers_distance(&[1.0, 0.0, 0.0], &[0.0, 0.0, 1.0]),
+            2.0
+        );
+        assert!(matches!(
+            bucket_hands(&board, 0, 8),
+            Err(AnalysisError::InvalidBucketCount(0))
+        ));
+    }
+}
@@ -0,0 +1,331 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+use super::{known_cards, AnalysisError};
+use crate::core::{Card, CardSet, Suit};
+use crate::poker::evaluators::high_evaluator;
+use crate::poker::range::{Combo, Range};
+use crate::poker::ranks::{HandClass, HighRank};
+
+/// A kind of holding that can be counted within a range with `count_combos()`
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, EnumIter, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Holding {
+    /// A pocket pair that is higher than every card on the board
+    Overpair,
+    /// One hole card pairs the highest card on the board, while the other does not pair the board
+    TopPair,
+    /// A pocket pair that matches one card on the board
+    Set,
+    /// A flush that uses at least one hole card
+    Flush,
+    /// A flush that uses the highest card of the suit that is not on the board
+    NutFlush,
+}
+
+impl Holding {
+    /// Returns whether the combo makes this holding on the board
+    pub fn matches(&self, combo: Combo, board: &[Card]) -> bool {
+        let [high, low] = combo.cards();
+        let board_count = |card: Card| board.iter().filter(|c| c.value == card.value).count();
+        let top_value = board.iter().map(|card| card.value).max();
+
+        match self {
+            Self::Overpair => combo.is_pair() && board.iter().all(|card| card.value < high.value),
+            Self::TopPair => {
+                !combo.is_pair()
+                    && [(high, low), (low, high)]
+                        .into_iter()
+                        .any(|(pair, kicker)| {
+                            Some(pair.value) == top_value
+                                && board_count(pair) == 1
+                                && board_count(kicker) == 0
+                        })
+            }
+            Self::Set => combo.is_pair() && board_count(high) == 1,
+            Self::Flush => flush_suit(combo, board).is_some(),
+            Self::NutFlush => flush_suit(combo, board).is_some_and(|suit| {
+                let best = (CardSet::full() - CardSet::from(board))
+                    .iter()
+                    .filter(|card| card.suit == suit)
+                    .map(|card| card.value)
+                    .max();
+                [high, low]
+                    .iter()
+                    .any(|card| card.suit == suit && Some(card.value) == best)
+            }),
+        }
+    }
+}
+
+/// Returns the suit of the flush made by the combo, if the flush uses at least one hole card
+fn flush_suit(combo: Combo, board: &[Card]) -> Option<Suit> {
+    Suit::iter().find(|&suit| {
+        let mut suited: Vec<Card> = board
+            .iter()
+            .chain(&combo.cards())
+            .filter(|card| card.suit == suit)
+            .copied()
+            .collect();
+        suited.sort_unstable_by_key(|card| std::cmp::Reverse(card.value));
+        suited.len() >= 5 && suited[..5].iter().any(|card| combo.contains(*card))
+    })
+}
+
+/// Counts the combos of a holding that remain within a range
+///
+/// Combos that contain a card from the board or from `dead`, such as the cards in our own hand,
+/// are not counted. The count is weighted by each combo's weight within the range. An error will
+/// return if the board does not have 3 to 5 cards, or if any card on the board is given more than
+/// once or is a joker.
+///
+/// ```rust
+/// use playing_cards::{
+///     core::{Card, CardSet},
+///     poker::{analysis::{self, Holding}, range::Range},
+/// };
+///
+/// let range: Range = "AA, KK, 77, AK, KQs".parse().unwrap();
+/// let board = Card::vec_from_str("Kh7s2c").unwrap();
+///
+/// assert_eq!(analysis::count_combos(&range, &board, CardSet::new(), Holding::Set).unwrap(), 6.0);
+///
+/// // holding the king of spades removes the combos that contain it
+/// let dead = CardSet::from(&["Ks".parse().unwrap()][..]);
+/// assert_eq!(analysis::count_combos(&range, &board, dead, Holding::Set).unwrap(), 4.0);
+/// assert_eq!(analysis::count_combos(&range, &board, dead, Holding::TopPair).unwrap(), 10.0);
+/// ```
+pub fn count_combos(
+    range: &Range,
+    board: &[Card],
+    dead: CardSet,
+    holding: Holding,
+) -> Result<f64, AnalysisError> {
+    if !(3..=5).contains(&board.len()) {
+        return Err(AnalysisError::InvalidBoardSize(board.len()));
+    }
+    let dead = dead | known_cards(board)?;
+
+    Ok(range
+        .without_dead(dead)
+        .iter()
+        .filter(|(combo, _)| holding.matches(*combo, board))
+        .map(|(_, weight)| weight)
+        .sum())
+}
+
+/// Returns the combos of a range that make a holding on a board of 3 to 5 cards, keeping their
+/// weights
+///
+/// Combos that contain a card from the board are left out. An error will return if the board does
+/// not have 3 to 5 cards, or if any card on the board is given more than once or is a joker.
+pub fn filter_by_holding(
+    range: &Range,
+    board: &[Card],
+    holding: Holding,
+) -> Result<Range, AnalysisError> {
+    if !(3..=5).contains(&board.len()) {
+        return Err(AnalysisError::InvalidBoardSize(board.len()));
+    }
+    let mut range = range.without_dead(known_cards(board)?);
+    range.retain(|combo, _| holding.matches(combo, board));
+    Ok(range)
+}
+
+/// Returns the combos of a range that make at least a hand class on a board of 3 to 5 cards,
+/// keeping their weights
+///
+/// The hand class is that of the best hand made from the combo and the board, so on a paired
+/// board every combo makes at least a pair. Combos that contain a card from the board are left
+/// out. An error will return if the board does not have 3 to 5 cards, or if any card on the board
+/// is given more than once or is a joker.
+///
+/// ```rust
+/// use playing_cards::{
+///     core::Card,
+///     poker::{analysis, range::Range, ranks::HandClass},
+/// };
+///
+/// let range: Range = "KK+, AK, AQ, 76s".parse().unwrap();
+/// let flop = Card::vec_from_str("Kh7s2c").unwrap();
+///
+/// // ace-queen misses the flop, and the board takes out a combo of every other hand
+/// let pairs = analysis::filter_by_class(&range, &flop, HandClass::Pair).unwrap();
+/// assert_eq!(pairs.len(), 6 + 3 + 12 + 3);
+///
+/// let sets = analysis::filter_by_class(&range, &flop, HandClass::ThreeOfAKind).unwrap();
+/// assert_eq!(sets, "KsKd, KsKc, KdKc".parse().unwrap());
+/// ```
+pub fn filter_by_class(
+    range: &Range,
+    board: &[Card],
+    class: HandClass,
+) -> Result<Range, AnalysisError> {
+    if !(3..=5).contains(&board.len()) {
+        return Err(AnalysisError::InvalidBoardSize(board.len()));
+    }
+    let mut range = range.without_dead(known_cards(board)?);
+
+    let mut cards = [board[0]; 7];
+    let cards = &mut cards[..2 + board.len()];
+    cards[2..].copy_from_slice(board);
+    range.retain(|combo, _| {
+        cards[..2].copy_from_slice(&combo.cards());
+        high_evaluator::best_strength(cards)
+            .and_then(HighRank::from_strength)
+            .and_then(|rank| rank.hand_class())
+            .is_some_and(|hand_class| hand_class >= class)
+    });
+
+    Ok(range)
+}
+
+/// The combos of an opponent's range that one of our cards blocks
+#[derive(Debug, Clone, PartialEq)]
+pub struct Blocker {
+    /// The card that blocks the combos
+    pub card: Card,
+    /// The combos of the range that contain the card
+    pub blocked: Range,
+    /// The weighted number of blocked combos of each holding, for every holding that is blocked
+    pub holdings: Vec<(Holding, f64)>,
+}
+
+/// Reports which combos of an opponent's range each card in our hand blocks
+///
+/// Combos that contain a card from the board are ignored, since the opponent cannot hold them.
+/// A `Blocker` is returned for each card in the hand, in the same order. An error will return if
+/// the board does not have 3 to 5 cards, or if any card is given more than once or is a joker.
+///
+/// ```rust
+/// use playing_cards::{
+///     core::Card,
+///     poker::{analysis::{self, Holding}, range::Range},
+/// };
+///
+/// let range: Range = "AhQh, AhJh, 8h7h, KK".parse().unwrap();
+/// let hand = Card::vec_from_str("AhKc").unwrap();
+/// let board = Card::vec_from_str("Th5h2h").unwrap();
+///
+/// let blockers = analysis::blockers(&hand, &board, &range).unwrap();
+///
+/// // our ace of hearts blocks both nut flushes
+/// assert_eq!(blockers[0].blocked.len(), 2);
+/// assert!(blockers[0].holdings.contains(&(Holding::NutFlush, 2.0)));
+/// assert_eq!(blockers[1].blocked.len(), 3);
+/// ```
+pub fn blockers(
+    hand: &[Card],
+    board: &[Card],
+    range: &Range,
+) -> Result<Vec<Blocker>, AnalysisError> {
+    if !(3..=5).contains(&board.len()) {
+        return Err(AnalysisError::InvalidBoardSize(board.len()));
+    }
+    let live = range.without_dead(known_cards(board)?);
+    known_cards(hand.iter().chain(board))?;
+
+    Ok(hand
+        .iter()
+        .map(|&card| {
+            let mut blocked = Range::new();
+            for (combo, weight) in live.iter().filter(|(combo, _)| combo.contains(card)) {
+                blocked.set_weight(combo, weight);
+            }
+
+            let holdings = Holding::iter()
+                .map(|holding| {
+                    let count = blocked
+                        .iter()
+                        .filter(|(combo, _)| holding.matches(*combo, board))
+                        .map(|(_, weight)| weight)
+                        .sum();
+                    (holding, count)
+                })
+                .filter(|(_, count)| *count > 0.0)
+                .collect();
+
+            Blocker {
+                card,
+                blocked,
+                holdings,
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn holdings() {
+        let board = Card::vec_from_str("Qh9h4h8c").unwrap();
+        let matches = |holding: Holding, combo: &str| {
+            let cards = Card::vec_from_str(combo).unwrap();
+            holding.matches(Combo::new(cards[0], cards[1]).unwrap(), &board)
+        };
+
+        assert!(matches(Holding::Overpair, "KsKd"));
+        assert!(!matches(Holding::Overpair, "JsJd"));
+        assert!(matches(Holding::TopPair, "AsQd"));
+        assert!(!matches(Holding::TopPair, "Qd9s"));
+        assert!(matches(Holding::Set, "9s9d"));
+        assert!(matches(Holding::Flush, "2h3h"));
+        assert!(!matches(Holding::Flush, "2h3c"));
+        assert!(!matches(Holding::NutFlush, "Kh2h"));
+        assert!(matches(Holding::NutFlush, "Ah2h"));
+
+        // the king becomes the nuts once the ace is on the board
+        let board = Card::vec_from_str("AhTh4h").unwrap();
+        let combo = Combo::new("Kh".parse().unwrap(), "2h".parse().unwrap()).unwrap();
+        assert!(Holding::NutFlush.matches(combo, &board));
+    }
+
+    #[test]
+    fn counting_and_blocking() {
+        let range: Range = "QQ, 99, AQ, KhJh, 2h2c".parse().unwrap();
+        let board = Card::vec_from_str("Qh9h4h").unwrap();
+
+        // the board removes combos containing its cards
+        let count = |holding| count_combos(&range, &board, CardSet::new(), holding).unwrap();
+        assert_eq!(count(Holding::Set), 6.0);
+        assert_eq!(count(Holding::TopPair), 12.0);
+        assert_eq!(count(Holding::Flush), 1.0);
+
+        let hand = Card::vec_from_str("QsJh").unwrap();
+        let report = blockers(&hand, &board, &range).unwrap();
+        assert_eq!(report[0].card, hand[0]);
+        assert_eq!(
+            report[0].holdings,
+            vec![(Holding::TopPair, 4.0), (Holding::Set, 2.0)]
+        );
+        assert!(report[1]
+            .blocked
+            .contains(Combo::new("Kh".parse().unwrap(), "Jh".parse().unwrap()).unwrap()));
+
+        assert!(matches!(
+            blockers(&hand, &board[..2], &range),
+            Err(AnalysisError::InvalidBoardSize(2))
+        ));
+    }
+
+    #[test]
+    fn filtering() {
+        let range: Range = "QQ, 99, AQ, KhJh, 2h2c".parse().unwrap();
+        let board = Card::vec_from_str("Qh9h4h").unwrap();
+
+        let sets = filter_by_holding(&range, &board, Holding::Set).unwrap();
+        assert_eq!(sets, "QsQd, QsQc, QdQc, 9s9d, 9s9c, 9d9c".parse().unwrap());
+
+        let flushes = filter_by_class(&range, &board, HandClass::Flush).unwrap();
+        assert_eq!(flushes, "KhJh".parse().unwrap());
+        assert_eq!(
+            filter_by_class(&range, &board, HandClass::HighCard).unwrap(),
+            range.without_dead(CardSet::from(&board[..]))
+        );
+    }
+}
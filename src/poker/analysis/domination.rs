@@ -0,0 +1,208 @@
+use std::cmp::Ordering;
+
+use rand_core::{RngCore, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::AnalysisError;
+use crate::core::{Card, CardDeck, Value};
+use crate::poker::equity::{self, Equity};
+
+/// How the hole cards of one hand relate to the hole cards of another
+///
+/// The relationship is described from the perspective of the first hand, and only depends on the
+/// values of the hole cards.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Matchup {
+    /// The hand shares a value with the other hand and has a higher card alongside it, such as AK
+    /// against AQ or KK against K7, or is a higher pair
+    Dominating,
+    /// The other hand shares a value with this hand and has a higher card alongside it, or is a
+    /// higher pair
+    Dominated,
+    /// A pair against two higher cards, or two higher cards against a pair, such as 22 against AK
+    CoinFlip,
+    /// The hands share a value without either one dominating, such as AK against AK or KK against
+    /// AK
+    SharedCard,
+    /// The hands do not share a value and are not a coin flip, such as AK against 76
+    Unrelated,
+}
+
+impl Matchup {
+    /// Returns the same relationship from the perspective of the other hand
+    pub fn swapped(&self) -> Self {
+        match self {
+            Self::Dominating => Self::Dominated,
+            Self::Dominated => Self::Dominating,
+            matchup => *matchup,
+        }
+    }
+}
+
+/// The relationship between two hands and the equity of the first hand against the second
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Domination {
+    /// How the hole cards of the first hand relate to the hole cards of the second
+    pub matchup: Matchup,
+    /// The equity of the first hand against the second on the given board
+    pub equity: Equity,
+}
+
+/// Classifies how two Texas Hold'em hands relate to one another, and estimates the equity of the
+/// first hand against the second by dealing out the rest of the board `iterations` times
+///
+/// The random number generator is seeded from entropy. See `domination_with_rng()` for details
+/// and for reproducible results.
+pub fn domination(
+    hand1: &[Card],
+    hand2: &[Card],
+    board: &[Card],
+    iterations: u64,
+) -> Result<Domination, AnalysisError> {
+    let mut rng = Xoshiro256PlusPlus::from_seed(CardDeck::seed_or_entropy(None)?);
+    domination_with_rng(hand1, hand2, board, iterations, &mut rng)
+}
+
+/// Classifies how two Texas Hold'em hands relate to one another, and estimates the equity of the
+/// first hand against the second by dealing out the rest of the board `iterations` times with the
+/// given random number generator
+///
+/// The matchup only depends on the values of the hole cards, while the equity also accounts for
+/// the board, so a dominated hand that has already made a stronger hand than the other on the
+/// flop will show it in its equity. The hands and board are the same as for
+/// `equity::monte_carlo_with_rng()`.
+///
+/// ```rust
+/// use playing_cards::{
+///     core::Card,
+///     poker::analysis::{self, Matchup},
+/// };
+/// use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+///
+/// let hero = Card::vec_from_str("AsKd").unwrap();
+/// let villain = Card::vec_from_str("AcQh").unwrap();
+///
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(8);
+/// let domination =
+///     analysis::domination_with_rng(&hero, &villain, &[], 10_000, &mut rng).unwrap();
+///
+/// assert_eq!(domination.matchup, Matchup::Dominating);
+/// assert!(domination.equity.equity_percentage() > 70.0);
+/// ```
+pub fn domination_with_rng<R: RngCore + ?Sized>(
+    hand1: &[Card],
+    hand2: &[Card],
+    board: &[Card],
+    iterations: u64,
+    rng: &mut R,
+) -> Result<Domination, AnalysisError> {
+    let equity = equity::monte_carlo_with_rng(hand1, hand2, board, &[], iterations, rng)?;
+
+    Ok(Domination {
+        matchup: matchup(hand1, hand2),
+        equity,
+    })
+}
+
+/// Classifies the relationship between two hands of 2 valid cards each
+fn matchup(hand1: &[Card], hand2: &[Card]) -> Matchup {
+    let values = |hand: &[Card]| {
+        let (a, b) = (hand[0].value, hand[1].value);
+        (a.max(b), a.min(b))
+    };
+    let ((high1, low1), (high2, low2)) = (values(hand1), values(hand2));
+
+    match (high1 == low1, high2 == low2) {
+        (true, true) => match high1.cmp(&high2) {
+            Ordering::Greater => Matchup::Dominating,
+            Ordering::Less => Matchup::Dominated,
+            Ordering::Equal => Matchup::SharedCard,
+        },
+        (true, false) => pair_matchup(high1, high2, low2),
+        (false, true) => pair_matchup(high2, high1, low1).swapped(),
+        (false, false) if (high1, low1) == (high2, low2) => Matchup::SharedCard,
+        (false, false) => {
+            // the card that is not shared decides which hand dominates
+            let shared = [high1, low1]
+                .into_iter()
+                .find(|value| *value == high2 || *value == low2);
+            match shared {
+                Some(shared) => {
+                    let other = |high: Value, low: Value| if high == shared { low } else { high };
+                    if other(high1, low1) > other(high2, low2) {
+                        Matchup::Dominating
+                    } else {
+                        Matchup::Dominated
+                    }
+                }
+                None => Matchup::Unrelated,
+            }
+        }
+    }
+}
+
+/// Classifies the relationship of a pair against two unpaired values, from the pair's perspective
+fn pair_matchup(pair: Value, high: Value, low: Value) -> Matchup {
+    if pair == high {
+        Matchup::Dominating
+    } else if pair == low {
+        Matchup::SharedCard
+    } else if pair < low {
+        Matchup::CoinFlip
+    } else {
+        Matchup::Unrelated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hand;
+
+    #[test]
+    fn matchups() {
+        let matchup = |hand1: &str, hand2: &str| {
+            super::matchup(
+                &Card::vec_from_str(hand1).unwrap(),
+                &Card::vec_from_str(hand2).unwrap(),
+            )
+        };
+
+        assert_eq!(matchup("AsKd", "AcQh"), Matchup::Dominating);
+        assert_eq!(matchup("Qh8c", "KsQd"), Matchup::Dominated);
+        assert_eq!(matchup("KsKd", "7cKh"), Matchup::Dominating);
+        assert_eq!(matchup("QsQd", "AcAh"), Matchup::Dominated);
+        assert_eq!(matchup("2s2d", "AcKh"), Matchup::CoinFlip);
+        assert_eq!(matchup("AcKh", "2s2d"), Matchup::CoinFlip);
+        assert_eq!(matchup("KsKd", "AcKh"), Matchup::SharedCard);
+        assert_eq!(matchup("AsKd", "AcKh"), Matchup::SharedCard);
+        assert_eq!(matchup("AsKd", "7c6c"), Matchup::Unrelated);
+        assert_eq!(matchup("9s9d", "Tc6c"), Matchup::Unrelated);
+    }
+
+    #[test]
+    fn equity_on_the_board() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(10);
+
+        // the dominated hand can still be ahead once the board comes
+        let result = domination_with_rng(
+            &hand!("AcQh"),
+            &hand!("AsKd"),
+            &hand!("Qd7s2c"),
+            2_000,
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(result.matchup, Matchup::Dominated);
+        assert!(result.equity.equity_percentage() > 80.0);
+
+        assert!(matches!(
+            domination(&hand!("AcQh"), &hand!("AcKd"), &[], 1),
+            Err(AnalysisError::EquityError(_))
+        ));
+    }
+}
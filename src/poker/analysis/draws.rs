@@ -0,0 +1,237 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use super::{known_cards, value_mask, AnalysisError, STRAIGHTS};
+use crate::core::{Card, CardSet, Suit};
+
+/// A kind of draw to a straight
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StraightDraw {
+    /// A single value fills a gap in the straight, such as a 7 for 5-6-8-9
+    Gutshot,
+    /// Either of two values fills a gap in a straight, such as a 6 or a 10 for 5-7-8-9-J
+    DoubleGutshot,
+    /// Four values in a row that can be completed on either end, such as a 5 or a 10 for 6-7-8-9
+    OpenEnded,
+}
+
+/// The draws a hand has on a flop or turn
+///
+/// Only draws that use at least one card from the hand are included, since a draw that is made
+/// entirely from the board is shared by every player.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Draws {
+    /// Four cards of the same suit, needing one more to make a flush
+    pub flush_draw: bool,
+    /// The best draw to a straight, if any
+    ///
+    /// This is `None` if the hand has already made a straight.
+    pub straight_draw: Option<StraightDraw>,
+    /// Three cards of the same suit on the flop, needing both the turn and river to make a flush
+    pub backdoor_flush_draw: bool,
+    /// Three cards within a straight on the flop, needing both the turn and river to make a
+    /// straight
+    ///
+    /// This is only set if the hand does not have a straight draw.
+    pub backdoor_straight_draw: bool,
+    /// The cards that would complete a flush or straight draw on the next street
+    pub outs: CardSet,
+}
+
+impl Draws {
+    /// Returns whether the hand has both a flush draw and a straight draw
+    pub fn is_combo_draw(&self) -> bool {
+        self.flush_draw && self.straight_draw.is_some()
+    }
+}
+
+/// Finds the draws a hand has on a board of 3 or 4 cards
+///
+/// The hand must have at least 1 card. An error will return if the board has the wrong number of
+/// cards, or if any card is given more than once or is a joker.
+///
+/// ```rust
+/// use playing_cards::{
+///     core::Card,
+///     poker::analysis::{self, StraightDraw},
+/// };
+///
+/// let hand = Card::vec_from_str("JhTh").unwrap();
+/// let flop = Card::vec_from_str("9h8c2h").unwrap();
+///
+/// let draws = analysis::find_draws(&hand, &flop).unwrap();
+///
+/// assert!(draws.flush_draw);
+/// assert_eq!(draws.straight_draw, Some(StraightDraw::OpenEnded));
+/// assert!(draws.is_combo_draw());
+/// assert_eq!(draws.outs.len(), 15);
+/// ```
+pub fn find_draws(hand: &[Card], board: &[Card]) -> Result<Draws, AnalysisError> {
+    if hand.is_empty() {
+        return Err(AnalysisError::InvalidHandSize(0));
+    }
+    if !(3..=4).contains(&board.len()) {
+        return Err(AnalysisError::InvalidBoardSize(board.len()));
+    }
+
+    let unseen = CardSet::full() - known_cards(hand.iter().chain(board))?;
+    let is_flop = board.len() == 3;
+    let mut draws = Draws::default();
+
+    for suit in Suit::iter() {
+        let in_hand = hand.iter().filter(|card| card.suit == suit).count();
+        let on_board = board.iter().filter(|card| card.suit == suit).count();
+        if in_hand == 0 {
+            continue;
+        }
+
+        match in_hand + on_board {
+            4 => {
+                draws.flush_draw = true;
+                draws
+                    .outs
+                    .extend(unseen.iter().filter(|card| card.suit == suit));
+            }
+            3 if is_flop => draws.backdoor_flush_draw = true,
+            _ => (),
+        }
+    }
+
+    let values = value_mask(hand.iter().chain(board));
+    let board_values = value_mask(board);
+    if STRAIGHTS
+        .into_iter()
+        .any(|straight| values & straight == straight)
+    {
+        return Ok(draws);
+    }
+
+    // values that complete a straight which the board would not make on its own
+    let mut completing = 0;
+    for straight in STRAIGHTS {
+        let missing = straight & !values;
+        if straight & !(board_values | missing) == 0 {
+            continue;
+        }
+
+        match missing.count_ones() {
+            1 => completing |= missing,
+            2 if is_flop => draws.backdoor_straight_draw = true,
+            _ => (),
+        }
+    }
+
+    draws.straight_draw = match completing.count_ones() {
+        0 => None,
+        1 => Some(StraightDraw::Gutshot),
+        _ if is_open_ended(values, completing) => Some(StraightDraw::OpenEnded),
+        _ => Some(StraightDraw::DoubleGutshot),
+    };
+    if draws.straight_draw.is_some() {
+        draws.backdoor_straight_draw = false;
+        draws.outs.extend(
+            unseen
+                .iter()
+                .filter(|card| completing & 1 << card.value as u16 != 0),
+        );
+    }
+
+    Ok(draws)
+}
+
+/// Returns whether four values in a row can be completed into a straight on either end
+fn is_open_ended(values: u16, completing: u16) -> bool {
+    (0..=8).any(|low| {
+        let below = if low == 0 { 1 << 12 } else { 1 << (low - 1) };
+        let above = 1 << (low + 4);
+        values & 0xf << low == 0xf << low && completing & below != 0 && completing & above != 0
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draws(hand: &str, board: &str) -> Draws {
+        find_draws(
+            &Card::vec_from_str(hand).unwrap(),
+            &Card::vec_from_str(board).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn straight_draws() {
+        let open_ended = draws("9h8h", "Td7c2s");
+        assert_eq!(open_ended.straight_draw, Some(StraightDraw::OpenEnded));
+        assert_eq!(open_ended.outs.len(), 8);
+        assert!(!open_ended.flush_draw && !open_ended.backdoor_flush_draw);
+
+        let double_gutshot = draws("9c7d", "Jh8s5c");
+        assert_eq!(
+            double_gutshot.straight_draw,
+            Some(StraightDraw::DoubleGutshot)
+        );
+        assert_eq!(double_gutshot.outs.len(), 8);
+
+        let gutshot = draws("AcKd", "QhJs3c");
+        assert_eq!(gutshot.straight_draw, Some(StraightDraw::Gutshot));
+        assert_eq!(gutshot.outs.len(), 4);
+
+        // the wheel draw can only be completed by a 5
+        assert_eq!(
+            draws("Ac2d", "3h4s9c").straight_draw,
+            Some(StraightDraw::Gutshot)
+        );
+
+        // a straight draw made entirely by the board does not count
+        assert_eq!(draws("2c2d", "9h8sTc6d").straight_draw, None);
+
+        // a made straight is not a draw
+        assert_eq!(draws("9c8d", "7h6s5c").straight_draw, None);
+    }
+
+    #[test]
+    fn flush_and_backdoor_draws() {
+        let nut_flush_draw = draws("AhKh", "Qh7h2d");
+        assert!(nut_flush_draw.flush_draw);
+        assert_eq!(nut_flush_draw.straight_draw, None);
+        assert!(nut_flush_draw.backdoor_straight_draw);
+        assert!(!nut_flush_draw.is_combo_draw());
+        assert_eq!(nut_flush_draw.outs.len(), 9);
+
+        let backdoors = draws("Ks4s", "Qs8c2d");
+        assert!(backdoors.backdoor_flush_draw);
+        assert!(!backdoors.backdoor_straight_draw);
+        assert!(backdoors.outs.is_empty());
+
+        // backdoors are gone by the turn
+        let turn = draws("Ks4s", "Qs8c2d3h");
+        assert!(!turn.backdoor_flush_draw);
+        assert!(!turn.backdoor_straight_draw);
+
+        // a flush draw on the board alone does not count
+        assert!(!draws("AcKd", "2h5h8hTh").flush_draw);
+    }
+
+    #[test]
+    fn invalid_boards() {
+        let hand = Card::vec_from_str("AcKd").unwrap();
+
+        assert!(matches!(
+            find_draws(&hand, &Card::vec_from_str("2h5h8hTh3s").unwrap()),
+            Err(AnalysisError::InvalidBoardSize(5))
+        ));
+        assert!(matches!(
+            find_draws(&[], &Card::vec_from_str("2h5h8h").unwrap()),
+            Err(AnalysisError::InvalidHandSize(0))
+        ));
+        assert!(matches!(
+            find_draws(&hand, &Card::vec_from_str("2h5hAc").unwrap()),
+            Err(AnalysisError::DuplicateCard(_))
+        ));
+    }
+}
@@ -0,0 +1,95 @@
+//! Tools for analyzing hands and boards without dealing any cards
+//!
+//! These describe the situation at the table in the terms players use, such as which draws a hand
+//! has, and are intended for trainers, heads up displays, and bot logic.
+
+use thiserror::Error;
+
+use crate::core::{Card, CardDeckError, CardSet};
+use crate::poker::equity::EquityError;
+
+mod draws;
+pub use draws::{find_draws, Draws, StraightDraw};
+
+mod board_texture;
+pub use board_texture::{BoardTexture, Pairing, SuitTexture};
+
+mod combos;
+pub use combos::{blockers, count_combos, filter_by_class, filter_by_holding, Blocker, Holding};
+
+mod nuts;
+pub use nuts::{nuts, NutHand};
+
+mod domination;
+pub use domination::{domination, domination_with_rng, Domination, Matchup};
+
+mod runouts;
+pub use runouts::{
+    class_frequencies, hole_card_frequencies, runout_distribution, HandClassDistribution,
+};
+
+mod strength;
+pub use strength::{hand_strength, HandStrength};
+
+mod buckets;
+pub use buckets::{bucket_hands, strength_histograms, BucketMap, MAX_KMEANS_ROUNDS};
+
+mod advantage;
+pub use advantage::{equity_distribution, range_advantage, EquityDistribution, RangeAdvantage};
+
+/// Error type for hand and board analysis
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum AnalysisError {
+    /// A hand had the wrong number of cards for the analysis
+    #[error("A hand cannot have {0} cards")]
+    InvalidHandSize(usize),
+    /// The board had the wrong number of cards for the analysis
+    #[error("The board cannot have {0} cards")]
+    InvalidBoardSize(usize),
+    /// A card was given more than once
+    #[error("Card {0} was given more than once")]
+    DuplicateCard(Card),
+    /// A card that cannot be analyzed, such as a joker, was given
+    #[error("Card {0} cannot be analyzed")]
+    UnsupportedCard(Card),
+    /// A number of buckets or histogram bins that cannot be used
+    #[error("Hands cannot be split into {0} buckets")]
+    InvalidBucketCount(usize),
+    /// Every combo of a range shares a card with the known cards
+    #[error("The range has no combos that can be dealt")]
+    EmptyRange,
+    /// An equity calculation that supports the analysis failed
+    #[error(transparent)]
+    EquityError(#[from] EquityError),
+    /// Something went wrong with the deck, such as failing to sample entropy
+    #[error(transparent)]
+    DeckError(#[from] CardDeckError),
+}
+
+/// Checks that no card is repeated or a joker, and returns the set of every card given
+fn known_cards<'a>(cards: impl IntoIterator<Item = &'a Card>) -> Result<CardSet, AnalysisError> {
+    let mut known = CardSet::new();
+    for &card in cards {
+        if card.is_joker() {
+            return Err(AnalysisError::UnsupportedCard(card));
+        }
+        if !known.insert(card) {
+            return Err(AnalysisError::DuplicateCard(card));
+        }
+    }
+
+    Ok(known)
+}
+
+/// Returns a bit mask of the values of the cards, where bit `i` is set for the value `i`
+fn value_mask<'a>(cards: impl IntoIterator<Item = &'a Card>) -> u16 {
+    cards
+        .into_iter()
+        .fold(0, |mask, card| mask | 1 << card.value as u16)
+}
+
+/// The value masks of every five-card straight, from the wheel up to the broadway straight
+const STRAIGHTS: [u16; 10] = [
+    0x100f, 0x001f, 0x003e, 0x007c, 0x00f8, 0x01f0, 0x03e0, 0x07c0, 0x0f80, 0x1f00,
+];
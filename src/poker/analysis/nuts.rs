@@ -0,0 +1,123 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{known_cards, AnalysisError};
+use crate::core::{Card, CardSet};
+use crate::poker::evaluators::high_evaluator;
+use crate::poker::range::Combo;
+use crate::poker::ranks::HighRank;
+
+/// One of the strongest hands that can be made on a board, and every combo that makes it
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NutHand {
+    /// The rank of the hand, which includes its hand class and description
+    pub rank: HighRank,
+    /// The combos of hole cards that make the hand
+    pub combos: Vec<Combo>,
+}
+
+/// Finds the `count` strongest hands that can be made with two hole cards on a board of 3 to 5
+/// cards
+///
+/// The nuts comes first, followed by the second nuts, and so on. Combos that make hands of the
+/// same strength are grouped together, so each `NutHand` has a different rank. An error will
+/// return if the board has the wrong number of cards, or if any card is given more than once or is
+/// a joker.
+///
+/// ```rust
+/// use playing_cards::{
+///     core::Card,
+///     poker::{analysis, ranks::HandClass},
+/// };
+///
+/// let flop = Card::vec_from_str("Ks8d3c").unwrap();
+/// let nuts = analysis::nuts(&flop, 3).unwrap();
+///
+/// assert_eq!(nuts[0].rank.hand_class(), Some(HandClass::ThreeOfAKind));
+/// assert_eq!(nuts[0].rank.to_string(), "Trip Kings");
+/// assert_eq!(nuts[0].combos.len(), 3);
+/// assert_eq!(nuts[2].rank.to_string(), "Trip 3s");
+/// ```
+pub fn nuts(board: &[Card], count: usize) -> Result<Vec<NutHand>, AnalysisError> {
+    if !(3..=5).contains(&board.len()) {
+        return Err(AnalysisError::InvalidBoardSize(board.len()));
+    }
+    let unseen = CardSet::full() - known_cards(board)?;
+
+    let mut cards = [board[0]; 7];
+    cards[..board.len()].copy_from_slice(board);
+    let hand_size = board.len() + 2;
+
+    let mut strengths: Vec<(u32, Combo)> = Combo::all()
+        .filter(|combo| combo.cards().iter().all(|card| unseen.contains(*card)))
+        .filter_map(|combo| {
+            cards[board.len()..hand_size].copy_from_slice(&combo.cards());
+            Some((high_evaluator::best_strength(&cards[..hand_size])?, combo))
+        })
+        .collect();
+    strengths.sort_by_key(|(strength, _)| std::cmp::Reverse(*strength));
+
+    let mut hands: Vec<NutHand> = Vec::with_capacity(count);
+    for (strength, combo) in strengths {
+        if let Some(hand) = hands
+            .last_mut()
+            .filter(|hand| hand.rank.strength == strength)
+        {
+            hand.combos.push(combo);
+        } else if hands.len() == count {
+            break;
+        } else {
+            hands.push(NutHand {
+                rank: HighRank::from_strength(strength).expect("Strength should be valid"),
+                combos: vec![combo],
+            });
+        }
+    }
+
+    Ok(hands)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poker::ranks::HandClass;
+
+    fn combo(s: &str) -> Combo {
+        let cards = Card::vec_from_str(s).unwrap();
+        Combo::new(cards[0], cards[1]).unwrap()
+    }
+
+    #[test]
+    fn flush_boards() {
+        let board = Card::vec_from_str("Qh9h4h").unwrap();
+        let hands = nuts(&board, 3).unwrap();
+
+        assert_eq!(hands.len(), 3);
+        assert_eq!(hands[0].rank.hand_class(), Some(HandClass::Flush));
+        assert_eq!(hands[0].combos, vec![combo("AhKh")]);
+        assert_eq!(hands[1].combos, vec![combo("AhJh")]);
+        assert_eq!(hands[2].combos, vec![combo("AhTh")]);
+        assert!(hands[0].rank > hands[1].rank && hands[1].rank > hands[2].rank);
+    }
+
+    #[test]
+    fn rivers() {
+        // any ten of spades makes the royal flush
+        let board = Card::vec_from_str("AsKsQsJs2d").unwrap();
+        let hands = nuts(&board, 1).unwrap();
+        assert_eq!(hands[0].rank.hand_class(), Some(HandClass::StraightFlush));
+        assert_eq!(hands[0].combos.len(), 46);
+
+        // when the board plays, every combo is the nuts
+        let board = Card::vec_from_str("AsAhAdAcKs").unwrap();
+        let hands = nuts(&board, 2).unwrap();
+        assert_eq!(hands.len(), 1);
+        assert_eq!(hands[0].combos.len(), 47 * 46 / 2);
+
+        assert!(matches!(
+            nuts(&board[..2], 1),
+            Err(AnalysisError::InvalidBoardSize(2))
+        ));
+    }
+}
@@ -0,0 +1,232 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use super::{known_cards, AnalysisError};
+use crate::core::{Card, CardSet};
+use crate::poker::boards::Boards;
+use crate::poker::evaluators::high_evaluator;
+use crate::poker::ranks::HandClass;
+
+/// How often hands end up in each hand class, such as a hand on every runout of a board
+///
+/// ```rust
+/// use playing_cards::{
+///     core::Card,
+///     poker::{analysis, ranks::HandClass},
+/// };
+///
+/// let hand = Card::vec_from_str("AhKh").unwrap();
+/// let flop = Card::vec_from_str("Qh7h2d").unwrap();
+///
+/// let distribution = analysis::runout_distribution(&hand, &flop).unwrap();
+///
+/// assert_eq!(distribution.runouts(), 1081);
+/// assert!((distribution.percentage(HandClass::Flush) - 35.0).abs() < 1.0);
+/// assert_eq!(distribution.percentage(HandClass::FourOfAKind), 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HandClassDistribution {
+    counts: [u64; 9],
+}
+
+impl HandClassDistribution {
+    /// Returns the number of runouts that end in the hand class
+    pub fn count(&self, class: HandClass) -> u64 {
+        self.counts[class as usize - 1]
+    }
+
+    /// Returns the number of runouts that were counted
+    pub fn runouts(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Returns the probability from 0 to 1 of ending in the hand class
+    pub fn probability(&self, class: HandClass) -> f64 {
+        match self.runouts() {
+            0 => 0.0,
+            runouts => self.count(class) as f64 / runouts as f64,
+        }
+    }
+
+    /// Returns the percentage of runouts that end in the hand class
+    pub fn percentage(&self, class: HandClass) -> f64 {
+        100.0 * self.probability(class)
+    }
+
+    /// Returns an iterator over every hand class and its probability, from the weakest class to
+    /// the strongest
+    pub fn iter(&self) -> impl Iterator<Item = (HandClass, f64)> + '_ {
+        HandClass::iter().map(|class| (class, self.probability(class)))
+    }
+}
+
+/// Counts the hand class a hand ends up with on every possible runout of a board of 3 to 5 cards
+///
+/// Every combination of the cards left to come is counted once, so a flop has 1,081 runouts and a
+/// turn has 46. The hand must have 2 cards. An error will return if the hand or board has the
+/// wrong number of cards, or if any card is given more than once or is a joker.
+pub fn runout_distribution(
+    hand: &[Card],
+    board: &[Card],
+) -> Result<HandClassDistribution, AnalysisError> {
+    if hand.len() != 2 {
+        return Err(AnalysisError::InvalidHandSize(hand.len()));
+    }
+    if !(3..=5).contains(&board.len()) {
+        return Err(AnalysisError::InvalidBoardSize(board.len()));
+    }
+    known_cards(hand.iter().chain(board))?;
+
+    Ok(count_classes(hand, board, 5))
+}
+
+/// Counts the hand class of every hand of `card_count` cards from a standard deck
+///
+/// The counts are taken from the rank tables of the high evaluator rather than by evaluating every
+/// hand, and are built upon first use. Only card counts within the domain [5, 7] are supported, so
+/// `None` is returned for any other count.
+///
+/// ```rust
+/// use playing_cards::poker::{analysis, ranks::HandClass};
+///
+/// let five_cards = analysis::class_frequencies(5).unwrap();
+/// assert_eq!(five_cards.runouts(), 2_598_960);
+/// assert_eq!(five_cards.count(HandClass::FullHouse), 3_744);
+///
+/// // a seven-card hand is more likely to hold a pair than nothing at all
+/// let seven_cards = analysis::class_frequencies(7).unwrap();
+/// assert!(seven_cards.probability(HandClass::Pair) > seven_cards.probability(HandClass::HighCard));
+/// ```
+pub fn class_frequencies(card_count: usize) -> Option<HandClassDistribution> {
+    let cumulative = high_evaluator::cumulative_hand_counts(card_count)?;
+
+    let mut distribution = HandClassDistribution::default();
+    for class in HandClass::iter() {
+        let strengths = class.strengths();
+        distribution.counts[class as usize - 1] =
+            cumulative[*strengths.end() as usize] - cumulative[*strengths.start() as usize - 1];
+    }
+
+    Some(distribution)
+}
+
+/// Counts the hand class of every hand of `card_count` cards that holds the hole cards
+///
+/// Every set of `card_count - 2` cards that can go alongside the hole cards is counted once, so
+/// this is the same as the distribution of a Texas Hold'em hand on every possible board when
+/// `card_count` is 7. The hand must have 2 cards and `card_count` must be 5 to 7. An error will
+/// return if either has the wrong number of cards, or if a hole card is repeated or is a joker.
+pub fn hole_card_frequencies(
+    hand: &[Card],
+    card_count: usize,
+) -> Result<HandClassDistribution, AnalysisError> {
+    if hand.len() != 2 {
+        return Err(AnalysisError::InvalidHandSize(hand.len()));
+    }
+    if !(5..=7).contains(&card_count) {
+        return Err(AnalysisError::InvalidHandSize(card_count));
+    }
+    known_cards(hand)?;
+
+    Ok(count_classes(hand, &[], card_count - 2))
+}
+
+/// Counts the hand classes of the hand on every board of `size` cards that starts with `board`
+fn count_classes(hand: &[Card], board: &[Card], size: usize) -> HandClassDistribution {
+    let boards =
+        Boards::new(board, size, CardSet::from(hand)).expect("The board should already be checked");
+
+    let mut cards = [hand[0]; 7];
+    cards[..2].copy_from_slice(hand);
+    let cards = &mut cards[..2 + size];
+
+    let mut distribution = HandClassDistribution::default();
+    boards.for_each(|board| {
+        cards[2..].copy_from_slice(board);
+        if let Some(class) = high_evaluator::best_strength(cards).and_then(HandClass::from_strength)
+        {
+            distribution.counts[class as usize - 1] += 1;
+        }
+    });
+
+    distribution
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distributions() {
+        let distribution = |hand: &str, board: &str| {
+            runout_distribution(
+                &Card::vec_from_str(hand).unwrap(),
+                &Card::vec_from_str(board).unwrap(),
+            )
+            .unwrap()
+        };
+
+        // a set on the turn fills up or makes quads with 10 of the 46 rivers
+        let set = distribution("7c7d", "7hKs2c9d");
+        assert_eq!(set.runouts(), 46);
+        assert_eq!(set.count(HandClass::FullHouse), 9);
+        assert_eq!(set.count(HandClass::FourOfAKind), 1);
+        assert_eq!(set.count(HandClass::ThreeOfAKind), 36);
+        assert_eq!(set.iter().map(|(_, p)| p).sum::<f64>(), 1.0);
+
+        // the river has a single runout
+        let river = distribution("AsKs", "QsJsTs2d3c");
+        assert_eq!(river.runouts(), 1);
+        assert_eq!(river.probability(HandClass::StraightFlush), 1.0);
+    }
+
+    #[test]
+    fn frequencies() {
+        let counts = |card_count| {
+            let distribution = class_frequencies(card_count).unwrap();
+            HandClass::iter()
+                .map(|class| distribution.count(class))
+                .collect::<Vec<u64>>()
+        };
+
+        assert_eq!(
+            counts(5),
+            [1_302_540, 1_098_240, 123_552, 54_912, 10_200, 5_108, 3_744, 624, 40]
+        );
+        assert_eq!(counts(6).iter().sum::<u64>(), 20_358_520);
+        assert_eq!(
+            counts(7),
+            [
+                23_294_460, 58_627_800, 31_433_400, 6_461_620, 6_180_020, 4_047_644, 3_473_184,
+                224_848, 41_584
+            ]
+        );
+        assert!(class_frequencies(8).is_none());
+
+        // suited aces make a flush more often than the average hand
+        let hand = Card::vec_from_str("AsKs").unwrap();
+        let suited = hole_card_frequencies(&hand, 6).unwrap();
+        assert_eq!(suited.runouts(), 230_300);
+        assert!(suited.probability(HandClass::Flush) > counts(6)[5] as f64 / 20_358_520.0);
+        assert_eq!(
+            hole_card_frequencies(&hand, 5)
+                .unwrap()
+                .count(HandClass::StraightFlush),
+            1
+        );
+        assert!(matches!(
+            hole_card_frequencies(&hand, 8),
+            Err(AnalysisError::InvalidHandSize(8))
+        ));
+    }
+
+    #[test]
+    fn missing_board() {
+        assert!(matches!(
+            runout_distribution(&Card::vec_from_str("AsKs").unwrap(), &[]),
+            Err(AnalysisError::InvalidBoardSize(0))
+        ));
+    }
+}
@@ -0,0 +1,161 @@
+use std::cmp::Ordering;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{known_cards, AnalysisError};
+use crate::core::{Card, CardSet};
+use crate::poker::boards::Boards;
+use crate::poker::evaluators::high_evaluator;
+use crate::poker::range::{Combo, Range};
+
+/// How strong a hand is against a range, now and once the board is complete
+///
+/// Each strength is the weighted share of the range's combos that the hand beats, from 0 to 1, with
+/// ties counting as half. The expected hand strength (EHS) averages the strength on the river over
+/// every runout, while EHS² averages its square, which rewards hands whose strength varies, such as
+/// draws, over hands that are equally strong on average. These are the usual inputs for grouping
+/// hands in poker abstractions.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HandStrength {
+    /// The strength of the hand on the current board
+    pub strength: f64,
+    /// The expected strength of the hand on the river
+    pub ehs: f64,
+    /// The expected square of the strength of the hand on the river
+    pub ehs2: f64,
+}
+
+impl HandStrength {
+    /// Returns the variance of the strength of the hand on the river, which is higher for draws
+    pub fn variance(&self) -> f64 {
+        (self.ehs2 - self.ehs * self.ehs).max(0.0)
+    }
+}
+
+/// Calculates the exact strength, EHS, and EHS² of a Texas Hold'em hand against a range on a board
+/// of 3 to 5 cards
+///
+/// Every runout of the board is visited once, and on each one the hand is compared against every
+/// combo of the range that does not share a card with the hand or the board, weighted by the
+/// combo's weight. Use `Range::full()` to measure the hand against random hands. The hand must have
+/// 2 cards. An error will return if the hand or board has the wrong number of cards, if any card is
+/// given more than once or is a joker, or if every combo of the range shares a card with the hand
+/// or the board.
+///
+/// ```rust
+/// use playing_cards::{
+///     core::Card,
+///     poker::{analysis, range::Range},
+/// };
+///
+/// let turn = Card::vec_from_str("Qh7h2d3s").unwrap();
+/// let range = Range::full();
+///
+/// let flush_draw = Card::vec_from_str("AhKh").unwrap();
+/// let draw = analysis::hand_strength(&flush_draw, &turn, &range).unwrap();
+/// let second_pair = Card::vec_from_str("7s6s").unwrap();
+/// let made = analysis::hand_strength(&second_pair, &turn, &range).unwrap();
+///
+/// // the draw is behind now, but its strength on the river varies far more
+/// assert!(draw.strength < made.strength);
+/// assert!(draw.variance() > made.variance());
+/// ```
+pub fn hand_strength(
+    hand: &[Card],
+    board: &[Card],
+    range: &Range,
+) -> Result<HandStrength, AnalysisError> {
+    if hand.len() != 2 {
+        return Err(AnalysisError::InvalidHandSize(hand.len()));
+    }
+    if !(3..=5).contains(&board.len()) {
+        return Err(AnalysisError::InvalidBoardSize(board.len()));
+    }
+    let known = known_cards(hand.iter().chain(board))?;
+    let combos: Vec<(Combo, f64)> = range.without_dead(known).iter().collect();
+    let strength = share(hand, board, &combos).ok_or(AnalysisError::EmptyRange)?;
+
+    let (mut ehs, mut ehs2, mut runouts) = (0.0, 0.0, 0);
+    Boards::new(board, 5, CardSet::from(hand))
+        .expect("The board should already be checked")
+        .for_each(|runout| {
+            if let Some(strength) = share(hand, runout, &combos) {
+                ehs += strength;
+                ehs2 += strength * strength;
+                runouts += 1;
+            }
+        });
+
+    Ok(HandStrength {
+        strength,
+        ehs: ehs / runouts as f64,
+        ehs2: ehs2 / runouts as f64,
+    })
+}
+
+/// Returns the weighted share of the combos that the hand beats on the board, with ties counting as
+/// half, or `None` if every combo shares a card with the board
+fn share(hand: &[Card], board: &[Card], combos: &[(Combo, f64)]) -> Option<f64> {
+    let on_board = CardSet::from(board);
+    let mut cards = [hand[0]; 7];
+    let cards = &mut cards[..2 + board.len()];
+    cards[..2].copy_from_slice(hand);
+    cards[2..].copy_from_slice(board);
+    let strength = high_evaluator::best_strength(cards);
+
+    let (mut won, mut total) = (0.0, 0.0);
+    for (combo, weight) in combos {
+        let combo = combo.cards();
+        if combo.iter().any(|card| on_board.contains(*card)) {
+            continue;
+        }
+
+        cards[..2].copy_from_slice(&combo);
+        won += weight
+            * match strength.cmp(&high_evaluator::best_strength(cards)) {
+                Ordering::Greater => 1.0,
+                Ordering::Equal => 0.5,
+                Ordering::Less => 0.0,
+            };
+        total += weight;
+    }
+
+    (total > 0.0).then(|| won / total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hand;
+
+    #[test]
+    fn strengths() {
+        // the nuts on the river beat every hand
+        let nuts = hand_strength(&hand!("AsKs"), &hand!("QsJsTs2d3c"), &Range::full()).unwrap();
+        assert_eq!(nuts.strength, 1.0);
+        assert_eq!((nuts.ehs, nuts.ehs2), (1.0, 1.0));
+        assert_eq!(nuts.variance(), 0.0);
+
+        // the strength against a range only counts the range's combos that can still be dealt
+        let range: Range = "KK, QJ".parse().unwrap();
+        let turn = hand!("Qh7h2d3s");
+        let top_pair = hand_strength(&hand!("KsQs"), &turn, &range).unwrap();
+        assert_eq!(top_pair.strength, 8.0 / 11.0);
+        assert!(top_pair.ehs2 <= top_pair.ehs);
+
+        // weights scale how much each combo counts
+        let mut weighted = range.clone();
+        for (combo, _) in "KK".parse::<Range>().unwrap().iter() {
+            weighted.set_weight(combo, 3.0);
+        }
+        let against_weighted = hand_strength(&hand!("KsQs"), &turn, &weighted).unwrap();
+        assert_eq!(against_weighted.strength, 8.0 / 17.0);
+
+        assert!(matches!(
+            hand_strength(&hand!("KsQs"), &turn, &"Ks7s".parse().unwrap()),
+            Err(AnalysisError::EmptyRange)
+        ));
+    }
+}
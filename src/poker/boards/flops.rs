@@ -0,0 +1,286 @@
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::Boards;
+use crate::core::{Card, CardSet};
+
+/// The number of flops that differ once suits are relabeled
+pub const CANONICAL_FLOP_COUNT: usize = 1755;
+
+/// The number of flops that can be dealt from a standard deck
+pub const FLOP_COUNT: u32 = 22_100;
+
+/// A flop that stands for every flop that is the same once suits are relabeled
+///
+/// For example, `A♠K♠2♥` stands for every flop with the ace and king of one suit and the deuce of
+/// another, such as `A♦K♦2♣`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CanonicalFlop {
+    /// The cards of the flop, from the highest card to the lowest
+    pub cards: [Card; 3],
+    /// The number of flops that the flop stands for
+    pub weight: u32,
+}
+
+/// Returns the canonical form of a flop, which is shared by every flop that is the same once suits
+/// are relabeled
+///
+/// The cards are returned from the highest card to the lowest. Returns `None` if the flop does not
+/// have 3 cards, or if any card is repeated or is a joker.
+///
+/// ```rust
+/// use playing_cards::{core::Card, poker::boards};
+///
+/// let flop = Card::vec_from_str("2hAdKd").unwrap();
+/// let other = Card::vec_from_str("KcAc2s").unwrap();
+///
+/// assert_eq!(boards::canonical_flop(&flop), boards::canonical_flop(&other));
+/// ```
+pub fn canonical_flop(flop: &[Card]) -> Option<[Card; 3]> {
+    let [a, b, c] = flop else {
+        return None;
+    };
+    if [a, b, c].iter().any(|card| card.is_joker()) || CardSet::from(flop).len() != 3 {
+        return None;
+    }
+
+    Some(canonicalize([u8::from(*a), u8::from(*b), u8::from(*c)]).map(card))
+}
+
+/// Returns every canonical flop along with the number of flops it stands for
+///
+/// There are `CANONICAL_FLOP_COUNT` canonical flops, ordered from the highest cards to the lowest,
+/// and their weights add up to `FLOP_COUNT`. The flops are calculated the first time this is
+/// called.
+///
+/// ```rust
+/// use playing_cards::poker::boards;
+///
+/// let flops = boards::canonical_flops();
+///
+/// assert_eq!(flops.len(), boards::CANONICAL_FLOP_COUNT);
+/// assert_eq!(flops.iter().map(|flop| flop.weight).sum::<u32>(), boards::FLOP_COUNT);
+/// ```
+pub fn canonical_flops() -> &'static [CanonicalFlop] {
+    static FLOPS: OnceLock<Vec<CanonicalFlop>> = OnceLock::new();
+
+    FLOPS.get_or_init(|| {
+        let mut weights: BTreeMap<[u8; 3], u32> = BTreeMap::new();
+        Boards::new(&[], 3, CardSet::new())
+            .expect("An empty board is valid")
+            .for_each(|flop| {
+                let codes = [u8::from(flop[0]), u8::from(flop[1]), u8::from(flop[2])];
+                *weights.entry(canonicalize(codes)).or_default() += 1;
+            });
+
+        weights
+            .into_iter()
+            .rev()
+            .map(|(codes, weight)| CanonicalFlop {
+                cards: codes.map(card),
+                weight,
+            })
+            .collect()
+    })
+}
+
+/// Returns a representative subset of `size` canonical flops, whose weights stand for every flop
+///
+/// Solvers commonly study a subset of flops, such as 184 or 95 flops, instead of all 1,755. The
+/// subset is chosen by splitting the canonical flops by their pairing and suits, giving each group
+/// a share of the subset in proportion to its weight (with at least one flop each), and then
+/// picking flops evenly from the highest cards to the lowest within each group. Each chosen flop
+/// takes on the weights of the flops around it that it represents, so the weights still add up to
+/// `FLOP_COUNT`.
+///
+/// These subsets are chosen by this library, and are not the same as the subsets shipped with any
+/// particular solver. If `size` is at least `CANONICAL_FLOP_COUNT`, every canonical flop is
+/// returned. Since every group keeps at least one flop, a `size` smaller than the 6 groups returns
+/// one flop from each group.
+///
+/// ```rust
+/// use playing_cards::poker::boards;
+///
+/// let flops = boards::flop_subset(184);
+///
+/// assert_eq!(flops.len(), 184);
+/// assert_eq!(flops.iter().map(|flop| flop.weight).sum::<u32>(), boards::FLOP_COUNT);
+/// ```
+pub fn flop_subset(size: usize) -> Vec<CanonicalFlop> {
+    let flops = canonical_flops();
+    if size >= flops.len() {
+        return flops.to_vec();
+    }
+
+    let mut groups: BTreeMap<(usize, usize), Vec<CanonicalFlop>> = BTreeMap::new();
+    for flop in flops {
+        groups.entry(texture(flop)).or_default().push(*flop);
+    }
+    let groups: Vec<Vec<CanonicalFlop>> = groups.into_values().collect();
+    let size = size.max(groups.len());
+
+    let mut subset = Vec::with_capacity(size);
+    for (group, share) in groups.iter().zip(shares(&groups, size)) {
+        let total: u32 = group.iter().map(|flop| flop.weight).sum();
+
+        // each flop joins the part of the group that its midpoint falls within
+        let mut parts: Vec<Vec<CanonicalFlop>> = vec![Vec::new(); share];
+        let mut before = 0;
+        for flop in group {
+            let midpoint = 2 * before as usize + flop.weight as usize;
+            let part = (midpoint * share / (2 * total as usize)).min(share - 1);
+            parts[part].push(*flop);
+            before += flop.weight;
+        }
+
+        subset.extend(
+            parts
+                .iter()
+                .filter(|part| !part.is_empty())
+                .map(|part| CanonicalFlop {
+                    cards: part[part.len() / 2].cards,
+                    weight: part.iter().map(|flop| flop.weight).sum(),
+                }),
+        );
+    }
+
+    subset
+}
+
+/// Splits `size` flops between the groups in proportion to their weights, with at least one flop
+/// for each group
+fn shares(groups: &[Vec<CanonicalFlop>], size: usize) -> Vec<usize> {
+    let weights: Vec<usize> = groups
+        .iter()
+        .map(|group| group.iter().map(|flop| flop.weight as usize).sum())
+        .collect();
+    let total: usize = weights.iter().sum();
+
+    let mut shares: Vec<usize> = weights
+        .iter()
+        .map(|weight| (weight * size / total).max(1))
+        .collect();
+
+    // hand out the rest by the largest remainders, or take back from the largest shares
+    let mut by_remainder: Vec<usize> = (0..groups.len()).collect();
+    by_remainder.sort_by_key(|&i| std::cmp::Reverse(weights[i] * size % total));
+    let mut i = 0;
+    while shares.iter().sum::<usize>() < size {
+        let group = by_remainder[i % groups.len()];
+        if shares[group] < groups[group].len() {
+            shares[group] += 1;
+        }
+        i += 1;
+    }
+    while shares.iter().sum::<usize>() > size {
+        let largest = (0..groups.len()).max_by_key(|&i| shares[i]).unwrap_or(0);
+        shares[largest] -= 1;
+    }
+
+    shares
+}
+
+/// Returns the number of distinct values and suits on a flop
+fn texture(flop: &CanonicalFlop) -> (usize, usize) {
+    let values = flop
+        .cards
+        .iter()
+        .fold(0u16, |mask, card| mask | 1 << card.value as u16);
+    let suits = flop
+        .cards
+        .iter()
+        .fold(0u8, |mask, card| mask | 1 << card.suit as u8);
+    (values.count_ones() as usize, suits.count_ones() as usize)
+}
+
+/// Returns the highest ordering of the cards over every relabeling of the suits
+fn canonicalize(codes: [u8; 3]) -> [u8; 3] {
+    let mut best = [0; 3];
+    for a in 0..4u8 {
+        for b in (0..4).filter(|&b| b != a) {
+            for c in (0..4).filter(|&c| c != a && c != b) {
+                let d = 6 - a - b - c;
+                let suits = [a, b, c, d];
+
+                let mut relabeled = codes.map(|code| code / 4 * 4 + suits[(code % 4) as usize]);
+                relabeled.sort_unstable_by(|x, y| y.cmp(x));
+                best = best.max(relabeled);
+            }
+        }
+    }
+    best
+}
+
+fn card(code: u8) -> Card {
+    Card::try_from(code).expect("Flops only contain cards of a standard deck")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_forms() {
+        let canonical = |flop: &str| canonical_flop(&Card::vec_from_str(flop).unwrap());
+
+        assert_eq!(canonical("AsKs2h"), canonical("Ac2dKc"));
+        assert_ne!(canonical("AsKs2h"), canonical("AsKh2s"));
+        assert_eq!(canonical("7c7d7h"), canonical("7s7h7d"));
+        assert_eq!(canonical("AsKs"), None);
+        assert_eq!(canonical("AsAsKs"), None);
+
+        // every flop has the weight of the flops it stands for
+        let flops = canonical_flops();
+        let weight = |flop: &str| {
+            let cards = canonical(flop).unwrap();
+            flops
+                .iter()
+                .find(|flop| flop.cards == cards)
+                .unwrap()
+                .weight
+        };
+        assert_eq!(weight("AsKsQs"), 4);
+        assert_eq!(weight("AsKsQh"), 12);
+        assert_eq!(weight("AsKhQd"), 24);
+        assert_eq!(weight("AsAhKs"), 12);
+        assert_eq!(weight("2s2h2d"), 4);
+    }
+
+    #[test]
+    fn subsets() {
+        for size in [95, 184, 500] {
+            let subset = flop_subset(size);
+            assert_eq!(subset.len(), size);
+            assert_eq!(
+                subset.iter().map(|flop| flop.weight).sum::<u32>(),
+                FLOP_COUNT
+            );
+
+            // the monotone flops keep their share of about 5%
+            let monotone: u32 = subset
+                .iter()
+                .filter(|flop| texture(flop).1 == 1)
+                .map(|flop| flop.weight)
+                .sum();
+            assert_eq!(monotone, 4 * 286);
+        }
+
+        assert_eq!(flop_subset(2_000).len(), CANONICAL_FLOP_COUNT);
+    }
+
+    #[test]
+    fn small_subsets() {
+        for size in 0..=7 {
+            let subset = flop_subset(size);
+            assert_eq!(subset.len(), size.max(6));
+            assert_eq!(
+                subset.iter().map(|flop| flop.weight).sum::<u32>(),
+                FLOP_COUNT
+            );
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//! Exhaustive enumeration of boards
+//!
+//! Exact calculations, such as counting how a hand ends up on every runout, need to visit every
+//! board that can still come. `Boards` does so without allocating for each board.
+//!
+//! Flops that only differ by their suits play the same way, so `canonical_flops()` groups the
+//! 22,100 flops into 1,755 canonical flops with weights, and `flop_subset()` picks a smaller
+//! representative set of them for studies that cannot afford every flop.
+
+mod flops;
+
+pub use flops::{
+    canonical_flop, canonical_flops, flop_subset, CanonicalFlop, CANONICAL_FLOP_COUNT, FLOP_COUNT,
+};
+
+use crate::core::{cards, Card, CardSet};
+
+/// Every board of a given size that can be made by adding cards to a partial board
+///
+/// Each board starts with the cards of the partial board, followed by the cards that were added
+/// in ascending order of their `u8` encoding. Every combination of cards that are not on the
+/// partial board or dead is visited exactly once.
+///
+/// Boards are lent out one at a time by `next_board()`, which reuses the same buffer for every
+/// board, so this does not implement `Iterator`.
+///
+/// ```rust
+/// use playing_cards::{
+///     core::{Card, CardSet},
+///     poker::boards::Boards,
+/// };
+///
+/// let flop = Card::vec_from_str("Ah7c2d").unwrap();
+/// let dead: CardSet = Card::vec_from_str("KsKh").unwrap().into();
+///
+/// // every turn and river that can come
+/// let mut rivers = Boards::new(&flop, 5, dead).unwrap();
+/// assert_eq!(rivers.len(), 47 * 46 / 2);
+///
+/// let mut count = 0;
+/// while let Some(board) = rivers.next_board() {
+///     assert_eq!(&board[..3], &flop[..]);
+///     count += 1;
+/// }
+/// assert_eq!(count, 1081);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Boards {
+    remaining: Vec<Card>,
+    indices: Vec<usize>,
+    board: Vec<Card>,
+    known: usize,
+    started: bool,
+    done: bool,
+}
+
+impl Boards {
+    /// Creates an enumeration of every board of `size` cards that starts with `board`, without
+    /// using any of the `dead` cards
+    ///
+    /// Returns `None` if `board` has more than `size` cards, or if any card on the board is
+    /// repeated or is a joker.
+    pub fn new(board: &[Card], size: usize, dead: CardSet) -> Option<Self> {
+        if board.len() > size {
+            return None;
+        }
+
+        let mut known = CardSet::new();
+        for &card in board {
+            if card.is_joker() || !known.insert(card) {
+                return None;
+            }
+        }
+
+        // the cards after the partial board are placeholders until the first board is made
+        let mut cards = board.to_vec();
+        cards.resize(size, cards::ACE_OF_SPADES);
+
+        Some(Self {
+            remaining: (CardSet::full() - known - dead).into_iter().collect(),
+            indices: (0..size - board.len()).collect(),
+            board: cards,
+            known: board.len(),
+            started: false,
+            done: false,
+        })
+    }
+
+    /// Returns the number of boards in the enumeration, including those already visited
+    pub fn len(&self) -> usize {
+        let (n, k) = (self.remaining.len(), self.indices.len());
+        if k > n {
+            return 0;
+        }
+
+        (0..k).fold(1, |count, i| count * (n - i) / (i + 1))
+    }
+
+    /// Returns whether there are no boards in the enumeration
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the next board, or `None` once every board has been visited
+    ///
+    /// The slice is only valid until the next call, since the same buffer is reused for every
+    /// board.
+    pub fn next_board(&mut self) -> Option<&[Card]> {
+        if self.done {
+            return None;
+        }
+
+        let (n, k) = (self.remaining.len(), self.indices.len());
+        let first_changed = if !self.started {
+            self.started = true;
+            if k > n {
+                self.done = true;
+                return None;
+            }
+            0
+        } else {
+            // advance the rightmost index that is not already at its last position
+            match (0..k).rev().find(|&i| self.indices[i] < n - k + i) {
+                Some(i) => {
+                    self.indices[i] += 1;
+                    for j in i + 1..k {
+                        self.indices[j] = self.indices[j - 1] + 1;
+                    }
+                    i
+                }
+                None => {
+                    self.done = true;
+                    return None;
+                }
+            }
+        };
+
+        for i in first_changed..k {
+            self.board[self.known + i] = self.remaining[self.indices[i]];
+        }
+
+        Some(&self.board)
+    }
+
+    /// Calls a closure on every board that has not been visited yet
+    pub fn for_each(mut self, mut f: impl FnMut(&[Card])) {
+        while let Some(board) = self.next_board() {
+            f(board);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_board_once() {
+        let mut seen = std::collections::HashSet::new();
+        let boards = Boards::new(&[], 3, CardSet::new()).unwrap();
+        assert_eq!(boards.len(), 22_100);
+        boards.for_each(|board| {
+            assert!(u8::from(board[0]) < u8::from(board[1]));
+            assert!(u8::from(board[1]) < u8::from(board[2]));
+            assert!(seen.insert(CardSet::from(board)));
+        });
+        assert_eq!(seen.len(), 22_100);
+    }
+
+    #[test]
+    fn partial_boards() {
+        let turn = Card::vec_from_str("2c3c4c5c").unwrap();
+        let dead =
+            CardSet::full() - CardSet::from(&turn[..]) - Card::vec_from_str("As6c").unwrap().into();
+
+        let mut rivers = Boards::new(&turn, 5, dead).unwrap();
+        assert_eq!(
+            rivers.next_board().unwrap()[4],
+            Card::vec_from_str("6c").unwrap()[0]
+        );
+        assert_eq!(
+            rivers.next_board().unwrap()[4],
+            Card::vec_from_str("As").unwrap()[0]
+        );
+        assert_eq!(rivers.next_board(), None);
+        assert_eq!(rivers.next_board(), None);
+
+        // a complete board is its only board
+        let mut complete = Boards::new(&turn, 4, CardSet::new()).unwrap();
+        assert_eq!(complete.len(), 1);
+        assert_eq!(complete.next_board(), Some(&turn[..]));
+        assert_eq!(complete.next_board(), None);
+
+        // there are not enough cards left for any board
+        let mut empty = Boards::new(&turn, 7, dead).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(empty.next_board(), None);
+
+        assert!(Boards::new(&turn, 3, CardSet::new()).is_none());
+        assert!(Boards::new(&Card::vec_from_str("2c2c").unwrap(), 3, CardSet::new()).is_none());
+    }
+}
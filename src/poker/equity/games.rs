@@ -0,0 +1,390 @@
+use rand::Rng;
+use rand_core::{RngCore, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::hi_lo::{award, omaha_strengths};
+use super::{deal, stub, EquityError, HiLoEquity};
+use crate::core::{Card, CardDeck, CardSet};
+use crate::poker::evaluators::high_evaluator;
+
+/// A game with a shared board supported by `game_monte_carlo()` and `range_monte_carlo()`
+///
+/// Every game is dealt a board of 5 cards. The results are given as a `HiLoEquity`, where games
+/// without a low hand only ever win high pots.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Game {
+    /// Texas Hold'em, where each player has 2 hole cards and can use any 5 of their hole cards and
+    /// the board
+    Holdem,
+    /// Omaha, where each player has 4 hole cards and must use exactly 2 of them with 3 cards from
+    /// the board
+    Omaha,
+    /// Omaha Hi-Lo, which is played like Omaha but splits the pot with the best 8-or-better low
+    /// hand
+    Omaha8,
+}
+
+impl Game {
+    /// Returns the number of hole cards each player has
+    pub fn hand_size(&self) -> usize {
+        match self {
+            Self::Holdem => 2,
+            Self::Omaha | Self::Omaha8 => 4,
+        }
+    }
+
+    /// Returns the strength of the best high hand and, in split-pot games, of the best qualifying
+    /// low hand on a complete board
+    fn evaluate(&self, hand: &[Card], board: &[Card]) -> (u32, Option<u32>) {
+        match self {
+            Self::Holdem => {
+                let mut cards = [hand[0]; 7];
+                cards[..2].copy_from_slice(hand);
+                cards[2..].copy_from_slice(board);
+                (high_evaluator::best_strength(&cards).unwrap_or(0), None)
+            }
+            Self::Omaha => omaha_strengths(hand, board, false),
+            Self::Omaha8 => omaha_strengths(hand, board, true),
+        }
+    }
+
+    /// Checks that every hand and the board have the right number of cards for the game
+    fn check(&self, hands: &[&[Card]], board: &[Card]) -> Result<(), EquityError> {
+        if let Some(hand) = hands.iter().find(|hand| hand.len() != self.hand_size()) {
+            return Err(EquityError::InvalidHandSize(hand.len()));
+        }
+        if board.len() > 5 {
+            return Err(EquityError::InvalidBoardSize(board.len()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Estimates the equity of `hand1` against `hand2` in a game by dealing out the rest of the board
+/// `iterations` times
+///
+/// The random number generator is seeded from entropy. See `game_monte_carlo_with_rng()` for
+/// details and for reproducible results.
+pub fn game_monte_carlo(
+    game: Game,
+    hand1: &[Card],
+    hand2: &[Card],
+    board: &[Card],
+    dead: &[Card],
+    iterations: u64,
+) -> Result<HiLoEquity, EquityError> {
+    let mut rng = Xoshiro256PlusPlus::from_seed(CardDeck::seed_or_entropy(None)?);
+    game_monte_carlo_with_rng(game, hand1, hand2, board, dead, iterations, &mut rng)
+}
+
+/// Estimates the equity of `hand1` against `hand2` in a game by dealing out the rest of the board
+/// `iterations` times with the given random number generator
+///
+/// Each hand must have the game's number of hole cards, and the board can have up to 5 cards. Each
+/// hand is evaluated the way the game requires, so in Omaha only the hands that use exactly 2 hole
+/// cards with 3 cards from the board are considered. The results are from the perspective of the
+/// first hand. An error will return if a hand or the board has the wrong number of cards, or if any
+/// card is given more than once or is a joker.
+///
+/// ```rust
+/// use playing_cards::{
+///     core::Card,
+///     poker::equity::{self, Game},
+/// };
+/// use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+///
+/// let hero = Card::vec_from_str("AcAhKsKh").unwrap();
+/// let villain = Card::vec_from_str("JdTd9c8c").unwrap();
+/// let board = Card::vec_from_str("As7d6c2h").unwrap();
+///
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(4);
+/// let equity =
+///     equity::game_monte_carlo_with_rng(Game::Omaha, &hero, &villain, &board, &[], 5_000, &mut rng)
+///         .unwrap();
+///
+/// // the set of aces is ahead of the villain's draws to a straight
+/// assert!(equity.equity_percentage() > 60.0);
+/// assert_eq!(equity.low_pots, 0.0);
+/// ```
+pub fn game_monte_carlo_with_rng<R: RngCore + ?Sized>(
+    game: Game,
+    hand1: &[Card],
+    hand2: &[Card],
+    board: &[Card],
+    dead: &[Card],
+    iterations: u64,
+    rng: &mut R,
+) -> Result<HiLoEquity, EquityError> {
+    game.check(&[hand1, hand2], board)?;
+    let mut stub = stub(&[hand1, hand2], board, dead)?;
+    let missing = 5 - board.len();
+    if missing > stub.len() {
+        return Err(EquityError::NotEnoughCards(missing));
+    }
+
+    let mut full_board = [hand1[0]; 5];
+    full_board[..board.len()].copy_from_slice(board);
+    let mut results = [HiLoEquity::default(); 2];
+    for _ in 0..iterations {
+        deal(&mut stub, missing, rng);
+        full_board[board.len()..].copy_from_slice(&stub[..missing]);
+        let strengths = [
+            game.evaluate(hand1, &full_board),
+            game.evaluate(hand2, &full_board),
+        ];
+        award(&strengths, &mut results);
+    }
+
+    Ok(results[0])
+}
+
+/// Estimates the equity of `hand` against a range of hands in a game by dealing out an opponent and
+/// the rest of the board `iterations` times
+///
+/// The random number generator is seeded from entropy. See `range_monte_carlo_with_rng()` for
+/// details and for reproducible results.
+pub fn range_monte_carlo(
+    game: Game,
+    hand: &[Card],
+    range: &[&[Card]],
+    board: &[Card],
+    dead: &[Card],
+    iterations: u64,
+) -> Result<HiLoEquity, EquityError> {
+    let mut rng = Xoshiro256PlusPlus::from_seed(CardDeck::seed_or_entropy(None)?);
+    range_monte_carlo_with_rng(game, hand, range, board, dead, iterations, &mut rng)
+}
+
+/// Estimates the equity of `hand` against a range of hands in a game by dealing out an opponent and
+/// the rest of the board `iterations` times with the given random number generator
+///
+/// Each iteration picks one of the hands of the range that does not share a card with `hand`, the
+/// board, or the dead cards, with every such hand equally likely, and then deals the rest of the
+/// board from the cards that are left. The hands of a Texas Hold'em `Range` can be given with
+/// `Combo::cards()`. An error will return if `hand`, any hand of the range, or the board has the
+/// wrong number of cards, if any known card is given more than once or is a joker, if every hand
+/// of the range shares a card with the known cards, or if too many cards are dead to finish the
+/// board.
+///
+/// ```rust
+/// use playing_cards::{
+///     core::Card,
+///     poker::equity::{self, Game},
+/// };
+/// use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+///
+/// let hero = Card::vec_from_str("As2s3dKd").unwrap();
+/// let range = ["KcKhQsJs", "AdAcTh9h", "Ah4h5c6c"].map(|hand| Card::vec_from_str(hand).unwrap());
+/// let range: Vec<&[Card]> = range.iter().map(Vec::as_slice).collect();
+/// let flop = Card::vec_from_str("7c5d4s").unwrap();
+///
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(8);
+/// let equity =
+///     equity::range_monte_carlo_with_rng(Game::Omaha8, &hero, &range, &flop, &[], 3_000, &mut rng)
+///         .unwrap();
+///
+/// // the hero already has a low and draws to the wheel
+/// assert!(equity.equity_percentage() > 40.0);
+/// assert!(equity.low_percentage() > equity.high_percentage());
+/// assert!(equity.low_pots > 0.0);
+/// ```
+pub fn range_monte_carlo_with_rng<R: RngCore + ?Sized>(
+    game: Game,
+    hand: &[Card],
+    range: &[&[Card]],
+    board: &[Card],
+    dead: &[Card],
+    iterations: u64,
+    rng: &mut R,
+) -> Result<HiLoEquity, EquityError> {
+    game.check(&[hand], board)?;
+    game.check(range, board)?;
+    let mut stub = stub(&[hand], board, dead)?;
+    let available: CardSet = stub.iter().copied().collect();
+    let opponents: Vec<&[Card]> = range
+        .iter()
+        .copied()
+        .filter(|opponent| {
+            let mut seen = CardSet::new();
+            opponent
+                .iter()
+                .all(|card| available.contains(*card) && seen.insert(*card))
+        })
+        .collect();
+    if opponents.is_empty() {
+        return Err(EquityError::EmptyRange);
+    }
+    // every opponent holds the same number of cards, which cannot be dealt to the board
+    let missing = 5 - board.len();
+    if missing + opponents[0].len() > stub.len() {
+        return Err(EquityError::NotEnoughCards(missing));
+    }
+
+    let mut full_board = [hand[0]; 5];
+    full_board[..board.len()].copy_from_slice(board);
+    let mut results = [HiLoEquity::default(); 2];
+    for _ in 0..iterations {
+        let opponent = opponents[rng.gen_range(0..opponents.len())];
+
+        // move the opponent's cards to the back of the stub so that they are not dealt
+        let mut usable = stub.len();
+        for card in opponent {
+            let position = stub[..usable]
+                .iter()
+                .position(|other| other == card)
+                .expect("The opponent's cards should be in the stub");
+            usable -= 1;
+            stub.swap(position, usable);
+        }
+
+        deal(&mut stub[..usable], missing, rng);
+        full_board[board.len()..].copy_from_slice(&stub[..missing]);
+        let strengths = [
+            game.evaluate(hand, &full_board),
+            game.evaluate(opponent, &full_board),
+        ];
+        award(&strengths, &mut results);
+    }
+
+    Ok(results[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hand;
+    use crate::poker::equity::monte_carlo_with_rng;
+    use crate::poker::range::Range;
+
+    #[test]
+    fn omaha_uses_two_hole_cards() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(12);
+
+        // four spades on the board are no flush with a single spade in an Omaha hand
+        let board = hand!("2s5s8sTsKd");
+        let one_spade = hand!("AsKhKcQd");
+        let two_spades = hand!("3s4s7d7c");
+        let equity = game_monte_carlo_with_rng(
+            Game::Omaha,
+            &one_spade,
+            &two_spades,
+            &board,
+            &[],
+            10,
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(equity.high_pots, 0.0);
+        assert_eq!(equity.trials, 10);
+
+        // in Hold'em the ace of spades makes the nut flush
+        let equity = game_monte_carlo_with_rng(
+            Game::Holdem,
+            &one_spade[..2],
+            &two_spades[..2],
+            &board,
+            &[],
+            10,
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(equity.scoops, 10);
+
+        // a wheel scoops an Omaha Hi-Lo pot
+        let board = hand!("3d4d5cKsQh");
+        let equity = game_monte_carlo_with_rng(
+            Game::Omaha8,
+            &hand!("As2sJcJd"),
+            &hand!("KcKdTh9h"),
+            &board,
+            &[],
+            10,
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(equity.scoops, 10);
+        assert_eq!(equity.low_percentage(), 50.0);
+    }
+
+    #[test]
+    fn holdem_matches_monte_carlo() {
+        let (hero, villain) = (hand!("AsKs"), hand!("QdQc"));
+
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(2);
+        let holdem = monte_carlo_with_rng(&hero, &villain, &[], &[], 5_000, &mut rng).unwrap();
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(2);
+        let game =
+            game_monte_carlo_with_rng(Game::Holdem, &hero, &villain, &[], &[], 5_000, &mut rng)
+                .unwrap();
+        assert!((game.equity_percentage() - holdem.equity_percentage()).abs() < 1e-9);
+
+        // a range of a single hand is the same matchup
+        let range: Range = "QdQc".parse().unwrap();
+        let combos: Vec<[Card; 2]> = range.iter().map(|(combo, _)| combo.cards()).collect();
+        let combos: Vec<&[Card]> = combos.iter().map(|combo| combo.as_slice()).collect();
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(3);
+        let against_range =
+            range_monte_carlo_with_rng(Game::Holdem, &hero, &combos, &[], &[], 5_000, &mut rng)
+                .unwrap();
+        assert!((against_range.equity_percentage() - holdem.equity_percentage()).abs() < 2.0);
+    }
+
+    #[test]
+    fn ranges() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(10);
+        let board = hand!("AhKh7c2d");
+
+        // the only opponent that can be dealt draws to the nut flush against a set of kings
+        let (blocked, live) = (hand!("AsAcQdJd"), hand!("QhJhTs9s"));
+        let equity = range_monte_carlo_with_rng(
+            Game::Omaha,
+            &hand!("AsKsKd3c"),
+            &[&blocked, &live],
+            &board,
+            &[],
+            2_000,
+            &mut rng,
+        )
+        .unwrap();
+        assert!(equity.equity_percentage() < 90.0 && equity.equity_percentage() > 50.0);
+
+        assert!(matches!(
+            range_monte_carlo(Game::Omaha, &hand!("AsKsKd3c"), &[&blocked], &board, &[], 1),
+            Err(EquityError::EmptyRange)
+        ));
+        assert!(matches!(
+            range_monte_carlo(
+                Game::Omaha,
+                &hand!("AsKsKd3c"),
+                &[&hand!("QsQc")],
+                &board,
+                &[],
+                1
+            ),
+            Err(EquityError::InvalidHandSize(2))
+        ));
+        assert!(matches!(
+            game_monte_carlo(Game::Omaha8, &hand!("AsKs"), &live, &board, &[], 1),
+            Err(EquityError::InvalidHandSize(2))
+        ));
+
+        // once the opponent is dealt, a single card is left to deal the turn and river
+        let (hero, villain, flop) = (hand!("AsKs"), hand!("QdQc"), hand!("3c4c5c"));
+        let known: CardSet = [&hero, &villain, &flop]
+            .into_iter()
+            .flatten()
+            .copied()
+            .collect();
+        let dead: Vec<Card> = (CardSet::full() - known).iter().skip(1).collect();
+        assert!(matches!(
+            range_monte_carlo(Game::Holdem, &hero, &[&villain], &flop, &dead, 1),
+            Err(EquityError::NotEnoughCards(2))
+        ));
+        assert!(range_monte_carlo(Game::Holdem, &hero, &[&villain], &flop, &dead[1..], 1).is_ok());
+    }
+}
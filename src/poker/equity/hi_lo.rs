@@ -0,0 +1,428 @@
+use rand::Rng;
+use rand_core::{RngCore, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{stub, EquityError};
+use crate::core::{AceOrdering, Card, CardDeck, Value};
+use crate::poker::evaluators::{high_evaluator, omaha_hilo_evaluator};
+
+/// A split-pot game supported by `hi_lo_monte_carlo()`
+///
+/// Both games split the pot between the best high hand and the best 8-or-better low hand. If no
+/// player makes a qualifying low, the best high hand wins the whole pot.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HiLoGame {
+    /// Omaha Hi-Lo, where each player has 4 hole cards and must use exactly 2 of them with 3
+    /// cards from a board of up to 5 cards
+    Omaha8,
+    /// Seven Card Stud Hi-Lo, where each player has up to 7 cards of their own and there is no
+    /// board
+    Stud8,
+}
+
+impl HiLoGame {
+    /// The number of cards each player has at showdown
+    fn hand_size(&self) -> usize {
+        match self {
+            Self::Omaha8 => 4,
+            Self::Stud8 => 7,
+        }
+    }
+
+    /// The number of cards on the board at showdown
+    fn board_size(&self) -> usize {
+        match self {
+            Self::Omaha8 => 5,
+            Self::Stud8 => 0,
+        }
+    }
+
+    /// Returns whether a hand of `len` known cards can be played
+    fn is_valid_hand(&self, len: usize) -> bool {
+        match self {
+            Self::Omaha8 => len == 4,
+            Self::Stud8 => len <= 7,
+        }
+    }
+
+    /// Returns the strength of the best high hand and of the best qualifying low hand
+    fn evaluate(&self, hand: &[Card], board: &[Card]) -> (u32, Option<u32>) {
+        match self {
+            Self::Omaha8 => omaha_strengths(hand, board, true),
+            Self::Stud8 => {
+                let high = high_evaluator::best_strength(hand).unwrap_or(0);
+
+                // the five lowest distinct values always make the best low
+                let mut bits = hand.iter().fold(0, |acc, &card| acc | low_bit(card));
+                while bits.count_ones() > 5 {
+                    bits &= !(0x80 >> bits.leading_zeros());
+                }
+                (high, omaha_hilo_evaluator::low_8_strength(bits))
+            }
+        }
+    }
+}
+
+/// Returns the strength of the best Omaha high hand, and of the best qualifying low hand if `low`
+/// is set, using exactly 2 cards from the hand and 3 from the board
+pub(super) fn omaha_strengths(hand: &[Card], board: &[Card], low: bool) -> (u32, Option<u32>) {
+    let mut best_high = 0;
+    let mut best_low = None;
+    for (i, &h0) in hand.iter().enumerate() {
+        for &h1 in &hand[i + 1..] {
+            for (j, &b0) in board.iter().enumerate() {
+                for (k, &b1) in board.iter().enumerate().skip(j + 1) {
+                    for &b2 in &board[k + 1..] {
+                        let cards = [h0, h1, b0, b1, b2];
+                        best_high =
+                            best_high.max(high_evaluator::best_strength(&cards).unwrap_or(0));
+                        if !low {
+                            continue;
+                        }
+
+                        let bits = cards.map(low_bit);
+                        let union = bits.iter().fold(0, |acc, bit| acc | bit);
+                        if union.count_ones() == 5 {
+                            best_low = best_low.max(omaha_hilo_evaluator::low_8_strength(union));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    (best_high, best_low)
+}
+
+/// Returns the ace-low bit of a card that can be used in an 8-or-better low, or 0 if it cannot
+fn low_bit(card: Card) -> u8 {
+    if card.value <= Value::Eight || card.value == Value::Ace {
+        1 << AceOrdering::Low.index(card.value)
+    } else {
+        0
+    }
+}
+
+/// The results of a split-pot equity calculation for one player
+///
+/// Pots that are split count fractionally, so a player that wins the high hand alone while tying
+/// the low hand with one other player wins 0.5 high pots and 0.25 low pots.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HiLoEquity {
+    /// The number of pots that were played
+    pub trials: u64,
+    /// The number of pots won with the high hand
+    ///
+    /// When no player makes a qualifying low, the high hand wins the whole pot.
+    pub high_pots: f64,
+    /// The number of pots won with the low hand
+    pub low_pots: f64,
+    /// The number of pots the player won entirely by themselves
+    pub scoops: u64,
+    /// The number of pots where the player only won a quarter, by tying one half with one other
+    /// player and losing the other half
+    pub quartered: u64,
+}
+
+impl HiLoEquity {
+    /// Returns the percentage of the pot the player wins on average
+    ///
+    /// Like the other percentages, this returns 0 if no pots were played.
+    pub fn equity_percentage(&self) -> f64 {
+        self.percentage(self.high_pots + self.low_pots)
+    }
+
+    /// Returns the percentage of the pot the player wins on average with the high hand
+    pub fn high_percentage(&self) -> f64 {
+        self.percentage(self.high_pots)
+    }
+
+    /// Returns the percentage of the pot the player wins on average with the low hand
+    pub fn low_percentage(&self) -> f64 {
+        self.percentage(self.low_pots)
+    }
+
+    /// Returns the percentage of pots the player scooped
+    pub fn scoop_percentage(&self) -> f64 {
+        self.percentage(self.scoops as f64)
+    }
+
+    /// Returns the percentage of pots where the player was quartered
+    pub fn quartered_percentage(&self) -> f64 {
+        self.percentage(self.quartered as f64)
+    }
+
+    fn percentage(&self, pots: f64) -> f64 {
+        match self.trials {
+            0 => 0.0,
+            trials => 100.0 * pots / trials as f64,
+        }
+    }
+}
+
+/// Estimates the split-pot equity of every hand by dealing out the rest of the cards
+/// `iterations` times
+///
+/// The random number generator is seeded from entropy. See `hi_lo_monte_carlo_with_rng()` for
+/// details and for reproducible results.
+pub fn hi_lo_monte_carlo(
+    game: HiLoGame,
+    hands: &[&[Card]],
+    board: &[Card],
+    dead: &[Card],
+    iterations: u64,
+) -> Result<Vec<HiLoEquity>, EquityError> {
+    let mut rng = Xoshiro256PlusPlus::from_seed(CardDeck::seed_or_entropy(None)?);
+    hi_lo_monte_carlo_with_rng(game, hands, board, dead, iterations, &mut rng)
+}
+
+/// Estimates the split-pot equity of every hand by dealing out the rest of the cards
+/// `iterations` times with the given random number generator
+///
+/// Each hand is dealt up to the number of cards it has at showdown, as is the board. The `dead`
+/// cards are never dealt, such as the upcards of players that folded in stud. The results are
+/// returned in the same order as `hands`. An error will return if a hand or the board has the
+/// wrong number of cards for the game, if any card is given more than once or is a joker, or if
+/// there are not enough cards left to finish dealing.
+///
+/// ```rust
+/// use playing_cards::{
+///     core::Card,
+///     poker::equity::{self, HiLoGame},
+/// };
+/// use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+///
+/// let hero = Card::vec_from_str("As2sKd3d").unwrap();
+/// let villain = Card::vec_from_str("KsKhQcJc").unwrap();
+/// let flop = Card::vec_from_str("7h5c4d").unwrap();
+///
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(3);
+/// let results = equity::hi_lo_monte_carlo_with_rng(
+///     HiLoGame::Omaha8,
+///     &[&hero, &villain],
+///     &flop,
+///     &[],
+///     2_000,
+///     &mut rng,
+/// )
+/// .unwrap();
+///
+/// // the villain can never make a low, so the hero always takes the low half
+/// assert_eq!(results[0].low_percentage(), 50.0);
+/// assert_eq!(results[1].low_pots, 0.0);
+/// assert_eq!(results[1].scoops, 0);
+/// ```
+pub fn hi_lo_monte_carlo_with_rng<R: RngCore + ?Sized>(
+    game: HiLoGame,
+    hands: &[&[Card]],
+    board: &[Card],
+    dead: &[Card],
+    iterations: u64,
+    rng: &mut R,
+) -> Result<Vec<HiLoEquity>, EquityError> {
+    if let Some(hand) = hands.iter().find(|hand| !game.is_valid_hand(hand.len())) {
+        return Err(EquityError::InvalidHandSize(hand.len()));
+    }
+    if board.len() > game.board_size() {
+        return Err(EquityError::InvalidBoardSize(board.len()));
+    }
+
+    let mut stub = stub(hands, board, dead)?;
+    let missing = hands
+        .iter()
+        .map(|hand| game.hand_size() - hand.len())
+        .sum::<usize>()
+        + game.board_size()
+        - board.len();
+    if missing > stub.len() {
+        return Err(EquityError::NotEnoughCards(missing));
+    }
+
+    let mut full_hands: Vec<Vec<Card>> = hands.iter().map(|hand| hand.to_vec()).collect();
+    let mut full_board = board.to_vec();
+    let mut strengths = Vec::with_capacity(hands.len());
+    let mut results = vec![HiLoEquity::default(); hands.len()];
+
+    for _ in 0..iterations {
+        for i in 0..missing {
+            let j = rng.gen_range(i..stub.len());
+            stub.swap(i, j);
+        }
+
+        let mut dealt = stub[..missing].iter();
+        for (full_hand, hand) in full_hands.iter_mut().zip(hands) {
+            full_hand.truncate(hand.len());
+            full_hand.extend(dealt.by_ref().take(game.hand_size() - hand.len()));
+        }
+        full_board.truncate(board.len());
+        full_board.extend(dealt);
+
+        strengths.clear();
+        strengths.extend(
+            full_hands
+                .iter()
+                .map(|hand| game.evaluate(hand, &full_board)),
+        );
+
+        award(&strengths, &mut results);
+    }
+
+    Ok(results)
+}
+
+/// Splits a pot between players with the given high and low strengths, adding each player's
+/// share to their results
+pub(super) fn award(strengths: &[(u32, Option<u32>)], results: &mut [HiLoEquity]) {
+    let best_high = strengths.iter().map(|(high, _)| *high).max();
+    let best_low = strengths.iter().filter_map(|(_, low)| *low).max();
+    let high_winners = strengths
+        .iter()
+        .filter(|(high, _)| Some(*high) == best_high)
+        .count();
+    let low_winners = strengths
+        .iter()
+        .filter(|(_, low)| low.is_some() && *low == best_low)
+        .count();
+    let high_pot = if best_low.is_some() { 0.5 } else { 1.0 };
+
+    for (result, (high, low)) in results.iter_mut().zip(strengths) {
+        result.trials += 1;
+
+        let mut share = 0.0;
+        if Some(*high) == best_high {
+            result.high_pots += high_pot / high_winners as f64;
+            share += high_pot / high_winners as f64;
+        }
+        if low.is_some() && *low == best_low {
+            result.low_pots += 0.5 / low_winners as f64;
+            share += 0.5 / low_winners as f64;
+        }
+
+        if share == 1.0 {
+            result.scoops += 1;
+        } else if share == 0.25 && best_low.is_some() {
+            result.quartered += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hand;
+
+    #[test]
+    fn scoops_and_quarters() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(5);
+
+        // a wheel takes both halves
+        let wheel = hand!("As2s3d4c5hKdKc");
+        let sixes = hand!("Ah2h3s4d6c9d9h");
+        let results =
+            hi_lo_monte_carlo_with_rng(HiLoGame::Stud8, &[&wheel, &sixes], &[], &[], 10, &mut rng)
+                .unwrap();
+        assert_eq!(results[0].scoops, 10);
+        assert_eq!(results[0].equity_percentage(), 100.0);
+        assert_eq!(results[1].equity_percentage(), 0.0);
+
+        // the same low with a worse high hand is quartered
+        let kings = hand!("Ac2d3h4s6dKcKh");
+        let queens = hand!("Ad2c3s4h6sQcQd");
+        let results =
+            hi_lo_monte_carlo_with_rng(HiLoGame::Stud8, &[&kings, &queens], &[], &[], 10, &mut rng)
+                .unwrap();
+        assert_eq!(results[0].equity_percentage(), 75.0);
+        assert_eq!(results[1].quartered_percentage(), 100.0);
+        assert_eq!(results[1].high_pots, 0.0);
+
+        // without a qualifying low, the high hand takes the whole pot
+        let board = hand!("KsQdJh9c9s");
+        let results = hi_lo_monte_carlo_with_rng(
+            HiLoGame::Omaha8,
+            &[&hand!("As2s3d4c"), &hand!("AhKhTd8c")],
+            &board,
+            &[],
+            10,
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(results[1].scoops, 10);
+        assert_eq!(results[1].high_percentage(), 100.0);
+    }
+
+    #[test]
+    fn shares_add_up_to_every_pot() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(9);
+
+        let (hero, villain, third) = (hand!("As2sKd3d"), hand!("Ah2h4c5c"), hand!("QsQhJdTd"));
+        let results = hi_lo_monte_carlo_with_rng(
+            HiLoGame::Omaha8,
+            &[&hero, &villain, &third],
+            &[],
+            &[],
+            2_000,
+            &mut rng,
+        )
+        .unwrap();
+
+        let total: f64 = results.iter().map(HiLoEquity::equity_percentage).sum();
+        assert!((total - 100.0).abs() < 1e-9);
+        assert!(results[0].quartered + results[1].quartered > 0);
+        assert_eq!(results[2].low_pots, 0.0);
+
+        // random stud hands for a full table of seven
+        let results = hi_lo_monte_carlo_with_rng(
+            HiLoGame::Stud8,
+            &[&[] as &[Card]; 7],
+            &[],
+            &[],
+            500,
+            &mut rng,
+        )
+        .unwrap();
+        let total: f64 = results.iter().map(HiLoEquity::equity_percentage).sum();
+        assert!((total - 100.0).abs() < 1e-9);
+
+        // with every other low card folded, the hero's wheel draw can never get there
+        let folded = hand!("Ac2c3c4c5c6c7c8cAd2d3d4d5d6d7d8dAh3h4h5h6h7h8h5s6s7s8s");
+        let results = hi_lo_monte_carlo_with_rng(
+            HiLoGame::Stud8,
+            &[&hand!("As2s3s4s"), &hand!("KcKdKhKs")],
+            &[],
+            &folded,
+            100,
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(results[0].low_pots, 0.0);
+        assert_eq!(results[1].scoops, 100);
+    }
+
+    #[test]
+    fn invalid_games() {
+        assert!(matches!(
+            hi_lo_monte_carlo(HiLoGame::Omaha8, &[&hand!("As2s")], &[], &[], 1),
+            Err(EquityError::InvalidHandSize(2))
+        ));
+        assert!(matches!(
+            hi_lo_monte_carlo(HiLoGame::Stud8, &[&hand!("As2s")], &hand!("3c"), &[], 1),
+            Err(EquityError::InvalidBoardSize(1))
+        ));
+        assert!(matches!(
+            hi_lo_monte_carlo(HiLoGame::Stud8, &[&[] as &[Card]; 8], &[], &[], 1),
+            Err(EquityError::NotEnoughCards(56))
+        ));
+
+        // the upcards of players that folded cannot be dealt
+        let folded = hand!("KcKdKhKs");
+        assert!(matches!(
+            hi_lo_monte_carlo(HiLoGame::Stud8, &[&[] as &[Card]; 7], &[], &folded, 1),
+            Err(EquityError::NotEnoughCards(49))
+        ));
+    }
+}
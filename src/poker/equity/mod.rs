@@ -0,0 +1,595 @@
+//! Equity calculations
+//!
+//! Equity is the share of the pot a hand wins on average once every card has been dealt. It is
+//! estimated by dealing the rest of the cards at random many times and comparing the hands at
+//! showdown. `monte_carlo()` compares Texas Hold'em hands, while `hi_lo_monte_carlo()` handles
+//! split-pot games, where the pot can be split between the high and low hands. For games with a
+//! shared board other than Texas Hold'em, such as Omaha, `game_monte_carlo()` compares two hands
+//! and `range_monte_carlo()` compares a hand against a range of hands. The equities of preflop
+//! matchups between starting hands are cached, and can be looked up with `preflop_equity()`.
+//! `win_probability()` estimates how often a hand beats several random opponents, which is also
+//! cached for every starting hand by `preflop_win_probability()`. `run_it_n_times()` deals the rest
+//! of the board several times for a single all-in, as many cash games do, to show how running it
+//! more than once reduces variance. `runout_equities()` calculates the exact equity for every card
+//! that can come next on the flop or turn. With the `rayon` feature, `par_monte_carlo()` splits
+//! large calculations across threads.
+//!
+//! ```rust
+//! use playing_cards::{core::Card, poker::equity};
+//! use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+//!
+//! let aces = Card::vec_from_str("AhAs").unwrap();
+//! let kings = Card::vec_from_str("KhKs").unwrap();
+//!
+//! let mut rng = Xoshiro256PlusPlus::from_seed(*b"aces against kings, all in pre..");
+//! let equity = equity::monte_carlo_with_rng(&aces, &kings, &[], &[], 20_000, &mut rng).unwrap();
+//!
+//! // aces are roughly an 82% favorite
+//! assert!((equity.equity_percentage() - 82.0).abs() < 1.5);
+//! ```
+
+use std::cmp::Ordering;
+
+use rand::Rng;
+use rand_core::{RngCore, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::core::{Card, CardDeck, CardDeckError, CardSet};
+use crate::poker::evaluators::high_evaluator;
+
+mod hi_lo;
+pub use hi_lo::{hi_lo_monte_carlo, hi_lo_monte_carlo_with_rng, HiLoEquity, HiLoGame};
+
+mod games;
+pub use games::{
+    game_monte_carlo, game_monte_carlo_with_rng, range_monte_carlo, range_monte_carlo_with_rng,
+    Game,
+};
+
+mod preflop;
+pub use preflop::{preflop_equity, preflop_win_probability, PREFLOP_SAMPLES};
+
+mod multiway;
+pub use multiway::{win_probability, win_probability_with_rng};
+
+mod run_it;
+pub use run_it::{run_it_n_times, run_it_n_times_with_rng, RunItEquity};
+
+mod runouts;
+pub use runouts::runout_equities;
+
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rayon")]
+pub use parallel::{par_monte_carlo, PAR_CHUNK_SIZE};
+
+/// Error type for equity calculations
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum EquityError {
+    /// A hand had the wrong number of cards for the game
+    #[error("A hand cannot have {0} cards")]
+    InvalidHandSize(usize),
+    /// The board had the wrong number of cards for the game
+    #[error("The board cannot have {0} cards")]
+    InvalidBoardSize(usize),
+    /// A card was given more than once
+    #[error("Card {0} was given more than once")]
+    DuplicateCard(Card),
+    /// There are not enough cards left in the deck to finish dealing every hand and the board
+    #[error("There are not enough cards left to deal {0} cards")]
+    NotEnoughCards(usize),
+    /// A card that cannot be evaluated, such as a joker, was given
+    #[error("Card {0} cannot be evaluated")]
+    UnsupportedCard(Card),
+    /// The board was asked to be run a number of times that is not supported
+    #[error("The board cannot be run {0} times")]
+    InvalidRunCount(usize),
+    /// Every hand of a range shares a card with the known cards
+    #[error("The range has no hands that can be dealt")]
+    EmptyRange,
+    /// Something went wrong with the deck, such as failing to sample entropy
+    #[error(transparent)]
+    DeckError(#[from] CardDeckError),
+}
+
+/// The results of a heads up equity calculation, from the perspective of the first hand
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Equity {
+    /// The number of boards where the first hand won
+    pub wins: u64,
+    /// The number of boards where the hands tied
+    pub ties: u64,
+    /// The number of boards where the first hand lost
+    pub losses: u64,
+}
+
+impl Equity {
+    /// Returns the number of boards that were dealt
+    pub fn trials(&self) -> u64 {
+        self.wins + self.ties + self.losses
+    }
+
+    /// Returns the percentage of boards the first hand won
+    ///
+    /// Like the other percentages, this returns 0 if no boards were dealt.
+    pub fn win_percentage(&self) -> f64 {
+        self.percentage(self.wins)
+    }
+
+    /// Returns the percentage of boards where the hands tied
+    pub fn tie_percentage(&self) -> f64 {
+        self.percentage(self.ties)
+    }
+
+    /// Returns the percentage of boards the first hand lost
+    pub fn loss_percentage(&self) -> f64 {
+        self.percentage(self.losses)
+    }
+
+    /// Returns the percentage of the pot the first hand wins on average, with ties splitting the
+    /// pot
+    pub fn equity_percentage(&self) -> f64 {
+        self.percentage(2 * self.wins + self.ties) / 2.0
+    }
+
+    /// Returns the standard error of `equity_percentage()`, in percentage points
+    ///
+    /// Each board counts as 1 for a win, 0.5 for a tie, and 0 for a loss. The standard error is
+    /// estimated from the variance of these outcomes, and is 0 if fewer than 2 boards were dealt.
+    pub fn standard_error(&self) -> f64 {
+        let trials = self.trials();
+        if trials < 2 {
+            return 0.0;
+        }
+
+        let n = trials as f64;
+        let mean = (self.wins as f64 + self.ties as f64 / 2.0) / n;
+        let mean_of_squares = (self.wins as f64 + self.ties as f64 / 4.0) / n;
+        let variance = (mean_of_squares - mean * mean).max(0.0) * n / (n - 1.0);
+        100.0 * (variance / n).sqrt()
+    }
+
+    /// Returns the lower and upper bounds of a confidence interval around `equity_percentage()`
+    ///
+    /// The interval spans `z` standard errors on either side, such as 1.96 for a 95% confidence
+    /// interval, and is clamped between 0 and 100.
+    pub fn confidence_interval(&self, z: f64) -> (f64, f64) {
+        let equity = self.equity_percentage();
+        let margin = z * self.standard_error();
+        ((equity - margin).max(0.0), (equity + margin).min(100.0))
+    }
+
+    /// Returns the same results from the perspective of the second hand
+    pub fn swapped(&self) -> Self {
+        Self {
+            wins: self.losses,
+            ties: self.ties,
+            losses: self.wins,
+        }
+    }
+
+    /// Adds the result of a single showdown
+    fn record(&mut self, result: Ordering) {
+        match result {
+            Ordering::Greater => self.wins += 1,
+            Ordering::Equal => self.ties += 1,
+            Ordering::Less => self.losses += 1,
+        }
+    }
+
+    fn percentage(&self, count: u64) -> f64 {
+        match self.trials() {
+            0 => 0.0,
+            trials => 100.0 * count as f64 / trials as f64,
+        }
+    }
+}
+
+impl std::ops::Add for Equity {
+    type Output = Self;
+
+    /// Combines the results of two calculations of the same matchup
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            wins: self.wins + rhs.wins,
+            ties: self.ties + rhs.ties,
+            losses: self.losses + rhs.losses,
+        }
+    }
+}
+
+impl std::ops::AddAssign for Equity {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+/// Estimates the equity of `hand1` against `hand2` by dealing out the rest of the board
+/// `iterations` times
+///
+/// The random number generator is seeded from entropy. See `monte_carlo_with_rng()` for details
+/// and for reproducible results.
+pub fn monte_carlo(
+    hand1: &[Card],
+    hand2: &[Card],
+    board: &[Card],
+    dead: &[Card],
+    iterations: u64,
+) -> Result<Equity, EquityError> {
+    let mut rng = Xoshiro256PlusPlus::from_seed(CardDeck::seed_or_entropy(None)?);
+    monte_carlo_with_rng(hand1, hand2, board, dead, iterations, &mut rng)
+}
+
+/// Estimates the equity of `hand1` against `hand2` by dealing out the rest of the board
+/// `iterations` times with the given random number generator
+///
+/// Each hand must have 2 hole cards, and the board can have up to 5 cards. The `dead` cards, such
+/// as the cards of players that folded, are never dealt. An error will return if any card is given
+/// more than once, if a joker is given, or if too many cards are dead to finish the board. If the
+/// board is already complete, every iteration results in the same showdown.
+///
+/// ```rust
+/// use playing_cards::{core::Card, poker::equity};
+/// use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+///
+/// let hero = Card::vec_from_str("AhKh").unwrap();
+/// let villain = Card::vec_from_str("QsQc").unwrap();
+/// let flop = Card::vec_from_str("Qh7h2d").unwrap();
+///
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(7);
+/// let equity =
+///     equity::monte_carlo_with_rng(&hero, &villain, &flop, &[], 10_000, &mut rng).unwrap();
+///
+/// assert_eq!(equity.trials(), 10_000);
+/// assert!(equity.swapped().equity_percentage() > 60.0);
+///
+/// // a player that folded two hearts leaves fewer outs to the flush
+/// let folded = Card::vec_from_str("Th3h").unwrap();
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(7);
+/// let without_outs =
+///     equity::monte_carlo_with_rng(&hero, &villain, &flop, &folded, 10_000, &mut rng).unwrap();
+///
+/// assert!(without_outs.equity_percentage() < equity.equity_percentage());
+/// ```
+pub fn monte_carlo_with_rng<R: RngCore + ?Sized>(
+    hand1: &[Card],
+    hand2: &[Card],
+    board: &[Card],
+    dead: &[Card],
+    iterations: u64,
+    rng: &mut R,
+) -> Result<Equity, EquityError> {
+    let mut stub = holdem_stub(hand1, hand2, board, dead)?;
+
+    let mut equity = Equity::default();
+    for _ in 0..iterations {
+        equity.record(showdown(hand1, hand2, board, &mut stub, rng));
+    }
+
+    Ok(equity)
+}
+
+/// Checks the cards of a heads up Texas Hold'em showdown and returns every card that can still be
+/// dealt, which must be enough to finish the board
+fn holdem_stub(
+    hand1: &[Card],
+    hand2: &[Card],
+    board: &[Card],
+    dead: &[Card],
+) -> Result<Vec<Card>, EquityError> {
+    if let Some(hand) = [hand1, hand2].into_iter().find(|hand| hand.len() != 2) {
+        return Err(EquityError::InvalidHandSize(hand.len()));
+    }
+    if board.len() > 5 {
+        return Err(EquityError::InvalidBoardSize(board.len()));
+    }
+
+    let stub = stub(&[hand1, hand2], board, dead)?;
+    let missing = 5 - board.len();
+    if missing > stub.len() {
+        return Err(EquityError::NotEnoughCards(missing));
+    }
+
+    Ok(stub)
+}
+
+/// The number of boards dealt between each check of `monte_carlo_until()`
+const PRECISION_BATCH: u64 = 1_000;
+
+/// Estimates the equity of `hand1` against `hand2` by dealing out the rest of the board until the
+/// estimate is within `precision` percentage points of the true equity with 95% confidence
+///
+/// The random number generator is seeded from entropy. See `monte_carlo_until_with_rng()` for
+/// details and for reproducible results.
+pub fn monte_carlo_until(
+    hand1: &[Card],
+    hand2: &[Card],
+    board: &[Card],
+    dead: &[Card],
+    precision: f64,
+    max_iterations: u64,
+) -> Result<Equity, EquityError> {
+    let mut rng = Xoshiro256PlusPlus::from_seed(CardDeck::seed_or_entropy(None)?);
+    monte_carlo_until_with_rng(
+        hand1,
+        hand2,
+        board,
+        dead,
+        precision,
+        max_iterations,
+        &mut rng,
+    )
+}
+
+/// Estimates the equity of `hand1` against `hand2` by dealing out the rest of the board until the
+/// estimate is within `precision` percentage points of the true equity with 95% confidence, using
+/// the given random number generator
+///
+/// Boards are dealt in batches of 1,000, and the calculation stops after the first batch where
+/// 1.96 standard errors are no more than `precision`, or once `max_iterations` boards have been
+/// dealt. The hands, board, and dead cards are the same as for `monte_carlo_with_rng()`.
+///
+/// ```rust
+/// use playing_cards::{core::Card, poker::equity};
+/// use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+///
+/// let hero = Card::vec_from_str("AhKh").unwrap();
+/// let villain = Card::vec_from_str("QsQc").unwrap();
+///
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(21);
+/// let equity =
+///     equity::monte_carlo_until_with_rng(&hero, &villain, &[], &[], 0.5, 1_000_000, &mut rng)
+///         .unwrap();
+///
+/// assert!(1.96 * equity.standard_error() <= 0.5);
+/// assert!(equity.trials() < 1_000_000);
+///
+/// let (low, high) = equity.confidence_interval(1.96);
+/// assert!(low < 46.0 && 46.0 < high);
+/// ```
+pub fn monte_carlo_until_with_rng<R: RngCore + ?Sized>(
+    hand1: &[Card],
+    hand2: &[Card],
+    board: &[Card],
+    dead: &[Card],
+    precision: f64,
+    max_iterations: u64,
+    rng: &mut R,
+) -> Result<Equity, EquityError> {
+    let mut stub = holdem_stub(hand1, hand2, board, dead)?;
+
+    let mut equity = Equity::default();
+    while equity.trials() < max_iterations {
+        for _ in 0..PRECISION_BATCH.min(max_iterations - equity.trials()) {
+            equity.record(showdown(hand1, hand2, board, &mut stub, rng));
+        }
+        if 1.96 * equity.standard_error() <= precision {
+            break;
+        }
+    }
+
+    Ok(equity)
+}
+
+/// Deals the rest of the board from `stub` and compares the high hands of two players
+///
+/// Each hand must have 2 cards and the board can have up to 5 cards. The order of `stub` is
+/// shuffled as the cards are dealt.
+fn showdown<R: RngCore + ?Sized>(
+    hand1: &[Card],
+    hand2: &[Card],
+    board: &[Card],
+    stub: &mut [Card],
+    rng: &mut R,
+) -> Ordering {
+    let missing = 5 - board.len();
+    deal(stub, missing, rng);
+    compare(hand1, hand2, board, &stub[..missing])
+}
+
+/// Deals `count` random cards to the front of `stub` with a partial Fisher-Yates shuffle
+pub(crate) fn deal<R: RngCore + ?Sized>(stub: &mut [Card], count: usize, rng: &mut R) {
+    for i in 0..count {
+        let j = rng.gen_range(i..stub.len());
+        stub.swap(i, j);
+    }
+}
+
+/// Compares the high hands of two players once `runout` completes the board
+fn compare(hand1: &[Card], hand2: &[Card], board: &[Card], runout: &[Card]) -> Ordering {
+    let mut cards1 = [hand1[0]; 7];
+    let mut cards2 = [hand2[0]; 7];
+    for (cards, hand) in [(&mut cards1, hand1), (&mut cards2, hand2)] {
+        cards[..2].copy_from_slice(hand);
+        cards[2..2 + board.len()].copy_from_slice(board);
+        cards[2 + board.len()..].copy_from_slice(runout);
+    }
+
+    high_evaluator::best_strength(&cards1).cmp(&high_evaluator::best_strength(&cards2))
+}
+
+/// Checks that no known card is repeated or a joker, and returns every card that can still be
+/// dealt
+///
+/// Dead cards are known to be out of play, so they are treated like the cards of the hands and the
+/// board.
+fn stub(hands: &[&[Card]], board: &[Card], dead: &[Card]) -> Result<Vec<Card>, EquityError> {
+    let mut known = CardSet::new();
+    for &card in hands.iter().copied().flatten().chain(board).chain(dead) {
+        if card.is_joker() {
+            return Err(EquityError::UnsupportedCard(card));
+        }
+        if !known.insert(card) {
+            return Err(EquityError::DuplicateCard(card));
+        }
+    }
+
+    Ok((CardSet::full() - known).into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cards;
+    use crate::hand;
+
+    #[test]
+    fn known_matchups() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(11);
+
+        // the dominated queen kicker is about a 3 to 1 underdog
+        let equity =
+            monte_carlo_with_rng(&hand!("AsKs"), &hand!("AcQd"), &[], &[], 20_000, &mut rng)
+                .unwrap();
+        assert!((equity.equity_percentage() - 75.0).abs() < 1.5);
+        assert!(equity.tie_percentage() > 0.0);
+
+        // the same hand in different suits always ties on a complete board
+        let board = hand!("2c7d9hJsKd");
+        let equity =
+            monte_carlo_with_rng(&hand!("AhTh"), &hand!("AdTs"), &board, &[], 100, &mut rng)
+                .unwrap();
+        assert_eq!(equity.ties, 100);
+        assert_eq!(equity.equity_percentage(), 50.0);
+
+        // drawing dead on the river
+        let equity = monte_carlo_with_rng(
+            &hand!("3c4c"),
+            &hand!("KsKh"),
+            &board[..4],
+            &[],
+            500,
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(equity.losses, 500);
+        assert_eq!(equity.swapped().win_percentage(), 100.0);
+        assert_eq!(Equity::default().loss_percentage(), 0.0);
+    }
+
+    #[test]
+    fn precision() {
+        let equity = Equity {
+            wins: 60,
+            ties: 20,
+            losses: 20,
+        };
+        assert_eq!(equity.equity_percentage(), 70.0);
+        // the outcomes have a variance of 0.16 * 100 / 99
+        assert!((equity.standard_error() - 40.0 / 99f64.sqrt()).abs() < 1e-9);
+        assert_eq!(Equity::default().standard_error(), 0.0);
+
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(17);
+        let equity = monte_carlo_until_with_rng(
+            &hand!("AsKs"),
+            &hand!("AcQd"),
+            &[],
+            &[],
+            1.0,
+            50_000,
+            &mut rng,
+        )
+        .unwrap();
+        assert!(equity.trials() % PRECISION_BATCH == 0 && equity.trials() < 50_000);
+        assert!(1.96 * equity.standard_error() <= 1.0);
+
+        // a finished board never varies, so a single batch is enough
+        let board = hand!("2c7d9hJsKd");
+        let equity = monte_carlo_until_with_rng(
+            &hand!("AhTh"),
+            &hand!("AdTs"),
+            &board,
+            &[],
+            0.0,
+            10_000,
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(equity.trials(), PRECISION_BATCH);
+        assert_eq!(equity.confidence_interval(1.96), (50.0, 50.0));
+
+        // the iteration limit always stops the calculation
+        let equity = monte_carlo_until_with_rng(
+            &hand!("AsKs"),
+            &hand!("AcQd"),
+            &[],
+            &[],
+            0.0,
+            2_500,
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(equity.trials(), 2_500);
+    }
+
+    #[test]
+    fn dead_cards() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(13);
+        let (hero, villain, turn) = (hand!("AhKh"), hand!("QsQc"), hand!("Qh7h2d3c"));
+
+        // every heart that completes the flush without filling up the set was folded
+        let folded = hand!("4h5h6h8h9hThJh");
+        let equity = monte_carlo_with_rng(&hero, &villain, &turn, &folded, 500, &mut rng).unwrap();
+        assert_eq!(equity.losses, 500);
+
+        assert!(matches!(
+            monte_carlo(&hero, &villain, &turn, &hand!("9s9hAh"), 1),
+            Err(EquityError::DuplicateCard(card)) if card == hero[0]
+        ));
+    }
+
+    #[test]
+    fn invalid_cards() {
+        assert!(matches!(
+            monte_carlo(&hand!("AsKsQs"), &hand!("2c2d"), &[], &[], 1),
+            Err(EquityError::InvalidHandSize(3))
+        ));
+        assert!(matches!(
+            monte_carlo(
+                &hand!("AsKs"),
+                &hand!("2c2d"),
+                &hand!("3c4c5c6c7c8c"),
+                &[],
+                1
+            ),
+            Err(EquityError::InvalidBoardSize(6))
+        ));
+        assert!(matches!(
+            monte_carlo(&hand!("AsKs"), &hand!("2cAs"), &[], &[], 1),
+            Err(EquityError::DuplicateCard(card)) if card == hand!("As")[0]
+        ));
+        assert!(matches!(
+            monte_carlo(
+                &hand!("AsKs"),
+                &[cards::ACE_OF_HEARTS, cards::RED_JOKER],
+                &[],
+                &[],
+                1
+            ),
+            Err(EquityError::UnsupportedCard(_))
+        ));
+
+        // a single card is left to deal the turn and river
+        let (hero, villain, flop) = (hand!("AsKs"), hand!("2c2d"), hand!("3c4c5c"));
+        let known: CardSet = [&hero, &villain, &flop]
+            .into_iter()
+            .flatten()
+            .copied()
+            .collect();
+        let dead: Vec<Card> = (CardSet::full() - known).iter().skip(1).collect();
+        assert!(matches!(
+            monte_carlo(&hero, &villain, &flop, &dead, 1),
+            Err(EquityError::NotEnoughCards(2))
+        ));
+        assert!(matches!(
+            monte_carlo_until(&hero, &villain, &flop, &dead, 1.0, 10),
+            Err(EquityError::NotEnoughCards(2))
+        ));
+        assert!(monte_carlo(&hero, &villain, &flop, &dead[1..], 1).is_ok());
+    }
+}
@@ -0,0 +1,95 @@
+use rand_core::{RngCore, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+use super::{deal, stub, Equity, EquityError};
+use crate::core::{Card, CardDeck};
+use crate::poker::evaluators::high_evaluator;
+
+/// Estimates how often `hand` wins against `opponents` random hands by dealing out the opponents'
+/// hands and the rest of the board `iterations` times
+///
+/// The random number generator is seeded from entropy. See `win_probability_with_rng()` for
+/// details and for reproducible results.
+pub fn win_probability(
+    hand: &[Card],
+    board: &[Card],
+    dead: &[Card],
+    opponents: usize,
+    iterations: u64,
+) -> Result<Equity, EquityError> {
+    let mut rng = Xoshiro256PlusPlus::from_seed(CardDeck::seed_or_entropy(None)?);
+    win_probability_with_rng(hand, board, dead, opponents, iterations, &mut rng)
+}
+
+/// Estimates how often `hand` wins against `opponents` random hands by dealing out the opponents'
+/// hands and the rest of the board `iterations` times with the given random number generator
+///
+/// Every opponent is dealt 2 random hole cards and plays to showdown. A board counts as a win if
+/// the hand beats every opponent, as a tie if it shares the best hand with at least one opponent,
+/// and as a loss otherwise. Since a tie may split the pot more than two ways, `win_percentage()` is
+/// the most meaningful figure of the results. The hand, board, and dead cards are the same as for
+/// `monte_carlo_with_rng()`, and an error will return if there are not enough cards left to deal
+/// every opponent.
+///
+/// ```rust
+/// use playing_cards::{core::Card, poker::equity};
+/// use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+///
+/// let hand = Card::vec_from_str("AhKh").unwrap();
+/// let flop = Card::vec_from_str("Ks7d2c").unwrap();
+///
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(6);
+/// let heads_up =
+///     equity::win_probability_with_rng(&hand, &flop, &[], 1, 5_000, &mut rng).unwrap();
+/// let four_way =
+///     equity::win_probability_with_rng(&hand, &flop, &[], 3, 5_000, &mut rng).unwrap();
+///
+/// // top pair with the best kicker wins less often against more opponents
+/// assert!(heads_up.win_percentage() > 85.0);
+/// assert!(four_way.win_percentage() < heads_up.win_percentage());
+/// ```
+pub fn win_probability_with_rng<R: RngCore + ?Sized>(
+    hand: &[Card],
+    board: &[Card],
+    dead: &[Card],
+    opponents: usize,
+    iterations: u64,
+    rng: &mut R,
+) -> Result<Equity, EquityError> {
+    if hand.len() != 2 {
+        return Err(EquityError::InvalidHandSize(hand.len()));
+    }
+    if board.len() > 5 {
+        return Err(EquityError::InvalidBoardSize(board.len()));
+    }
+
+    let mut stub = stub(&[hand], board, dead)?;
+    let missing = 5 - board.len();
+    let needed = 2 * opponents + missing;
+    if needed > stub.len() {
+        return Err(EquityError::NotEnoughCards(needed));
+    }
+
+    let mut cards = [hand[0]; 7];
+    cards[..2].copy_from_slice(hand);
+    cards[2..2 + board.len()].copy_from_slice(board);
+
+    let mut equity = Equity::default();
+    for _ in 0..iterations {
+        deal(&mut stub, needed, rng);
+        let (runout, hole_cards) = stub[..needed].split_at(missing);
+        cards[2 + board.len()..].copy_from_slice(runout);
+        let strength = high_evaluator::best_strength(&cards);
+
+        let mut best_opponent = None;
+        for opponent in hole_cards.chunks_exact(2) {
+            let mut opponent_cards = cards;
+            opponent_cards[..2].copy_from_slice(opponent);
+            best_opponent = best_opponent.max(high_evaluator::best_strength(&opponent_cards));
+        }
+
+        equity.record(strength.cmp(&best_opponent));
+    }
+
+    Ok(equity)
+}
@@ -0,0 +1,103 @@
+use rand_core::SeedableRng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+use rayon::prelude::*;
+
+use super::{holdem_stub, showdown, Equity, EquityError};
+use crate::core::{Card, CardDeck};
+
+/// The number of iterations `par_monte_carlo()` runs within each chunk
+///
+/// Each chunk is dealt from its own random number stream, so the results only depend on the seed
+/// and the number of iterations, and never on the number of threads.
+pub const PAR_CHUNK_SIZE: u64 = 1 << 16;
+
+/// Estimates the equity of `hand1` against `hand2` by dealing out the rest of the board
+/// `iterations` times, split across threads
+///
+/// This requires the `rayon` feature. The iterations are split into chunks of `PAR_CHUNK_SIZE`,
+/// where each chunk is dealt with a Xoshiro256PlusPlus generator that has jumped ahead of the
+/// previous chunk's, giving every chunk a stream that does not overlap with any other. If a seed
+/// is given, the results are the same no matter how many threads there are, and if no seed is
+/// given, one is sampled from entropy. The hands, board, and dead cards are the same as for
+/// `monte_carlo_with_rng()`.
+///
+/// ```rust
+/// use playing_cards::{core::Card, poker::equity};
+///
+/// let hero = Card::vec_from_str("AhKh").unwrap();
+/// let villain = Card::vec_from_str("2c2d").unwrap();
+/// let seed = [9; 32];
+///
+/// let equity = equity::par_monte_carlo(&hero, &villain, &[], &[], 100_000, Some(seed)).unwrap();
+///
+/// assert_eq!(equity.trials(), 100_000);
+/// assert!((equity.equity_percentage() - 50.0).abs() < 1.0);
+/// assert_eq!(
+///     equity::par_monte_carlo(&hero, &villain, &[], &[], 100_000, Some(seed)).unwrap(),
+///     equity
+/// );
+/// ```
+pub fn par_monte_carlo(
+    hand1: &[Card],
+    hand2: &[Card],
+    board: &[Card],
+    dead: &[Card],
+    iterations: u64,
+    seed: Option<[u8; 32]>,
+) -> Result<Equity, EquityError> {
+    let stub = holdem_stub(hand1, hand2, board, dead)?;
+
+    let mut rng =
+        Xoshiro256PlusPlus::from_seed(CardDeck::expand_seed(CardDeck::seed_or_entropy(seed)?));
+    let mut chunks = Vec::new();
+    for start in (0..iterations).step_by(PAR_CHUNK_SIZE as usize) {
+        chunks.push((PAR_CHUNK_SIZE.min(iterations - start), rng.clone()));
+        rng.jump();
+    }
+
+    Ok(chunks
+        .into_par_iter()
+        .map(|(iterations, mut rng)| {
+            let mut stub = stub.clone();
+            let mut equity = Equity::default();
+            for _ in 0..iterations {
+                equity.record(showdown(hand1, hand2, board, &mut stub, &mut rng));
+            }
+            equity
+        })
+        .reduce(Equity::default, |a, b| a + b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hand;
+
+    #[test]
+    fn deterministic_across_threads() {
+        let (hero, villain, flop) = (hand!("JsTs"), hand!("AdAc"), hand!("9s8d2s"));
+        let seed = *b"parallel equity, seeded densely!";
+        let iterations = 2 * PAR_CHUNK_SIZE + 17;
+
+        let run = |threads| {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap()
+                .install(|| par_monte_carlo(&hero, &villain, &flop, &[], iterations, Some(seed)))
+                .unwrap()
+        };
+
+        let equity = run(1);
+        assert_eq!(equity.trials(), iterations);
+        assert_eq!(run(4), equity);
+
+        // the flush and straight draws are slight favourites against the aces
+        assert!((equity.equity_percentage() - 56.0).abs() < 2.0);
+
+        assert!(matches!(
+            par_monte_carlo(&hero, &villain, &hand!("9s9s"), &[], 1, None),
+            Err(EquityError::DuplicateCard(_))
+        ));
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use rand::Rng;
+use rand_core::SeedableRng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+use super::{showdown, stub, win_probability_with_rng, Equity, EquityError};
+use crate::core::Card;
+use crate::poker::range::StartingHand;
+
+/// The number of boards dealt for each matchup by `preflop_equity()`
+pub const PREFLOP_SAMPLES: u64 = 50_000;
+
+/// Matchups that have already been calculated, keyed by the indices of both starting hands
+static TABLE: OnceLock<Mutex<HashMap<(usize, usize), Equity>>> = OnceLock::new();
+
+/// Win probabilities that have already been calculated, keyed by the index of the starting hand
+/// and the number of opponents
+static OPPONENT_TABLE: OnceLock<Mutex<HashMap<(usize, usize), Equity>>> = OnceLock::new();
+
+/// Looks up the preflop equity of one starting hand against another
+///
+/// The equity is a Monte Carlo estimate rather than an exact enumeration. Each of the 169 × 169
+/// matchups is estimated the first time it is looked up, by dealing `PREFLOP_SAMPLES` random
+/// boards to combos of both hands that do not share a card, and is cached for the rest of the
+/// program, so only the first lookup of a matchup is slow. The random number generator is seeded
+/// from the indices of the hands, so a matchup always gives the same results, and looking up the
+/// reverse matchup gives the same results swapped.
+///
+/// With `PREFLOP_SAMPLES` boards, the standard error of `Equity::equity_percentage()` is at most
+/// about 0.22 percentage points, so most estimates are within half a point of the exact equity.
+/// The standard error of a particular matchup is given by `Equity::standard_error()`.
+///
+/// ```rust
+/// use playing_cards::poker::{equity, range::StartingHand};
+///
+/// let aces: StartingHand = "AA".parse().unwrap();
+/// let kings: StartingHand = "KK".parse().unwrap();
+///
+/// let equity = equity::preflop_equity(aces, kings);
+///
+/// assert_eq!(equity.trials(), equity::PREFLOP_SAMPLES);
+/// assert!(equity.standard_error() < 0.25);
+/// assert!((equity.equity_percentage() - 82.0).abs() < 1.0);
+/// assert_eq!(equity::preflop_equity(kings, aces), equity.swapped());
+/// ```
+pub fn preflop_equity(hand: StartingHand, villain: StartingHand) -> Equity {
+    if hand.index() > villain.index() {
+        return preflop_equity(villain, hand).swapped();
+    }
+
+    let key = (hand.index(), villain.index());
+    let table = TABLE.get_or_init(Default::default);
+    if let Some(equity) = table.lock().unwrap().get(&key) {
+        return *equity;
+    }
+
+    // the lock is not held while calculating, so other matchups can be looked up meanwhile
+    let equity = calculate(hand, villain);
+    *table.lock().unwrap().entry(key).or_insert(equity)
+}
+
+/// Looks up how often a starting hand wins against `opponents` random hands preflop
+///
+/// Like `preflop_equity()`, the results for each starting hand and number of opponents are
+/// calculated from `PREFLOP_SAMPLES` boards the first time they are looked up, and are cached for
+/// the rest of the program. See `win_probability_with_rng()` for how wins and ties are counted. An
+/// error will return if there are not enough cards to deal every opponent.
+///
+/// ```rust
+/// use playing_cards::poker::{equity, range::StartingHand};
+///
+/// let aces: StartingHand = "AA".parse().unwrap();
+///
+/// let heads_up = equity::preflop_win_probability(aces, 1).unwrap();
+/// let full_ring = equity::preflop_win_probability(aces, 8).unwrap();
+///
+/// assert!((heads_up.win_percentage() - 85.0).abs() < 1.0);
+/// assert!((full_ring.win_percentage() - 35.0).abs() < 1.5);
+/// ```
+pub fn preflop_win_probability(
+    hand: StartingHand,
+    opponents: usize,
+) -> Result<Equity, EquityError> {
+    let key = (hand.index(), opponents);
+    let table = OPPONENT_TABLE.get_or_init(Default::default);
+    if let Some(equity) = table.lock().unwrap().get(&key) {
+        return Ok(*equity);
+    }
+
+    // every combo of a starting hand wins equally often against random hands, so any one will do
+    let cards = hand.combos()[0].cards();
+    let seed = (hand.index() as u64) << 32 | opponents as u64;
+    let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+    let equity = win_probability_with_rng(&cards, &[], &[], opponents, PREFLOP_SAMPLES, &mut rng)?;
+
+    Ok(*table.lock().unwrap().entry(key).or_insert(equity))
+}
+
+/// Deals boards to a random pair of combos of the two hands that do not share a card
+fn calculate(hand: StartingHand, villain: StartingHand) -> Equity {
+    let mut matchups: Vec<([Card; 2], [Card; 2], Vec<Card>)> = Vec::new();
+    for combo1 in hand.combos() {
+        for combo2 in villain.combos() {
+            let (cards1, cards2) = (combo1.cards(), combo2.cards());
+            if let Ok(stub) = stub(&[&cards1, &cards2], &[], &[]) {
+                matchups.push((cards1, cards2, stub));
+            }
+        }
+    }
+
+    let seed = (1 << 63) | (hand.index() as u64) << 32 | villain.index() as u64;
+    let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+
+    let mut equity = Equity::default();
+    for _ in 0..PREFLOP_SAMPLES {
+        let i = rng.gen_range(0..matchups.len());
+        let (cards1, cards2, stub) = &mut matchups[i];
+        equity.record(showdown(cards1, cards2, &[], stub, &mut rng));
+    }
+
+    equity
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_matchups() {
+        let equity = |hand: &str, villain: &str| {
+            preflop_equity(hand.parse().unwrap(), villain.parse().unwrap())
+        };
+
+        // a coin flip between two overcards and a pair
+        let flip = equity("AKo", "22");
+        assert!((flip.equity_percentage() - 47.0).abs() < 1.0);
+        assert_eq!(equity("22", "AKo"), flip.swapped());
+
+        // the same hand in other suits splits the pot on average
+        let mirror = equity("T9s", "T9s");
+        assert!((mirror.equity_percentage() - 50.0).abs() < 1.0);
+        assert!(mirror.tie_percentage() > 50.0);
+    }
+
+    #[test]
+    fn opponents() {
+        let hand: StartingHand = "72o".parse().unwrap();
+
+        let heads_up = preflop_win_probability(hand, 1).unwrap();
+        assert_eq!(heads_up.trials(), PREFLOP_SAMPLES);
+        assert!(preflop_win_probability(hand, 5).unwrap().wins < heads_up.wins);
+        assert_eq!(preflop_win_probability(hand, 1).unwrap(), heads_up);
+        assert_eq!(
+            preflop_win_probability(hand, 0).unwrap().wins,
+            PREFLOP_SAMPLES
+        );
+
+        assert!(matches!(
+            preflop_win_probability(hand, 23),
+            Err(EquityError::NotEnoughCards(51))
+        ));
+    }
+}
@@ -0,0 +1,221 @@
+use std::cmp::Ordering;
+
+use rand_core::{RngCore, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{compare, deal, holdem_stub, EquityError};
+use crate::core::{Card, CardDeck};
+
+/// The results of running the rest of the board several times for each all-in, from the
+/// perspective of the first hand
+///
+/// Each run is worth an equal share of the pot, so the first hand wins a share of the pot from 0
+/// to 1 in each all-in.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RunItEquity {
+    /// The number of times the board was run for each all-in
+    pub runs: usize,
+    /// The number of all-ins that were played
+    pub trials: u64,
+    /// The total share of the pots the first hand won
+    pub pots: f64,
+    /// The total of the squares of each share of the pot the first hand won, which is used to
+    /// calculate the variance
+    pub squared_pots: f64,
+    /// The number of all-ins where the first hand won every run
+    pub scoops: u64,
+    /// The number of all-ins where the first hand lost every run
+    pub scooped: u64,
+}
+
+impl RunItEquity {
+    /// Returns the percentage of the pot the first hand wins on average
+    ///
+    /// This is the same no matter how many times the board is run.
+    pub fn equity_percentage(&self) -> f64 {
+        self.percentage(self.pots)
+    }
+
+    /// Returns the percentage of all-ins where the first hand won every run
+    pub fn scoop_percentage(&self) -> f64 {
+        self.percentage(self.scoops as f64)
+    }
+
+    /// Returns the percentage of all-ins where the first hand lost every run
+    pub fn scooped_percentage(&self) -> f64 {
+        self.percentage(self.scooped as f64)
+    }
+
+    /// Returns the variance of the share of the pot the first hand wins in a single all-in
+    ///
+    /// Running the board more times keeps the equity the same while lowering the variance. This
+    /// returns 0 if no all-ins were played.
+    pub fn variance(&self) -> f64 {
+        match self.trials {
+            0 => 0.0,
+            trials => {
+                let mean = self.pots / trials as f64;
+                (self.squared_pots / trials as f64 - mean * mean).max(0.0)
+            }
+        }
+    }
+
+    /// Returns the standard deviation of the share of the pot the first hand wins in a single
+    /// all-in
+    pub fn standard_deviation(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    fn percentage(&self, pots: f64) -> f64 {
+        match self.trials {
+            0 => 0.0,
+            trials => 100.0 * pots / trials as f64,
+        }
+    }
+}
+
+/// Estimates the equity and variance of `hand1` against `hand2` when the rest of the board is run
+/// `runs` times, by playing out `iterations` all-ins
+///
+/// The random number generator is seeded from entropy. See `run_it_n_times_with_rng()` for
+/// details and for reproducible results.
+pub fn run_it_n_times(
+    hand1: &[Card],
+    hand2: &[Card],
+    board: &[Card],
+    dead: &[Card],
+    runs: usize,
+    iterations: u64,
+) -> Result<RunItEquity, EquityError> {
+    let mut rng = Xoshiro256PlusPlus::from_seed(CardDeck::seed_or_entropy(None)?);
+    run_it_n_times_with_rng(hand1, hand2, board, dead, runs, iterations, &mut rng)
+}
+
+/// Estimates the equity and variance of `hand1` against `hand2` when the rest of the board is run
+/// `runs` times, by playing out `iterations` all-ins with the given random number generator
+///
+/// Every run of an all-in is dealt from the same stub without replacement, so a card that came in
+/// one run cannot come in another. The hands, board, and dead cards are the same as for
+/// `monte_carlo_with_rng()`. An error will return if `runs` is 0, or if there are not enough cards
+/// left to deal every run.
+///
+/// ```rust
+/// use playing_cards::{core::Card, poker::equity};
+/// use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+///
+/// let hero = Card::vec_from_str("AsKs").unwrap();
+/// let villain = Card::vec_from_str("QhQd").unwrap();
+///
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(2);
+/// let once = equity::run_it_n_times_with_rng(&hero, &villain, &[], &[], 1, 20_000, &mut rng)
+///     .unwrap();
+/// let twice = equity::run_it_n_times_with_rng(&hero, &villain, &[], &[], 2, 20_000, &mut rng)
+///     .unwrap();
+///
+/// // running it twice keeps the equity the same, but lowers the variance
+/// assert!((once.equity_percentage() - twice.equity_percentage()).abs() < 1.5);
+/// assert!(twice.variance() < once.variance());
+/// assert!(twice.scoop_percentage() < once.scoop_percentage());
+/// ```
+pub fn run_it_n_times_with_rng<R: RngCore + ?Sized>(
+    hand1: &[Card],
+    hand2: &[Card],
+    board: &[Card],
+    dead: &[Card],
+    runs: usize,
+    iterations: u64,
+    rng: &mut R,
+) -> Result<RunItEquity, EquityError> {
+    let mut stub = holdem_stub(hand1, hand2, board, dead)?;
+    if runs == 0 {
+        return Err(EquityError::InvalidRunCount(runs));
+    }
+    let missing = 5 - board.len();
+    if missing * runs > stub.len() {
+        return Err(EquityError::NotEnoughCards(missing * runs));
+    }
+
+    let mut results = RunItEquity {
+        runs,
+        ..Default::default()
+    };
+    for _ in 0..iterations {
+        deal(&mut stub, missing * runs, rng);
+
+        let mut won = 0.0;
+        let mut lost = 0;
+        for run in 0..runs {
+            let runout = &stub[run * missing..(run + 1) * missing];
+            match compare(hand1, hand2, board, runout) {
+                Ordering::Greater => won += 1.0,
+                Ordering::Equal => won += 0.5,
+                Ordering::Less => lost += 1,
+            }
+        }
+
+        let share = won / runs as f64;
+        results.trials += 1;
+        results.pots += share;
+        results.squared_pots += share * share;
+        if share == 1.0 {
+            results.scoops += 1;
+        } else if lost == runs {
+            results.scooped += 1;
+        }
+    }
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hand;
+
+    #[test]
+    fn running_it_more_times() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(4);
+
+        // 7 of the 44 rivers complete the flush without filling up the set
+        let (hero, villain, turn) = (hand!("AhKh"), hand!("QsQc"), hand!("Qh7h2d3c"));
+        let mut previous = f64::INFINITY;
+        for runs in 1..=4 {
+            let results =
+                run_it_n_times_with_rng(&hero, &villain, &turn, &[], runs, 20_000, &mut rng)
+                    .unwrap();
+            assert_eq!(results.runs, runs);
+            assert!((results.equity_percentage() - 100.0 * 7.0 / 44.0).abs() < 1.0);
+            assert!(results.variance() < previous);
+            previous = results.variance();
+        }
+
+        // without replacement, running it 44 times deals every river exactly once
+        let results =
+            run_it_n_times_with_rng(&hero, &villain, &turn, &[], 44, 100, &mut rng).unwrap();
+        assert!((results.pots - 100.0 * 7.0 / 44.0).abs() < 1e-9);
+        assert!(results.variance() < 1e-9);
+
+        // a complete board gives the same result for every run
+        let river = hand!("Qh7h2d3c4h");
+        let results =
+            run_it_n_times_with_rng(&hero, &villain, &river, &[], 3, 10, &mut rng).unwrap();
+        assert_eq!(results.scoops, 10);
+    }
+
+    #[test]
+    fn invalid_runs() {
+        let (hero, villain) = (hand!("AhKh"), hand!("QsQc"));
+
+        assert!(matches!(
+            run_it_n_times(&hero, &villain, &[], &[], 0, 1),
+            Err(EquityError::InvalidRunCount(0))
+        ));
+        assert!(matches!(
+            run_it_n_times(&hero, &villain, &hand!("Qh7h2d3c"), &[], 45, 1),
+            Err(EquityError::NotEnoughCards(45))
+        ));
+    }
+}
@@ -0,0 +1,105 @@
+use super::{compare, holdem_stub, Equity, EquityError};
+use crate::core::{Card, CardSet};
+use crate::poker::boards::Boards;
+
+/// Calculates the exact equity of `hand1` against `hand2` for every card that can come next on a
+/// flop or turn
+///
+/// Each card that can still be dealt is paired with the equity of the first hand once that card is
+/// added to the board, in ascending order of the cards' `u8` encoding. On the flop, the equity of
+/// each turn card is counted over every river that can follow it; on the turn, each river card
+/// settles the hand, so its equity is a single win, tie, or loss. This shows which cards help or
+/// hurt a hand, and is the basis for grouping turn cards by how they change the matchup.
+///
+/// Both hands must have 2 cards and the board must have 3 or 4 cards. Dead cards are known to be
+/// out of play and cannot come next. An error will return if any card is given more than once or is
+/// a joker.
+///
+/// ```rust
+/// use playing_cards::{core::Card, poker::equity};
+///
+/// let aces = Card::vec_from_str("AhAd").unwrap();
+/// let kings = Card::vec_from_str("KsKc").unwrap();
+/// let flop = Card::vec_from_str("9c5d2h").unwrap();
+///
+/// let turns = equity::runout_equities(&aces, &kings, &flop, &[]).unwrap();
+/// let equity_on = |card: &str| {
+///     let card: Card = card.parse().unwrap();
+///     turns.iter().find(|(turn, _)| *turn == card).unwrap().1
+/// };
+///
+/// assert_eq!(turns.len(), 45);
+/// // a king on the turn leaves the aces drawing to the last two aces
+/// assert!(equity_on("Kh").equity_percentage() < 5.0);
+/// assert!(equity_on("7s").equity_percentage() > 95.0);
+/// ```
+pub fn runout_equities(
+    hand1: &[Card],
+    hand2: &[Card],
+    board: &[Card],
+    dead: &[Card],
+) -> Result<Vec<(Card, Equity)>, EquityError> {
+    if !(3..=4).contains(&board.len()) {
+        return Err(EquityError::InvalidBoardSize(board.len()));
+    }
+    let stub = holdem_stub(hand1, hand2, board, dead)?;
+    let out_of_play = CardSet::from(hand1) | CardSet::from(hand2) | CardSet::from(dead);
+
+    let mut next_board = [hand1[0]; 5];
+    next_board[..board.len()].copy_from_slice(board);
+    let next_board = &mut next_board[..board.len() + 1];
+
+    Ok(stub
+        .into_iter()
+        .map(|card| {
+            *next_board.last_mut().expect("The next board has a card") = card;
+
+            let mut equity = Equity::default();
+            Boards::new(next_board, 5, out_of_play)
+                .expect("The board should already be checked")
+                .for_each(|runout| equity.record(compare(hand1, hand2, runout, &[])));
+
+            (card, equity)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hand;
+
+    #[test]
+    fn every_next_card() {
+        // the equities of every turn add up to the equity on the flop
+        let turns = runout_equities(
+            &hand!("AsKs"),
+            &hand!("QdQc"),
+            &hand!("Js7s2d"),
+            &hand!("9s"),
+        )
+        .unwrap();
+        assert_eq!(turns.len(), 44);
+        assert!(turns.iter().all(|(_, equity)| equity.trials() == 43));
+        assert!(turns.iter().all(|(turn, _)| hand!("9s")[0] != *turn));
+        let flop = turns
+            .iter()
+            .fold(Equity::default(), |total, (_, equity)| total + *equity);
+        assert_eq!(flop.trials(), 44 * 43);
+        assert!((flop.equity_percentage() - 55.0).abs() < 5.0);
+
+        // each river settles the hand
+        let rivers =
+            runout_equities(&hand!("AsKs"), &hand!("QdQc"), &hand!("Js7s2d3h"), &[]).unwrap();
+        assert_eq!(rivers.len(), 44);
+        assert!(rivers.iter().all(|(_, equity)| equity.trials() == 1));
+        let wins = rivers.iter().filter(|(_, equity)| equity.wins == 1).count();
+        // 9 spades, 3 aces, and 3 kings
+        assert_eq!(wins, 15);
+
+        assert!(matches!(
+            runout_equities(&hand!("AsKs"), &hand!("QdQc"), &[], &[]),
+            Err(EquityError::InvalidBoardSize(0))
+        ));
+    }
+}
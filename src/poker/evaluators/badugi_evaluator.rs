@@ -1,11 +1,10 @@
-use num_traits::FromPrimitive;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
 use itertools::Itertools;
 
 use crate::{
-    core::{Card, Value},
-    poker::ranks::{BadugiRank, BasicRank},
+    core::{AceOrdering, Card},
+    poker::ranks::{BadugiRank, BasicRank, RankCategory, RankDescription},
 };
 
 use super::EvaluatorError;
@@ -21,9 +20,9 @@ fn choose(n: u64, k: u64) -> u64 {
 ///
 /// Returns a `BadugiRank`. If the card count is less than 4, then an error will return.
 ///
-/// This implementation does not support the use of duplicate cards. If duplicate cards are found,
+/// This implementation does not support the use of duplicate cards or jokers. If either are found,
 /// a `FailedToCalculateRank` error will return.
-pub fn evaluate_hand(player_hand: &Vec<Card>) -> Result<BadugiRank, EvaluatorError> {
+pub fn evaluate_hand(player_hand: &[Card]) -> Result<BadugiRank, EvaluatorError> {
     if player_hand.len() < 4 {
         return Err(EvaluatorError::NotEnoughCards {
             card_set_type: "Player hand".to_string(),
@@ -38,6 +37,12 @@ pub fn evaluate_hand(player_hand: &Vec<Card>) -> Result<BadugiRank, EvaluatorErr
         ));
     }
 
+    if player_hand.iter().any(Card::is_joker) {
+        return Err(EvaluatorError::FailedToCalculateRank(
+            "Found joker cards".to_string(),
+        ));
+    }
+
     let mut suit_bits = 0;
     let mut rank_bits = 0;
     for c in player_hand {
@@ -77,7 +82,7 @@ pub fn evaluate_hand(player_hand: &Vec<Card>) -> Result<BadugiRank, EvaluatorErr
         .map(|canidate_hand| {
             let card_ranks = canidate_hand
                 .iter()
-                .map(|&card| (card.value as u8 + 1) % 13)
+                .map(|&card| AceOrdering::Low.index(card.value))
                 .sorted_by(|a, b| b.cmp(a))
                 .collect::<Vec<_>>();
 
@@ -88,7 +93,7 @@ pub fn evaluate_hand(player_hand: &Vec<Card>) -> Result<BadugiRank, EvaluatorErr
                 base_strength += choose(13, i as u64);
             }
 
-            let (_, rank) = card_ranks.iter().enumerate().fold(
+            let (_, mut rank) = card_ranks.iter().enumerate().fold(
                 (
                     13,
                     BasicRank {
@@ -99,20 +104,6 @@ pub fn evaluate_hand(player_hand: &Vec<Card>) -> Result<BadugiRank, EvaluatorErr
                     },
                 ),
                 |(prev_rank_strength, mut acc), (i, rank_strength)| {
-                    if acc.description.is_none() {
-                        let hand_name_mapping: HashMap<usize, &str> = HashMap::from([
-                            (1, "1-card hand"),
-                            (2, "2-card hand"),
-                            (3, "3-card hand"),
-                            (4, "Badugi"),
-                        ]);
-                        let value_str: String = Value::from_u8((rank_strength - 1) % 13)
-                            .map_or("".to_string(), |v| {
-                                format!("{}-high ", v.get_readable_string())
-                            });
-                        acc.description =
-                            Some(format!("{}{}", value_str, hand_name_mapping[&card_count]));
-                    }
                     for s in (rank_strength + 1)..prev_rank_strength {
                         let strength_inc = choose((s - 1) as u64, (card_count - i - 1) as u64);
                         acc.strength += strength_inc as u32;
@@ -123,20 +114,30 @@ pub fn evaluate_hand(player_hand: &Vec<Card>) -> Result<BadugiRank, EvaluatorErr
                 },
             );
 
+            let mut values = card_ranks
+                .iter()
+                .map(|&rank_strength| AceOrdering::Low.value(rank_strength).unwrap());
+            rank.description = Some(RankDescription {
+                category: RankCategory::Badugi(card_count as u8),
+                primary: values.next().unwrap(),
+                secondary: None,
+                kickers: values.collect(),
+            });
+
             BadugiRank(rank)
         })
         .reduce(|acc, rank| if rank > acc { rank } else { acc })
-        .map_or(
-            Err(EvaluatorError::FailedToCalculateRank(
-                "Badugi rank failed to generate".to_string(),
-            )),
-            Ok,
-        )
+        .ok_or(EvaluatorError::FailedToCalculateRank(
+            "Badugi rank failed to generate".to_string(),
+        ))
 }
 
 #[cfg(test)]
+#[allow(clippy::identity_op)]
 mod tests {
     use super::*;
+    use crate::core::Value;
+    use crate::poker::ranks::Rank;
 
     #[test]
     fn hand_all_same_suit() {
@@ -147,7 +148,12 @@ mod tests {
             strength: 12,
             hand_rank: 1,
             sub_rank: 12,
-            description: Some("2-high 1-card hand".to_string()),
+            description: Some(RankDescription {
+                category: RankCategory::Badugi(1),
+                primary: Value::Two,
+                secondary: None,
+                kickers: vec![],
+            }),
         });
         assert_eq!(rank, expected_rank);
     }
@@ -161,7 +167,12 @@ mod tests {
             strength: 2,
             hand_rank: 1,
             sub_rank: 2,
-            description: Some("Queen-high 1-card hand".to_string()),
+            description: Some(RankDescription {
+                category: RankCategory::Badugi(1),
+                primary: Value::Queen,
+                secondary: None,
+                kickers: vec![],
+            }),
         });
         assert_eq!(expected_rank, rank);
     }
@@ -179,7 +190,12 @@ mod tests {
             strength: 1 + 13 + 63 + 1,
             hand_rank: 2,
             sub_rank: 65,
-            description: Some("4-high 2-card hand".to_string()),
+            description: Some(RankDescription {
+                category: RankCategory::Badugi(2),
+                primary: Value::Four,
+                secondary: None,
+                kickers: vec![Value::Two],
+            }),
         });
         assert_eq!(rank, expected_rank);
     }
@@ -198,7 +214,12 @@ mod tests {
             strength: 1 + 91 + 200 + 0 + 2,
             hand_rank: 3,
             sub_rank: 203,
-            description: Some("7-high 3-card hand".to_string()),
+            description: Some(RankDescription {
+                category: RankCategory::Badugi(3),
+                primary: Value::Seven,
+                secondary: None,
+                kickers: vec![Value::Six, Value::Three],
+            }),
         });
         assert_eq!(expected_rank, rank)
     }
@@ -218,7 +239,12 @@ mod tests {
             strength: 1 + 377 + 0 + 161 + 2 + 1,
             hand_rank: 4,
             sub_rank: 165,
-            description: Some("King-high Badugi".to_string()),
+            description: Some(RankDescription {
+                category: RankCategory::Badugi(4),
+                primary: Value::King,
+                secondary: None,
+                kickers: vec![Value::Five, Value::Three, Value::Ace],
+            }),
         });
         assert_eq!(expected_rank, rank);
     }
@@ -238,7 +264,12 @@ mod tests {
             strength: 1 + 377 + 490 + 0 + 3 + 0,
             hand_rank: 4,
             sub_rank: 494,
-            description: Some("6-high Badugi".to_string()),
+            description: Some(RankDescription {
+                category: RankCategory::Badugi(4),
+                primary: Value::Six,
+                secondary: None,
+                kickers: vec![Value::Five, Value::Two, Value::Ace],
+            }),
         });
         assert_eq!(expected_rank, rank);
     }
@@ -258,9 +289,16 @@ mod tests {
             strength: 1 + 377 + 495 + 0 + 0 + 0,
             hand_rank: 4,
             sub_rank: 496,
-            description: Some("4-high Badugi".to_string()),
+            description: Some(RankDescription {
+                category: RankCategory::Badugi(4),
+                primary: Value::Four,
+                secondary: None,
+                kickers: vec![Value::Three, Value::Two, Value::Ace],
+            }),
         });
         assert_eq!(expected_rank, rank);
+        assert_eq!(rank.strength, BadugiRank::MAX_STRENGTH);
+        assert_eq!(rank.normalized(), 1.0);
     }
 
     #[test]
@@ -278,7 +316,12 @@ mod tests {
             strength: 1 + 377 + 495 + 0 + 0 + 0,
             hand_rank: 4,
             sub_rank: 496,
-            description: Some("4-high Badugi".to_string()),
+            description: Some(RankDescription {
+                category: RankCategory::Badugi(4),
+                primary: Value::Four,
+                secondary: None,
+                kickers: vec![Value::Three, Value::Two, Value::Ace],
+            }),
         });
         assert_eq!(expected_rank, rank);
     }
@@ -296,7 +339,12 @@ mod tests {
             strength: 1 + 13 + 60 + 0,
             hand_rank: 2,
             sub_rank: 61,
-            description: Some("5-high 2-card hand".to_string()),
+            description: Some(RankDescription {
+                category: RankCategory::Badugi(2),
+                primary: Value::Five,
+                secondary: None,
+                kickers: vec![Value::Four],
+            }),
         });
         assert_eq!(expected_rank, rank);
     }
@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+use std::sync::{PoisonError, RwLock};
+
+use super::EvaluatorError;
+use crate::core::{canonicalize_suits, Card, CardSet};
+
+/// A wrapper that memoizes the ranks returned by an evaluator
+///
+/// Ranks do not depend on the suits of the cards, only on whether they match, so every set of
+/// cards that only differs by a relabelling of the suits shares the same rank. A
+/// `CachedEvaluator` stores each rank under the canonical cards returned by
+/// `canonicalize_suits()`, which makes workloads that revisit the same boards, such as
+/// range-vs-range enumeration, evaluate each distinct holding only once.
+///
+/// The cards are given in groups, such as a player's hand followed by the board, and the groups
+/// are passed unchanged to the wrapped evaluator. Only successful evaluations are cached, and
+/// groups holding duplicate cards are always passed to the evaluator so that it can reject them.
+/// Canonicalizing the cards has a cost of its own, so the cache pays off for evaluators that
+/// check many combinations, such as the Omaha evaluators, rather than for 7-card high hands.
+///
+/// The cache can be shared between threads, and it grows without bound until it is cleared.
+///
+/// ```rust
+/// use playing_cards::{
+///     core::Card,
+///     poker::evaluators::{omaha_hi_evaluator, CachedEvaluator},
+/// };
+///
+/// let evaluator =
+///     CachedEvaluator::new(|groups: &[&[Card]]| omaha_hi_evaluator::evaluate_hand(groups[0], groups[1]));
+///
+/// let board = Card::vec_from_str("KsQsJs8s2c").unwrap();
+/// let hero = Card::vec_from_str("AhKh9d7d").unwrap();
+/// let villain = Card::vec_from_str("Ad9h7hKd").unwrap();
+///
+/// let hero_rank = evaluator.evaluate(&[&hero, &board]).unwrap();
+/// let villain_rank = evaluator.evaluate(&[&villain, &board]).unwrap();
+///
+/// // both hands share the same canonical cards, so only one was evaluated
+/// assert_eq!(hero_rank, villain_rank);
+/// assert_eq!(evaluator.len(), 1);
+/// ```
+pub struct CachedEvaluator<E, R> {
+    evaluator: E,
+    cache: RwLock<HashMap<Vec<CardSet>, R>>,
+}
+
+impl<E, R> CachedEvaluator<E, R> {
+    /// Creates a new `CachedEvaluator` around an evaluator that takes groups of cards
+    ///
+    /// Closures should annotate their argument types so that they can be called with arguments
+    /// of any lifetime.
+    pub fn new(evaluator: E) -> Self {
+        Self {
+            evaluator,
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Evaluates the groups of cards, reusing the rank of any suit-isomorphic groups that were
+    /// evaluated before
+    ///
+    /// Errors from the wrapped evaluator are returned as is and are not cached.
+    pub fn evaluate(&self, groups: &[&[Card]]) -> Result<R, EvaluatorError>
+    where
+        E: Fn(&[&[Card]]) -> Result<R, EvaluatorError>,
+        R: Clone,
+    {
+        let (canonical_groups, _) = canonicalize_suits(groups);
+        let key: Vec<CardSet> = canonical_groups
+            .iter()
+            .map(|group| CardSet::from(group.as_slice()))
+            .collect();
+        if key
+            .iter()
+            .zip(groups)
+            .any(|(set, group)| set.len() != group.len())
+        {
+            return (self.evaluator)(groups);
+        }
+
+        let cached = self
+            .cache
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&key)
+            .cloned();
+        if let Some(rank) = cached {
+            return Ok(rank);
+        }
+
+        let rank = (self.evaluator)(groups)?;
+        self.cache
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, rank.clone());
+        Ok(rank)
+    }
+
+    /// Returns the number of distinct canonical holdings that are cached
+    pub fn len(&self) -> usize {
+        self.cache
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Returns true if nothing is cached
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes every cached rank
+    pub fn clear(&self) {
+        self.cache
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poker::evaluators::{high_evaluator, omaha_hi_evaluator};
+
+    #[test]
+    fn isomorphic_hands_share_entries() {
+        let evaluator = CachedEvaluator::new(|groups: &[&[Card]]| {
+            omaha_hi_evaluator::evaluate_hand(groups[0], groups[1])
+        });
+        let board = Card::vec_from_str("3s8sKs3dQs").unwrap();
+
+        // only hearts and clubs are interchangeable, since the board holds a diamond
+        for hand in ["AsQh2h5d", "AsQc2c5d", "5dAs2cQc"] {
+            let hand = Card::vec_from_str(hand).unwrap();
+            assert_eq!(
+                evaluator.evaluate(&[&hand, &board]),
+                omaha_hi_evaluator::evaluate_hand(&hand, &board)
+            );
+        }
+        assert_eq!(evaluator.len(), 1);
+
+        // moving a suit between the cards changes the holding
+        let hand = Card::vec_from_str("AsJs2h5d").unwrap();
+        assert_eq!(
+            evaluator.evaluate(&[&hand, &board]),
+            omaha_hi_evaluator::evaluate_hand(&hand, &board)
+        );
+        assert_eq!(evaluator.len(), 2);
+
+        evaluator.clear();
+        assert!(evaluator.is_empty());
+    }
+
+    #[test]
+    fn errors_are_not_cached() {
+        let evaluator =
+            CachedEvaluator::new(|groups: &[&[Card]]| high_evaluator::evaluate_hand(groups[0]));
+
+        let hand = Card::vec_from_str("AsKsQsJsTs").unwrap();
+        assert!(evaluator.evaluate(&[&hand]).is_ok());
+
+        // the duplicate would otherwise share its canonical cards with the hand above
+        let duplicate = Card::vec_from_str("AsKsQsJsTsTs").unwrap();
+        assert_eq!(
+            evaluator.evaluate(&[&duplicate]),
+            Err(EvaluatorError::FailedToCalculateRank(
+                "Found duplicate cards".to_string()
+            ))
+        );
+        assert!(evaluator.evaluate(&[&hand[..4]]).is_err());
+        assert_eq!(evaluator.len(), 1);
+    }
+}
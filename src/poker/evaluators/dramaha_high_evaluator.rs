@@ -15,8 +15,8 @@ use crate::poker::ranks::DramahaHighRank;
 /// when both the player's cards and the board are chained, a `FailedToCalculateRank` error will
 /// return.
 pub fn evaluate_hand(
-    player_hand: &Vec<Card>,
-    board: &Vec<Card>,
+    player_hand: &[Card],
+    board: &[Card],
 ) -> Result<DramahaHighRank, EvaluatorError> {
     let expected_card_count = 5;
     match player_hand.len().cmp(&expected_card_count) {
@@ -62,8 +62,18 @@ mod tests {
         let player_ranks = evaluate_hand(&player_hand, &board).expect("Evaluation failed");
 
         let string_ranks = vec![
-            (*player_ranks.omaha_rank).description.as_ref().unwrap(),
-            (*player_ranks.draw_rank).description.as_ref().unwrap(),
+            player_ranks
+                .omaha_rank
+                .description
+                .as_ref()
+                .unwrap()
+                .to_string(),
+            player_ranks
+                .draw_rank
+                .description
+                .as_ref()
+                .unwrap()
+                .to_string(),
         ];
         assert_eq!(
             vec!["Kings Full of Queens", "Two Pair of Aces and Queens"],
@@ -79,8 +89,18 @@ mod tests {
         let player_ranks = evaluate_hand(&player_hand, &board).expect("Evaluation failed");
 
         let string_ranks = vec![
-            (*player_ranks.omaha_rank).description.as_ref().unwrap(),
-            (*player_ranks.draw_rank).description.as_ref().unwrap(),
+            player_ranks
+                .omaha_rank
+                .description
+                .as_ref()
+                .unwrap()
+                .to_string(),
+            player_ranks
+                .draw_rank
+                .description
+                .as_ref()
+                .unwrap()
+                .to_string(),
         ];
         assert_eq!(vec!["Two Pair of Queens and 3s", "Ace High"], string_ranks);
     }
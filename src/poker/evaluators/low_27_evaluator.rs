@@ -9,7 +9,7 @@ use crate::{core::Card, poker::ranks::Low27Rank};
 ///
 /// This implementation does not support the use of duplicate cards. If duplicate cards are found,
 /// a `FailedToCalculateRank` error will return.
-pub fn evaluate_hand(cards: &Vec<Card>) -> Result<Low27Rank, EvaluatorError> {
+pub fn evaluate_hand(cards: &[Card]) -> Result<Low27Rank, EvaluatorError> {
     high_evaluator::evaluate_hand(cards).map(|high_rank| {
         let mut rank = (*high_rank).clone();
         rank.strength = 7463 - rank.strength;
@@ -17,9 +17,18 @@ pub fn evaluate_hand(cards: &Vec<Card>) -> Result<Low27Rank, EvaluatorError> {
     })
 }
 
+/// Evaluates only the strength of the low hand for one player
+///
+/// The same cards are accepted and the same errors are returned as `evaluate_hand()`, but no
+/// `Low27Rank` is built, so nothing is allocated unless an error is returned.
+pub fn evaluate_strength(cards: &[Card]) -> Result<u32, EvaluatorError> {
+    high_evaluator::evaluate_strength(cards).map(|strength| 7463 - strength)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::poker::ranks::LowRank;
 
     #[test]
     fn threes_full_of_deuces_six_cards() {
@@ -59,6 +68,27 @@ mod tests {
         assert!(player2_rank > player1_rank);
     }
 
+    #[test]
+    fn matches_reversed_high_rank() {
+        let hand = Card::vec_from_str("2h4h5h6h7h").unwrap();
+
+        let low_rank = evaluate_hand(&hand).expect("Evaluation failed");
+        let high_rank = LowRank(high_evaluator::evaluate_hand(&hand).expect("Evaluation failed"));
+
+        assert_eq!(LowRank::from(low_rank.clone()), high_rank);
+        assert_eq!(Low27Rank::from(high_rank), low_rank);
+    }
+
+    #[test]
+    fn out_of_range_strength_does_not_overflow() {
+        let mut rank = high_evaluator::evaluate_hand(&Card::vec_from_str("2h4h5h6h7h").unwrap())
+            .expect("Evaluation failed");
+        rank.0.strength = u32::MAX;
+
+        assert_eq!(Low27Rank::from(LowRank(rank.clone())).strength, 0);
+        assert_eq!(LowRank::from(Low27Rank(rank.0)).strength, 0);
+    }
+
     #[test]
     fn cooler_2to7_example_1() {
         let player1_hand = Card::vec_from_str("5h3d7h2s9c").unwrap();
@@ -71,14 +101,16 @@ mod tests {
             player1_rank
                 .description
                 .as_ref()
-                .expect("Player 1 hand generated bad rank"),
+                .expect("Player 1 hand generated bad rank")
+                .to_string(),
             "9 High"
         );
         assert_eq!(
             player2_rank
                 .description
                 .as_ref()
-                .expect("Player 2 hand generated bad rank"),
+                .expect("Player 2 hand generated bad rank")
+                .to_string(),
             "8 High"
         );
         assert!(player1_rank < player2_rank);
@@ -96,14 +128,16 @@ mod tests {
             player1_rank
                 .description
                 .as_ref()
-                .expect("Player 1 hand generated bad rank"),
+                .expect("Player 1 hand generated bad rank")
+                .to_string(),
             "8 High"
         );
         assert_eq!(
             player2_rank
                 .description
                 .as_ref()
-                .expect("Player 2 hand generated bad rank"),
+                .expect("Player 2 hand generated bad rank")
+                .to_string(),
             "8 High"
         );
         assert!(player1_rank < player2_rank);
@@ -123,12 +157,13 @@ mod tests {
             let player_hand = Card::vec_from_str(h).unwrap();
 
             let player_rank = evaluate_hand(&player_hand)
-                .expect(&format!("Evaluation failed for hand {:?}", player_hand));
+                .unwrap_or_else(|_| panic!("Evaluation failed for hand {:?}", player_hand));
 
             let string_rank = player_rank
                 .description
                 .as_ref()
-                .expect("Hand generated bad rank");
+                .expect("Hand generated bad rank")
+                .to_string();
             assert_eq!(expected_str, string_rank, "\nFailed on hand {}\n", h);
         }
     }
@@ -150,7 +185,8 @@ mod tests {
             let string_rank = player_rank
                 .description
                 .as_ref()
-                .expect("Hand generated bad rank");
+                .expect("Hand generated bad rank")
+                .to_string();
             assert_eq!(expected_str, string_rank, "\nFailed on hand {}\n", h);
         }
     }
@@ -177,7 +213,8 @@ mod tests {
             let string_rank = player_rank
                 .description
                 .as_ref()
-                .expect("Hand generated bad rank");
+                .expect("Hand generated bad rank")
+                .to_string();
             assert_eq!(expected_str, string_rank, "\nFailed on hand {}\n", h);
         }
     }
@@ -199,7 +236,8 @@ mod tests {
             let string_rank = player_rank
                 .description
                 .as_ref()
-                .expect("Hand generated bad rank");
+                .expect("Hand generated bad rank")
+                .to_string();
             assert_eq!(expected_str, string_rank, "\nFailed on hand {}\n", h);
         }
     }
@@ -225,7 +263,8 @@ mod tests {
             let string_rank = player_rank
                 .description
                 .as_ref()
-                .expect("Hand generated bad rank");
+                .expect("Hand generated bad rank")
+                .to_string();
             assert_eq!(expected_str, string_rank, "\nFailed on hand {}\n", h);
         }
     }
@@ -246,7 +285,8 @@ mod tests {
             let string_rank = player_rank
                 .description
                 .as_ref()
-                .expect("Hand generated bad rank");
+                .expect("Hand generated bad rank")
+                .to_string();
             assert_eq!(expected_str, string_rank, "\nFailed on hand {}\n", h);
         }
     }
@@ -273,7 +313,8 @@ mod tests {
             let string_rank = player_rank
                 .description
                 .as_ref()
-                .expect("Hand generated bad rank");
+                .expect("Hand generated bad rank")
+                .to_string();
             assert_eq!(expected_str, string_rank, "\nFailed on hand {}\n", h);
         }
     }
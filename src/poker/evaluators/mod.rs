@@ -1,11 +1,18 @@
 //! Contains all Poker hand evaluators
 //!
 //! Please note that all evaluators will error if the appropriate number of cards are given or if
-//! any duplicate cards are detected.
+//! any duplicate cards are detected. Jokers are not supported by any of the evaluators, so they
+//! will also error if any jokers are given.
 
 mod evaluator_errors;
 pub use self::evaluator_errors::EvaluatorError;
 
+mod split_evaluator;
+pub use self::split_evaluator::SplitEvaluator;
+
+mod cached_evaluator;
+pub use self::cached_evaluator::CachedEvaluator;
+
 /// An evaluator for high hands
 ///
 /// This evaluator is typically used for games like Texas Hold'em, Five Card Draw, and Stud.
@@ -22,7 +29,7 @@ pub use self::evaluator_errors::EvaluatorError;
 ///
 /// let rank = high_evaluator::evaluate_hand(&all_cards).unwrap();
 ///
-/// assert_eq!(rank.description.as_ref().unwrap(), "Trip 5s");
+/// assert_eq!(rank.description.as_ref().unwrap().to_string(), "Trip 5s");
 /// ```
 ///
 /// ```rust
@@ -36,7 +43,7 @@ pub use self::evaluator_errors::EvaluatorError;
 ///
 /// let rank = high_evaluator::evaluate_hand(&all_cards).unwrap();
 ///
-/// assert_eq!(rank.description.as_ref().unwrap(), "Two Pair of Aces and Kings");
+/// assert_eq!(rank.description.as_ref().unwrap().to_string(), "Two Pair of Aces and Kings");
 /// ```
 ///
 /// ```rust
@@ -52,8 +59,8 @@ pub use self::evaluator_errors::EvaluatorError;
 /// let hero_rank = high_evaluator::evaluate_hand(&hero_hand).unwrap();
 /// let villan_rank = high_evaluator::evaluate_hand(&villan_hand).unwrap();
 ///
-/// assert_eq!(hero_rank.description.as_ref().unwrap(), "Two Pair of Kings and Queens");
-/// assert_eq!(villan_rank.description.as_ref().unwrap(), "Two Pair of Aces and 2s");
+/// assert_eq!(hero_rank.description.as_ref().unwrap().to_string(), "Two Pair of Kings and Queens");
+/// assert_eq!(villan_rank.description.as_ref().unwrap().to_string(), "Two Pair of Aces and 2s");
 ///
 /// assert!(hero_rank < villan_rank); // Villan's hand is better than the hero's
 /// ```
@@ -71,7 +78,7 @@ pub mod high_evaluator;
 ///
 /// let rank = low_27_evaluator::evaluate_hand(&hand).unwrap();
 ///
-/// assert_eq!(rank.description.as_ref().unwrap(), "Ace High");
+/// assert_eq!(rank.description.as_ref().unwrap().to_string(), "Ace High");
 /// ```
 ///
 /// ```rust
@@ -81,7 +88,7 @@ pub mod high_evaluator;
 ///
 /// let rank = low_27_evaluator::evaluate_hand(&hand).unwrap();
 ///
-/// assert_eq!(rank.description.as_ref().unwrap(), "Two Pair of Kings and 2s");
+/// assert_eq!(rank.description.as_ref().unwrap().to_string(), "Two Pair of Kings and 2s");
 /// ```
 ///
 /// ```rust
@@ -93,8 +100,8 @@ pub mod high_evaluator;
 /// let hero_rank = low_27_evaluator::evaluate_hand(&hero_hand).unwrap();
 /// let villan_rank = low_27_evaluator::evaluate_hand(&villan_hand).unwrap();
 ///
-/// assert_eq!(hero_rank.description.as_ref().unwrap(), "10 High");
-/// assert_eq!(villan_rank.description.as_ref().unwrap(), "6 High Straight");
+/// assert_eq!(hero_rank.description.as_ref().unwrap().to_string(), "10 High");
+/// assert_eq!(villan_rank.description.as_ref().unwrap().to_string(), "6 High Straight");
 ///
 /// assert!(hero_rank > villan_rank); // Hero's hand is better than the villan's
 /// ```
@@ -123,7 +130,7 @@ pub mod low_27_evaluator;
 ///
 /// // Notice: Even though we have Aces in our hand, we can only use 2 cards from out hand to
 /// // make the best hand (e.g. the king and the deuce pair with the board).
-/// assert_eq!(rank.description.as_ref().unwrap(), "Two Pair of Kings and 2s");
+/// assert_eq!(rank.description.as_ref().unwrap().to_string(), "Two Pair of Kings and 2s");
 /// ```
 ///
 /// ```rust
@@ -136,7 +143,7 @@ pub mod low_27_evaluator;
 ///
 /// // Notice: Even though we have the Ace of Clubs in out hand, we do not have a flush, as we
 /// // need another club within our hand.
-/// assert_eq!(rank.description.as_ref().unwrap(), "Pair of Kings");
+/// assert_eq!(rank.description.as_ref().unwrap().to_string(), "Pair of Kings");
 /// ```
 ///
 /// ```rust
@@ -149,8 +156,8 @@ pub mod low_27_evaluator;
 /// let hero_rank = omaha_hi_evaluator::evaluate_hand(&hero_hand, &board).unwrap();
 /// let villan_rank = omaha_hi_evaluator::evaluate_hand(&villan_hand, &board).unwrap();
 ///
-/// assert_eq!(hero_rank.description.as_ref().unwrap(), "10 High Straight");
-/// assert_eq!(villan_rank.description.as_ref().unwrap(), "Trip Aces");
+/// assert_eq!(hero_rank.description.as_ref().unwrap().to_string(), "10 High Straight");
+/// assert_eq!(villan_rank.description.as_ref().unwrap().to_string(), "Trip Aces");
 ///
 /// assert!(hero_rank > villan_rank); // Hero's hand is better than the villan's
 /// ```
@@ -176,7 +183,7 @@ pub mod omaha_hi_evaluator;
 ///
 /// // Notice: Even though we have Aces in our hand, we can only use 2 cards from out hand to
 /// // make the best hand (e.g. the K and the 2 pair with the board).
-/// assert_eq!(ranks.hi_rank.description.as_ref().unwrap(), "Two Pair of Kings and 2s");
+/// assert_eq!(ranks.hi_rank.description.as_ref().unwrap().to_string(), "Two Pair of Kings and 2s");
 /// // While we have A2 in our hand, the board has 278, which is only 4 distinct ranks, so no
 /// // lo rank exists
 /// assert_eq!(ranks.lo_rank, None);
@@ -190,8 +197,8 @@ pub mod omaha_hi_evaluator;
 ///
 /// let ranks = omaha_hilo_evaluator::evaluate_hand(&hand, &board).unwrap();
 ///
-/// assert_eq!(ranks.hi_rank.description.as_ref().unwrap(), "Pair of Aces");
-/// assert_eq!(ranks.lo_rank.as_ref().unwrap().description.as_ref().unwrap(), "6-5-3-2-A");
+/// assert_eq!(ranks.hi_rank.description.as_ref().unwrap().to_string(), "Pair of Aces");
+/// assert_eq!(ranks.lo_rank.as_ref().unwrap().description.as_ref().unwrap().to_string(), "6-5-3-2-A");
 /// ```
 ///
 /// ```rust
@@ -204,10 +211,10 @@ pub mod omaha_hi_evaluator;
 /// let hero_ranks = omaha_hilo_evaluator::evaluate_hand(&hero_hand, &board).unwrap();
 /// let villan_ranks = omaha_hilo_evaluator::evaluate_hand(&villan_hand, &board).unwrap();
 ///
-/// assert_eq!(hero_ranks.hi_rank.description.as_ref().unwrap(), "10 High Straight");
-/// assert_eq!(villan_ranks.hi_rank.description.as_ref().unwrap(), "Trip Aces");
+/// assert_eq!(hero_ranks.hi_rank.description.as_ref().unwrap().to_string(), "10 High Straight");
+/// assert_eq!(villan_ranks.hi_rank.description.as_ref().unwrap().to_string(), "Trip Aces");
 ///
-/// assert_eq!(hero_ranks.lo_rank.as_ref().unwrap().description.as_ref().unwrap(), "8-7-6-5-A");
+/// assert_eq!(hero_ranks.lo_rank.as_ref().unwrap().description.as_ref().unwrap().to_string(), "8-7-6-5-A");
 /// assert_eq!(villan_ranks.lo_rank, None);
 ///
 /// assert!(hero_ranks.hi_rank > villan_ranks.hi_rank); // Hero's hi hand is better than the villan's
@@ -229,8 +236,8 @@ pub mod omaha_hilo_evaluator;
 ///
 /// let rank = dramaha_high_evaluator::evaluate_hand(&hand, &board).unwrap();
 ///
-/// assert_eq!(rank.omaha_rank.description.as_ref().unwrap(), "Trip Kings");
-/// assert_eq!(rank.draw_rank.description.as_ref().unwrap(), "Two Pair of Aces and Kings");
+/// assert_eq!(rank.omaha_rank.description.as_ref().unwrap().to_string(), "Trip Kings");
+/// assert_eq!(rank.draw_rank.description.as_ref().unwrap().to_string(), "Two Pair of Aces and Kings");
 /// ```
 ///
 /// ```rust
@@ -241,8 +248,8 @@ pub mod omaha_hilo_evaluator;
 ///
 /// let rank = dramaha_high_evaluator::evaluate_hand(&hand, &board).unwrap();
 ///
-/// assert_eq!(rank.omaha_rank.description.as_ref().unwrap(), "Two Pair of Queens and 10s");
-/// assert_eq!(rank.draw_rank.description.as_ref().unwrap(), "Ace High Flush");
+/// assert_eq!(rank.omaha_rank.description.as_ref().unwrap().to_string(), "Two Pair of Queens and 10s");
+/// assert_eq!(rank.draw_rank.description.as_ref().unwrap().to_string(), "Ace High Flush");
 /// ```
 ///
 /// ```rust
@@ -256,14 +263,14 @@ pub mod omaha_hilo_evaluator;
 /// let villan_rank = dramaha_high_evaluator::evaluate_hand(&villan_hand, &board).unwrap();
 ///
 /// // Omaha Rank
-/// assert_eq!(hero_rank.omaha_rank.description.as_ref().unwrap(), "Trip 8s");
-/// assert_eq!(villan_rank.omaha_rank.description.as_ref().unwrap(), "Aces Full of 8s");
+/// assert_eq!(hero_rank.omaha_rank.description.as_ref().unwrap().to_string(), "Trip 8s");
+/// assert_eq!(villan_rank.omaha_rank.description.as_ref().unwrap().to_string(), "Aces Full of 8s");
 ///
 /// assert!(hero_rank.omaha_rank < villan_rank.omaha_rank); // Villan's hand is better than the hero's
 ///
 /// // 5-card Draw Rank
-/// assert_eq!(hero_rank.draw_rank.description.as_ref().unwrap(), "Queen High Straight");
-/// assert_eq!(villan_rank.draw_rank.description.as_ref().unwrap(), "Two Pair of Aces and Queens");
+/// assert_eq!(hero_rank.draw_rank.description.as_ref().unwrap().to_string(), "Queen High Straight");
+/// assert_eq!(villan_rank.draw_rank.description.as_ref().unwrap().to_string(), "Two Pair of Aces and Queens");
 ///
 /// assert!(hero_rank.draw_rank > villan_rank.draw_rank); // Hero's hand is better than the villan's
 /// ```
@@ -284,7 +291,7 @@ pub mod dramaha_high_evaluator;
 ///
 /// let rank = badugi_evaluator::evaluate_hand(&hand).unwrap();
 ///
-/// assert_eq!(rank.description.as_ref().unwrap(), "10-high Badugi");
+/// assert_eq!(rank.description.as_ref().unwrap().to_string(), "10-high Badugi");
 /// ```
 ///
 /// ```rust
@@ -294,7 +301,7 @@ pub mod dramaha_high_evaluator;
 ///
 /// let rank = badugi_evaluator::evaluate_hand(&hand).unwrap();
 ///
-/// assert_eq!(rank.description.as_ref().unwrap(), "Jack-high 2-card hand");
+/// assert_eq!(rank.description.as_ref().unwrap().to_string(), "Jack-high 2-card hand");
 /// ```
 ///
 /// ```rust
@@ -306,9 +313,31 @@ pub mod dramaha_high_evaluator;
 /// let hero_rank = badugi_evaluator::evaluate_hand(&hero_hand).unwrap();
 /// let villan_rank = badugi_evaluator::evaluate_hand(&villan_hand).unwrap();
 ///
-/// assert_eq!(hero_rank.description.as_ref().unwrap(), "Jack-high 3-card hand");
-/// assert_eq!(villan_rank.description.as_ref().unwrap(), "Jack-high 3-card hand");
+/// assert_eq!(hero_rank.description.as_ref().unwrap().to_string(), "Jack-high 3-card hand");
+/// assert_eq!(villan_rank.description.as_ref().unwrap().to_string(), "Jack-high 3-card hand");
 ///
 /// assert!(hero_rank > villan_rank);
 /// ```
 pub mod badugi_evaluator;
+
+/// An evaluator for high hands backed by the Two Plus Two lookup table
+///
+/// This requires the `two-plus-two` feature. The table evaluates a 7-card hand with 7 array
+/// lookups, which makes it the fastest high evaluator once the table is loaded, at the cost of
+/// about 130 MB of memory and a few seconds to generate the table the first time. The table is
+/// usually generated once, saved to disk, and memory-mapped on later runs.
+///
+/// ## Examples
+/// ```rust,no_run
+/// use playing_cards::{core::Card, poker::evaluators::two_plus_two::TwoPlusTwoTable};
+///
+/// // SAFETY: nothing else modifies HandRanks.dat while the table is mapped
+/// let table = unsafe { TwoPlusTwoTable::open_or_generate("HandRanks.dat") }.unwrap();
+///
+/// let hero = Card::vec_from_str("KhQcAhKsQs9c2h").unwrap();
+/// let villain = Card::vec_from_str("Ac2cAhKsQs9c2h").unwrap();
+///
+/// assert!(table.strength(&hero) < table.strength(&villain));
+/// ```
+#[cfg(feature = "two-plus-two")]
+pub mod two_plus_two;
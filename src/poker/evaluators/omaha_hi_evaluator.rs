@@ -1,8 +1,6 @@
 use super::EvaluatorError;
 
-use itertools::Itertools;
-
-use crate::core::Card;
+use crate::core::{Card, CardSet};
 use crate::poker::evaluators::high_evaluator;
 use crate::poker::ranks::HighRank;
 
@@ -14,10 +12,21 @@ use crate::poker::ranks::HighRank;
 /// This implementation does not support the use of duplicate cards. If duplicate cards are found
 /// when both the player's cards and the board are chained, a `FailedToCalculateRank` error will
 /// return.
-pub fn evaluate_hand(
-    player_hand: &Vec<Card>,
-    board: &Vec<Card>,
-) -> Result<HighRank, EvaluatorError> {
+pub fn evaluate_hand(player_hand: &[Card], board: &[Card]) -> Result<HighRank, EvaluatorError> {
+    let strength = evaluate_strength(player_hand, board)?;
+    HighRank::from_strength(strength).ok_or_else(|| {
+        EvaluatorError::FailedToCalculateRank(
+            "Cactus-Kev lookup tables returned an out of range rank".to_string(),
+        )
+    })
+}
+
+/// Evaluates only the strength of the Omaha high hand for one player
+///
+/// The same cards are accepted and the same errors are returned as `evaluate_hand()`, but no
+/// `HighRank` is built and every combination of 2 cards from the hand and 3 from the board is
+/// evaluated in place, so nothing is allocated unless an error is returned.
+pub fn evaluate_strength(player_hand: &[Card], board: &[Card]) -> Result<u32, EvaluatorError> {
     if player_hand.len() < 4 {
         return Err(EvaluatorError::NotEnoughCards {
             card_set_type: "Player hand".to_string(),
@@ -35,27 +44,43 @@ pub fn evaluate_hand(
         });
     }
 
-    let hand_combinations: Vec<Vec<Card>> = player_hand.iter().cloned().combinations(2).collect();
-    let board_combinations: Vec<Vec<Card>> = board.iter().cloned().combinations(3).collect();
+    let all_cards = || player_hand.iter().chain(board);
+    if all_cards().collect::<CardSet>().len() != player_hand.len() + board.len() {
+        return Err(EvaluatorError::FailedToCalculateRank(
+            "Found duplicate cards".to_string(),
+        ));
+    }
 
-    let best_rank = hand_combinations
-        .iter()
-        .cartesian_product(board_combinations.iter())
-        .map(|(hand, board)| {
-            let mut all_cards = hand.clone();
-            all_cards.extend(board.iter());
-            high_evaluator::evaluate_hand(&all_cards)
-        })
-        .reduce(|acc, rank_res| {
-            let acc = acc?;
-            let rank = rank_res?;
-            Ok(std::cmp::max(rank, acc))
-        })
-        .unwrap_or(Err(EvaluatorError::FailedToCalculateRank(
-            "No hand combos were evaluated".to_string(),
-        )))?;
+    if all_cards().any(Card::is_joker) {
+        return Err(EvaluatorError::FailedToCalculateRank(
+            "Found joker cards".to_string(),
+        ));
+    }
+
+    best_strength(player_hand, board).ok_or_else(|| {
+        EvaluatorError::FailedToCalculateRank(
+            "Cactus-Kev lookup tables could not find a valid rank entry".to_string(),
+        )
+    })
+}
+
+/// Finds the strength of the best high hand using exactly 2 cards from the hand and 3 from the
+/// board, without validating the cards
+pub(crate) fn best_strength(player_hand: &[Card], board: &[Card]) -> Option<u32> {
+    let mut best = None;
+    for (i, &h0) in player_hand.iter().enumerate() {
+        for &h1 in &player_hand[i + 1..] {
+            for (j, &b0) in board.iter().enumerate() {
+                for (k, &b1) in board.iter().enumerate().skip(j + 1) {
+                    for &b2 in &board[k + 1..] {
+                        best = best.max(high_evaluator::best_strength(&[h0, h1, b0, b1, b2]));
+                    }
+                }
+            }
+        }
+    }
 
-    Ok(best_rank)
+    best
 }
 
 #[cfg(test)]
@@ -72,7 +97,8 @@ mod tests {
         let string_rank = player_rank
             .description
             .as_ref()
-            .expect("Hand generated bad rank");
+            .expect("Hand generated bad rank")
+            .to_string();
         assert_eq!("Trip Kings", string_rank);
     }
 
@@ -86,7 +112,8 @@ mod tests {
         let string_rank = player_rank
             .description
             .as_ref()
-            .expect("Hand generated bad rank");
+            .expect("Hand generated bad rank")
+            .to_string();
         assert_eq!("Two Pair of Queens and 3s", string_rank);
     }
 
@@ -103,10 +130,33 @@ mod tests {
             EvaluatorError::FailedToCalculateRank("Found duplicate cards".to_string())
         );
 
-        // If the duplicate guard did not exist in high_evaluator::evaluate_hand, then the evaluator
-        // would output the following error:
+        // If the duplicate guard did not exist, then the evaluator would output the following
+        // error:
         // assert_eq!(player_rank, EvaluatorError::FailedToCalculateRank("Cactus-Kev lookup tables could not find a valid rank entry".to_string()));
     }
+
+    #[test]
+    fn strength_matches_rank() {
+        let player_hand = Card::vec_from_str("AsQh2h5d").unwrap();
+        let board = Card::vec_from_str("3s8sKs3dQs").unwrap();
+
+        let rank = evaluate_hand(&player_hand, &board).unwrap();
+        assert_eq!(evaluate_strength(&player_hand, &board), Ok(rank.strength));
+        assert_eq!(
+            evaluate_strength(&player_hand, &board[..2]),
+            Err(EvaluatorError::NotEnoughCards {
+                card_set_type: "Board".to_string(),
+                expected_count: 3,
+                actual_count: 2,
+            })
+        );
+        assert_eq!(
+            evaluate_strength(&player_hand, &Card::vec_from_str("3s8sKsAs").unwrap()),
+            Err(EvaluatorError::FailedToCalculateRank(
+                "Found duplicate cards".to_string()
+            ))
+        );
+    }
 }
 
 #[cfg(all(feature = "unstable", test))]
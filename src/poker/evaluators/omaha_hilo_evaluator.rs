@@ -3,11 +3,12 @@ use std::collections::HashSet;
 use super::{omaha_hi_evaluator, EvaluatorError};
 
 use itertools::Itertools;
+use num_traits::FromPrimitive;
 use phf::phf_map;
 
 use crate::{
-    core::{Card, Value},
-    poker::ranks::{BasicRank, LowA5Rank, OmahaHiLoRank},
+    core::{AceOrdering, Card, Value},
+    poker::ranks::{BasicRank, LowA5Rank, OmahaHiLoRank, RankCategory, RankDescription},
 };
 
 /// Evaluates the Omaha hi/lo hand for one player
@@ -19,8 +20,8 @@ use crate::{
 /// when both the player's cards and the board are chained, a `FailedToCalculateRank` error will
 /// return.
 pub fn evaluate_hand(
-    player_hand: &Vec<Card>,
-    board: &Vec<Card>,
+    player_hand: &[Card],
+    board: &[Card],
 ) -> Result<OmahaHiLoRank, EvaluatorError> {
     if player_hand.len() < 4 {
         return Err(EvaluatorError::NotEnoughCards {
@@ -41,7 +42,46 @@ pub fn evaluate_hand(
         // Board does not have at least 3 cards
     }
 
-    let hi_hand = omaha_hi_evaluator::evaluate_hand(player_hand, board)?;
+    Ok(OmahaHiLoRank {
+        hi_rank: omaha_hi_evaluator::evaluate_hand(player_hand, board)?,
+        lo_rank: evaluate_low(player_hand, board)?,
+    })
+}
+
+/// Evaluates only the Omaha 8-or-better low hand for one player
+///
+/// Returns `None` if the player cannot make a qualifying low hand. The same errors as
+/// `evaluate_hand()` are returned for invalid hands and boards. This can be paired with
+/// `omaha_hi_evaluator::evaluate_hand()` through a `SplitEvaluator`.
+pub fn evaluate_low(
+    player_hand: &[Card],
+    board: &[Card],
+) -> Result<Option<LowA5Rank>, EvaluatorError> {
+    if player_hand.len() < 4 {
+        return Err(EvaluatorError::NotEnoughCards {
+            card_set_type: "Player hand".to_string(),
+            expected_count: 4,
+            actual_count: player_hand.len() as u64,
+        });
+        // Player hand does not have at least 4 cards
+    }
+
+    if board.len() < 3 {
+        // 3 because it allows for evaluation on flop-only flop-turn-only boards
+        return Err(EvaluatorError::NotEnoughCards {
+            card_set_type: "Board".to_string(),
+            expected_count: 3,
+            actual_count: board.len() as u64,
+        });
+        // Board does not have at least 3 cards
+    }
+
+    if player_hand.iter().chain(board).any(Card::is_joker) {
+        return Err(EvaluatorError::FailedToCalculateRank(
+            "Found joker cards".to_string(),
+        ));
+    }
+
     let mut lo_hand: Option<LowA5Rank> = None;
 
     let player_hand_sub_8: Vec<Card> = player_hand
@@ -75,16 +115,16 @@ pub fn evaluate_hand(
                     return None;
                 }
 
-                let bit_strength = cards
-                    .iter()
-                    .fold(0, |acc, card| acc | (1 << ((card.value as u8 + 1) % 13)));
+                let bit_strength = cards.iter().fold(0, |acc, card| {
+                    acc | (1 << AceOrdering::Low.index(card.value))
+                });
 
-                if let Some(&(strength, hand_rank, sub_rank, desc)) = LO_8_MAP.get(&bit_strength) {
+                if let Some(&(strength, hand_rank, sub_rank)) = LO_8_MAP.get(&bit_strength) {
                     Some(LowA5Rank(BasicRank {
                         strength,
                         hand_rank,
                         sub_rank,
-                        description: Some(desc.to_string()),
+                        description: Some(get_low_description(bit_strength)),
                     }))
                 } else {
                     None
@@ -93,67 +133,91 @@ pub fn evaluate_hand(
             .fold(None, |acc, rank| if acc < rank { rank } else { acc });
     }
 
-    Ok(OmahaHiLoRank {
-        hi_rank: hi_hand,
-        lo_rank: lo_hand,
-    })
+    Ok(lo_hand)
+}
+
+/// Builds the description of a low hand from the bits of its ace-low values
+fn get_low_description(bit_strength: u8) -> RankDescription {
+    let mut values = (0..8)
+        .rev()
+        .filter(|bit| bit_strength & (1 << bit) != 0)
+        .map(|bit| match bit {
+            0 => Value::Ace,
+            _ => Value::from_u8(bit - 1).unwrap(),
+        });
+
+    RankDescription {
+        category: RankCategory::LowA5,
+        primary: values.next().unwrap(),
+        secondary: None,
+        kickers: values.collect(),
+    }
+}
+
+/// Looks up the strength of an 8-or-better low from the bits of its five ace-low values
+///
+/// Returns `None` if the bits do not make a qualifying low.
+pub(crate) fn low_8_strength(bit_strength: u8) -> Option<u32> {
+    LO_8_MAP
+        .get(&bit_strength)
+        .map(|&(strength, _, _)| strength)
 }
 
-static LO_8_MAP: phf::Map<u8, (u32, u16, u16, &'static str)> = phf_map! {
-    0xf8u8 => (1, 1, 1, "8-7-6-5-4"),
-    0xf4u8 => (2, 1, 2, "8-7-6-5-3"),
-    0xf2u8 => (3, 1, 3, "8-7-6-5-2"),
-    0xf1u8 => (4, 1, 4, "8-7-6-5-A"),
-    0xecu8 => (5, 1, 5, "8-7-6-4-3"),
-    0xeau8 => (6, 1, 6, "8-7-6-4-2"),
-    0xe9u8 => (7, 1, 7, "8-7-6-4-A"),
-    0xe6u8 => (8, 1, 8, "8-7-6-3-2"),
-    0xe5u8 => (9, 1, 9, "8-7-6-3-A"),
-    0xe3u8 => (10, 1, 10, "8-7-6-2-A"),
-    0xdcu8 => (11, 1, 11, "8-7-5-4-3"),
-    0xdau8 => (12, 1, 12, "8-7-5-4-2"),
-    0xd9u8 => (13, 1, 13, "8-7-5-4-A"),
-    0xd6u8 => (14, 1, 14, "8-7-5-3-2"),
-    0xd5u8 => (15, 1, 15, "8-7-5-3-A"),
-    0xd3u8 => (16, 1, 16, "8-7-5-2-A"),
-    0xceu8 => (17, 1, 17, "8-7-4-3-2"),
-    0xcdu8 => (18, 1, 18, "8-7-4-3-A"),
-    0xcbu8 => (19, 1, 19, "8-7-4-2-A"),
-    0xc7u8 => (20, 1, 20, "8-7-3-2-A"),
-    0xbcu8 => (21, 1, 21, "8-6-5-4-3"),
-    0xbau8 => (22, 1, 22, "8-6-5-4-2"),
-    0xb9u8 => (23, 1, 23, "8-6-5-4-A"),
-    0xb6u8 => (24, 1, 24, "8-6-5-3-2"),
-    0xb5u8 => (25, 1, 25, "8-6-5-3-A"),
-    0xb3u8 => (26, 1, 26, "8-6-5-2-A"),
-    0xaeu8 => (27, 1, 27, "8-6-4-3-2"),
-    0xadu8 => (28, 1, 28, "8-6-4-3-A"),
-    0xabu8 => (29, 1, 29, "8-6-4-2-A"),
-    0xa7u8 => (30, 1, 30, "8-6-3-2-A"),
-    0x9eu8 => (31, 1, 31, "8-5-4-3-2"),
-    0x9du8 => (32, 1, 32, "8-5-4-3-A"),
-    0x9bu8 => (33, 1, 33, "8-5-4-2-A"),
-    0x97u8 => (34, 1, 34, "8-5-3-2-A"),
-    0x8fu8 => (35, 1, 35, "8-4-3-2-A"),
-    0x7cu8 => (36, 2, 1, "7-6-5-4-3"),
-    0x7au8 => (37, 2, 2, "7-6-5-4-2"),
-    0x79u8 => (38, 2, 3, "7-6-5-4-A"),
-    0x76u8 => (39, 2, 4, "7-6-5-3-2"),
-    0x75u8 => (40, 2, 5, "7-6-5-3-A"),
-    0x73u8 => (41, 2, 6, "7-6-5-2-A"),
-    0x6eu8 => (42, 2, 7, "7-6-4-3-2"),
-    0x6du8 => (43, 2, 8, "7-6-4-3-A"),
-    0x6bu8 => (44, 2, 9, "7-6-4-2-A"),
-    0x67u8 => (45, 2, 10, "7-6-3-2-A"),
-    0x5eu8 => (46, 2, 11, "7-5-4-3-2"),
-    0x5du8 => (47, 2, 12, "7-5-4-3-A"),
-    0x5bu8 => (48, 2, 13, "7-5-4-2-A"),
-    0x57u8 => (49, 2, 14, "7-5-3-2-A"),
-    0x4fu8 => (50, 2, 15, "7-4-3-2-A"),
-    0x3eu8 => (51, 3, 1, "6-5-4-3-2"),
-    0x3du8 => (52, 3, 2, "6-5-4-3-A"),
-    0x3bu8 => (53, 3, 3, "6-5-4-2-A"),
-    0x37u8 => (54, 3, 4, "6-5-3-2-A"),
-    0x2fu8 => (55, 3, 5, "6-4-3-2-A"),
-    0x1fu8 => (56, 4, 1, "5-4-3-2-A"),
+static LO_8_MAP: phf::Map<u8, (u32, u16, u16)> = phf_map! {
+    0xf8u8 => (1, 1, 1),
+    0xf4u8 => (2, 1, 2),
+    0xf2u8 => (3, 1, 3),
+    0xf1u8 => (4, 1, 4),
+    0xecu8 => (5, 1, 5),
+    0xeau8 => (6, 1, 6),
+    0xe9u8 => (7, 1, 7),
+    0xe6u8 => (8, 1, 8),
+    0xe5u8 => (9, 1, 9),
+    0xe3u8 => (10, 1, 10),
+    0xdcu8 => (11, 1, 11),
+    0xdau8 => (12, 1, 12),
+    0xd9u8 => (13, 1, 13),
+    0xd6u8 => (14, 1, 14),
+    0xd5u8 => (15, 1, 15),
+    0xd3u8 => (16, 1, 16),
+    0xceu8 => (17, 1, 17),
+    0xcdu8 => (18, 1, 18),
+    0xcbu8 => (19, 1, 19),
+    0xc7u8 => (20, 1, 20),
+    0xbcu8 => (21, 1, 21),
+    0xbau8 => (22, 1, 22),
+    0xb9u8 => (23, 1, 23),
+    0xb6u8 => (24, 1, 24),
+    0xb5u8 => (25, 1, 25),
+    0xb3u8 => (26, 1, 26),
+    0xaeu8 => (27, 1, 27),
+    0xadu8 => (28, 1, 28),
+    0xabu8 => (29, 1, 29),
+    0xa7u8 => (30, 1, 30),
+    0x9eu8 => (31, 1, 31),
+    0x9du8 => (32, 1, 32),
+    0x9bu8 => (33, 1, 33),
+    0x97u8 => (34, 1, 34),
+    0x8fu8 => (35, 1, 35),
+    0x7cu8 => (36, 2, 1),
+    0x7au8 => (37, 2, 2),
+    0x79u8 => (38, 2, 3),
+    0x76u8 => (39, 2, 4),
+    0x75u8 => (40, 2, 5),
+    0x73u8 => (41, 2, 6),
+    0x6eu8 => (42, 2, 7),
+    0x6du8 => (43, 2, 8),
+    0x6bu8 => (44, 2, 9),
+    0x67u8 => (45, 2, 10),
+    0x5eu8 => (46, 2, 11),
+    0x5du8 => (47, 2, 12),
+    0x5bu8 => (48, 2, 13),
+    0x57u8 => (49, 2, 14),
+    0x4fu8 => (50, 2, 15),
+    0x3eu8 => (51, 3, 1),
+    0x3du8 => (52, 3, 2),
+    0x3bu8 => (53, 3, 3),
+    0x37u8 => (54, 3, 4),
+    0x2fu8 => (55, 3, 5),
+    0x1fu8 => (56, 4, 1),
 };
@@ -0,0 +1,90 @@
+use super::EvaluatorError;
+use crate::poker::ranks::HiLoRank;
+
+/// A combinator that evaluates both halves of a split-pot game in one call
+///
+/// A `SplitEvaluator` wraps a high evaluator and a low evaluator that take the same arguments
+/// (e.g. a slice of cards, or a player's hand and a board). The low evaluator returns an `Option`
+/// to signify whether the player made a qualifying low hand. Evaluating returns a `HiLoRank`
+/// which can be passed directly to `generate_winner_list()`.
+///
+/// ```rust
+/// use playing_cards::{
+///     core::Card,
+///     poker::evaluators::{omaha_hi_evaluator, omaha_hilo_evaluator, SplitEvaluator},
+/// };
+///
+/// let omaha_8 = SplitEvaluator::new(
+///     |(hand, board): (&[Card], &[Card])| omaha_hi_evaluator::evaluate_hand(hand, board),
+///     |(hand, board): (&[Card], &[Card])| omaha_hilo_evaluator::evaluate_low(hand, board),
+/// );
+///
+/// let hand = Card::vec_from_str("As2d5sAd").unwrap();
+/// let board = Card::vec_from_str("Tc5c3s6c8c").unwrap();
+///
+/// let rank = omaha_8.evaluate((&hand, &board)).unwrap();
+///
+/// assert_eq!(rank, omaha_hilo_evaluator::evaluate_hand(&hand, &board).unwrap());
+/// assert_eq!(rank.to_string(), "Pair of Aces / 6-5-3-2-A");
+/// ```
+pub struct SplitEvaluator<H, L> {
+    high: H,
+    low: L,
+}
+
+impl<H, L> SplitEvaluator<H, L> {
+    /// Creates a new `SplitEvaluator` from a high evaluator and a low evaluator
+    ///
+    /// Closures should annotate their argument types so that they can be called with arguments
+    /// of any lifetime.
+    pub fn new(high: H, low: L) -> Self {
+        Self { high, low }
+    }
+
+    /// Evaluates the high and low hands
+    ///
+    /// The same arguments are given to both evaluators. If either evaluator returns an error, that
+    /// error is returned, with the high evaluator's error taking precedence.
+    pub fn evaluate<A, HR, LR>(&self, args: A) -> Result<HiLoRank<HR, LR>, EvaluatorError>
+    where
+        A: Copy,
+        H: Fn(A) -> Result<HR, EvaluatorError>,
+        L: Fn(A) -> Result<Option<LR>, EvaluatorError>,
+    {
+        Ok(HiLoRank {
+            hi_rank: (self.high)(args)?,
+            lo_rank: (self.low)(args)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Card;
+    use crate::poker::evaluators::{badugi_evaluator, high_evaluator};
+    use crate::poker::ranks::{BadugiRank, HighRank};
+
+    #[test]
+    fn single_slice_evaluators() {
+        // a high-badugi split where the badugi only qualifies with 4 cards
+        let evaluator = SplitEvaluator::new(high_evaluator::evaluate_hand, |cards: &[Card]| {
+            badugi_evaluator::evaluate_hand(&cards[..4])
+                .map(|rank| (rank.hand_rank == 4).then_some(rank))
+        });
+
+        let rank: HiLoRank<HighRank, BadugiRank> = evaluator
+            .evaluate(&Card::vec_from_str("As2d3c4hKs").unwrap())
+            .unwrap();
+        assert_eq!(rank.to_string(), "Ace High / 4-high Badugi");
+
+        let rank = evaluator
+            .evaluate(&Card::vec_from_str("As2s3c4hKs").unwrap())
+            .unwrap();
+        assert_eq!(rank.lo_rank, None);
+
+        assert!(evaluator
+            .evaluate(&Card::vec_from_str("As2s3c").unwrap())
+            .is_err());
+    }
+}
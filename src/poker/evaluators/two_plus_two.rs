@@ -0,0 +1,440 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use memmap2::Mmap;
+
+use super::{high_evaluator, EvaluatorError};
+use crate::core::Card;
+use crate::poker::ranks::{HandClass, HighRank};
+
+/// The number of entries in the table, which are 53 for each of the 612,977 states of up to 6
+/// cards
+pub const TABLE_LEN: usize = 32_487_834;
+
+/// The Two Plus Two lookup table, which evaluates a hand with one lookup per card
+///
+/// The table is a state machine where each entry points to the state after adding a card, and the
+/// entries reached by the last card hold the rank of the hand. Cards whose suits can no longer
+/// make a flush share a state, which keeps the table to 32,487,834 entries, or about 130 MB. The
+/// states are laid out as in the `HandRanks.dat` of the original generator, with each rank stored
+/// as the hand class in the top bits and the rank within the class in the bottom 12 bits.
+///
+/// The table can be generated in memory with `generate()`, which takes a few seconds, and saved
+/// with `save()` so that later runs can memory-map it with `open()` instead.
+pub struct TwoPlusTwoTable {
+    ranks: Ranks,
+}
+
+/// Where the entries of a table are stored
+enum Ranks {
+    Generated(Vec<u32>),
+    Mapped(Mmap),
+}
+
+impl TwoPlusTwoTable {
+    /// Generates the table in memory
+    pub fn generate() -> Self {
+        Self {
+            ranks: Ranks::Generated(generate_ranks()),
+        }
+    }
+
+    /// Memory-maps a table that was saved with `save()`
+    ///
+    /// An error will return if the file cannot be opened or mapped, or if it is not the size of a
+    /// table. A file that is the right size but holds other data does not cause a panic, but gives
+    /// meaningless strengths.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this or any other process, for as long as the
+    /// table is alive. The table reads the file through the mapping, so a change to the file is
+    /// undefined behavior, and a truncated file can crash the process.
+    pub unsafe fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: the caller guarantees that the file is not modified while it is mapped
+        let map = unsafe { Mmap::map(&file)? };
+        if map.len() != TABLE_LEN * 4 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("The file has {} bytes, not {}", map.len(), TABLE_LEN * 4),
+            ));
+        }
+
+        Ok(Self {
+            ranks: Ranks::Mapped(map),
+        })
+    }
+
+    /// Memory-maps the table saved at `path`, first generating and saving it if there is no file
+    ///
+    /// # Safety
+    ///
+    /// The same as for `open()`.
+    pub unsafe fn open_or_generate(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            Self::generate().save(path)?;
+        }
+
+        // SAFETY: the caller upholds the requirements of `open()`
+        unsafe { Self::open(path) }
+    }
+
+    /// Saves the table to a file, with each entry as a little-endian `u32`
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        match &self.ranks {
+            Ranks::Generated(ranks) => {
+                for rank in ranks {
+                    writer.write_all(&rank.to_le_bytes())?;
+                }
+            }
+            Ranks::Mapped(map) => writer.write_all(map)?,
+        }
+
+        writer.flush()
+    }
+
+    /// Finds the strength of the best five-card hand within 5 to 7 cards, from 1 for the weakest
+    /// high card to 7462 for a royal flush
+    ///
+    /// Like the strengths of the other high evaluators, a higher strength is a better hand. The
+    /// cards are not checked for duplicates, which makes this suitable for hot loops. Returns
+    /// `None` if there are not 5 to 7 cards, if any card is a joker, or if the table leads to an
+    /// entry or rank that does not exist.
+    pub fn strength(&self, cards: &[Card]) -> Option<u32> {
+        if !(5..=7).contains(&cards.len()) {
+            return None;
+        }
+
+        let mut state = 53;
+        for card in cards {
+            let card = u8::from(*card) as usize;
+            if card >= 52 {
+                return None;
+            }
+            state = self.entry(state + card + 1)? as usize;
+        }
+        let rank = if cards.len() == 7 {
+            state as u32
+        } else {
+            self.entry(state)?
+        };
+
+        rank_strength(rank)
+    }
+
+    /// Evaluates the high hand for one player
+    ///
+    /// This gives the same results as `high_evaluator::evaluate_hand()`, and errors in the same
+    /// cases.
+    ///
+    /// ```rust,no_run
+    /// use playing_cards::{
+    ///     core::Card,
+    ///     poker::evaluators::two_plus_two::TwoPlusTwoTable,
+    /// };
+    ///
+    /// // SAFETY: nothing else modifies HandRanks.dat while the table is mapped
+    /// let table = unsafe { TwoPlusTwoTable::open_or_generate("HandRanks.dat") }.unwrap();
+    /// let cards = Card::vec_from_str("5h5s2dTdKs5dAc").unwrap();
+    ///
+    /// let rank = table.evaluate_hand(&cards).unwrap();
+    ///
+    /// assert_eq!(rank.description.as_ref().unwrap().to_string(), "Trip 5s");
+    /// ```
+    pub fn evaluate_hand(&self, cards: &[Card]) -> Result<HighRank, EvaluatorError> {
+        high_evaluator::check_cards(cards)?;
+
+        self.strength(cards)
+            .and_then(HighRank::from_strength)
+            .ok_or_else(|| {
+                EvaluatorError::FailedToCalculateRank(
+                    "Two Plus Two table returned an out of range rank".to_string(),
+                )
+            })
+    }
+
+    /// Returns the entry at `index`, or `None` if the table has no such entry
+    fn entry(&self, index: usize) -> Option<u32> {
+        match &self.ranks {
+            Ranks::Generated(ranks) => ranks.get(index).copied(),
+            Ranks::Mapped(map) => {
+                let start = index.checked_mul(4)?;
+                let bytes = map.get(start..start + 4)?;
+                Some(u32::from_le_bytes(bytes.try_into().ok()?))
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for TwoPlusTwoTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let storage = match self.ranks {
+            Ranks::Generated(_) => "Generated",
+            Ranks::Mapped(_) => "Mapped",
+        };
+        f.debug_struct("TwoPlusTwoTable")
+            .field("ranks", &storage)
+            .finish()
+    }
+}
+
+/// Converts a rank from the table into a strength, returning `None` if it is not a valid rank
+fn rank_strength(rank: u32) -> Option<u32> {
+    let class = HandClass::try_from(u16::try_from(rank >> 12).ok()?).ok()?;
+    let sub_rank = rank & 0xfff;
+    (1..=class.sub_rank_count() as u32)
+        .contains(&sub_rank)
+        .then(|| class.strengths().start() - 1 + sub_rank)
+}
+
+/// Builds every entry of the table
+///
+/// The states are identified by their cards packed one per byte from the highest card down, where
+/// each card holds its value plus 1 in the top 4 bits and its suit plus 1 in the bottom 4 bits, or
+/// 0 if its suit cannot make a flush. Sorting the IDs puts every state with fewer cards first, so
+/// the table lays out the same as the original generator's.
+fn generate_ranks() -> Vec<u32> {
+    let mut ids: Vec<u64> = vec![0];
+    let mut level = 0..1;
+    for _ in 0..6 {
+        let mut next: Vec<u64> = ids[level]
+            .iter()
+            .flat_map(|&id| (1..=52).map(move |card| make_id(id, card)))
+            .filter(|&(id, count)| id != 0 && count < 7)
+            .map(|(id, _)| id)
+            .collect();
+        next.sort_unstable();
+        next.dedup();
+
+        level = ids.len()..ids.len() + next.len();
+        ids.extend(next);
+    }
+
+    let mut ranks = vec![0; ids.len() * 53 + 53];
+    for (index, &id) in ids.iter().enumerate() {
+        let mut count = 0;
+        for card in 1..=52 {
+            let (next, next_count) = make_id(id, card);
+            count = next_count;
+            ranks[index * 53 + card as usize + 53] = if next_count < 7 {
+                let next_index = ids.binary_search(&next).unwrap_or(0);
+                (next_index * 53 + 53) as u32
+            } else {
+                hand_rank(next)
+            };
+        }
+
+        // states of 5 or 6 cards also hold their own rank
+        if count == 6 || count == 7 {
+            ranks[index * 53 + 53] = hand_rank(id);
+        }
+    }
+
+    ranks
+}
+
+/// Adds a card, from 1 for the lowest card to 52 for the highest, to the state with the given ID
+///
+/// Returns the ID of the new state, or 0 if the card is already in the state or would make five
+/// of a value, along with the number of cards in the new state.
+fn make_id(id: u64, card: u8) -> (u64, usize) {
+    let mut cards = [0u8; 8];
+    for (i, slot) in cards[1..7].iter_mut().enumerate() {
+        *slot = (id >> (8 * i)) as u8;
+    }
+    let card = card - 1;
+    cards[0] = (((card >> 2) + 1) << 4) + (card & 3) + 1;
+
+    let mut suits = [0; 5];
+    let mut values = [0; 14];
+    let mut count = 0;
+    let mut duplicate = false;
+    while cards[count] != 0 {
+        suits[(cards[count] & 0xf) as usize] += 1;
+        values[(cards[count] >> 4) as usize] += 1;
+        duplicate |= count > 0 && cards[0] == cards[count];
+        count += 1;
+    }
+    if duplicate || (count > 4 && values.iter().any(|&n| n > 4)) {
+        return (0, count);
+    }
+
+    // a suit can only make a flush if it has all but 2 of the cards so far
+    let needed = count.saturating_sub(2);
+    if needed > 1 {
+        for card in &mut cards[..count] {
+            if suits[(*card & 0xf) as usize] < needed {
+                *card &= 0xf0;
+            }
+        }
+    }
+    cards[..count].sort_unstable_by(|a, b| b.cmp(a));
+
+    let id = cards[..7]
+        .iter()
+        .enumerate()
+        .fold(0, |id, (i, &card)| id | (card as u64) << (8 * i));
+    (id, count)
+}
+
+/// Returns the rank of the 5 to 7 cards of a state, or 0 for the ID 0
+///
+/// Cards whose suits cannot make a flush are given suits in turn, skipping the suit that can, so
+/// that no flush is made that was not there.
+fn hand_rank(id: u64) -> u32 {
+    let held: Vec<u8> = (0..7)
+        .map(|i| (id >> (8 * i)) as u8)
+        .take_while(|&card| card != 0)
+        .collect();
+    if held.is_empty() {
+        return 0;
+    }
+    let main_suit = held
+        .iter()
+        .rev()
+        .map(|card| card & 0xf)
+        .find(|&suit| suit != 0);
+
+    let mut next_suit = 1;
+    let mut take_suit = || {
+        let suit = next_suit;
+        next_suit = next_suit % 4 + 1;
+        suit
+    };
+    let cards: Vec<Card> = held
+        .iter()
+        .map(|&card| {
+            let mut suit = card & 0xf;
+            if suit == 0 {
+                suit = take_suit();
+                if Some(suit) == main_suit {
+                    suit = take_suit();
+                }
+            }
+            Card::try_from(((card >> 4) - 1) * 4 + suit - 1).expect("The card should be valid")
+        })
+        .collect();
+
+    let strength = high_evaluator::best_strength(&cards).unwrap_or(0);
+    match HandClass::from_strength(strength) {
+        Some(class) => (class as u32) << 12 | (strength - class.strengths().start() + 1),
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn states() {
+        let card = |s: &str| u8::from(s.parse::<Card>().unwrap()) + 1;
+
+        let (id, count) = make_id(0, card("Ah"));
+        assert_eq!(count, 1);
+        let (id, count) = make_id(id, card("Ks"));
+        assert_eq!(count, 2);
+        assert_eq!(make_id(id, card("Ah")).0, 0);
+
+        // the order of the cards does not matter
+        let (other, _) = make_id(make_id(0, card("Ks")).0, card("Ah"));
+        assert_eq!(other, id);
+
+        // five cards of different suits share a state with the same values in other suits
+        let add = |cards: &[&str]| cards.iter().fold(0, |id, c| make_id(id, card(c)).0);
+        assert_eq!(
+            add(&["Ah", "Ks", "Qd", "Jc", "9h"]),
+            add(&["As", "Kh", "Qc", "Jd", "9s"])
+        );
+        assert_ne!(
+            add(&["Ah", "Kh", "Qh", "Jc", "9h"]),
+            add(&["Ah", "Kh", "Qh", "Jc", "9s"])
+        );
+
+        // the ranks keep the hand class in the top bits
+        let royal = hand_rank(add(&["Ah", "Kh", "Qh", "Jh", "Th"]));
+        assert_eq!((royal >> 12, royal & 0xfff), (9, 10));
+        let flush = hand_rank(add(&["Ah", "Kh", "Qh", "9c", "8h", "2h"]));
+        assert_eq!(flush >> 12, 6);
+        let worst = hand_rank(add(&["7h", "5s", "4d", "3c", "2h"]));
+        assert_eq!((worst >> 12, worst & 0xfff), (1, 1));
+        assert_eq!(hand_rank(0), 0);
+    }
+
+    #[test]
+    fn state_ranks_match_high_evaluator() {
+        use rand::seq::SliceRandom;
+        use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+
+        let mut deck: Vec<Card> = (0..52u8).map(|n| Card::try_from(n).unwrap()).collect();
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(23);
+        for count in 5..=7 {
+            for _ in 0..2_000 {
+                deck.shuffle(&mut rng);
+                let cards = &deck[..count];
+                let id = cards
+                    .iter()
+                    .fold(0, |id, card| make_id(id, u8::from(*card) + 1).0);
+                assert_eq!(
+                    rank_strength(hand_rank(id)),
+                    high_evaluator::best_strength(cards)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn corrupt_tables() {
+        let cards = Card::vec_from_str("5h5s2dTdKs5dAc").unwrap();
+
+        // states that point past the end of the table
+        let table = TwoPlusTwoTable {
+            ranks: Ranks::Generated(vec![u32::MAX; 200]),
+        };
+        assert_eq!(table.strength(&cards), None);
+        assert!(table.evaluate_hand(&cards).is_err());
+
+        // ranks of hand classes or sub ranks that do not exist
+        for rank in [0, 10 << 12, 1 << 12 | 1278, u32::MAX] {
+            assert_eq!(rank_strength(rank), None);
+        }
+        assert_eq!(rank_strength(9 << 12 | 10), Some(7462));
+    }
+
+    #[test]
+    #[ignore = "generating the table takes too long without optimizations"]
+    fn matches_high_evaluator() {
+        use rand::seq::SliceRandom;
+        use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+
+        let table = TwoPlusTwoTable::generate();
+        let Ranks::Generated(ranks) = &table.ranks else {
+            unreachable!()
+        };
+        assert_eq!(ranks.len(), TABLE_LEN);
+
+        let mut deck: Vec<Card> = (0..52u8).map(|n| Card::try_from(n).unwrap()).collect();
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(19);
+        for count in 5..=7 {
+            for _ in 0..100_000 {
+                deck.shuffle(&mut rng);
+                let cards = &deck[..count];
+                assert_eq!(table.strength(cards), high_evaluator::best_strength(cards));
+            }
+        }
+
+        let path = std::env::temp_dir().join("playing-cards-two-plus-two-test.dat");
+        table.save(&path).unwrap();
+        let mapped = unsafe { TwoPlusTwoTable::open(&path) }.unwrap();
+        let cards = Card::vec_from_str("5h5s2dTdKs5dAc").unwrap();
+        assert_eq!(
+            mapped.evaluate_hand(&cards).unwrap(),
+            high_evaluator::evaluate_hand(&cards).unwrap()
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,195 @@
+//! Tournament equity with the Independent Chip Model
+//!
+//! Chips in a tournament are not worth a fixed amount of prize money, since a player can only win
+//! each payout once. The Independent Chip Model (ICM) converts stacks into their share of the
+//! prize pool with the Malmuth-Harville model, where the chance of a player finishing in the best
+//! place still open is their share of the chips among the players who have not yet placed.
+//!
+//! ```rust
+//! use playing_cards::poker::icm;
+//!
+//! let equities = icm::icm(&[5_000.0, 3_000.0, 2_000.0], &[50.0, 30.0, 20.0]).unwrap();
+//!
+//! // the chip leader has half of the chips but far less than half of the prize pool
+//! assert!((equities[0] - 38.39).abs() < 0.01);
+//! assert!((equities.iter().sum::<f64>() - 100.0).abs() < 1e-9);
+//! ```
+
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+/// The most players that an ICM calculation supports, since each set of players is a 64-bit mask
+pub const MAX_PLAYERS: usize = 64;
+
+/// The most sets of players that an ICM calculation will consider before returning an error
+///
+/// This allows every place to be paid for up to 20 players, or 5 paid places for 64 players.
+pub const MAX_SETS: u64 = 1 << 20;
+
+/// Error type for ICM calculations
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum IcmError {
+    /// There were no players, or more than `MAX_PLAYERS`
+    #[error("ICM cannot be calculated for {0} players")]
+    InvalidPlayerCount(usize),
+    /// A stack was not a positive number of chips
+    #[error("A stack of {0} chips is not valid")]
+    InvalidStack(f64),
+    /// A payout was negative or not a number
+    #[error("A payout of {0} is not valid")]
+    InvalidPayout(f64),
+    /// The players and paid places would require considering more than `MAX_SETS` sets of players
+    #[error("ICM cannot be calculated for {0} players with {1} paid places")]
+    TooManyPlaces(usize, usize),
+}
+
+/// Calculates the prize money each stack is worth with the Malmuth-Harville model
+///
+/// `payouts` holds the prize for each place, starting with first place. Places beyond the end of
+/// `payouts` pay nothing, and payouts for places beyond the number of players are ignored. The
+/// equities are returned in the same order as `stacks`, in the same units as `payouts`.
+///
+/// Only the orders of the paid places need to be considered, so the probability of each set of
+/// players filling the paid places is calculated once and shared between every order that leads
+/// to it. The number of sets still grows quickly with both the players and the paid places: a
+/// 9-handed final table with every place paid considers 511 sets of players, 64 players with 5
+/// paid places consider 679,121, and 64 players with 10 paid places would consider over 32
+/// billion. An error will return if there are no players or more than `MAX_PLAYERS`, if any stack
+/// is not a positive number, if any payout is negative or not a number, or if more than `MAX_SETS`
+/// sets of players would be considered. Trailing payouts of 0 do not count as paid places.
+pub fn icm(stacks: &[f64], payouts: &[f64]) -> Result<Vec<f64>, IcmError> {
+    if stacks.is_empty() || stacks.len() > MAX_PLAYERS {
+        return Err(IcmError::InvalidPlayerCount(stacks.len()));
+    }
+    if let Some(stack) = stacks
+        .iter()
+        .find(|stack| !(stack.is_finite() && **stack > 0.0))
+    {
+        return Err(IcmError::InvalidStack(*stack));
+    }
+    if let Some(payout) = payouts
+        .iter()
+        .find(|payout| !(payout.is_finite() && **payout >= 0.0))
+    {
+        return Err(IcmError::InvalidPayout(*payout));
+    }
+
+    let places = payouts
+        .iter()
+        .take(stacks.len())
+        .rposition(|payout| *payout > 0.0)
+        .map_or(0, |last| last + 1);
+    if set_count(stacks.len(), places) > MAX_SETS {
+        return Err(IcmError::TooManyPlaces(stacks.len(), places));
+    }
+
+    let total: f64 = stacks.iter().sum();
+    let mut equities = vec![0.0; stacks.len()];
+
+    // the probability of each set of players, as a bit mask, having filled the places so far
+    let mut placed: HashMap<u64, f64> = HashMap::from([(0, 1.0)]);
+    for payout in &payouts[..places] {
+        let mut next: HashMap<u64, f64> = HashMap::with_capacity(placed.len() * stacks.len());
+        for (mask, probability) in placed {
+            let remaining = total
+                - (0..stacks.len())
+                    .filter(|player| mask & 1 << player != 0)
+                    .map(|player| stacks[player])
+                    .sum::<f64>();
+
+            for (player, stack) in stacks.iter().enumerate() {
+                if mask & 1 << player != 0 {
+                    continue;
+                }
+                let finishes = probability * stack / remaining;
+                equities[player] += finishes * payout;
+                *next.entry(mask | 1 << player).or_default() += finishes;
+            }
+        }
+        placed = next;
+    }
+
+    Ok(equities)
+}
+
+/// Counts the sets of players that fill fewer than `places` places, stopping once there are more
+/// than `MAX_SETS`
+fn set_count(players: usize, places: usize) -> u64 {
+    let mut count = 0;
+    let mut sets: u128 = 1;
+    for placed in 0..places {
+        count += sets as u64;
+        if count > MAX_SETS {
+            break;
+        }
+        sets = sets * (players - placed) as u128 / (placed + 1) as u128;
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equities() {
+        let close = |a: &[f64], b: &[f64]| a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-9);
+
+        // winner takes all is the same as the chip count
+        let equities = icm(&[1.0, 2.0, 7.0], &[100.0]).unwrap();
+        assert!(close(&equities, &[10.0, 20.0, 70.0]));
+
+        // equal stacks split the prize pool evenly
+        let equities = icm(&[1_500.0; 9], &[40.0, 25.0, 15.0, 10.0, 6.0, 4.0]).unwrap();
+        assert!(close(&equities, &[100.0 / 9.0; 9]));
+
+        // the short stack gains and the big stack loses compared to their chip shares
+        let equities = icm(&[50.0, 30.0, 20.0], &[0.5, 0.3, 0.2]).unwrap();
+        assert!(close(
+            &equities,
+            &[0.38392857142857145, 0.3275, 0.28857142857142853]
+        ));
+
+        // payouts beyond the number of players are never won
+        let equities = icm(&[3.0, 1.0], &[60.0, 30.0, 10.0]).unwrap();
+        assert!(close(&equities, &[52.5, 37.5]));
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        assert_eq!(icm(&[], &[1.0]), Err(IcmError::InvalidPlayerCount(0)));
+        assert_eq!(
+            icm(&[1.0; 65], &[1.0]),
+            Err(IcmError::InvalidPlayerCount(65))
+        );
+        assert_eq!(icm(&[1.0, 0.0], &[1.0]), Err(IcmError::InvalidStack(0.0)));
+        assert_eq!(icm(&[1.0], &[-1.0]), Err(IcmError::InvalidPayout(-1.0)));
+    }
+
+    #[test]
+    fn large_fields() {
+        assert_eq!(set_count(9, 9), 511);
+        assert_eq!(set_count(20, 20), MAX_SETS - 1);
+        assert_eq!(set_count(64, 5), 679_121);
+        assert!(set_count(64, 10) > MAX_SETS);
+
+        let stacks = [1.0; 64];
+        let equities = icm(&stacks, &[50.0, 30.0, 20.0]).unwrap();
+        assert!(equities
+            .iter()
+            .all(|equity| (equity - 100.0 / 64.0).abs() < 1e-9));
+
+        let mut payouts = [0.0; 10];
+        payouts[..6].copy_from_slice(&[30.0, 20.0, 15.0, 10.0, 8.0, 7.0]);
+        assert_eq!(icm(&stacks, &payouts), Err(IcmError::TooManyPlaces(64, 6)));
+
+        // unpaid places at the end are not considered
+        assert_eq!(
+            icm(&stacks, &[60.0, 40.0, 0.0, 0.0, 0.0, 0.0, 0.0]),
+            icm(&stacks, &[60.0, 40.0])
+        );
+    }
+}
@@ -1,7 +1,19 @@
 //! An optional feature that includes tools for poker hand evaluation
-pub(crate) mod tables;
+pub mod tables;
 
 pub mod evaluators;
 
+pub mod equity;
+
+pub mod analysis;
+
+pub mod range;
+
+pub mod boards;
+
+pub mod query;
+
+pub mod icm;
+
 /// Contains structs for contains rank metadata and logic for parsing ranks
 pub mod ranks;
@@ -0,0 +1,602 @@
+//! Probability queries in the style of the Poker Query Language
+//!
+//! A `Query` asks for the probability of an event given what is known about a Texas Hold'em hand,
+//! and is parsed from a statement such as:
+//!
+//! ```text
+//! P(river hand class == Flush | hero = AhKh, board starts Qh 7h 2s)
+//! ```
+//!
+//! The event comes before the `|`, and is one of:
+//!
+//! - `<street> hand class <comparison> <class>`, where the street is `flop`, `turn`, or `river`
+//!   (the river if left out), the comparison is one of `==`, `!=`, `<`, `<=`, `>`, or `>=`, and
+//!   the class is a `HandClass` such as `Flush` or `Full House`
+//! - `hero wins` or `hero ties`, which compare the hero's hand against the villain's at showdown
+//!
+//! The conditions come after the `|`, separated by commas, and are any of:
+//!
+//! - `hero = <cards>`, the hero's 2 hole cards, which must be given
+//! - `board starts <cards>` or `board = <cards>`, the first 0 to 5 cards of the board
+//! - `villain in <range>` or `villain = <range>`, the villain's range in the format of `Range`,
+//!   which must be given to compare hands at showdown
+//! - `dead = <cards>`, cards that are known to be out of play
+//!
+//! `Query::probability()` calculates the exact probability by visiting every villain combo and
+//! every runout, and `Query::estimate()` samples them instead for queries that would take too
+//! long to enumerate, such as those with a preflop board.
+//!
+//! ```rust
+//! use playing_cards::poker::query::Query;
+//!
+//! let query: Query = "P(river hand class == Flush | hero = AhKh, board starts Qh 7h 2s)"
+//!     .parse()
+//!     .unwrap();
+//!
+//! // 9 hearts are left among the 47 unseen cards
+//! assert!((query.probability() - 0.35).abs() < 0.01);
+//! ```
+
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use rand::Rng;
+use rand_core::{RngCore, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use thiserror::Error;
+
+use crate::core::{Card, CardDeck, CardDeckError, CardSet};
+use crate::poker::boards::Boards;
+use crate::poker::equity::deal;
+use crate::poker::evaluators::high_evaluator;
+use crate::poker::range::{Combo, ParseRangeError, Range};
+use crate::poker::ranks::{HandClass, HighRank};
+
+/// Error type for parsing and evaluating queries
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum QueryError {
+    /// Part of the query could not be understood
+    #[error(r#"Could not understand "{0}""#)]
+    InvalidSyntax(String),
+    /// A list of cards could not be parsed
+    #[error(r#""{0}" is not a valid list of cards"#)]
+    InvalidCards(String),
+    /// The villain's range could not be parsed
+    #[error(transparent)]
+    InvalidRange(#[from] ParseRangeError),
+    /// The hero's hand was missing or did not have 2 cards
+    #[error("The hero's hand cannot have {0} cards")]
+    InvalidHandSize(usize),
+    /// The board had more than 5 cards
+    #[error("The board cannot have {0} cards")]
+    InvalidBoardSize(usize),
+    /// A card was given more than once
+    #[error("Card {0} was given more than once")]
+    DuplicateCard(Card),
+    /// A card that cannot be evaluated, such as a joker, was given
+    #[error("Card {0} cannot be evaluated")]
+    UnsupportedCard(Card),
+    /// The event compares hands at showdown, but no villain was given
+    #[error("The query needs a villain range to compare hands")]
+    MissingVillain,
+    /// Every combo of the villain's range shares a card with the known cards
+    #[error("The villain's range has no combos that can be dealt")]
+    EmptyRange,
+    /// Something went wrong with the deck, such as failing to sample entropy
+    #[error(transparent)]
+    DeckError(#[from] CardDeckError),
+}
+
+/// A street of Texas Hold'em after the cards of the board are dealt
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Street {
+    /// The first 3 cards of the board
+    Flop,
+    /// The fourth card of the board
+    Turn,
+    /// The fifth and last card of the board
+    River,
+}
+
+impl Street {
+    /// Returns the number of cards on the board once the street is dealt
+    pub fn board_size(&self) -> usize {
+        match self {
+            Self::Flop => 3,
+            Self::Turn => 4,
+            Self::River => 5,
+        }
+    }
+}
+
+/// How a value is compared to another in a query
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Comparison {
+    /// `==`
+    Equal,
+    /// `!=`
+    NotEqual,
+    /// `<`
+    Less,
+    /// `<=`
+    LessOrEqual,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterOrEqual,
+}
+
+impl Comparison {
+    /// Returns whether the comparison holds for the ordering of the left side to the right side
+    pub fn holds(&self, ordering: Ordering) -> bool {
+        match self {
+            Self::Equal => ordering.is_eq(),
+            Self::NotEqual => ordering.is_ne(),
+            Self::Less => ordering.is_lt(),
+            Self::LessOrEqual => ordering.is_le(),
+            Self::Greater => ordering.is_gt(),
+            Self::GreaterOrEqual => ordering.is_ge(),
+        }
+    }
+}
+
+/// The event whose probability a query asks for
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Event {
+    /// The hero's hand class on a street compares to a class
+    HandClass {
+        /// The street the hand class is taken on
+        street: Street,
+        /// How the hero's hand class compares to `class`
+        comparison: Comparison,
+        /// The hand class the hero's is compared to
+        class: HandClass,
+    },
+    /// The hero's hand beats the villain's at showdown
+    HeroWins,
+    /// The hero's hand ties the villain's at showdown
+    HeroTies,
+}
+
+impl Event {
+    /// Returns the number of board cards needed to decide the event
+    fn board_size(&self) -> usize {
+        match self {
+            Self::HandClass { street, .. } => street.board_size(),
+            Self::HeroWins | Self::HeroTies => 5,
+        }
+    }
+
+    /// Returns whether the event happens on a board of the needed size
+    fn happens(&self, hero: &[Card], villain: Option<Combo>, board: &[Card]) -> bool {
+        let strength = |hand: &[Card]| {
+            let mut cards = [board[0]; 7];
+            cards[..2].copy_from_slice(hand);
+            cards[2..2 + board.len()].copy_from_slice(board);
+            high_evaluator::best_strength(&cards[..2 + board.len()])
+        };
+
+        match self {
+            Self::HandClass {
+                comparison, class, ..
+            } => strength(hero)
+                .and_then(HighRank::from_strength)
+                .and_then(|rank| rank.hand_class())
+                .is_some_and(|hand_class| comparison.holds(hand_class.cmp(class))),
+            Self::HeroWins | Self::HeroTies => {
+                let villain = villain.expect("The query should have a villain").cards();
+                let ordering = strength(hero).cmp(&strength(&villain));
+                match self {
+                    Self::HeroWins => ordering.is_gt(),
+                    _ => ordering.is_eq(),
+                }
+            }
+        }
+    }
+}
+
+/// A question about the probability of an event in a Texas Hold'em hand
+///
+/// See the module documentation for the syntax that queries are parsed from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    event: Event,
+    hero: Vec<Card>,
+    board: Vec<Card>,
+    dead: Vec<Card>,
+    villain: Option<Range>,
+}
+
+impl Query {
+    /// Returns the event whose probability the query asks for
+    pub fn event(&self) -> Event {
+        self.event
+    }
+
+    /// Calculates the exact probability of the event, from 0 to 1
+    ///
+    /// Every combo of the villain's range is weighted by its weight, and every runout of the board
+    /// up to the street the event needs is visited once for each combo. This can take a long time
+    /// when many cards are left to come; see `estimate()` for a faster approximation.
+    pub fn probability(&self) -> f64 {
+        let (board, size) = self.known_board();
+        let (mut hits, mut total) = (0.0, 0.0);
+
+        for (villain, weight) in self.villain_combos() {
+            let mut out_of_play = CardSet::from(&self.hero[..]) | CardSet::from(&self.dead[..]);
+            if let Some(combo) = villain {
+                out_of_play = out_of_play | combo.card_set();
+            }
+
+            let (mut happened, mut runouts) = (0, 0);
+            Boards::new(board, size, out_of_play)
+                .expect("The query should already be checked")
+                .for_each(|board| {
+                    happened += self.event.happens(&self.hero, villain, board) as u64;
+                    runouts += 1;
+                });
+
+            hits += weight * happened as f64;
+            total += weight * runouts as f64;
+        }
+
+        hits / total
+    }
+
+    /// Estimates the probability of the event by sampling `iterations` villain combos and runouts
+    ///
+    /// The random number generator is seeded from entropy. See `estimate_with_rng()` for details
+    /// and for reproducible results.
+    pub fn estimate(&self, iterations: u64) -> Result<f64, QueryError> {
+        let mut rng = Xoshiro256PlusPlus::from_seed(CardDeck::seed_or_entropy(None)?);
+        Ok(self.estimate_with_rng(iterations, &mut rng))
+    }
+
+    /// Estimates the probability of the event by sampling `iterations` villain combos and runouts
+    /// with the given random number generator
+    ///
+    /// Each iteration picks a villain combo at random in proportion to its weight and deals the
+    /// rest of the board up to the street the event needs. Returns 0 if `iterations` is 0.
+    ///
+    /// ```rust
+    /// use playing_cards::poker::query::Query;
+    /// use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+    ///
+    /// let query: Query = "P(hero wins | hero = AsAh, villain in KK, QQ)".parse().unwrap();
+    ///
+    /// let mut rng = Xoshiro256PlusPlus::seed_from_u64(12);
+    /// let estimate = query.estimate_with_rng(20_000, &mut rng);
+    ///
+    /// assert!((estimate - 0.815).abs() < 0.015);
+    /// ```
+    pub fn estimate_with_rng<R: RngCore + ?Sized>(&self, iterations: u64, rng: &mut R) -> f64 {
+        let (board, size) = self.known_board();
+        let missing = size - board.len();
+        let mut stub: Vec<Card> = (CardSet::full()
+            - CardSet::from(&self.hero[..])
+            - CardSet::from(board)
+            - CardSet::from(&self.dead[..]))
+        .into_iter()
+        .collect();
+
+        let combos = self.villain_combos();
+        let total_weight: f64 = combos.iter().map(|(_, weight)| weight).sum();
+
+        let mut cards = [self.hero[0]; 5];
+        cards[..board.len()].copy_from_slice(board);
+
+        let mut hits = 0;
+        for _ in 0..iterations {
+            let mut pick = rng.gen::<f64>() * total_weight;
+            let villain = combos
+                .iter()
+                .find(|(_, weight)| {
+                    pick -= weight;
+                    pick < 0.0
+                })
+                .unwrap_or(&combos[combos.len() - 1])
+                .0;
+
+            // dealing 2 extra cards leaves enough once any of the villain's cards are skipped
+            let count = (missing + 2).min(stub.len());
+            deal(&mut stub, count, rng);
+            let runout = stub[..count]
+                .iter()
+                .filter(|card| villain.is_none_or(|combo| !combo.contains(**card)));
+            for (slot, card) in cards[board.len()..size].iter_mut().zip(runout) {
+                *slot = *card;
+            }
+
+            hits += self.event.happens(&self.hero, villain, &cards[..size]) as u64;
+        }
+
+        match iterations {
+            0 => 0.0,
+            iterations => hits as f64 / iterations as f64,
+        }
+    }
+
+    /// Returns the part of the board that is known up to the street the event needs, along with
+    /// the size of the board on that street
+    fn known_board(&self) -> (&[Card], usize) {
+        let size = self.event.board_size();
+        (&self.board[..self.board.len().min(size)], size)
+    }
+
+    /// Returns every combo of the villain's range that can be dealt along with its weight, or a
+    /// single entry without a combo if there is no villain
+    fn villain_combos(&self) -> Vec<(Option<Combo>, f64)> {
+        match &self.villain {
+            Some(range) => range
+                .without_dead(self.known_cards())
+                .iter()
+                .map(|(combo, weight)| (Some(combo), weight))
+                .collect(),
+            None => vec![(None, 1.0)],
+        }
+    }
+
+    /// Returns every card of the hero's hand, the board, and the dead cards
+    fn known_cards(&self) -> CardSet {
+        self.hero
+            .iter()
+            .chain(&self.board)
+            .chain(&self.dead)
+            .collect()
+    }
+}
+
+impl FromStr for Query {
+    type Err = QueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |part: &str| QueryError::InvalidSyntax(part.trim().to_string());
+        let inner = s
+            .trim()
+            .strip_prefix("P(")
+            .and_then(|rest| rest.strip_suffix(')'))
+            .ok_or_else(|| invalid(s))?;
+        let (event, conditions) = inner.split_once('|').unwrap_or((inner, ""));
+
+        let mut query = Self {
+            event: parse_event(event)?,
+            hero: Vec::new(),
+            board: Vec::new(),
+            dead: Vec::new(),
+            villain: None,
+        };
+
+        for condition in split_conditions(conditions) {
+            let words = condition.trim();
+            if let Some(cards) = strip_keyword(words, &["hero", "="]) {
+                query.hero = parse_cards(cards)?;
+            } else if let Some(cards) = strip_keyword(words, &["board", "starts"])
+                .or_else(|| strip_keyword(words, &["board", "="]))
+            {
+                query.board = parse_cards(cards)?;
+            } else if let Some(range) = strip_keyword(words, &["villain", "in"])
+                .or_else(|| strip_keyword(words, &["villain", "="]))
+            {
+                query.villain = Some(range.parse()?);
+            } else if let Some(cards) = strip_keyword(words, &["dead", "="]) {
+                query.dead = parse_cards(cards)?;
+            } else {
+                return Err(invalid(words));
+            }
+        }
+
+        query.check()?;
+        Ok(query)
+    }
+}
+
+impl Query {
+    /// Checks that the query can be evaluated
+    fn check(&self) -> Result<(), QueryError> {
+        if self.hero.len() != 2 {
+            return Err(QueryError::InvalidHandSize(self.hero.len()));
+        }
+        if self.board.len() > 5 {
+            return Err(QueryError::InvalidBoardSize(self.board.len()));
+        }
+
+        let mut known = CardSet::new();
+        for &card in self.hero.iter().chain(&self.board).chain(&self.dead) {
+            if card.is_joker() {
+                return Err(QueryError::UnsupportedCard(card));
+            }
+            if !known.insert(card) {
+                return Err(QueryError::DuplicateCard(card));
+            }
+        }
+
+        match (&self.event, &self.villain) {
+            (Event::HeroWins | Event::HeroTies, None) => Err(QueryError::MissingVillain),
+            (_, Some(range)) if range.without_dead(known).is_empty() => Err(QueryError::EmptyRange),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Parses the event of a query
+fn parse_event(event: &str) -> Result<Event, QueryError> {
+    let invalid = || QueryError::InvalidSyntax(event.trim().to_string());
+    let words: Vec<&str> = event.split_whitespace().collect();
+
+    match words.as_slice() {
+        ["hero", "wins"] => return Ok(Event::HeroWins),
+        ["hero", "ties"] => return Ok(Event::HeroTies),
+        _ => {}
+    }
+
+    let (street, rest) = match words.as_slice() {
+        ["flop", rest @ ..] => (Street::Flop, rest),
+        ["turn", rest @ ..] => (Street::Turn, rest),
+        ["river", rest @ ..] => (Street::River, rest),
+        rest => (Street::River, rest),
+    };
+    let [_, _, comparison, class @ ..] = rest else {
+        return Err(invalid());
+    };
+    if rest[..2] != ["hand", "class"] {
+        return Err(invalid());
+    }
+
+    let comparison = match *comparison {
+        "==" | "=" => Comparison::Equal,
+        "!=" => Comparison::NotEqual,
+        "<" => Comparison::Less,
+        "<=" => Comparison::LessOrEqual,
+        ">" => Comparison::Greater,
+        ">=" => Comparison::GreaterOrEqual,
+        _ => return Err(invalid()),
+    };
+    let name: String = class.concat().to_lowercase();
+    let class = HandClass::iter()
+        .find(|class| format!("{class:?}").to_lowercase() == name)
+        .ok_or_else(invalid)?;
+
+    Ok(Event::HandClass {
+        street,
+        comparison,
+        class,
+    })
+}
+
+/// Splits the conditions of a query on commas, keeping the commas within a villain's range
+fn split_conditions(conditions: &str) -> Vec<String> {
+    let mut split: Vec<String> = Vec::new();
+    for part in conditions.split(',') {
+        let starts_condition = ["hero", "board", "villain", "dead"]
+            .iter()
+            .any(|keyword| part.trim_start().starts_with(keyword));
+        match split.last_mut() {
+            Some(last) if !starts_condition && last.trim_start().starts_with("villain") => {
+                last.push(',');
+                last.push_str(part);
+            }
+            _ => split.push(part.to_string()),
+        }
+    }
+    split.retain(|condition| !condition.trim().is_empty());
+    split
+}
+
+/// Strips the keywords from the start of a condition, allowing any whitespace between them
+fn strip_keyword<'a>(condition: &'a str, keywords: &[&str]) -> Option<&'a str> {
+    keywords.iter().try_fold(condition, |rest, keyword| {
+        rest.trim_start().strip_prefix(keyword)
+    })
+}
+
+/// Parses a list of cards such as `Qh 7h 2s` or `Qh7h2s`
+fn parse_cards(cards: &str) -> Result<Vec<Card>, QueryError> {
+    let invalid = || QueryError::InvalidCards(cards.trim().to_string());
+    let chars: Vec<char> = cards.chars().filter(|c| !c.is_whitespace()).collect();
+    if chars.len() % 2 != 0 {
+        return Err(invalid());
+    }
+
+    chars
+        .chunks_exact(2)
+        .map(|card| Card::from_str(&String::from_iter(card)).map_err(|_| invalid()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(s: &str) -> Query {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn exact_probabilities() {
+        // 7 of the 47 turns improve the set to a full house or quads
+        let trips = query("P(turn hand class == Three of a Kind | hero = 7c7d, board = Ks 7h 2c)");
+        assert_eq!(trips.probability(), 40.0 / 47.0);
+        let set_or_better =
+            query("P(turn hand class >= ThreeOfAKind | hero = 7c7d, board = Ks 7h 2c)");
+        assert_eq!(set_or_better.probability(), 1.0);
+
+        // the flop is decided by the board that is already known
+        let flop = query("P(flop hand class == Pair | hero = AsKd, board starts Ah 7h 2s 3c)");
+        assert_eq!(flop.probability(), 1.0);
+
+        // the villain's range removes cards from the deck
+        let flush = query("P(hand class == Flush | hero = AhKh, board = Qh7h2s3c, dead = 9h)");
+        assert_eq!(flush.probability(), 8.0 / 45.0);
+        let blocked = query(
+            "P(hand class == Flush | hero = AhKh, board = Qh7h2s3c, dead = 9h, villain in JhTh)",
+        );
+        assert_eq!(blocked.probability(), 6.0 / 43.0);
+
+        let showdown =
+            query("P(hero wins | hero = AsAh, villain in KK, QhQd, board = 2c 3c 4d 8s)");
+        // every villain combo only wins with the 2 rivers that make a set
+        assert_eq!(showdown.probability(), 42.0 / 44.0);
+    }
+
+    #[test]
+    fn estimates() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(13);
+        let flush = query("P(river hand class == Flush | hero = AhKh, board starts Qh 7h 2s)");
+        let estimate = flush.estimate_with_rng(20_000, &mut rng);
+        assert!((estimate - flush.probability()).abs() < 0.01);
+
+        let showdown = query("P(hero ties | hero = AsKs, villain = AK)");
+        assert!(showdown.estimate_with_rng(5_000, &mut rng) > 0.3);
+        assert_eq!(showdown.estimate_with_rng(0, &mut rng), 0.0);
+    }
+
+    #[test]
+    fn invalid_queries() {
+        let error = |s: &str| s.parse::<Query>().unwrap_err();
+
+        assert!(matches!(error("hero wins"), QueryError::InvalidSyntax(_)));
+        assert!(matches!(
+            error("P(hand class ~ Flush | hero = AhKh)"),
+            QueryError::InvalidSyntax(_)
+        ));
+        assert!(matches!(
+            error("P(hand class == Flushes | hero = AhKh)"),
+            QueryError::InvalidSyntax(_)
+        ));
+        assert!(matches!(
+            error("P(hand class == Flush | hero = AhKh, villian in AA)"),
+            QueryError::InvalidSyntax(_)
+        ));
+        assert!(matches!(
+            error("P(hand class == Flush | hero = AhK)"),
+            QueryError::InvalidCards(_)
+        ));
+        assert!(matches!(
+            error("P(hand class == Flush | board = AhKh)"),
+            QueryError::InvalidHandSize(0)
+        ));
+        assert!(matches!(
+            error("P(hand class == Flush | hero = AhKh, board = Ah)"),
+            QueryError::DuplicateCard(_)
+        ));
+        assert!(matches!(
+            error("P(hero wins | hero = AhKh)"),
+            QueryError::MissingVillain
+        ));
+        assert!(matches!(
+            error("P(hero wins | hero = AhKh, villain in AhKh)"),
+            QueryError::EmptyRange
+        ));
+        assert!(matches!(
+            error("P(hero wins | hero = AhKh, villain in AKx)"),
+            QueryError::InvalidRange(_)
+        ));
+    }
+}
@@ -0,0 +1,1178 @@
+//! Ranges of two-card starting hands
+//!
+//! A `Range` assigns a weight to each of the 1,326 possible two-card combos, where a weight of 0
+//! means the combo is not in the range. Ranges can be parsed from a comma-separated list of
+//! hands, where each hand is either a pair (`QQ`), suited cards (`AKs`), offsuit cards (`AKo`),
+//! both (`AK`), or a specific combo (`AhKh`). Each of these hands other than a specific combo is
+//! one of the 169 `StartingHand`s. A `+` after a hand adds every better hand of the same kind,
+//! so `TT+` is every pair from tens to aces and `ATs+` is every suited ace from ace-ten to
+//! ace-king, while a `-` between two hands of the same kind spans every hand between them, such
+//! as `99-66` or `K9o-K6o`.
+//!
+//! A `PreflopMatrix` holds a value for each starting hand in the usual 13x13 grid, and converts to
+//! and from a range of weights.
+//!
+//! Weights can be given in the formats used by common solvers and equity tools, which are
+//! described by `RangeFormat`: either after a colon (`AKs:0.5`), or as a percentage in brackets
+//! around one or more hands (`[50]AKs,AQs[/50]`). `Range::to_format()` writes a range back out in
+//! any of these formats.
+//!
+//! ```rust
+//! use playing_cards::{core::CardSet, poker::range::Range};
+//!
+//! let range: Range = "QQ, AKs, AhKd".parse().unwrap();
+//! assert_eq!(range.len(), 11);
+//!
+//! // holding the ace of hearts blocks some of the combos
+//! let dead = CardSet::from(&["Ah".parse().unwrap()][..]);
+//! assert_eq!(range.without_dead(dead).len(), 9);
+//! ```
+
+use std::ops::{BitAnd, BitOr, Index, IndexMut, Sub};
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use thiserror::Error;
+
+use num_traits::FromPrimitive;
+
+use crate::core::{Card, CardSet, Suit, Value};
+
+/// A pair of two distinct cards, such as a player's hole cards in Texas Hold'em
+///
+/// The cards are stored with the higher card first, so two combos with the same cards are always
+/// equal no matter the order they were given in.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "[Card; 2]"))]
+pub struct Combo([Card; 2]);
+
+impl Combo {
+    /// The number of possible combos in a standard 52-card deck
+    pub const COUNT: usize = 1326;
+
+    /// Creates a combo from two cards, returning `None` if the cards are the same or either card
+    /// is a joker
+    pub fn new(first: Card, second: Card) -> Option<Self> {
+        let (a, b) = (u8::from(first), u8::from(second));
+        if a == b || a >= 52 || b >= 52 {
+            return None;
+        }
+
+        Some(if a > b {
+            Self([first, second])
+        } else {
+            Self([second, first])
+        })
+    }
+
+    /// Gets the combo at an index from 0 to 1325, as returned by `index()`
+    pub fn from_index(index: usize) -> Option<Self> {
+        if index >= Self::COUNT {
+            return None;
+        }
+
+        // the high card `b` is the largest value where b * (b - 1) / 2 <= index
+        let mut b = 1;
+        while (b + 1) * b / 2 <= index {
+            b += 1;
+        }
+        let a = index - b * (b - 1) / 2;
+
+        Some(Self([
+            Card::try_from(b as u8).ok()?,
+            Card::try_from(a as u8).ok()?,
+        ]))
+    }
+
+    /// Returns the index of the combo, from 0 to 1325
+    pub fn index(&self) -> usize {
+        let (b, a) = (u8::from(self.0[0]) as usize, u8::from(self.0[1]) as usize);
+        b * (b - 1) / 2 + a
+    }
+
+    /// Returns the cards of the combo, with the higher card first
+    pub fn cards(&self) -> [Card; 2] {
+        self.0
+    }
+
+    /// Returns the cards of the combo as a set
+    pub fn card_set(&self) -> CardSet {
+        CardSet::from(&self.0[..])
+    }
+
+    /// Returns whether the combo contains the card
+    pub fn contains(&self, card: Card) -> bool {
+        self.0.contains(&card)
+    }
+
+    /// Returns whether both cards have the same value
+    pub fn is_pair(&self) -> bool {
+        self.0[0].value == self.0[1].value
+    }
+
+    /// Returns whether both cards have the same suit
+    pub fn is_suited(&self) -> bool {
+        self.0[0].suit == self.0[1].suit
+    }
+
+    /// Returns an iterator over every possible combo
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0..Self::COUNT).filter_map(Self::from_index)
+    }
+}
+
+/// Creates a combo from two cards like `Combo::new()`, returning an `InvalidHand` error if the
+/// cards are the same or either card is a joker
+impl TryFrom<[Card; 2]> for Combo {
+    type Error = ParseRangeError;
+    fn try_from([first, second]: [Card; 2]) -> Result<Self, Self::Error> {
+        Self::new(first, second)
+            .ok_or_else(|| ParseRangeError::InvalidHand(format!("{}{}", first, second)))
+    }
+}
+
+impl Combo {
+    /// Returns the combo in the ASCII form used by other tools, such as `AhKd`
+    pub fn to_ascii_string(&self) -> String {
+        self.0
+            .iter()
+            .flat_map(|card| [card.value.get_char(), card.suit.get_char()])
+            .collect()
+    }
+}
+
+impl std::fmt::Display for Combo {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}{}", self.0[0], self.0[1])
+    }
+}
+
+/// An error that is returned when a string could not be parsed into a `Range`
+#[non_exhaustive]
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum ParseRangeError {
+    /// A hand within the range was not valid
+    #[error(r#"Hand "{0}" is not a valid hand"#)]
+    InvalidHand(String),
+    /// A weight within the range was not valid, or a bracketed weight was never closed
+    #[error(r#"Weight "{0}" is not a valid weight"#)]
+    InvalidWeight(String),
+}
+
+/// A text format for ranges used by external poker tools
+///
+/// Every format lists hands separated by commas, and all of them can be parsed by `Range`'s
+/// `FromStr` implementation. They only differ in how weights are written.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RangeFormat {
+    /// PioSolver's format, where a weight from 0 to 1 follows a hand after a colon, such as
+    /// `AA,AKs:0.5`
+    PioSolver,
+    /// GTO+'s format, where a percentage in brackets opens and closes a group of hands, such as
+    /// `AA,[50]AKs,AQs[/50]`
+    GtoPlus,
+    /// Flopzilla's format, which writes weights in brackets the same way as GTO+
+    Flopzilla,
+}
+
+/// A weighted set of two-card combos
+///
+/// See the module documentation for the format used to parse ranges.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RangeFields"))]
+pub struct Range {
+    weights: Vec<f64>,
+}
+
+/// The serialized fields of a `Range`, which are checked before a range is deserialized
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RangeFields {
+    weights: Vec<f64>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RangeFields> for Range {
+    type Error = String;
+    fn try_from(fields: RangeFields) -> Result<Self, Self::Error> {
+        if fields.weights.len() != Combo::COUNT {
+            return Err(format!(
+                "expected {} weights, found {}",
+                Combo::COUNT,
+                fields.weights.len()
+            ));
+        }
+
+        Ok(Self {
+            weights: fields.weights,
+        })
+    }
+}
+
+impl Default for Range {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Range {
+    /// Creates an empty range
+    pub fn new() -> Self {
+        Self {
+            weights: vec![0.0; Combo::COUNT],
+        }
+    }
+
+    /// Creates a range containing every combo
+    pub fn full() -> Self {
+        Self {
+            weights: vec![1.0; Combo::COUNT],
+        }
+    }
+
+    /// Returns the weight of a combo, which is 0 if the combo is not in the range
+    pub fn weight(&self, combo: Combo) -> f64 {
+        self.weights[combo.index()]
+    }
+
+    /// Sets the weight of a combo, where a weight of 0 removes the combo from the range
+    pub fn set_weight(&mut self, combo: Combo, weight: f64) {
+        self.weights[combo.index()] = weight;
+    }
+
+    /// Adds a combo to the range with a weight of 1
+    pub fn insert(&mut self, combo: Combo) {
+        self.set_weight(combo, 1.0);
+    }
+
+    /// Removes a combo from the range
+    pub fn remove(&mut self, combo: Combo) {
+        self.set_weight(combo, 0.0);
+    }
+
+    /// Returns whether a combo is in the range
+    pub fn contains(&self, combo: Combo) -> bool {
+        self.weight(combo) > 0.0
+    }
+
+    /// Returns an iterator over the combos in the range and their weights
+    pub fn iter(&self) -> impl Iterator<Item = (Combo, f64)> + '_ {
+        self.weights
+            .iter()
+            .enumerate()
+            .filter(|(_, weight)| **weight > 0.0)
+            .filter_map(|(index, weight)| Some((Combo::from_index(index)?, *weight)))
+    }
+
+    /// Returns the number of combos in the range, ignoring their weights
+    pub fn len(&self) -> usize {
+        self.weights.iter().filter(|weight| **weight > 0.0).count()
+    }
+
+    /// Returns whether the range has no combos
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the sum of the weights of every combo in the range
+    pub fn total_weight(&self) -> f64 {
+        self.weights.iter().sum()
+    }
+
+    /// Returns the combos in either range, with the higher of their two weights
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, f64::max)
+    }
+
+    /// Returns the combos in both ranges, with the lower of their two weights
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, f64::min)
+    }
+
+    /// Returns the combos of this range that are not in the other range
+    pub fn difference(&self, other: &Self) -> Self {
+        self.combine(
+            other,
+            |weight, other| if other > 0.0 { 0.0 } else { weight },
+        )
+    }
+
+    /// Keeps only the combos for which the predicate returns true, given each combo and its weight
+    ///
+    /// ```rust
+    /// use playing_cards::poker::range::Range;
+    ///
+    /// let mut range: Range = "TT+, AQs+, AKo".parse().unwrap();
+    /// range.retain(|combo, _| !combo.is_pair());
+    ///
+    /// assert_eq!(range, "AQs, AK".parse().unwrap());
+    /// ```
+    pub fn retain(&mut self, mut f: impl FnMut(Combo, f64) -> bool) {
+        for (combo, weight) in self.clone().iter() {
+            if !f(combo, weight) {
+                self.remove(combo);
+            }
+        }
+    }
+
+    /// Combines the weights of each combo in two ranges
+    fn combine(&self, other: &Self, f: impl Fn(f64, f64) -> f64) -> Self {
+        Self {
+            weights: self
+                .weights
+                .iter()
+                .zip(&other.weights)
+                .map(|(weight, other)| f(*weight, *other))
+                .collect(),
+        }
+    }
+
+    /// Writes the range in the format of an external tool
+    ///
+    /// Starting hands whose combos all have the same weight are written as one hand, such as
+    /// `AKs`, while the combos of any other starting hand are written one by one, such as `AhKh`.
+    /// Hands are written in the order of the starting hand grid, and hands with a weight of 1 are
+    /// written without one.
+    ///
+    /// ```rust
+    /// use playing_cards::poker::range::{Range, RangeFormat};
+    ///
+    /// let range: Range = "AA, [50]AKs, KQs[/50], AhKd:0.25".parse().unwrap();
+    ///
+    /// assert_eq!(range.to_format(RangeFormat::PioSolver), "AA,AKs:0.5,AhKd:0.25,KQs:0.5");
+    /// assert_eq!(
+    ///     range.to_format(RangeFormat::GtoPlus),
+    ///     "AA,[50]AKs[/50],[25]AhKd[/25],[50]KQs[/50]"
+    /// );
+    /// assert_eq!(range.to_format(RangeFormat::PioSolver).parse::<Range>().unwrap(), range);
+    /// ```
+    pub fn to_format(&self, format: RangeFormat) -> String {
+        let mut hands: Vec<(String, f64)> = Vec::new();
+        for hand in StartingHand::all() {
+            let combos = hand.combos();
+            let weight = self.weight(combos[0]);
+            if combos.iter().all(|combo| self.weight(*combo) == weight) {
+                if weight > 0.0 {
+                    hands.push((hand.to_string(), weight));
+                }
+            } else {
+                hands.extend(
+                    combos
+                        .iter()
+                        .filter(|combo| self.contains(**combo))
+                        .map(|combo| (combo.to_ascii_string(), self.weight(*combo))),
+                );
+            }
+        }
+
+        match format {
+            RangeFormat::PioSolver => hands
+                .iter()
+                .map(|(hand, weight)| match weight {
+                    w if *w == 1.0 => hand.clone(),
+                    w => format!("{hand}:{}", format_weight(*w)),
+                })
+                .collect::<Vec<String>>()
+                .join(","),
+            RangeFormat::GtoPlus | RangeFormat::Flopzilla => {
+                // consecutive hands with the same weight share a pair of brackets
+                let mut groups: Vec<(Vec<&str>, f64)> = Vec::new();
+                for (hand, weight) in &hands {
+                    match groups.last_mut() {
+                        Some((group, w)) if w == weight => group.push(hand),
+                        _ => groups.push((vec![hand], *weight)),
+                    }
+                }
+                groups
+                    .iter()
+                    .map(|(group, weight)| match weight {
+                        w if *w == 1.0 => group.join(","),
+                        w => {
+                            let percentage = format_weight(100.0 * w);
+                            format!("[{percentage}]{}[/{percentage}]", group.join(","))
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join(",")
+            }
+        }
+    }
+
+    /// Returns a copy of the range without any combo that contains a dead card
+    pub fn without_dead(&self, dead: CardSet) -> Self {
+        let mut range = self.clone();
+        for (combo, _) in self.iter() {
+            if combo.cards().iter().any(|card| dead.contains(*card)) {
+                range.remove(combo);
+            }
+        }
+        range
+    }
+}
+
+impl FromIterator<Combo> for Range {
+    fn from_iter<T: IntoIterator<Item = Combo>>(iter: T) -> Self {
+        let mut range = Self::new();
+        for combo in iter {
+            range.insert(combo);
+        }
+        range
+    }
+}
+
+impl BitOr for &Range {
+    type Output = Range;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for &Range {
+    type Output = Range;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl Sub for &Range {
+    type Output = Range;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl FromStr for Range {
+    type Err = ParseRangeError;
+
+    /// Parses a comma-separated list of hands, with weights in any `RangeFormat`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut range = Self::new();
+        let mut bracket: Option<(&str, f64)> = None;
+
+        for entry in s
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+        {
+            let mut hand = entry;
+            if let Some(rest) = hand.strip_prefix('[') {
+                let (weight, rest) = rest
+                    .split_once(']')
+                    .ok_or_else(|| ParseRangeError::InvalidWeight(entry.to_string()))?;
+                if bracket.is_some() {
+                    return Err(ParseRangeError::InvalidWeight(entry.to_string()));
+                }
+                bracket = Some((weight, parse_weight(weight)? / 100.0));
+                hand = rest;
+            }
+
+            let mut closed = false;
+            if let Some((open, rest)) = hand.split_once("[/") {
+                match (bracket, rest.strip_suffix(']')) {
+                    (Some((weight, _)), Some(close)) if close == weight => closed = true,
+                    _ => return Err(ParseRangeError::InvalidWeight(entry.to_string())),
+                }
+                hand = open;
+            }
+
+            let (hand, weight) = match hand.split_once(':') {
+                Some((hand, weight)) => (hand, parse_weight(weight)?),
+                None => (hand, bracket.map_or(1.0, |(_, weight)| weight)),
+            };
+            for combo in parse_hand(hand.trim())? {
+                range.set_weight(combo, weight);
+            }
+
+            if closed {
+                bracket = None;
+            }
+        }
+
+        match bracket {
+            Some((weight, _)) => Err(ParseRangeError::InvalidWeight(format!("[{weight}]"))),
+            None => Ok(range),
+        }
+    }
+}
+
+/// Parses a non-negative weight
+fn parse_weight(weight: &str) -> Result<f64, ParseRangeError> {
+    match weight.trim().parse::<f64>() {
+        Ok(parsed) if parsed.is_finite() && parsed >= 0.0 => Ok(parsed),
+        _ => Err(ParseRangeError::InvalidWeight(weight.to_string())),
+    }
+}
+
+/// Writes a weight without trailing zeros or floating point noise
+fn format_weight(weight: f64) -> String {
+    let rounded = (weight * 1e6).round() / 1e6;
+    format!("{rounded}")
+}
+
+/// Parses a single hand of a range into its combos
+fn parse_hand(hand: &str) -> Result<Vec<Combo>, ParseRangeError> {
+    let invalid = || ParseRangeError::InvalidHand(hand.to_string());
+    let chars: Vec<char> = hand.chars().collect();
+
+    if let Some(base) = hand.strip_suffix('+') {
+        let (high, low, suited) = parse_values(base).map_err(|_| invalid())?;
+        return Ok(match high == low {
+            true => values_between(low, Value::Ace)
+                .flat_map(|value| expand(value, value, suited))
+                .collect(),
+            false => values_between(low, high.prev().unwrap_or(low))
+                .flat_map(|value| expand(high, value, suited))
+                .collect(),
+        });
+    }
+    if let Some((first, last)) = hand.split_once('-') {
+        let (high1, low1, suited1) = parse_values(first).map_err(|_| invalid())?;
+        let (high2, low2, suited2) = parse_values(last).map_err(|_| invalid())?;
+        let lows = values_between(low1.min(low2), low1.max(low2));
+
+        return match (high1 == low1, high2 == low2) {
+            (true, true) => Ok(lows
+                .flat_map(|value| expand(value, value, suited1))
+                .collect()),
+            (false, false) if high1 == high2 && suited1 == suited2 && low1.max(low2) < high1 => {
+                Ok(lows
+                    .flat_map(|value| expand(high1, value, suited1))
+                    .collect())
+            }
+            _ => Err(invalid()),
+        };
+    }
+
+    match chars.as_slice() {
+        [_, _, _, _] => {
+            let first = Card::from_str(&String::from_iter(&chars[..2])).map_err(|_| invalid())?;
+            let second = Card::from_str(&String::from_iter(&chars[2..])).map_err(|_| invalid())?;
+            Combo::new(first, second)
+                .map(|combo| vec![combo])
+                .ok_or_else(invalid)
+        }
+        _ => {
+            let (high, low, suited) = parse_values(hand)?;
+            Ok(expand(high, low, suited))
+        }
+    }
+}
+
+/// Parses a hand such as `AKs`, `AK`, or `QQ` into its values and whether it is suited, where
+/// `None` stands for both suited and offsuit
+fn parse_values(hand: &str) -> Result<(Value, Value, Option<bool>), ParseRangeError> {
+    match hand.chars().collect::<Vec<char>>().as_slice() {
+        [high, low] if !high.eq_ignore_ascii_case(low) => {
+            let suited = StartingHand::from_str(&format!("{high}{low}s"))?;
+            Ok((suited.high, suited.low, None))
+        }
+        _ => {
+            let hand = StartingHand::from_str(hand)?;
+            Ok((hand.high, hand.low, Some(hand.suited)))
+        }
+    }
+}
+
+/// Returns every combo of the starting hands with the given values
+fn expand(high: Value, low: Value, suited: Option<bool>) -> Vec<Combo> {
+    [true, false]
+        .into_iter()
+        .filter(|s| suited.is_none_or(|suited| suited == *s))
+        .filter_map(|suited| StartingHand::new(high, low, suited))
+        .flat_map(|hand| hand.combos())
+        .collect()
+}
+
+/// Returns every value from `from` up to `to`
+fn values_between(from: Value, to: Value) -> impl Iterator<Item = Value> {
+    (from as usize..=to as usize).filter_map(Value::from_usize)
+}
+
+/// One of the 169 starting hands in Texas Hold'em once suits are ignored, such as `AKs`, `QQ`, or
+/// `72o`
+///
+/// Starting hands are indexed in the layout of the usual 13x13 grid, where the rows and columns
+/// go from the ace down to the 2. Pairs sit on the diagonal, suited hands above it, and offsuit
+/// hands below it.
+///
+/// ```rust
+/// use playing_cards::poker::range::StartingHand;
+///
+/// let hand: StartingHand = "AKs".parse().unwrap();
+///
+/// assert_eq!(hand.combos().len(), 4);
+/// assert_eq!(hand.index(), 1);
+/// assert_eq!(StartingHand::from_index(13).unwrap().to_string(), "AKo");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "StartingHandFields"))]
+pub struct StartingHand {
+    high: Value,
+    low: Value,
+    suited: bool,
+}
+
+/// The serialized fields of a `StartingHand`, which are checked before a starting hand is
+/// deserialized
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct StartingHandFields {
+    high: Value,
+    low: Value,
+    suited: bool,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<StartingHandFields> for StartingHand {
+    type Error = String;
+    fn try_from(fields: StartingHandFields) -> Result<Self, Self::Error> {
+        match Self::new(fields.high, fields.low, fields.suited) {
+            Some(hand) if hand.high == fields.high => Ok(hand),
+            _ => Err(format!(
+                "{}{} is not a valid {} starting hand",
+                fields.high.get_char(),
+                fields.low.get_char(),
+                if fields.suited { "suited" } else { "offsuit" }
+            )),
+        }
+    }
+}
+
+impl StartingHand {
+    /// The number of starting hands
+    pub const COUNT: usize = 169;
+
+    /// Creates a starting hand from two values
+    ///
+    /// Returns `None` if either value is a joker, or if a pair is suited.
+    pub fn new(first: Value, second: Value, suited: bool) -> Option<Self> {
+        if first == Value::Joker || second == Value::Joker || (first == second && suited) {
+            return None;
+        }
+
+        Some(Self {
+            high: first.max(second),
+            low: first.min(second),
+            suited,
+        })
+    }
+
+    /// Returns the starting hand that a combo belongs to
+    pub fn of(combo: Combo) -> Self {
+        let [high, low] = combo.cards();
+        Self {
+            high: high.value.max(low.value),
+            low: high.value.min(low.value),
+            suited: combo.is_suited(),
+        }
+    }
+
+    /// Gets the starting hand at an index from 0 to 168, as returned by `index()`
+    pub fn from_index(index: usize) -> Option<Self> {
+        if index >= Self::COUNT {
+            return None;
+        }
+
+        let (row, col) = (index / 13, index % 13);
+        let value = |i: usize| Value::from_usize(12 - i);
+        Self::new(value(row)?, value(col)?, row < col)
+    }
+
+    /// Returns the index of the starting hand within the 13x13 grid, from 0 to 168
+    pub fn index(&self) -> usize {
+        let (high, low) = (12 - self.high as usize, 12 - self.low as usize);
+        if self.suited {
+            high * 13 + low
+        } else {
+            low * 13 + high
+        }
+    }
+
+    /// Returns an iterator over every starting hand in the order of their indices
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0..Self::COUNT).filter_map(Self::from_index)
+    }
+
+    /// Returns the higher value of the hand
+    pub fn high(&self) -> Value {
+        self.high
+    }
+
+    /// Returns the lower value of the hand
+    pub fn low(&self) -> Value {
+        self.low
+    }
+
+    /// Returns whether the hand is suited
+    pub fn is_suited(&self) -> bool {
+        self.suited
+    }
+
+    /// Returns whether the hand is a pair
+    pub fn is_pair(&self) -> bool {
+        self.high == self.low
+    }
+
+    /// Returns every combo of the hand
+    ///
+    /// Pairs have 6 combos, suited hands have 4, and offsuit hands have 12.
+    pub fn combos(&self) -> Vec<Combo> {
+        let mut combos = Vec::new();
+        for suit1 in Suit::iter() {
+            for suit2 in Suit::iter() {
+                if (suit1 == suit2) != self.suited {
+                    continue;
+                }
+                if let Some(combo) =
+                    Combo::new(Card::new(self.high, suit1), Card::new(self.low, suit2))
+                {
+                    if !combos.contains(&combo) {
+                        combos.push(combo);
+                    }
+                }
+            }
+        }
+        combos
+    }
+}
+
+impl std::fmt::Display for StartingHand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}{}", self.high.get_char(), self.low.get_char())?;
+        match (self.is_pair(), self.suited) {
+            (true, _) => Ok(()),
+            (false, true) => write!(f, "s"),
+            (false, false) => write!(f, "o"),
+        }
+    }
+}
+
+impl FromStr for StartingHand {
+    type Err = ParseRangeError;
+
+    /// Parses a starting hand such as `AKs`, `QQ`, or `72o`
+    ///
+    /// Since pairs are never suited, they may also be given as offsuit, such as `QQo`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseRangeError::InvalidHand(s.to_string());
+        let value = |c: char| Value::from_char(c).ok_or_else(invalid);
+
+        let chars: Vec<char> = s.chars().collect();
+        let (high, low, suited) = match chars.as_slice() {
+            [high, low] if high.eq_ignore_ascii_case(low) => (value(*high)?, value(*low)?, false),
+            [high, low, suffix] => (
+                value(*high)?,
+                value(*low)?,
+                match suffix.to_ascii_lowercase() {
+                    's' => true,
+                    'o' => false,
+                    _ => return Err(invalid()),
+                },
+            ),
+            _ => return Err(invalid()),
+        };
+
+        Self::new(high, low, suited).ok_or_else(invalid)
+    }
+}
+
+/// A value for each of the 169 starting hands, laid out like the usual 13x13 grid
+///
+/// Rows and columns go from the ace down to the 2. Pairs sit on the diagonal, suited hands above
+/// it, and offsuit hands below it, so the cell in row `i` and column `j` holds the starting hand
+/// at index `13 * i + j`.
+///
+/// ```rust
+/// use playing_cards::poker::range::{PreflopMatrix, Range, StartingHand};
+///
+/// let range: Range = "QQ+, AKs".parse().unwrap();
+/// let mut matrix = PreflopMatrix::from(&range);
+///
+/// let aces: StartingHand = "AA".parse().unwrap();
+/// assert_eq!(matrix[aces], 1.0);
+/// assert_eq!(matrix.get(0, 1), Some(&1.0));
+/// assert_eq!(matrix.get(1, 0), Some(&0.0));
+///
+/// matrix[aces] = 0.5;
+/// assert_eq!(Range::from(&matrix).total_weight(), 3.0 + 6.0 + 6.0 + 4.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "PreflopMatrixFields<T>"))]
+pub struct PreflopMatrix<T> {
+    cells: Vec<T>,
+}
+
+/// The serialized fields of a `PreflopMatrix`, which are checked before a matrix is deserialized
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct PreflopMatrixFields<T> {
+    cells: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<PreflopMatrixFields<T>> for PreflopMatrix<T> {
+    type Error = String;
+    fn try_from(fields: PreflopMatrixFields<T>) -> Result<Self, Self::Error> {
+        if fields.cells.len() != StartingHand::COUNT {
+            return Err(format!(
+                "expected {} cells, found {}",
+                StartingHand::COUNT,
+                fields.cells.len()
+            ));
+        }
+
+        Ok(Self {
+            cells: fields.cells,
+        })
+    }
+}
+
+impl<T> PreflopMatrix<T> {
+    /// Creates a matrix by calling a function for each starting hand in the order of their indices
+    pub fn from_fn(f: impl FnMut(StartingHand) -> T) -> Self {
+        Self {
+            cells: StartingHand::all().map(f).collect(),
+        }
+    }
+
+    /// Returns the value in a row and column of the grid, from 0 to 12 each, or `None` if either
+    /// is out of bounds
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        (row < 13 && column < 13).then(|| &self.cells[13 * row + column])
+    }
+
+    /// Returns a mutable reference to the value in a row and column of the grid, from 0 to 12
+    /// each, or `None` if either is out of bounds
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        (row < 13 && column < 13).then(|| &mut self.cells[13 * row + column])
+    }
+
+    /// Returns an iterator over every starting hand and its value in the order of their indices
+    pub fn iter(&self) -> impl Iterator<Item = (StartingHand, &T)> {
+        StartingHand::all().zip(&self.cells)
+    }
+
+    /// Returns an iterator over every starting hand and a mutable reference to its value in the
+    /// order of their indices
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (StartingHand, &mut T)> {
+        StartingHand::all().zip(&mut self.cells)
+    }
+
+    /// Returns an iterator over the rows of the grid, from the ace row down to the 2 row
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(13)
+    }
+
+    /// Creates a new matrix by calling a function on every value
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> PreflopMatrix<U> {
+        PreflopMatrix {
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> PreflopMatrix<T> {
+    /// Creates a matrix with the same value for every starting hand
+    pub fn filled(value: T) -> Self {
+        Self {
+            cells: vec![value; StartingHand::COUNT],
+        }
+    }
+}
+
+impl<T: Default> Default for PreflopMatrix<T> {
+    fn default() -> Self {
+        Self::from_fn(|_| T::default())
+    }
+}
+
+impl<T> Index<StartingHand> for PreflopMatrix<T> {
+    type Output = T;
+    fn index(&self, hand: StartingHand) -> &Self::Output {
+        &self.cells[hand.index()]
+    }
+}
+
+impl<T> IndexMut<StartingHand> for PreflopMatrix<T> {
+    fn index_mut(&mut self, hand: StartingHand) -> &mut Self::Output {
+        &mut self.cells[hand.index()]
+    }
+}
+
+/// Converts a range into the average weight of each starting hand's combos
+impl From<&Range> for PreflopMatrix<f64> {
+    fn from(range: &Range) -> Self {
+        Self::from_fn(|hand| {
+            let combos = hand.combos();
+            combos.iter().map(|combo| range.weight(*combo)).sum::<f64>() / combos.len() as f64
+        })
+    }
+}
+
+/// Converts a matrix of weights into a range, where every combo of a starting hand gets the
+/// hand's weight
+impl From<&PreflopMatrix<f64>> for Range {
+    fn from(matrix: &PreflopMatrix<f64>) -> Self {
+        let mut range = Range::new();
+        for (hand, weight) in matrix.iter() {
+            for combo in hand.combos() {
+                range.set_weight(combo, *weight);
+            }
+        }
+        range
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combo_indices() {
+        let combos: Vec<Combo> = Combo::all().collect();
+        assert_eq!(combos.len(), Combo::COUNT);
+        for (i, combo) in combos.iter().enumerate() {
+            assert_eq!(combo.index(), i);
+        }
+
+        let ace = Card::from_str("As").unwrap();
+        let king = Card::from_str("Kh").unwrap();
+        assert_eq!(Combo::new(ace, king), Combo::new(king, ace));
+        assert_eq!(Combo::new(king, ace).unwrap().to_string(), "A♠K♥");
+        assert_eq!(Combo::new(ace, ace), None);
+        assert_eq!(Combo::from_index(Combo::COUNT), None);
+    }
+
+    #[test]
+    fn parsing_ranges() {
+        let count = |s: &str| s.parse::<Range>().unwrap().len();
+
+        assert_eq!(count("QQ"), 6);
+        assert_eq!(count("AKs"), 4);
+        assert_eq!(count("AKo"), 12);
+        assert_eq!(count("AK, AKs"), 16);
+        assert_eq!(count("AhKh, KhAh"), 1);
+        assert_eq!(count("QQo"), 6);
+        assert_eq!(count(""), 0);
+        assert_eq!(Range::full().len(), Combo::COUNT);
+
+        assert_eq!(count("A♠K♥"), 1);
+        for invalid in ["QQs", "AKx", "A", "AhAh", "XX", "AKso"] {
+            assert_eq!(
+                invalid.parse::<Range>(),
+                Err(ParseRangeError::InvalidHand(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn shorthand() {
+        let range = |s: &str| s.parse::<Range>().unwrap();
+
+        assert_eq!(range("TT+"), range("TT, JJ, QQ, KK, AA"));
+        assert_eq!(range("ATs+"), range("ATs, AJs, AQs, AKs"));
+        assert_eq!(range("K9+"), range("K9, KT, KJ, KQ"));
+        assert_eq!(range("22+"), range("AA-22"));
+        assert_eq!(range("99-66"), range("66, 77, 88, 99"));
+        assert_eq!(range("K6o-K9o"), range("K9o, K8o, K7o, K6o"));
+        assert_eq!(range("AKs+"), range("AKs"));
+        assert_eq!(range("[50]QQ+[/50]").total_weight(), 9.0);
+        for invalid in [
+            "AKs-AQo", "AK-QJ", "QQ-AK", "AhKh+", "Q+", "AKs-", "KsKh-QQ",
+        ] {
+            assert_eq!(
+                invalid.parse::<Range>(),
+                Err(ParseRangeError::InvalidHand(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn set_operations() {
+        let mut wide: Range = "99+, AJs+".parse().unwrap();
+        let narrow: Range = "QQ+, AKs, 72o".parse().unwrap();
+        let combo = Combo::new("As".parse().unwrap(), "Ks".parse().unwrap()).unwrap();
+        wide.set_weight(combo, 0.5);
+
+        let union = &wide | &narrow;
+        assert_eq!(union.len(), 48 + 12);
+        assert_eq!(union.weight(combo), 1.0);
+
+        let intersection = &wide & &narrow;
+        assert_eq!(intersection.len(), 18 + 4);
+        assert_eq!(intersection.weight(combo), 0.5);
+
+        assert_eq!(&wide - &narrow, "99-JJ, AJs, AQs".parse().unwrap());
+        assert!((&narrow - &Range::full()).is_empty());
+    }
+
+    #[test]
+    fn formats() {
+        let range: Range = "QQ:0.75, [25]AKs, KhQh[/25], 72o".parse().unwrap();
+        let weight = |s: &str| {
+            let cards = Card::vec_from_str(s).unwrap();
+            range.weight(Combo::new(cards[0], cards[1]).unwrap())
+        };
+        assert_eq!(weight("QsQd"), 0.75);
+        assert_eq!(weight("AsKs"), 0.25);
+        assert_eq!(weight("KhQh"), 0.25);
+        assert_eq!(weight("7s2d"), 1.0);
+        assert_eq!(weight("KsQs"), 0.0);
+
+        for format in [
+            RangeFormat::PioSolver,
+            RangeFormat::GtoPlus,
+            RangeFormat::Flopzilla,
+        ] {
+            let written = range.to_format(format);
+            assert_eq!(written.parse::<Range>().unwrap(), range, "{written}");
+        }
+        assert_eq!(
+            range.to_format(RangeFormat::Flopzilla),
+            "[25]AKs,KhQh[/25],[75]QQ[/75],72o"
+        );
+        assert_eq!(Range::new().to_format(RangeFormat::PioSolver), "");
+
+        for invalid in [
+            "AA:x",
+            "AA:-1",
+            "[50]AA",
+            "AA[/50]",
+            "[50]AA,[25]KK[/25][/50]",
+            "[50]AA[/25]",
+        ] {
+            assert!(matches!(
+                invalid.parse::<Range>(),
+                Err(ParseRangeError::InvalidWeight(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn starting_hands() {
+        let hands: Vec<StartingHand> = StartingHand::all().collect();
+        assert_eq!(hands.len(), StartingHand::COUNT);
+        assert_eq!(
+            hands.iter().map(|hand| hand.combos().len()).sum::<usize>(),
+            Combo::COUNT
+        );
+        for (i, hand) in hands.iter().enumerate() {
+            assert_eq!(hand.index(), i);
+            assert_eq!(hand.to_string().parse::<StartingHand>().unwrap(), *hand);
+        }
+
+        assert_eq!(hands[0].to_string(), "AA");
+        assert_eq!(hands[168].to_string(), "22");
+        assert_eq!(hands[12].to_string(), "A2s");
+        assert!(
+            "72s".parse::<StartingHand>().unwrap().index()
+                < "72o".parse::<StartingHand>().unwrap().index()
+        );
+        assert_eq!(
+            StartingHand::of(Combo::new("7c".parse().unwrap(), "Ad".parse().unwrap()).unwrap()),
+            "A7o".parse().unwrap()
+        );
+        assert!("AK".parse::<StartingHand>().is_err());
+        assert!("XXo".parse::<StartingHand>().is_err());
+    }
+
+    #[test]
+    fn preflop_matrices() {
+        let matrix = PreflopMatrix::from_fn(|hand| hand.to_string());
+        assert_eq!(matrix.get(0, 0).unwrap(), "AA");
+        assert_eq!(matrix.get(0, 12).unwrap(), "A2s");
+        assert_eq!(matrix.get(12, 0).unwrap(), "A2o");
+        assert_eq!(matrix.get(13, 0), None);
+        assert_eq!(matrix.rows().count(), 13);
+        assert!(matrix.rows().enumerate().all(|(i, row)| row[i].len() == 2));
+        assert!(matrix.iter().all(|(hand, name)| hand.to_string() == *name));
+
+        // a partly weighted hand averages out over its combos
+        let mut range: Range = "KK".parse().unwrap();
+        let combo = range.iter().next().unwrap().0;
+        range.remove(combo);
+        let mut weights = PreflopMatrix::from(&range);
+        assert_eq!(weights["KK".parse().unwrap()], 5.0 / 6.0);
+        assert_eq!(
+            weights.iter().filter(|(_, weight)| **weight > 0.0).count(),
+            1
+        );
+
+        weights
+            .iter_mut()
+            .for_each(|(_, weight)| *weight = 1.0 - *weight);
+        let inverted = Range::from(&weights);
+        assert_eq!(inverted.len(), Combo::COUNT);
+        assert_eq!(inverted.weight(combo), 1.0 - 5.0 / 6.0);
+        assert_eq!(
+            PreflopMatrix::filled(1.0),
+            PreflopMatrix::from(&Range::full())
+        );
+        assert_eq!(
+            PreflopMatrix::<u8>::default().map(|count| count + 1),
+            PreflopMatrix::filled(1)
+        );
+    }
+
+    #[test]
+    fn weights() {
+        let mut range: Range = "AA".parse().unwrap();
+        let combo = range.iter().next().unwrap().0;
+
+        range.set_weight(combo, 0.5);
+        assert_eq!(range.total_weight(), 5.5);
+        range.remove(combo);
+        assert!(!range.contains(combo));
+        assert_eq!(range.len(), 5);
+
+        let dead = combo.card_set();
+        assert_eq!(range.without_dead(dead).len(), 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserializing_checks_invariants() {
+        let range: Range = "QQ+, AKs".parse().unwrap();
+        let json = serde_json::to_string(&range).unwrap();
+        assert_eq!(serde_json::from_str::<Range>(&json).unwrap(), range);
+        assert!(serde_json::from_str::<Range>(r#"{"weights":[1.0]}"#).is_err());
+
+        let combo = Combo::new("As".parse().unwrap(), "2h".parse().unwrap()).unwrap();
+        assert_eq!(serde_json::to_string(&combo).unwrap(), r#"["As","2h"]"#);
+        assert_eq!(
+            serde_json::from_str::<Combo>(r#"["As","2h"]"#).unwrap(),
+            combo
+        );
+        let reversed: Combo = serde_json::from_str(r#"["2h","As"]"#).unwrap();
+        assert_eq!(reversed, combo);
+        assert_eq!(Range::full().weight(reversed), 1.0);
+        assert!(serde_json::from_str::<Combo>(r#"["As","As"]"#).is_err());
+        assert!(serde_json::from_str::<Combo>(r#"["As","Xh"]"#).is_err());
+
+        let hand: StartingHand = "AKs".parse().unwrap();
+        let json = serde_json::to_string(&hand).unwrap();
+        assert_eq!(serde_json::from_str::<StartingHand>(&json).unwrap(), hand);
+        for invalid in [
+            r#"{"high":"K","low":"A","suited":true}"#,
+            r#"{"high":"A","low":"A","suited":true}"#,
+            r#"{"high":"X","low":"A","suited":false}"#,
+        ] {
+            assert!(serde_json::from_str::<StartingHand>(invalid).is_err());
+        }
+
+        let matrix = PreflopMatrix::from(&range);
+        let json = serde_json::to_string(&matrix).unwrap();
+        assert_eq!(
+            serde_json::from_str::<PreflopMatrix<f64>>(&json).unwrap(),
+            matrix
+        );
+        assert!(serde_json::from_str::<PreflopMatrix<f64>>(r#"{"cells":[]}"#).is_err());
+    }
+}
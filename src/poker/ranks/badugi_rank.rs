@@ -1,9 +1,12 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 
-use super::{BasicRank, IntoRankStrengthIterator, RankStrengthIterator};
+use super::{BasicRank, IntoRankStrengthIterator, Rank, RankStrengthIterator};
 
 /// A rank of a Badugi hand
-#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BadugiRank(pub BasicRank);
 
 impl Deref for BadugiRank {
@@ -13,8 +16,44 @@ impl Deref for BadugiRank {
     }
 }
 
+impl PartialEq for BadugiRank {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.key() == other.0.key()
+    }
+}
+
+impl Eq for BadugiRank {}
+
+impl Hash for BadugiRank {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.key().hash(state);
+    }
+}
+
+impl Ord for BadugiRank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.compare(&other.0)
+    }
+}
+
+impl PartialOrd for BadugiRank {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Rank for BadugiRank {
+    const MAX_STRENGTH: u32 = 873;
+}
+
 impl IntoRankStrengthIterator for BadugiRank {
     fn into_strength_iter(self) -> RankStrengthIterator {
         RankStrengthIterator::from(self.0)
     }
 }
+
+impl std::fmt::Display for BadugiRank {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
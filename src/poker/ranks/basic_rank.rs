@@ -1,5 +1,7 @@
 use std::cmp::Ordering;
 
+use super::RankDescription;
+
 /// A foundational struct to contain hand strength metadata
 ///
 /// The `BasicRank` struct is fairly transparent allowing for easy access for evaluators to create
@@ -7,24 +9,34 @@ use std::cmp::Ordering;
 /// even containing information on hand rank and sub rank if the user wants to utilize those
 /// properties.
 ///
-/// A provided description is also included to allow for the user to understand what the rank is in
-/// English. Typically, the `description` field is going to be the strength of the hand, but if the
-/// evaluator fails for whatever reason, it will contain an error message instead.
+/// A provided description is also included to allow for the user to understand what the rank is
+/// made up of. Typically, the `description` field is going to describe the hand, but if the
+/// evaluator fails for whatever reason, it will be `None` instead.
+///
+/// The evaluators that are provided in the `evaluator` module produce rank types (e.g. `HighRank`,
+/// `BadugiRank`) that wrap this foundational struct. Only those rank types implement `Eq` and
+/// `Ord`, where ranks are ordered by `strength` first, with `hand_rank` and `sub_rank` breaking any
+/// ties. This ensures that ranks from different evaluators cannot be compared with one another.
+/// The description is derived from the other fields, so it does not take part in any comparison.
 ///
-/// Ranks can be compared with each other using the typical equality and inequality operations.
-/// The evaluators that are provided in the `evaluator` module produce structs that rely on this
-/// foundational struct.
-#[derive(Debug, Clone, Eq, PartialEq)]
+/// Rank types can also be hashed, allowing them to be used as keys within maps and histograms.
+/// Like comparisons, the description is not included within the hash.
+///
+/// Displaying a rank gives its description, falling back to its strength if no description is
+/// available.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BasicRank {
     /// The strength of the `Rank`
     ///
-    /// This field is what is used within the implementation of `Ord`.
+    /// This field is what is used within the implementation of `Ord` for each rank type.
     pub strength: u32,
 
     /// The hand rank of the associated rank
     ///
     /// Two different ranks from two different evaluators might have the same `strength`, they may
-    /// differ in `hand_rank`.
+    /// differ in `hand_rank`. Ranks that are based on standard poker hands (e.g. `HighRank` and
+    /// `Low27Rank`) expose this value as a `HandClass` through `hand_class()`.
     pub hand_rank: u16,
 
     /// The sub rank of the associated rank
@@ -34,21 +46,145 @@ pub struct BasicRank {
 
     /// An optional description for the rank
     ///
-    /// All the provided evaluators in this library fill this field. Typically, value that is
-    /// populated is a English version of the hand made (e.g. "Trip 5s", "Kings full of 9s"). If
-    /// the evaluator fails to generate a valid description, the error string will be populated
-    /// here.
-    pub description: Option<String>,
+    /// All the provided evaluators in this library fill this field. The description is structured
+    /// into the category of the hand, the values that make it up, and its kickers. Displaying the
+    /// description gives an English version of the hand made (e.g. "Trip 5s", "Kings Full of
+    /// 9s"). If the evaluator fails to generate a valid description, this field will be `None`.
+    pub description: Option<RankDescription>,
+}
+
+impl BasicRank {
+    /// Returns a stable integer id for the rank
+    ///
+    /// Every evaluator in this library assigns each equivalence class of hands its own strength,
+    /// so the id is simply the strength. This makes it unique among ranks of the same type, but
+    /// ranks from different evaluators can share the same id.
+    pub fn id(&self) -> u32 {
+        self.strength
+    }
+
+    /// Packs the hand rank and sub rank of the rank into a single `u32`
+    ///
+    /// The upper 16 bits hold the hand rank and the lower 16 bits hold the sub rank. This layout
+    /// is stable across versions, making it suitable for large precomputed tables and caches.
+    /// Within a rank type, encoded values sort in the same order as `hand_rank` followed by
+    /// `sub_rank`. Ranks that can be rebuilt from this value provide a `decode()` function (e.g.
+    /// `HighRank::decode()`).
+    pub fn encode(&self) -> u32 {
+        (self.hand_rank as u32) << 16 | self.sub_rank as u32
+    }
+
+    /// Compares the strength of two ranks, with `hand_rank` and `sub_rank` breaking any ties
+    ///
+    /// This is what each rank type uses to implement `Eq` and `Ord`. It is not exposed as an `Eq`
+    /// or `Ord` implementation on `BasicRank` itself to prevent ranks from different evaluators
+    /// from being compared.
+    pub(crate) fn compare(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+
+    /// Returns the fields that identify the rank, in order of significance
+    ///
+    /// Each rank type compares and hashes this key to implement `Eq`, `Ord`, and `Hash`.
+    pub(crate) fn key(&self) -> (u32, u16, u16) {
+        (self.strength, self.hand_rank, self.sub_rank)
+    }
 }
 
-impl Ord for BasicRank {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.strength.cmp(&other.strength)
+impl std::fmt::Display for BasicRank {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match &self.description {
+            Some(description) => write!(f, "{}", description),
+            None => write!(f, "Rank of strength {}", self.strength),
+        }
     }
 }
 
-impl PartialOrd for BasicRank {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poker::ranks::{HighRank, LowA5Rank};
+
+    #[test]
+    fn ordering_consistent_with_equality() {
+        let rank = BasicRank {
+            strength: 100,
+            hand_rank: 2,
+            sub_rank: 5,
+            description: None,
+        };
+        let other_evaluator_rank = BasicRank {
+            hand_rank: 3,
+            ..rank.clone()
+        };
+
+        assert_ne!(rank.compare(&other_evaluator_rank), Ordering::Equal);
+        assert_ne!(
+            HighRank(rank.clone()),
+            HighRank(other_evaluator_rank.clone())
+        );
+        assert_eq!(rank.to_string(), "Rank of strength 100");
+    }
+
+    #[test]
+    fn hash_ignores_description() {
+        use std::collections::HashMap;
+
+        let rank = BasicRank {
+            strength: 100,
+            hand_rank: 2,
+            sub_rank: 5,
+            description: None,
+        };
+        let described_rank = BasicRank {
+            description: Some(RankDescription {
+                category: crate::poker::ranks::RankCategory::LowA5,
+                primary: crate::core::Value::Eight,
+                secondary: None,
+                kickers: vec![],
+            }),
+            ..rank.clone()
+        };
+
+        let mut histogram = HashMap::new();
+        *histogram.entry(LowA5Rank(rank.clone())).or_insert(0) += 1;
+        *histogram.entry(LowA5Rank(described_rank)).or_insert(0) += 1;
+
+        assert_eq!(histogram.len(), 1);
+        assert_eq!(histogram[&LowA5Rank(rank.clone())], 2);
+        assert_eq!(rank.id(), 100);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        use crate::core::Value;
+        use crate::poker::ranks::{HandClass, RankCategory, RankDescription};
+
+        let rank = BasicRank {
+            strength: 4000,
+            hand_rank: 2,
+            sub_rank: 1450,
+            description: Some(RankDescription::from_hand_values(
+                HandClass::Pair,
+                &[
+                    Value::Nine,
+                    Value::Nine,
+                    Value::Ace,
+                    Value::Seven,
+                    Value::Two,
+                ],
+            )),
+        };
+
+        let json = serde_json::to_string(&rank).unwrap();
+        let deserialized: BasicRank = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized.compare(&rank), Ordering::Equal);
+        assert_eq!(deserialized.description, rank.description);
+        assert_eq!(
+            deserialized.description.unwrap().category,
+            RankCategory::Hand(HandClass::Pair)
+        );
     }
 }
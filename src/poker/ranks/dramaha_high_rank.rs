@@ -1,7 +1,12 @@
-use super::{HighRank, IntoRankStrengthIterator, RankStrengthIterator};
+use super::{HighRank, IntoRankStrengthIterator, Rank, RankStrengthIterator};
 
 /// A struct of ranks a Dramaha High hand
-#[derive(Debug, Clone, Eq, PartialEq, PartialOrd)]
+///
+/// Ranks are ordered by their Omaha rank first and then by their draw rank. Note that this ordering
+/// is only useful for sorting; use `generate_winner_list()` to determine how the pot should be
+/// awarded.
+#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DramahaHighRank {
     /// The Omaha high rank from the hand
     pub omaha_rank: HighRank,
@@ -9,8 +14,31 @@ pub struct DramahaHighRank {
     pub draw_rank: HighRank,
 }
 
+impl DramahaHighRank {
+    /// Returns a stable integer id for the rank
+    ///
+    /// The id is built from the strengths of the Omaha and draw ranks, so two Dramaha High ranks
+    /// have the same id if and only if they are equal.
+    pub fn id(&self) -> u32 {
+        self.omaha_rank.id() * 7463 + self.draw_rank.id()
+    }
+
+    /// Returns the normalized strengths of the Omaha and draw ranks, in that order
+    ///
+    /// See `Rank::normalized()`.
+    pub fn normalized(&self) -> (f64, f64) {
+        (self.omaha_rank.normalized(), self.draw_rank.normalized())
+    }
+}
+
 impl IntoRankStrengthIterator for DramahaHighRank {
     fn into_strength_iter(self) -> RankStrengthIterator {
         RankStrengthIterator::from(vec![self.omaha_rank.strength, self.draw_rank.strength])
     }
 }
+
+impl std::fmt::Display for DramahaHighRank {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} / {}", self.omaha_rank, self.draw_rank)
+    }
+}
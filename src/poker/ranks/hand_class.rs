@@ -0,0 +1,190 @@
+use std::ops::RangeInclusive;
+
+use num_traits::FromPrimitive;
+use strum_macros::EnumIter;
+
+/// An enum representation of the category of a standard poker hand
+///
+/// Each variant corresponds to the `hand_rank` value that the high and 2-7 lowball evaluators
+/// store within `BasicRank`, starting from 1 for a high card hand up to 9 for a straight flush.
+/// Variants are ordered by strength as they would be in a high hand.
+#[allow(missing_docs)]
+#[derive(
+    Debug, Clone, Copy, FromPrimitive, ToPrimitive, EnumIter, Eq, PartialEq, Ord, PartialOrd, Hash,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HandClass {
+    HighCard = 1,
+    Pair = 2,
+    TwoPair = 3,
+    ThreeOfAKind = 4,
+    Straight = 5,
+    Flush = 6,
+    FullHouse = 7,
+    FourOfAKind = 8,
+    StraightFlush = 9,
+}
+
+impl HandClass {
+    /// Returns the number of distinct equivalence classes (sub ranks) within the hand class
+    ///
+    /// These counts are based off of a standard 52-card deck. Summing across all hand classes
+    /// gives the 7462 distinct five-card hand ranks.
+    pub const fn sub_rank_count(&self) -> u16 {
+        match self {
+            Self::HighCard => 1277,
+            Self::Pair => 2860,
+            Self::TwoPair => 858,
+            Self::ThreeOfAKind => 858,
+            Self::Straight => 10,
+            Self::Flush => 1277,
+            Self::FullHouse => 156,
+            Self::FourOfAKind => 156,
+            Self::StraightFlush => 10,
+        }
+    }
+
+    /// Returns the high hand strengths that belong to the hand class
+    ///
+    /// High hand strengths run from 1 for the weakest high card hand up to 7462 for a royal flush,
+    /// so the hand classes cover consecutive ranges of `sub_rank_count()` strengths.
+    ///
+    /// ```rust
+    /// use playing_cards::poker::ranks::HandClass;
+    ///
+    /// assert_eq!(HandClass::HighCard.strengths(), 1..=1277);
+    /// assert_eq!(HandClass::StraightFlush.strengths(), 7453..=7462);
+    /// ```
+    pub fn strengths(&self) -> RangeInclusive<u32> {
+        let i = *self as usize - 1;
+        CLASS_STARTS[i]..=CLASS_STARTS[i + 1] - 1
+    }
+
+    /// Returns the hand class of a high hand strength
+    ///
+    /// Returns `None` if `strength` is not within the domain [1, 7462].
+    ///
+    /// ```rust
+    /// use playing_cards::poker::ranks::HandClass;
+    ///
+    /// assert_eq!(HandClass::from_strength(1278), Some(HandClass::Pair));
+    /// assert_eq!(HandClass::from_strength(0), None);
+    /// ```
+    pub fn from_strength(strength: u32) -> Option<Self> {
+        match CLASS_STARTS.partition_point(|start| *start <= strength) {
+            0 | 10 => None,
+            hand_rank => HandClass::from_usize(hand_rank),
+        }
+    }
+
+    /// Returns a prettified string of the hand class
+    pub fn get_readable_string(&self) -> &'static str {
+        match self {
+            Self::HighCard => "High Card",
+            Self::Pair => "Pair",
+            Self::TwoPair => "Two Pair",
+            Self::ThreeOfAKind => "Three of a Kind",
+            Self::Straight => "Straight",
+            Self::Flush => "Flush",
+            Self::FullHouse => "Full House",
+            Self::FourOfAKind => "Four of a Kind",
+            Self::StraightFlush => "Straight Flush",
+        }
+    }
+}
+
+/// The weakest high hand strength of each hand class, followed by one past the strongest strength
+///
+/// This is on the hot path of building ranks from strengths, so it is computed once at compile
+/// time from `sub_rank_count()`.
+const CLASS_STARTS: [u32; 10] = {
+    const CLASSES: [HandClass; 9] = [
+        HandClass::HighCard,
+        HandClass::Pair,
+        HandClass::TwoPair,
+        HandClass::ThreeOfAKind,
+        HandClass::Straight,
+        HandClass::Flush,
+        HandClass::FullHouse,
+        HandClass::FourOfAKind,
+        HandClass::StraightFlush,
+    ];
+
+    let mut starts = [1; 10];
+    let mut i = 0;
+    while i < CLASSES.len() {
+        starts[i + 1] = starts[i] + CLASSES[i].sub_rank_count() as u32;
+        i += 1;
+    }
+
+    starts
+};
+
+impl TryFrom<u16> for HandClass {
+    type Error = u16;
+    fn try_from(hand_rank: u16) -> Result<Self, Self::Error> {
+        match HandClass::from_u16(hand_rank) {
+            Some(class) => Ok(class),
+            None => Err(hand_rank),
+        }
+    }
+}
+
+impl From<HandClass> for u16 {
+    fn from(class: HandClass) -> Self {
+        class as u16
+    }
+}
+
+impl std::fmt::Display for HandClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.get_readable_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn hand_rank_round_trip() {
+        for (i, class) in HandClass::iter().enumerate() {
+            let hand_rank: u16 = class.into();
+            assert_eq!(hand_rank, i as u16 + 1);
+            assert_eq!(HandClass::try_from(hand_rank), Ok(class));
+        }
+
+        assert_eq!(HandClass::try_from(0), Err(0));
+        assert_eq!(HandClass::try_from(10), Err(10));
+    }
+
+    #[test]
+    fn sub_rank_counts_total() {
+        let total: u32 = HandClass::iter()
+            .map(|class| class.sub_rank_count() as u32)
+            .sum();
+        assert_eq!(total, 7462);
+    }
+
+    #[test]
+    fn strengths() {
+        assert_eq!(HandClass::from_strength(1), Some(HandClass::HighCard));
+        assert_eq!(HandClass::from_strength(1277), Some(HandClass::HighCard));
+        assert_eq!(HandClass::from_strength(1278), Some(HandClass::Pair));
+        assert_eq!(
+            HandClass::from_strength(7462),
+            Some(HandClass::StraightFlush)
+        );
+        assert_eq!(HandClass::from_strength(7463), None);
+
+        let mut next = 1;
+        for class in HandClass::iter() {
+            assert_eq!(*class.strengths().start(), next);
+            assert_eq!(class.strengths().count(), class.sub_rank_count() as usize);
+            assert_eq!(HandClass::from_strength(next), Some(class));
+            next = class.strengths().end() + 1;
+        }
+        assert_eq!(next, 7463);
+    }
+}
@@ -1,9 +1,15 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 
-use super::{BasicRank, IntoRankStrengthIterator, RankStrengthIterator};
+use crate::core::Card;
+use crate::poker::evaluators::high_evaluator;
+
+use super::{BasicRank, HandClass, IntoRankStrengthIterator, Rank, RankStrengthIterator};
 
 /// A rank of a high hand
-#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HighRank(pub BasicRank);
 
 impl Deref for HighRank {
@@ -13,8 +19,158 @@ impl Deref for HighRank {
     }
 }
 
+impl HighRank {
+    /// Creates a rank from its strength
+    ///
+    /// All other fields of the rank (hand rank, sub rank, and description) are recalculated from
+    /// the strength, making this useful for re-hydrating strengths that have been stored or
+    /// transmitted. Returns `None` if `strength` is not within the domain [1, 7462].
+    ///
+    /// ```rust
+    /// use playing_cards::poker::ranks::{HandClass, HighRank};
+    ///
+    /// let rank = HighRank::from_strength(7462).unwrap();
+    ///
+    /// assert_eq!(rank.hand_class(), Some(HandClass::StraightFlush));
+    /// assert_eq!(rank.to_string(), "Ace High Straight Flush");
+    /// assert!(HighRank::from_strength(0).is_none());
+    /// ```
+    pub fn from_strength(strength: u32) -> Option<Self> {
+        high_evaluator::rank_from_strength(strength).map(Self)
+    }
+
+    /// Creates a rank from a value produced by `BasicRank::encode()`
+    ///
+    /// Returns `None` if the encoded hand rank is not a valid `HandClass` or the encoded sub rank
+    /// is out of range for its hand class.
+    ///
+    /// ```rust
+    /// use playing_cards::{core::Card, poker::{evaluators::high_evaluator, ranks::HighRank}};
+    ///
+    /// let hand = Card::vec_from_str("KsKhKd5c5h").unwrap();
+    /// let rank = high_evaluator::evaluate_hand(&hand).unwrap();
+    ///
+    /// assert_eq!(HighRank::decode(rank.encode()), Some(rank));
+    /// ```
+    pub fn decode(encoded: u32) -> Option<Self> {
+        let class = HandClass::try_from((encoded >> 16) as u16).ok()?;
+        let sub_rank = encoded & 0xffff;
+        if !(1..=class.sub_rank_count() as u32).contains(&sub_rank) {
+            return None;
+        }
+
+        Self::from_strength(class.strengths().start() - 1 + sub_rank)
+    }
+
+    /// Returns a representative five-card hand of the rank's equivalence class
+    ///
+    /// The cards are ordered by significance (e.g. the pair first, followed by the kickers), which
+    /// makes this useful for documentation, testing, and showing what a given rank looks like.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `strength` is not within the domain [1, 7462]. Ranks produced by the high
+    /// evaluator always contain a valid strength.
+    ///
+    /// ```rust
+    /// use playing_cards::{core::Card, poker::ranks::HighRank};
+    ///
+    /// let rank = HighRank::from_strength(7400).unwrap();
+    /// let hand = rank.example_hand();
+    ///
+    /// assert_eq!(hand, Card::vec_from_str("ThTcTdTs9h").unwrap());
+    /// ```
+    pub fn example_hand(&self) -> Vec<Card> {
+        high_evaluator::example_hand(self.strength).expect("Rank has an invalid strength")
+    }
+
+    /// Returns the number of distinct five-card hand ranks that this rank beats
+    ///
+    /// There are 7462 distinct five-card hand ranks, so a royal flush beats 7461 of them.
+    pub fn ranks_beaten(&self) -> u32 {
+        self.strength.saturating_sub(1)
+    }
+
+    /// Returns the number of `card_count`-card hands that this rank beats
+    ///
+    /// A hand is beaten if the best five-card hand that can be made from its cards is weaker than
+    /// this rank. Returns `None` if `card_count` is not within the domain [5, 7] or the strength of
+    /// the rank is invalid. The hand counts for each card count are calculated upon first use.
+    pub fn hands_beaten(&self, card_count: usize) -> Option<u64> {
+        let counts = high_evaluator::cumulative_hand_counts(card_count)?;
+        if !(1..counts.len() as u32).contains(&self.strength) {
+            return None;
+        }
+
+        Some(counts[self.strength as usize - 1])
+    }
+
+    /// Returns the percentage of `card_count`-card hands that this rank beats
+    ///
+    /// This is useful for displaying relative hand strength (e.g. a pair of aces beats over 91%
+    /// of all 5-card hands). Returns `None` under the same conditions as `hands_beaten()`.
+    ///
+    /// ```rust
+    /// use playing_cards::{core::Card, poker::evaluators::high_evaluator};
+    ///
+    /// let hand = Card::vec_from_str("AsAhKd5c2h").unwrap();
+    /// let rank = high_evaluator::evaluate_hand(&hand).unwrap();
+    ///
+    /// let percentile = rank.percentile(5).unwrap();
+    /// assert!(percentile > 91.0 && percentile < 92.0);
+    /// ```
+    pub fn percentile(&self, card_count: usize) -> Option<f64> {
+        let total = *high_evaluator::cumulative_hand_counts(card_count)?.last()?;
+        let beaten = self.hands_beaten(card_count)?;
+
+        Some(beaten as f64 / total as f64 * 100.0)
+    }
+
+    /// Returns the hand class of the rank, or `None` if `hand_rank` does not correspond to a valid
+    /// `HandClass`
+    pub fn hand_class(&self) -> Option<HandClass> {
+        HandClass::try_from(self.hand_rank).ok()
+    }
+}
+
+impl PartialEq for HighRank {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.key() == other.0.key()
+    }
+}
+
+impl Eq for HighRank {}
+
+impl Hash for HighRank {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.key().hash(state);
+    }
+}
+
+impl Ord for HighRank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.compare(&other.0)
+    }
+}
+
+impl PartialOrd for HighRank {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Rank for HighRank {
+    const MAX_STRENGTH: u32 = 7462;
+}
+
 impl IntoRankStrengthIterator for HighRank {
     fn into_strength_iter(self) -> RankStrengthIterator {
         RankStrengthIterator::from(self.strength)
     }
 }
+
+impl std::fmt::Display for HighRank {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,95 @@
+use super::{IntoRankStrengthIterator, RankStrengthIterator};
+
+/// A rank for split-pot games made up of a high rank and an optional low rank
+///
+/// Iterating over the strengths of a `HiLoRank` yields the strengths of the high rank followed by
+/// the strengths of the low rank, so split-pot games can use `generate_winner_list()` the same way
+/// single-rank games do. If there is no low rank, a single `None` is yielded in its place; hence,
+/// the low rank type is expected to yield exactly one strength.
+///
+/// Ranks are ordered by their hi rank first and then by their lo rank, where having no lo rank is
+/// the weakest. Note that this ordering is only useful for sorting; use `generate_winner_list()`
+/// to determine how a split pot should be awarded.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HiLoRank<H, L> {
+    /// The hi rank from the hand
+    pub hi_rank: H,
+    /// The lo rank from the hand
+    ///
+    /// Note that not all hands will qualify for a lo hand.
+    pub lo_rank: Option<L>,
+}
+
+impl<H, L> IntoRankStrengthIterator for HiLoRank<H, L>
+where
+    H: IntoRankStrengthIterator,
+    L: IntoRankStrengthIterator,
+{
+    fn into_strength_iter(self) -> RankStrengthIterator {
+        let mut strengths: Vec<Option<u32>> = self.hi_rank.into_strength_iter().collect();
+        match self.lo_rank {
+            Some(lo_rank) => strengths.extend(lo_rank.into_strength_iter()),
+            None => strengths.push(None),
+        }
+
+        RankStrengthIterator::from(strengths)
+    }
+}
+
+impl<H, L> std::fmt::Display for HiLoRank<H, L>
+where
+    H: std::fmt::Display,
+    L: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match &self.lo_rank {
+            Some(lo_rank) => write!(f, "{} / {}", self.hi_rank, lo_rank),
+            None => write!(f, "{} / No low", self.hi_rank),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poker::ranks::{BadugiRank, BasicRank, HighRank};
+
+    fn rank(strength: u32) -> BasicRank {
+        BasicRank {
+            strength,
+            hand_rank: 1,
+            sub_rank: 1,
+            description: None,
+        }
+    }
+
+    #[test]
+    fn strengths_include_missing_low() {
+        let with_low: HiLoRank<HighRank, BadugiRank> = HiLoRank {
+            hi_rank: HighRank(rank(100)),
+            lo_rank: Some(BadugiRank(rank(50))),
+        };
+        let without_low: HiLoRank<HighRank, BadugiRank> = HiLoRank {
+            hi_rank: HighRank(rank(200)),
+            lo_rank: None,
+        };
+
+        assert_eq!(
+            with_low.clone().into_strength_iter().collect::<Vec<_>>(),
+            vec![Some(100), Some(50)]
+        );
+        assert_eq!(
+            without_low.clone().into_strength_iter().collect::<Vec<_>>(),
+            vec![Some(200), None]
+        );
+        assert!(without_low > with_low);
+    }
+
+    #[test]
+    fn strengths_keep_their_position() {
+        let iter = RankStrengthIterator::from(vec![None, Some(3), None, Some(7)]);
+
+        assert_eq!(iter.collect::<Vec<_>>(), vec![None, Some(3), None, Some(7)]);
+    }
+}
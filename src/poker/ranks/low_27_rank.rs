@@ -1,9 +1,17 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 
-use super::{BasicRank, IntoRankStrengthIterator, RankStrengthIterator};
+use crate::core::Card;
+use crate::poker::evaluators::high_evaluator;
+
+use super::{
+    BasicRank, HandClass, HighRank, IntoRankStrengthIterator, LowRank, Rank, RankStrengthIterator,
+};
 
 /// A rank of a 2-to-7 lowball hand
-#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Low27Rank(pub BasicRank);
 
 impl Deref for Low27Rank {
@@ -13,8 +21,109 @@ impl Deref for Low27Rank {
     }
 }
 
+impl Low27Rank {
+    /// Creates a rank from its strength
+    ///
+    /// All other fields of the rank (hand rank, sub rank, and description) are recalculated from
+    /// the strength. Returns `None` if `strength` is not within the domain [1, 7462].
+    ///
+    /// ```rust
+    /// use playing_cards::poker::ranks::Low27Rank;
+    ///
+    /// let rank = Low27Rank::from_strength(7462).unwrap();
+    ///
+    /// assert_eq!(rank.to_string(), "7 High");
+    /// ```
+    pub fn from_strength(strength: u32) -> Option<Self> {
+        let mut rank = high_evaluator::rank_from_strength(7463u32.checked_sub(strength)?)?;
+        rank.strength = strength;
+        Some(Self(rank))
+    }
+
+    /// Creates a rank from a value produced by `BasicRank::encode()`
+    ///
+    /// 2-7 lowball ranks share their hand ranks and sub ranks with high ranks, so this returns
+    /// `None` under the same conditions as `HighRank::decode()`.
+    pub fn decode(encoded: u32) -> Option<Self> {
+        HighRank::decode(encoded).map(|rank| Self::from(LowRank(rank)))
+    }
+
+    /// Returns a representative five-card hand of the rank's equivalence class
+    ///
+    /// The cards are ordered by significance, as with `HighRank::example_hand()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `strength` is not within the domain [1, 7462]. Ranks produced by the 2-7 lowball
+    /// evaluator always contain a valid strength.
+    pub fn example_hand(&self) -> Vec<Card> {
+        high_evaluator::example_hand(7463u32.saturating_sub(self.strength))
+            .expect("Rank has an invalid strength")
+    }
+
+    /// Returns the hand class of the rank, or `None` if `hand_rank` does not correspond to a valid
+    /// `HandClass`
+    pub fn hand_class(&self) -> Option<HandClass> {
+        HandClass::try_from(self.hand_rank).ok()
+    }
+}
+
+impl PartialEq for Low27Rank {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.key() == other.0.key()
+    }
+}
+
+impl Eq for Low27Rank {}
+
+impl Hash for Low27Rank {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.key().hash(state);
+    }
+}
+
+impl Ord for Low27Rank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.compare(&other.0)
+    }
+}
+
+impl PartialOrd for Low27Rank {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Rank for Low27Rank {
+    const MAX_STRENGTH: u32 = 7462;
+}
+
 impl IntoRankStrengthIterator for Low27Rank {
     fn into_strength_iter(self) -> RankStrengthIterator {
         RankStrengthIterator::from(self.strength)
     }
 }
+
+impl std::fmt::Display for Low27Rank {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Out-of-range strengths (above 7462) saturate to 0 instead of overflowing.
+impl From<LowRank<HighRank>> for Low27Rank {
+    fn from(rank: LowRank<HighRank>) -> Self {
+        let mut rank = rank.into_inner().0;
+        rank.strength = 7463u32.saturating_sub(rank.strength);
+        Self(rank)
+    }
+}
+
+/// Out-of-range strengths (above 7462) saturate to 0 instead of overflowing.
+impl From<Low27Rank> for LowRank<HighRank> {
+    fn from(rank: Low27Rank) -> Self {
+        let mut rank = rank.0;
+        rank.strength = 7463u32.saturating_sub(rank.strength);
+        LowRank(HighRank(rank))
+    }
+}
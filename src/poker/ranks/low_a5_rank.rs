@@ -1,9 +1,12 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 
-use crate::poker::ranks::{BasicRank, IntoRankStrengthIterator, RankStrengthIterator};
+use crate::poker::ranks::{BasicRank, IntoRankStrengthIterator, Rank, RankStrengthIterator};
 
 /// A rank of a Ace-to-5 lowball hand
-#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LowA5Rank(pub BasicRank);
 
 impl Deref for LowA5Rank {
@@ -12,8 +15,45 @@ impl Deref for LowA5Rank {
         &self.0
     }
 }
+
+impl PartialEq for LowA5Rank {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.key() == other.0.key()
+    }
+}
+
+impl Eq for LowA5Rank {}
+
+impl Hash for LowA5Rank {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.key().hash(state);
+    }
+}
+
+impl Ord for LowA5Rank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.compare(&other.0)
+    }
+}
+
+impl PartialOrd for LowA5Rank {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Rank for LowA5Rank {
+    const MAX_STRENGTH: u32 = 56;
+}
+
 impl IntoRankStrengthIterator for LowA5Rank {
     fn into_strength_iter(self) -> RankStrengthIterator {
         RankStrengthIterator::from(self.strength)
     }
 }
+
+impl std::fmt::Display for LowA5Rank {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,111 @@
+use std::cmp::Ordering;
+use std::ops::Deref;
+
+use super::{IntoRankStrengthIterator, RankStrengthIterator};
+
+/// A wrapper that reverses the ordering of a rank
+///
+/// Wrapping a rank in `LowRank` makes numerically lower ranks compare as greater. This allows
+/// ranks that are measured on a high scale to be used in lowball games, where the weakest hand
+/// wins, while still using `max()` or `generate_winner_list()` to find the winner.
+///
+/// Note that `Low27Rank` and `LowA5Rank` are already oriented such that the best low hand is the
+/// greatest, so they do not need to be wrapped.
+///
+/// ```rust
+/// use playing_cards::{core::Card, poker::{evaluators::high_evaluator, ranks::LowRank}};
+///
+/// let hand1 = Card::vec_from_str("7h5d4c3s2h").unwrap();
+/// let hand2 = Card::vec_from_str("8h5d4c3s2h").unwrap();
+///
+/// let rank1 = LowRank(high_evaluator::evaluate_hand(&hand1).unwrap());
+/// let rank2 = LowRank(high_evaluator::evaluate_hand(&hand2).unwrap());
+///
+/// assert!(rank1 > rank2);
+/// assert_eq!(vec![rank1.clone(), rank2].into_iter().max(), Some(rank1));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LowRank<T>(pub T);
+
+impl<T> LowRank<T> {
+    /// Unwraps the rank, returning the rank with its original ordering
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for LowRank<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Ord> Ord for LowRank<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl<T: Ord> PartialOrd for LowRank<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: IntoRankStrengthIterator> IntoRankStrengthIterator for LowRank<T> {
+    fn into_strength_iter(self) -> RankStrengthIterator {
+        let strengths: Vec<Option<u32>> = self.0.into_strength_iter().collect();
+        let len = strengths.len();
+
+        RankStrengthIterator::new(
+            strengths
+                .into_iter()
+                .enumerate()
+                .filter_map(|(i, strength)| strength.map(|strength| (i, u32::MAX - strength)))
+                .collect(),
+            len,
+        )
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for LowRank<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::core::Card;
+    use crate::poker::evaluators::high_evaluator;
+    use crate::poker::ranks::generate_winner_list;
+
+    #[test]
+    fn lowest_hand_wins() {
+        let hands = ["7h5d4c3s2h", "AsKsQsJsTs", "8h5d4c3s2c"];
+        let ranks: HashMap<usize, LowRank<_>> = hands
+            .iter()
+            .enumerate()
+            .map(|(i, h)| {
+                let rank = high_evaluator::evaluate_hand(&Card::vec_from_str(h).unwrap()).unwrap();
+                (i, LowRank(rank))
+            })
+            .collect();
+
+        let winners = generate_winner_list(&ranks);
+        let order: Vec<usize> = winners
+            .hand_number(0)
+            .unwrap()
+            .iter()
+            .map(|keys| *keys.iter().next().unwrap())
+            .collect();
+
+        assert_eq!(order, vec![0, 2, 1]);
+        assert_eq!(ranks.values().max(), ranks.get(&0));
+    }
+}
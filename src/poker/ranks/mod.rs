@@ -1,15 +1,33 @@
 mod basic_rank;
 pub use basic_rank::*;
 
+mod rank;
+pub use rank::*;
+
+mod hand_class;
+pub use hand_class::*;
+
+mod rank_description;
+pub use rank_description::*;
+
 mod high_rank;
 pub use high_rank::*;
 
+mod provisional_rank;
+pub use provisional_rank::*;
+
 mod low_27_rank;
 pub use low_27_rank::*;
 
 mod low_a5_rank;
 pub use low_a5_rank::*;
 
+mod low_rank;
+pub use low_rank::*;
+
+mod hilo_rank;
+pub use hilo_rank::*;
+
 mod omaha_hilo_rank;
 pub use omaha_hilo_rank::*;
 
@@ -1,21 +1,17 @@
-use super::{HighRank, IntoRankStrengthIterator, LowA5Rank, RankStrengthIterator};
+use super::{HiLoRank, HighRank, LowA5Rank};
 
-/// A struct of for a given Omaha Hi-Lo hand
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct OmahaHiLoRank {
-    /// The Omaha hi rank from the hand
-    pub hi_rank: HighRank,
-    /// The Omaha lo rank from the hand
-    ///
-    /// Note that not all Omaha hands + boards will result in a lo hand.
-    pub lo_rank: Option<LowA5Rank>,
-}
+/// A rank for a given Omaha Hi-Lo hand
+///
+/// The lo rank is an Ace-to-5 low which is only present if the hand qualifies for an 8-or-better
+/// low.
+pub type OmahaHiLoRank = HiLoRank<HighRank, LowA5Rank>;
 
-impl IntoRankStrengthIterator for OmahaHiLoRank {
-    fn into_strength_iter(self) -> RankStrengthIterator {
-        RankStrengthIterator::from(vec![
-            Some(self.hi_rank.strength),
-            self.lo_rank.map(|lo| lo.strength),
-        ])
+impl OmahaHiLoRank {
+    /// Returns a stable integer id for the rank
+    ///
+    /// The id is built from the strengths of the hi and lo ranks (a missing lo rank counts as 0),
+    /// so two Omaha Hi-Lo ranks have the same id if and only if they are equal.
+    pub fn id(&self) -> u32 {
+        self.hi_rank.id() * 57 + self.lo_rank.as_ref().map_or(0, |lo| lo.id())
     }
 }
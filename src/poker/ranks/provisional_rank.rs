@@ -0,0 +1,75 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+use super::{BasicRank, HandClass, IntoRankStrengthIterator, Rank, RankStrengthIterator};
+
+/// A provisional rank of a high hand with fewer than five cards
+///
+/// Provisional ranks are produced by `high_evaluator::evaluate_partial_hand()`. Since straights and
+/// flushes require five cards, a provisional rank can only be a high card, pair, two pair, three of
+/// a kind, or four of a kind. Ranks are ordered by hand class, then by the values that make up the
+/// hand, and then by the kickers, where having a kicker beats having no kicker at all. This
+/// matches how upcards are compared street by street in stud games.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProvisionalRank(pub BasicRank);
+
+impl Deref for ProvisionalRank {
+    type Target = BasicRank;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl ProvisionalRank {
+    /// Returns the hand class of the rank, or `None` if `hand_rank` does not correspond to a valid
+    /// `HandClass`
+    pub fn hand_class(&self) -> Option<HandClass> {
+        HandClass::try_from(self.hand_rank).ok()
+    }
+}
+
+impl PartialEq for ProvisionalRank {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.key() == other.0.key()
+    }
+}
+
+impl Eq for ProvisionalRank {}
+
+impl Hash for ProvisionalRank {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.key().hash(state);
+    }
+}
+
+impl Ord for ProvisionalRank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.compare(&other.0)
+    }
+}
+
+impl PartialOrd for ProvisionalRank {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Rank for ProvisionalRank {
+    // a single two, and four aces
+    const MIN_STRENGTH: u32 = 41160;
+    const MAX_STRENGTH: u32 = 343000;
+}
+
+impl IntoRankStrengthIterator for ProvisionalRank {
+    fn into_strength_iter(self) -> RankStrengthIterator {
+        RankStrengthIterator::from(self.strength)
+    }
+}
+
+impl std::fmt::Display for ProvisionalRank {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
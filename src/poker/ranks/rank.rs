@@ -0,0 +1,91 @@
+use std::ops::Deref;
+
+use super::BasicRank;
+
+/// A trait shared by the rank types produced by a single evaluator
+///
+/// Each evaluator returns its own rank type, and only ranks of the same type can be compared with
+/// each other. Comparing ranks from different evaluators (e.g. a `HighRank` with a `BadugiRank`)
+/// is a compile error rather than a silently meaningless result.
+///
+/// ```compile_fail
+/// use playing_cards::{core::Card, poker::evaluators::{badugi_evaluator, high_evaluator}};
+///
+/// let high = high_evaluator::evaluate_hand(&Card::vec_from_str("AsKsQsJsTs").unwrap()).unwrap();
+/// let badugi = badugi_evaluator::evaluate_hand(&Card::vec_from_str("As2d3c4h").unwrap()).unwrap();
+///
+/// assert!(high > badugi);
+/// ```
+///
+/// This also applies to the `BasicRank` that each rank type wraps:
+///
+/// ```compile_fail
+/// use playing_cards::{core::Card, poker::evaluators::{badugi_evaluator, high_evaluator}};
+///
+/// let high = high_evaluator::evaluate_hand(&Card::vec_from_str("AsKsQsJsTs").unwrap()).unwrap();
+/// let badugi = badugi_evaluator::evaluate_hand(&Card::vec_from_str("As2d3c4h").unwrap()).unwrap();
+///
+/// assert!(*high > *badugi);
+/// ```
+///
+/// ```compile_fail
+/// use playing_cards::{core::Card, poker::evaluators::{badugi_evaluator, high_evaluator}};
+///
+/// let high = high_evaluator::evaluate_hand(&Card::vec_from_str("AsKsQsJsTs").unwrap()).unwrap();
+/// let badugi = badugi_evaluator::evaluate_hand(&Card::vec_from_str("As2d3c4h").unwrap()).unwrap();
+///
+/// assert!(*high == *badugi);
+/// ```
+///
+/// The methods provided by this trait follow the same rule:
+///
+/// ```compile_fail
+/// use playing_cards::{
+///     core::Card,
+///     poker::{evaluators::{badugi_evaluator, high_evaluator}, ranks::Rank},
+/// };
+///
+/// let high = high_evaluator::evaluate_hand(&Card::vec_from_str("AsKsQsJsTs").unwrap()).unwrap();
+/// let badugi = badugi_evaluator::evaluate_hand(&Card::vec_from_str("As2d3c4h").unwrap()).unwrap();
+///
+/// high.distance(&badugi);
+/// ```
+pub trait Rank: Deref<Target = BasicRank> + Ord {
+    /// The strength of the weakest possible rank
+    const MIN_STRENGTH: u32 = 1;
+
+    /// The strength of the strongest possible rank
+    const MAX_STRENGTH: u32;
+
+    /// Returns the strength of the rank mapped onto the range [0.0, 1.0]
+    ///
+    /// The weakest possible rank maps to 0.0 and the strongest possible rank maps to 1.0, allowing
+    /// ranks from different evaluators to be compared on a common scale.
+    fn normalized(&self) -> f64 {
+        let strength = self.strength.clamp(Self::MIN_STRENGTH, Self::MAX_STRENGTH);
+        (strength - Self::MIN_STRENGTH) as f64 / (Self::MAX_STRENGTH - Self::MIN_STRENGTH) as f64
+    }
+
+    /// Returns the number of equivalence classes that separate two ranks
+    ///
+    /// Since every equivalence class of hands has its own strength, this is the difference in
+    /// strength between the two ranks. Equal ranks have a distance of 0.
+    fn distance(&self, other: &Self) -> u32 {
+        self.strength.abs_diff(other.strength)
+    }
+
+    /// Returns true if both ranks are within the same hand class, false otherwise
+    ///
+    /// For example, a pair of 2s and a pair of aces are in the same hand class.
+    fn same_hand_class(&self, other: &Self) -> bool {
+        self.hand_rank == other.hand_rank
+    }
+
+    /// Returns how many hand classes this rank is above `other`
+    ///
+    /// The result is negative if this rank is in a lower hand class than `other`, and 0 if both
+    /// ranks are in the same hand class. For example, a flush is 3 hand classes above two pair.
+    fn hand_class_gap(&self, other: &Self) -> i32 {
+        self.hand_rank as i32 - other.hand_rank as i32
+    }
+}
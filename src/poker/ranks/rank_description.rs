@@ -0,0 +1,225 @@
+use crate::core::Value;
+
+use super::HandClass;
+
+/// The category of hand a `RankDescription` describes
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RankCategory {
+    /// A standard poker hand, as made by the high and 2-7 lowball evaluators
+    Hand(HandClass),
+    /// An Ace-to-5 low hand, where aces are considered low (e.g. "8-7-6-5-A")
+    LowA5,
+    /// A Badugi hand made up of the given number of cards (1-4)
+    Badugi(u8),
+}
+
+/// The amount of detail to include when describing a rank
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum Verbosity {
+    /// Only the values that define the hand (e.g. "Pair of Queens")
+    #[default]
+    Brief,
+    /// The values that define the hand followed by any kickers (e.g. "Pair of Queens, Ace-9-4
+    /// kickers")
+    WithKickers,
+}
+
+/// A structured description of a rank
+///
+/// Rather than storing an English sentence, the description is broken down into the category of
+/// the hand, the values that define it, and any kickers. This allows bots and UIs to consume the
+/// individual parts of a hand without parsing text. The `Display` implementation produces the
+/// human-readable description (e.g. "Two Pair of Queens and 2s", "Aces Full of Kings"). Kickers
+/// of standard poker hands and Badugis can be included by using `describe()` or the alternate flag
+/// (`{:#}`). Ace-to-5 low descriptions always list every card.
+///
+/// The meaning of `primary` and `secondary` depends on the category:
+/// - For pairs, trips, and quads, `primary` is the value of the matched cards.
+/// - For two pair and full houses, `primary` is the higher pair (or the trips) and `secondary` is
+///   the lower pair.
+/// - For high cards, straights, flushes, low hands, and Badugis, `primary` is the highest card in
+///   the hand.
+///
+/// `kickers` are listed from most significant to least significant.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RankDescription {
+    /// The category of the hand
+    pub category: RankCategory,
+    /// The most significant value of the hand
+    pub primary: Value,
+    /// The second most significant value of the hand if the category requires one
+    pub secondary: Option<Value>,
+    /// The remaining values in the hand that break ties
+    pub kickers: Vec<Value>,
+}
+
+impl RankDescription {
+    /// Creates a description for a standard poker hand from its five values
+    ///
+    /// `values` must be ordered by significance, i.e. grouped cards first (e.g. `[Q, Q, 2, 2, K]`
+    /// for two pair, queens and deuces with a king kicker) followed by the remaining cards in
+    /// descending order. Straights are expected to start from their top card (e.g. `[5, 4, 3, 2,
+    /// A]` for a five-high straight).
+    pub fn from_hand_values(class: HandClass, values: &[Value; 5]) -> Self {
+        let (secondary, kickers) = match class {
+            HandClass::HighCard | HandClass::Flush => (None, &values[1..]),
+            HandClass::Pair => (None, &values[2..]),
+            HandClass::TwoPair => (Some(values[2]), &values[4..]),
+            HandClass::ThreeOfAKind => (None, &values[3..]),
+            HandClass::FullHouse => (Some(values[3]), &values[5..]),
+            HandClass::FourOfAKind => (None, &values[4..]),
+            HandClass::Straight | HandClass::StraightFlush => (None, &values[5..]),
+        };
+
+        Self {
+            category: RankCategory::Hand(class),
+            primary: values[0],
+            secondary,
+            kickers: kickers.to_vec(),
+        }
+    }
+
+    /// Returns the human-readable description with the given level of detail
+    ///
+    /// ```rust
+    /// use playing_cards::{core::Card, poker::{evaluators::high_evaluator, ranks::Verbosity}};
+    ///
+    /// let hand = Card::vec_from_str("QsQh2c2dKs").unwrap();
+    /// let rank = high_evaluator::evaluate_hand(&hand).unwrap();
+    /// let description = rank.description.as_ref().unwrap();
+    ///
+    /// assert_eq!(description.describe(Verbosity::Brief), "Two Pair of Queens and 2s");
+    /// assert_eq!(
+    ///     description.describe(Verbosity::WithKickers),
+    ///     "Two Pair of Queens and 2s, King kicker"
+    /// );
+    /// ```
+    pub fn describe(&self, verbosity: Verbosity) -> String {
+        match verbosity {
+            Verbosity::Brief => format!("{}", self),
+            Verbosity::WithKickers => format!("{:#}", self),
+        }
+    }
+
+    fn write_kickers(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.kickers.as_slice() {
+            [] => Ok(()),
+            [kicker] => write!(f, ", {} kicker", kicker.get_readable_string()),
+            kickers => {
+                let kickers: Vec<String> = kickers
+                    .iter()
+                    .map(|val| val.get_readable_string())
+                    .collect();
+                write!(f, ", {} kickers", kickers.join("-"))
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for RankDescription {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let primary = self.primary.get_readable_string();
+        let secondary = self
+            .secondary
+            .map_or("".to_string(), |val| val.get_readable_string());
+
+        match self.category {
+            RankCategory::Hand(class) => {
+                match class {
+                    HandClass::HighCard => write!(f, "{} High", primary),
+                    HandClass::Pair => write!(f, "Pair of {}s", primary),
+                    HandClass::TwoPair => write!(f, "Two Pair of {}s and {}s", primary, secondary),
+                    HandClass::ThreeOfAKind => write!(f, "Trip {}s", primary),
+                    HandClass::Straight => write!(f, "{} High Straight", primary),
+                    HandClass::Flush => write!(f, "{} High Flush", primary),
+                    HandClass::FullHouse => write!(f, "{}s Full of {}s", primary, secondary),
+                    HandClass::FourOfAKind => write!(f, "Quad {}s", primary),
+                    HandClass::StraightFlush => write!(f, "{} High Straight Flush", primary),
+                }?;
+                if f.alternate() {
+                    self.write_kickers(f)?;
+                }
+                Ok(())
+            }
+            RankCategory::LowA5 => {
+                let values: Vec<String> = std::iter::once(&self.primary)
+                    .chain(self.kickers.iter())
+                    .map(|val| val.get_char().to_string())
+                    .collect();
+                write!(f, "{}", values.join("-"))
+            }
+            RankCategory::Badugi(card_count) => {
+                match card_count {
+                    4 => write!(f, "{}-high Badugi", primary),
+                    _ => write!(f, "{}-high {}-card hand", primary, card_count),
+                }?;
+                if f.alternate() {
+                    self.write_kickers(f)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_pair_parts() {
+        let desc = RankDescription::from_hand_values(
+            HandClass::TwoPair,
+            &[
+                Value::Queen,
+                Value::Queen,
+                Value::Two,
+                Value::Two,
+                Value::King,
+            ],
+        );
+
+        assert_eq!(desc.primary, Value::Queen);
+        assert_eq!(desc.secondary, Some(Value::Two));
+        assert_eq!(desc.kickers, vec![Value::King]);
+        assert_eq!(desc.to_string(), "Two Pair of Queens and 2s");
+        assert_eq!(
+            format!("{:#}", desc),
+            "Two Pair of Queens and 2s, King kicker"
+        );
+    }
+
+    #[test]
+    fn low_and_badugi_strings() {
+        let low = RankDescription {
+            category: RankCategory::LowA5,
+            primary: Value::Eight,
+            secondary: None,
+            kickers: vec![Value::Seven, Value::Six, Value::Five, Value::Ace],
+        };
+        assert_eq!(low.to_string(), "8-7-6-5-A");
+
+        let badugi = RankDescription {
+            category: RankCategory::Badugi(4),
+            primary: Value::Ten,
+            secondary: None,
+            kickers: vec![Value::Seven, Value::Four, Value::Ace],
+        };
+        assert_eq!(badugi.to_string(), "10-high Badugi");
+
+        let three_card = RankDescription {
+            category: RankCategory::Badugi(3),
+            primary: Value::Jack,
+            secondary: None,
+            kickers: vec![Value::Ten, Value::Four],
+        };
+        assert_eq!(three_card.to_string(), "Jack-high 3-card hand");
+        assert_eq!(
+            three_card.describe(Verbosity::WithKickers),
+            "Jack-high 3-card hand, 10-4 kickers"
+        );
+        assert_eq!(low.describe(Verbosity::WithKickers), "8-7-6-5-A");
+    }
+}
@@ -16,6 +16,7 @@ use super::IntoRankStrengthIterator;
 /// The vectors returned by the iterator or `hand_number(&self)` is a list of hashsets of keys. The
 /// list is sorted by strength of the key's rank with index 0 being of the strongest rank.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RankResults<T: Hash + Eq + Copy> {
     hand_table: BTreeMap<usize, Vec<HashSet<T>>>,
 }
@@ -38,7 +39,7 @@ impl<T: Hash + Eq + Copy> RankResults<T> {
     }
 }
 
-impl<'a, T: Hash + Eq + Copy> IntoIterator for &'a RankResults<T> {
+impl<T: Hash + Eq + Copy> IntoIterator for &RankResults<T> {
     type Item = (usize, Vec<HashSet<T>>);
     type IntoIter = std::collections::btree_map::IntoIter<usize, Vec<HashSet<T>>>;
 
@@ -138,13 +139,13 @@ mod tests {
                         strength: 4321,
                         hand_rank: 5,
                         sub_rank: 12,
-                        description: Some("Some sick hand".to_string()),
+                        description: None,
                     }),
                     lo_rank: Some(LowA5Rank(BasicRank {
                         strength: 121,
                         hand_rank: 7,
                         sub_rank: 1,
-                        description: Some("Some good low hand".to_string()),
+                        description: None,
                     })),
                 },
             ),
@@ -155,7 +156,7 @@ mod tests {
                         strength: 2012,
                         hand_rank: 4,
                         sub_rank: 1232,
-                        description: Some("Another sick hand".to_string()),
+                        description: None,
                     }),
                     lo_rank: None,
                 },
@@ -168,9 +169,7 @@ mod tests {
             (0, vec![HashSet::from([1]), HashSet::from([2])]),
             (1, vec![HashSet::from([1])]),
         ]);
-        for (winner_col, expected_winner_col) in rank_results
-            .into_iter()
-            .zip(expected_rank_results.into_iter())
+        for (winner_col, expected_winner_col) in rank_results.into_iter().zip(expected_rank_results)
         {
             assert_eq!(winner_col, expected_winner_col);
         }
@@ -185,8 +184,8 @@ mod tests {
     impl IntoRankStrengthIterator for DoubleBoardRank {
         fn into_strength_iter(self) -> RankStrengthIterator {
             RankStrengthIterator::from(vec![
-                (*self.top_board_rank).strength,
-                (*self.bottom_board_rank).strength,
+                self.top_board_rank.strength,
+                self.bottom_board_rank.strength,
             ])
         }
     }
@@ -201,13 +200,13 @@ mod tests {
                         strength: 2034,
                         hand_rank: 4,
                         sub_rank: 8,
-                        description: Some("Decent high hand".to_string()),
+                        description: None,
                     }),
                     bottom_board_rank: HighRank(BasicRank {
                         strength: 7922,
                         hand_rank: 7,
                         sub_rank: 24,
-                        description: Some("Nutted high hand".to_string()),
+                        description: None,
                     }),
                 },
             ),
@@ -218,13 +217,13 @@ mod tests {
                         strength: 5000,
                         hand_rank: 6,
                         sub_rank: 54,
-                        description: Some("The nuts on the top board".to_string()),
+                        description: None,
                     }),
                     bottom_board_rank: HighRank(BasicRank {
                         strength: 800,
                         hand_rank: 1,
                         sub_rank: 27,
-                        description: Some("No pair hand".to_string()),
+                        description: None,
                     }),
                 },
             ),
@@ -237,9 +236,7 @@ mod tests {
             (1, vec![HashSet::from([1]), HashSet::from([2])]),
         ]);
 
-        for (winner_col, expected_winner_col) in rank_results
-            .into_iter()
-            .zip(expected_rank_results.into_iter())
+        for (winner_col, expected_winner_col) in rank_results.into_iter().zip(expected_rank_results)
         {
             assert_eq!(winner_col, expected_winner_col);
         }
@@ -254,7 +251,7 @@ mod tests {
                     strength: 4321,
                     hand_rank: 5,
                     sub_rank: 12,
-                    description: Some("Sick hand".to_string()),
+                    description: None,
                 }),
             ),
             (
@@ -263,7 +260,7 @@ mod tests {
                     strength: 4321,
                     hand_rank: 5,
                     sub_rank: 12,
-                    description: Some("Sick hand".to_string()),
+                    description: None,
                 }),
             ),
         ]);
@@ -271,9 +268,7 @@ mod tests {
         let rank_results = generate_winner_list(&ranks);
 
         let expected_rank_results = BTreeMap::from([(0, vec![HashSet::from([1, 2])])]);
-        for (winner_col, expected_winner_col) in rank_results
-            .into_iter()
-            .zip(expected_rank_results.into_iter())
+        for (winner_col, expected_winner_col) in rank_results.into_iter().zip(expected_rank_results)
         {
             assert_eq!(winner_col, expected_winner_col);
         }
@@ -287,9 +282,8 @@ mod tests {
 
         let expected_rank_results: RankResults<i32> = RankResults::new(BTreeMap::new());
 
-        for (winner_col, expected_winner_col) in rank_results
-            .into_iter()
-            .zip(expected_rank_results.into_iter())
+        for (winner_col, expected_winner_col) in
+            rank_results.into_iter().zip(&expected_rank_results)
         {
             assert_eq!(winner_col, expected_winner_col);
         }
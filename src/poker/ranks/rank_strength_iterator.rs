@@ -11,6 +11,7 @@ use super::BasicRank;
 /// representing a non-existent rank, useful in cases where not every player has a fully qualifying
 /// hand (e.g. Omaha Hi-Lo has an low hand criterion but it only applies if the player can make a
 /// hand with 5 distinct-rank cards within the range Ace to 8, 2 from theirs and 3 from the board).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RankStrengthIterator {
     ranks: HashMap<usize, u32>,
     idx: Option<usize>,
@@ -44,9 +45,8 @@ impl From<Vec<Option<u32>>> for RankStrengthIterator {
         Self {
             ranks: ranks
                 .into_iter()
-                .filter(|x| x.is_some())
-                .flatten()
                 .enumerate()
+                .filter_map(|(i, rank)| rank.map(|rank| (i, rank)))
                 .collect::<HashMap<usize, u32>>(),
             idx: None,
             len,
@@ -84,9 +84,8 @@ impl From<Vec<Option<BasicRank>>> for RankStrengthIterator {
         Self {
             ranks: ranks
                 .into_iter()
-                .filter(|opt_rank| opt_rank.is_some())
-                .map(|rank| rank.unwrap().strength)
                 .enumerate()
+                .filter_map(|(i, rank)| rank.map(|rank| (i, rank.strength)))
                 .collect::<HashMap<usize, u32>>(),
             idx: None,
             len,
@@ -140,3 +139,28 @@ pub trait IntoRankStrengthIterator {
     /// Creates a `RankStrengthIterator` from a rank type
     fn into_strength_iter(self) -> RankStrengthIterator;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_strengths_keep_their_positions() {
+        let strengths: Vec<Option<u32>> =
+            RankStrengthIterator::from(vec![None, Some(5), None, Some(7)]).collect();
+        assert_eq!(strengths, [None, Some(5), None, Some(7)]);
+    }
+
+    #[test]
+    fn missing_ranks_keep_their_positions() {
+        let rank = |strength| BasicRank {
+            strength,
+            hand_rank: 1,
+            sub_rank: 1,
+            description: None,
+        };
+        let strengths: Vec<Option<u32>> =
+            RankStrengthIterator::from(vec![None, Some(rank(5)), None, Some(rank(7))]).collect();
+        assert_eq!(strengths, [None, Some(5), None, Some(7)]);
+    }
+}
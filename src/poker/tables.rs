@@ -1,886 +0,0 @@
-// // The following hashmaps were generated based off of the Castus-Kev evaluator. All values within
-// // the hashmaps were derived from the tables in the `tables.h`.
-// //
-// // The following C code will generate a `tables.rs` file creating the two static hashmaps:
-//
-// #include "arrays.h"
-// #include <stddef.h>
-// #include <stdio.h>
-// #include <string.h>
-//
-// int main() {
-//     size_t flushes_size = sizeof(flushes) / sizeof(unsigned short);
-//
-//     FILE *table_file = fopen("tables.rs", "w+");
-//     FILE *code = fopen("main.c", "r");
-//
-//     if (!code) {
-//         fprintf(stderr, "Seems main.c is not in the current directory!\n");
-//     }
-//
-//     char *line = NULL;
-//     size_t len = 0;
-//     ssize_t bytes_read;
-//     while((bytes_read = getline(&line, &len, code)) != -1) {
-//         fprintf(table_file, "// %s", line);
-//     }
-//
-//     fclose(code);
-//     fprintf(table_file, "\nuse phf::phf_map;\n\n");
-//     fprintf(table_file, "pub static FLUSHES: phf::Map<u32, u16> = phf_map! {\n    ");
-//     {
-//         int written_elements = 0;
-//         for (unsigned i = 0; i < flushes_size; i++) {
-//             if (flushes[i] != 0) {
-//                 fprintf(table_file, "%uu32 => %u,", i, flushes[i]);
-//                 written_elements++;
-//                 if (written_elements % 10 == 0) {
-//                     fprintf(table_file, "\n    ");
-//                 } else {
-//                     fprintf(table_file, " ");
-//                 }
-//             }
-//         }
-//     }
-//     fprintf(table_file, "\n};\n\n");
-//
-//     size_t unique_size = sizeof(unique5) / sizeof(unsigned short);
-//     fprintf(table_file, "pub static UNIQUE5: phf::Map<u32, u16> = phf_map! {\n    ");
-//     {
-//         int written_elements = 0;
-//         for (unsigned i = 0; i < unique_size; i++) {
-//             if (unique5[i] != 0) {
-//                 fprintf(table_file, "%uu32 => %u,", i, unique5[i]);
-//                 written_elements++;
-//                 if (written_elements % 10 == 0) {
-//                     fprintf(table_file, "\n    ");
-//                 } else {
-//                     fprintf(table_file, " ");
-//                 }
-//             }
-//         }
-//     }
-//     fprintf(table_file, "\n};\n\n");
-//
-//     size_t hash_adjust_size = sizeof(hash_adjust) / sizeof(unsigned short);
-//     fprintf(table_file, "pub static HASH_ADJUST: [u16; %lu] = [\n    ", hash_adjust_size);
-//     {
-//         int written_elements = 0;
-//         for (unsigned i = 0; i < hash_adjust_size; i++) {
-//             fprintf(table_file, "%u,", hash_adjust[i]);
-//             written_elements++;
-//             if (written_elements % 10 == 0) {
-//                 fprintf(table_file, "\n    ");
-//             } else {
-//                 fprintf(table_file, " ");
-//             }
-//         }
-//     }
-//     fprintf(table_file, "\n];\n\n");
-//
-//     size_t hash_values_size = sizeof(hash_values) / sizeof(unsigned short);
-//     fprintf(table_file, "pub static HASH_VALUES: [u16; %lu] = [\n    ", hash_values_size);
-//     {
-//         int written_elements = 0;
-//         for (unsigned i = 0; i < hash_values_size; i++) {
-//             fprintf(table_file, "%u,", hash_values[i]);
-//             written_elements++;
-//             if (written_elements % 10 == 0) {
-//                 fprintf(table_file, "\n    ");
-//             } else {
-//                 fprintf(table_file, " ");
-//             }
-//         }
-//     }
-//     fprintf(table_file, "\n];\n");
-//     fclose(table_file);
-// }
-
-use phf::phf_map;
-
-pub static FLUSHES: phf::Map<u32, u16> = phf_map! {
-    31u32 => 9, 47u32 => 1599, 55u32 => 1598, 59u32 => 1597, 61u32 => 1596, 62u32 => 8, 79u32 => 1595, 87u32 => 1594, 91u32 => 1593, 93u32 => 1592,
-    94u32 => 1591, 103u32 => 1590, 107u32 => 1589, 109u32 => 1588, 110u32 => 1587, 115u32 => 1586, 117u32 => 1585, 118u32 => 1584, 121u32 => 1583, 122u32 => 1582,
-    124u32 => 7, 143u32 => 1581, 151u32 => 1580, 155u32 => 1579, 157u32 => 1578, 158u32 => 1577, 167u32 => 1576, 171u32 => 1575, 173u32 => 1574, 174u32 => 1573,
-    179u32 => 1572, 181u32 => 1571, 182u32 => 1570, 185u32 => 1569, 186u32 => 1568, 188u32 => 1567, 199u32 => 1566, 203u32 => 1565, 205u32 => 1564, 206u32 => 1563,
-    211u32 => 1562, 213u32 => 1561, 214u32 => 1560, 217u32 => 1559, 218u32 => 1558, 220u32 => 1557, 227u32 => 1556, 229u32 => 1555, 230u32 => 1554, 233u32 => 1553,
-    234u32 => 1552, 236u32 => 1551, 241u32 => 1550, 242u32 => 1549, 244u32 => 1548, 248u32 => 6, 271u32 => 1547, 279u32 => 1546, 283u32 => 1545, 285u32 => 1544,
-    286u32 => 1543, 295u32 => 1542, 299u32 => 1541, 301u32 => 1540, 302u32 => 1539, 307u32 => 1538, 309u32 => 1537, 310u32 => 1536, 313u32 => 1535, 314u32 => 1534,
-    316u32 => 1533, 327u32 => 1532, 331u32 => 1531, 333u32 => 1530, 334u32 => 1529, 339u32 => 1528, 341u32 => 1527, 342u32 => 1526, 345u32 => 1525, 346u32 => 1524,
-    348u32 => 1523, 355u32 => 1522, 357u32 => 1521, 358u32 => 1520, 361u32 => 1519, 362u32 => 1518, 364u32 => 1517, 369u32 => 1516, 370u32 => 1515, 372u32 => 1514,
-    376u32 => 1513, 391u32 => 1512, 395u32 => 1511, 397u32 => 1510, 398u32 => 1509, 403u32 => 1508, 405u32 => 1507, 406u32 => 1506, 409u32 => 1505, 410u32 => 1504,
-    412u32 => 1503, 419u32 => 1502, 421u32 => 1501, 422u32 => 1500, 425u32 => 1499, 426u32 => 1498, 428u32 => 1497, 433u32 => 1496, 434u32 => 1495, 436u32 => 1494,
-    440u32 => 1493, 451u32 => 1492, 453u32 => 1491, 454u32 => 1490, 457u32 => 1489, 458u32 => 1488, 460u32 => 1487, 465u32 => 1486, 466u32 => 1485, 468u32 => 1484,
-    472u32 => 1483, 481u32 => 1482, 482u32 => 1481, 484u32 => 1480, 488u32 => 1479, 496u32 => 5, 527u32 => 1478, 535u32 => 1477, 539u32 => 1476, 541u32 => 1475,
-    542u32 => 1474, 551u32 => 1473, 555u32 => 1472, 557u32 => 1471, 558u32 => 1470, 563u32 => 1469, 565u32 => 1468, 566u32 => 1467, 569u32 => 1466, 570u32 => 1465,
-    572u32 => 1464, 583u32 => 1463, 587u32 => 1462, 589u32 => 1461, 590u32 => 1460, 595u32 => 1459, 597u32 => 1458, 598u32 => 1457, 601u32 => 1456, 602u32 => 1455,
-    604u32 => 1454, 611u32 => 1453, 613u32 => 1452, 614u32 => 1451, 617u32 => 1450, 618u32 => 1449, 620u32 => 1448, 625u32 => 1447, 626u32 => 1446, 628u32 => 1445,
-    632u32 => 1444, 647u32 => 1443, 651u32 => 1442, 653u32 => 1441, 654u32 => 1440, 659u32 => 1439, 661u32 => 1438, 662u32 => 1437, 665u32 => 1436, 666u32 => 1435,
-    668u32 => 1434, 675u32 => 1433, 677u32 => 1432, 678u32 => 1431, 681u32 => 1430, 682u32 => 1429, 684u32 => 1428, 689u32 => 1427, 690u32 => 1426, 692u32 => 1425,
-    696u32 => 1424, 707u32 => 1423, 709u32 => 1422, 710u32 => 1421, 713u32 => 1420, 714u32 => 1419, 716u32 => 1418, 721u32 => 1417, 722u32 => 1416, 724u32 => 1415,
-    728u32 => 1414, 737u32 => 1413, 738u32 => 1412, 740u32 => 1411, 744u32 => 1410, 752u32 => 1409, 775u32 => 1408, 779u32 => 1407, 781u32 => 1406, 782u32 => 1405,
-    787u32 => 1404, 789u32 => 1403, 790u32 => 1402, 793u32 => 1401, 794u32 => 1400, 796u32 => 1399, 803u32 => 1398, 805u32 => 1397, 806u32 => 1396, 809u32 => 1395,
-    810u32 => 1394, 812u32 => 1393, 817u32 => 1392, 818u32 => 1391, 820u32 => 1390, 824u32 => 1389, 835u32 => 1388, 837u32 => 1387, 838u32 => 1386, 841u32 => 1385,
-    842u32 => 1384, 844u32 => 1383, 849u32 => 1382, 850u32 => 1381, 852u32 => 1380, 856u32 => 1379, 865u32 => 1378, 866u32 => 1377, 868u32 => 1376, 872u32 => 1375,
-    880u32 => 1374, 899u32 => 1373, 901u32 => 1372, 902u32 => 1371, 905u32 => 1370, 906u32 => 1369, 908u32 => 1368, 913u32 => 1367, 914u32 => 1366, 916u32 => 1365,
-    920u32 => 1364, 929u32 => 1363, 930u32 => 1362, 932u32 => 1361, 936u32 => 1360, 944u32 => 1359, 961u32 => 1358, 962u32 => 1357, 964u32 => 1356, 968u32 => 1355,
-    976u32 => 1354, 992u32 => 4, 1039u32 => 1353, 1047u32 => 1352, 1051u32 => 1351, 1053u32 => 1350, 1054u32 => 1349, 1063u32 => 1348, 1067u32 => 1347, 1069u32 => 1346,
-    1070u32 => 1345, 1075u32 => 1344, 1077u32 => 1343, 1078u32 => 1342, 1081u32 => 1341, 1082u32 => 1340, 1084u32 => 1339, 1095u32 => 1338, 1099u32 => 1337, 1101u32 => 1336,
-    1102u32 => 1335, 1107u32 => 1334, 1109u32 => 1333, 1110u32 => 1332, 1113u32 => 1331, 1114u32 => 1330, 1116u32 => 1329, 1123u32 => 1328, 1125u32 => 1327, 1126u32 => 1326,
-    1129u32 => 1325, 1130u32 => 1324, 1132u32 => 1323, 1137u32 => 1322, 1138u32 => 1321, 1140u32 => 1320, 1144u32 => 1319, 1159u32 => 1318, 1163u32 => 1317, 1165u32 => 1316,
-    1166u32 => 1315, 1171u32 => 1314, 1173u32 => 1313, 1174u32 => 1312, 1177u32 => 1311, 1178u32 => 1310, 1180u32 => 1309, 1187u32 => 1308, 1189u32 => 1307, 1190u32 => 1306,
-    1193u32 => 1305, 1194u32 => 1304, 1196u32 => 1303, 1201u32 => 1302, 1202u32 => 1301, 1204u32 => 1300, 1208u32 => 1299, 1219u32 => 1298, 1221u32 => 1297, 1222u32 => 1296,
-    1225u32 => 1295, 1226u32 => 1294, 1228u32 => 1293, 1233u32 => 1292, 1234u32 => 1291, 1236u32 => 1290, 1240u32 => 1289, 1249u32 => 1288, 1250u32 => 1287, 1252u32 => 1286,
-    1256u32 => 1285, 1264u32 => 1284, 1287u32 => 1283, 1291u32 => 1282, 1293u32 => 1281, 1294u32 => 1280, 1299u32 => 1279, 1301u32 => 1278, 1302u32 => 1277, 1305u32 => 1276,
-    1306u32 => 1275, 1308u32 => 1274, 1315u32 => 1273, 1317u32 => 1272, 1318u32 => 1271, 1321u32 => 1270, 1322u32 => 1269, 1324u32 => 1268, 1329u32 => 1267, 1330u32 => 1266,
-    1332u32 => 1265, 1336u32 => 1264, 1347u32 => 1263, 1349u32 => 1262, 1350u32 => 1261, 1353u32 => 1260, 1354u32 => 1259, 1356u32 => 1258, 1361u32 => 1257, 1362u32 => 1256,
-    1364u32 => 1255, 1368u32 => 1254, 1377u32 => 1253, 1378u32 => 1252, 1380u32 => 1251, 1384u32 => 1250, 1392u32 => 1249, 1411u32 => 1248, 1413u32 => 1247, 1414u32 => 1246,
-    1417u32 => 1245, 1418u32 => 1244, 1420u32 => 1243, 1425u32 => 1242, 1426u32 => 1241, 1428u32 => 1240, 1432u32 => 1239, 1441u32 => 1238, 1442u32 => 1237, 1444u32 => 1236,
-    1448u32 => 1235, 1456u32 => 1234, 1473u32 => 1233, 1474u32 => 1232, 1476u32 => 1231, 1480u32 => 1230, 1488u32 => 1229, 1504u32 => 1228, 1543u32 => 1227, 1547u32 => 1226,
-    1549u32 => 1225, 1550u32 => 1224, 1555u32 => 1223, 1557u32 => 1222, 1558u32 => 1221, 1561u32 => 1220, 1562u32 => 1219, 1564u32 => 1218, 1571u32 => 1217, 1573u32 => 1216,
-    1574u32 => 1215, 1577u32 => 1214, 1578u32 => 1213, 1580u32 => 1212, 1585u32 => 1211, 1586u32 => 1210, 1588u32 => 1209, 1592u32 => 1208, 1603u32 => 1207, 1605u32 => 1206,
-    1606u32 => 1205, 1609u32 => 1204, 1610u32 => 1203, 1612u32 => 1202, 1617u32 => 1201, 1618u32 => 1200, 1620u32 => 1199, 1624u32 => 1198, 1633u32 => 1197, 1634u32 => 1196,
-    1636u32 => 1195, 1640u32 => 1194, 1648u32 => 1193, 1667u32 => 1192, 1669u32 => 1191, 1670u32 => 1190, 1673u32 => 1189, 1674u32 => 1188, 1676u32 => 1187, 1681u32 => 1186,
-    1682u32 => 1185, 1684u32 => 1184, 1688u32 => 1183, 1697u32 => 1182, 1698u32 => 1181, 1700u32 => 1180, 1704u32 => 1179, 1712u32 => 1178, 1729u32 => 1177, 1730u32 => 1176,
-    1732u32 => 1175, 1736u32 => 1174, 1744u32 => 1173, 1760u32 => 1172, 1795u32 => 1171, 1797u32 => 1170, 1798u32 => 1169, 1801u32 => 1168, 1802u32 => 1167, 1804u32 => 1166,
-    1809u32 => 1165, 1810u32 => 1164, 1812u32 => 1163, 1816u32 => 1162, 1825u32 => 1161, 1826u32 => 1160, 1828u32 => 1159, 1832u32 => 1158, 1840u32 => 1157, 1857u32 => 1156,
-    1858u32 => 1155, 1860u32 => 1154, 1864u32 => 1153, 1872u32 => 1152, 1888u32 => 1151, 1921u32 => 1150, 1922u32 => 1149, 1924u32 => 1148, 1928u32 => 1147, 1936u32 => 1146,
-    1952u32 => 1145, 1984u32 => 3, 2063u32 => 1144, 2071u32 => 1143, 2075u32 => 1142, 2077u32 => 1141, 2078u32 => 1140, 2087u32 => 1139, 2091u32 => 1138, 2093u32 => 1137,
-    2094u32 => 1136, 2099u32 => 1135, 2101u32 => 1134, 2102u32 => 1133, 2105u32 => 1132, 2106u32 => 1131, 2108u32 => 1130, 2119u32 => 1129, 2123u32 => 1128, 2125u32 => 1127,
-    2126u32 => 1126, 2131u32 => 1125, 2133u32 => 1124, 2134u32 => 1123, 2137u32 => 1122, 2138u32 => 1121, 2140u32 => 1120, 2147u32 => 1119, 2149u32 => 1118, 2150u32 => 1117,
-    2153u32 => 1116, 2154u32 => 1115, 2156u32 => 1114, 2161u32 => 1113, 2162u32 => 1112, 2164u32 => 1111, 2168u32 => 1110, 2183u32 => 1109, 2187u32 => 1108, 2189u32 => 1107,
-    2190u32 => 1106, 2195u32 => 1105, 2197u32 => 1104, 2198u32 => 1103, 2201u32 => 1102, 2202u32 => 1101, 2204u32 => 1100, 2211u32 => 1099, 2213u32 => 1098, 2214u32 => 1097,
-    2217u32 => 1096, 2218u32 => 1095, 2220u32 => 1094, 2225u32 => 1093, 2226u32 => 1092, 2228u32 => 1091, 2232u32 => 1090, 2243u32 => 1089, 2245u32 => 1088, 2246u32 => 1087,
-    2249u32 => 1086, 2250u32 => 1085, 2252u32 => 1084, 2257u32 => 1083, 2258u32 => 1082, 2260u32 => 1081, 2264u32 => 1080, 2273u32 => 1079, 2274u32 => 1078, 2276u32 => 1077,
-    2280u32 => 1076, 2288u32 => 1075, 2311u32 => 1074, 2315u32 => 1073, 2317u32 => 1072, 2318u32 => 1071, 2323u32 => 1070, 2325u32 => 1069, 2326u32 => 1068, 2329u32 => 1067,
-    2330u32 => 1066, 2332u32 => 1065, 2339u32 => 1064, 2341u32 => 1063, 2342u32 => 1062, 2345u32 => 1061, 2346u32 => 1060, 2348u32 => 1059, 2353u32 => 1058, 2354u32 => 1057,
-    2356u32 => 1056, 2360u32 => 1055, 2371u32 => 1054, 2373u32 => 1053, 2374u32 => 1052, 2377u32 => 1051, 2378u32 => 1050, 2380u32 => 1049, 2385u32 => 1048, 2386u32 => 1047,
-    2388u32 => 1046, 2392u32 => 1045, 2401u32 => 1044, 2402u32 => 1043, 2404u32 => 1042, 2408u32 => 1041, 2416u32 => 1040, 2435u32 => 1039, 2437u32 => 1038, 2438u32 => 1037,
-    2441u32 => 1036, 2442u32 => 1035, 2444u32 => 1034, 2449u32 => 1033, 2450u32 => 1032, 2452u32 => 1031, 2456u32 => 1030, 2465u32 => 1029, 2466u32 => 1028, 2468u32 => 1027,
-    2472u32 => 1026, 2480u32 => 1025, 2497u32 => 1024, 2498u32 => 1023, 2500u32 => 1022, 2504u32 => 1021, 2512u32 => 1020, 2528u32 => 1019, 2567u32 => 1018, 2571u32 => 1017,
-    2573u32 => 1016, 2574u32 => 1015, 2579u32 => 1014, 2581u32 => 1013, 2582u32 => 1012, 2585u32 => 1011, 2586u32 => 1010, 2588u32 => 1009, 2595u32 => 1008, 2597u32 => 1007,
-    2598u32 => 1006, 2601u32 => 1005, 2602u32 => 1004, 2604u32 => 1003, 2609u32 => 1002, 2610u32 => 1001, 2612u32 => 1000, 2616u32 => 999, 2627u32 => 998, 2629u32 => 997,
-    2630u32 => 996, 2633u32 => 995, 2634u32 => 994, 2636u32 => 993, 2641u32 => 992, 2642u32 => 991, 2644u32 => 990, 2648u32 => 989, 2657u32 => 988, 2658u32 => 987,
-    2660u32 => 986, 2664u32 => 985, 2672u32 => 984, 2691u32 => 983, 2693u32 => 982, 2694u32 => 981, 2697u32 => 980, 2698u32 => 979, 2700u32 => 978, 2705u32 => 977,
-    2706u32 => 976, 2708u32 => 975, 2712u32 => 974, 2721u32 => 973, 2722u32 => 972, 2724u32 => 971, 2728u32 => 970, 2736u32 => 969, 2753u32 => 968, 2754u32 => 967,
-    2756u32 => 966, 2760u32 => 965, 2768u32 => 964, 2784u32 => 963, 2819u32 => 962, 2821u32 => 961, 2822u32 => 960, 2825u32 => 959, 2826u32 => 958, 2828u32 => 957,
-    2833u32 => 956, 2834u32 => 955, 2836u32 => 954, 2840u32 => 953, 2849u32 => 952, 2850u32 => 951, 2852u32 => 950, 2856u32 => 949, 2864u32 => 948, 2881u32 => 947,
-    2882u32 => 946, 2884u32 => 945, 2888u32 => 944, 2896u32 => 943, 2912u32 => 942, 2945u32 => 941, 2946u32 => 940, 2948u32 => 939, 2952u32 => 938, 2960u32 => 937,
-    2976u32 => 936, 3008u32 => 935, 3079u32 => 934, 3083u32 => 933, 3085u32 => 932, 3086u32 => 931, 3091u32 => 930, 3093u32 => 929, 3094u32 => 928, 3097u32 => 927,
-    3098u32 => 926, 3100u32 => 925, 3107u32 => 924, 3109u32 => 923, 3110u32 => 922, 3113u32 => 921, 3114u32 => 920, 3116u32 => 919, 3121u32 => 918, 3122u32 => 917,
-    3124u32 => 916, 3128u32 => 915, 3139u32 => 914, 3141u32 => 913, 3142u32 => 912, 3145u32 => 911, 3146u32 => 910, 3148u32 => 909, 3153u32 => 908, 3154u32 => 907,
-    3156u32 => 906, 3160u32 => 905, 3169u32 => 904, 3170u32 => 903, 3172u32 => 902, 3176u32 => 901, 3184u32 => 900, 3203u32 => 899, 3205u32 => 898, 3206u32 => 897,
-    3209u32 => 896, 3210u32 => 895, 3212u32 => 894, 3217u32 => 893, 3218u32 => 892, 3220u32 => 891, 3224u32 => 890, 3233u32 => 889, 3234u32 => 888, 3236u32 => 887,
-    3240u32 => 886, 3248u32 => 885, 3265u32 => 884, 3266u32 => 883, 3268u32 => 882, 3272u32 => 881, 3280u32 => 880, 3296u32 => 879, 3331u32 => 878, 3333u32 => 877,
-    3334u32 => 876, 3337u32 => 875, 3338u32 => 874, 3340u32 => 873, 3345u32 => 872, 3346u32 => 871, 3348u32 => 870, 3352u32 => 869, 3361u32 => 868, 3362u32 => 867,
-    3364u32 => 866, 3368u32 => 865, 3376u32 => 864, 3393u32 => 863, 3394u32 => 862, 3396u32 => 861, 3400u32 => 860, 3408u32 => 859, 3424u32 => 858, 3457u32 => 857,
-    3458u32 => 856, 3460u32 => 855, 3464u32 => 854, 3472u32 => 853, 3488u32 => 852, 3520u32 => 851, 3587u32 => 850, 3589u32 => 849, 3590u32 => 848, 3593u32 => 847,
-    3594u32 => 846, 3596u32 => 845, 3601u32 => 844, 3602u32 => 843, 3604u32 => 842, 3608u32 => 841, 3617u32 => 840, 3618u32 => 839, 3620u32 => 838, 3624u32 => 837,
-    3632u32 => 836, 3649u32 => 835, 3650u32 => 834, 3652u32 => 833, 3656u32 => 832, 3664u32 => 831, 3680u32 => 830, 3713u32 => 829, 3714u32 => 828, 3716u32 => 827,
-    3720u32 => 826, 3728u32 => 825, 3744u32 => 824, 3776u32 => 823, 3841u32 => 822, 3842u32 => 821, 3844u32 => 820, 3848u32 => 819, 3856u32 => 818, 3872u32 => 817,
-    3904u32 => 816, 3968u32 => 2, 4111u32 => 10, 4119u32 => 815, 4123u32 => 814, 4125u32 => 813, 4126u32 => 812, 4135u32 => 811, 4139u32 => 810, 4141u32 => 809,
-    4142u32 => 808, 4147u32 => 807, 4149u32 => 806, 4150u32 => 805, 4153u32 => 804, 4154u32 => 803, 4156u32 => 802, 4167u32 => 801, 4171u32 => 800, 4173u32 => 799,
-    4174u32 => 798, 4179u32 => 797, 4181u32 => 796, 4182u32 => 795, 4185u32 => 794, 4186u32 => 793, 4188u32 => 792, 4195u32 => 791, 4197u32 => 790, 4198u32 => 789,
-    4201u32 => 788, 4202u32 => 787, 4204u32 => 786, 4209u32 => 785, 4210u32 => 784, 4212u32 => 783, 4216u32 => 782, 4231u32 => 781, 4235u32 => 780, 4237u32 => 779,
-    4238u32 => 778, 4243u32 => 777, 4245u32 => 776, 4246u32 => 775, 4249u32 => 774, 4250u32 => 773, 4252u32 => 772, 4259u32 => 771, 4261u32 => 770, 4262u32 => 769,
-    4265u32 => 768, 4266u32 => 767, 4268u32 => 766, 4273u32 => 765, 4274u32 => 764, 4276u32 => 763, 4280u32 => 762, 4291u32 => 761, 4293u32 => 760, 4294u32 => 759,
-    4297u32 => 758, 4298u32 => 757, 4300u32 => 756, 4305u32 => 755, 4306u32 => 754, 4308u32 => 753, 4312u32 => 752, 4321u32 => 751, 4322u32 => 750, 4324u32 => 749,
-    4328u32 => 748, 4336u32 => 747, 4359u32 => 746, 4363u32 => 745, 4365u32 => 744, 4366u32 => 743, 4371u32 => 742, 4373u32 => 741, 4374u32 => 740, 4377u32 => 739,
-    4378u32 => 738, 4380u32 => 737, 4387u32 => 736, 4389u32 => 735, 4390u32 => 734, 4393u32 => 733, 4394u32 => 732, 4396u32 => 731, 4401u32 => 730, 4402u32 => 729,
-    4404u32 => 728, 4408u32 => 727, 4419u32 => 726, 4421u32 => 725, 4422u32 => 724, 4425u32 => 723, 4426u32 => 722, 4428u32 => 721, 4433u32 => 720, 4434u32 => 719,
-    4436u32 => 718, 4440u32 => 717, 4449u32 => 716, 4450u32 => 715, 4452u32 => 714, 4456u32 => 713, 4464u32 => 712, 4483u32 => 711, 4485u32 => 710, 4486u32 => 709,
-    4489u32 => 708, 4490u32 => 707, 4492u32 => 706, 4497u32 => 705, 4498u32 => 704, 4500u32 => 703, 4504u32 => 702, 4513u32 => 701, 4514u32 => 700, 4516u32 => 699,
-    4520u32 => 698, 4528u32 => 697, 4545u32 => 696, 4546u32 => 695, 4548u32 => 694, 4552u32 => 693, 4560u32 => 692, 4576u32 => 691, 4615u32 => 690, 4619u32 => 689,
-    4621u32 => 688, 4622u32 => 687, 4627u32 => 686, 4629u32 => 685, 4630u32 => 684, 4633u32 => 683, 4634u32 => 682, 4636u32 => 681, 4643u32 => 680, 4645u32 => 679,
-    4646u32 => 678, 4649u32 => 677, 4650u32 => 676, 4652u32 => 675, 4657u32 => 674, 4658u32 => 673, 4660u32 => 672, 4664u32 => 671, 4675u32 => 670, 4677u32 => 669,
-    4678u32 => 668, 4681u32 => 667, 4682u32 => 666, 4684u32 => 665, 4689u32 => 664, 4690u32 => 663, 4692u32 => 662, 4696u32 => 661, 4705u32 => 660, 4706u32 => 659,
-    4708u32 => 658, 4712u32 => 657, 4720u32 => 656, 4739u32 => 655, 4741u32 => 654, 4742u32 => 653, 4745u32 => 652, 4746u32 => 651, 4748u32 => 650, 4753u32 => 649,
-    4754u32 => 648, 4756u32 => 647, 4760u32 => 646, 4769u32 => 645, 4770u32 => 644, 4772u32 => 643, 4776u32 => 642, 4784u32 => 641, 4801u32 => 640, 4802u32 => 639,
-    4804u32 => 638, 4808u32 => 637, 4816u32 => 636, 4832u32 => 635, 4867u32 => 634, 4869u32 => 633, 4870u32 => 632, 4873u32 => 631, 4874u32 => 630, 4876u32 => 629,
-    4881u32 => 628, 4882u32 => 627, 4884u32 => 626, 4888u32 => 625, 4897u32 => 624, 4898u32 => 623, 4900u32 => 622, 4904u32 => 621, 4912u32 => 620, 4929u32 => 619,
-    4930u32 => 618, 4932u32 => 617, 4936u32 => 616, 4944u32 => 615, 4960u32 => 614, 4993u32 => 613, 4994u32 => 612, 4996u32 => 611, 5000u32 => 610, 5008u32 => 609,
-    5024u32 => 608, 5056u32 => 607, 5127u32 => 606, 5131u32 => 605, 5133u32 => 604, 5134u32 => 603, 5139u32 => 602, 5141u32 => 601, 5142u32 => 600, 5145u32 => 599,
-    5146u32 => 598, 5148u32 => 597, 5155u32 => 596, 5157u32 => 595, 5158u32 => 594, 5161u32 => 593, 5162u32 => 592, 5164u32 => 591, 5169u32 => 590, 5170u32 => 589,
-    5172u32 => 588, 5176u32 => 587, 5187u32 => 586, 5189u32 => 585, 5190u32 => 584, 5193u32 => 583, 5194u32 => 582, 5196u32 => 581, 5201u32 => 580, 5202u32 => 579,
-    5204u32 => 578, 5208u32 => 577, 5217u32 => 576, 5218u32 => 575, 5220u32 => 574, 5224u32 => 573, 5232u32 => 572, 5251u32 => 571, 5253u32 => 570, 5254u32 => 569,
-    5257u32 => 568, 5258u32 => 567, 5260u32 => 566, 5265u32 => 565, 5266u32 => 564, 5268u32 => 563, 5272u32 => 562, 5281u32 => 561, 5282u32 => 560, 5284u32 => 559,
-    5288u32 => 558, 5296u32 => 557, 5313u32 => 556, 5314u32 => 555, 5316u32 => 554, 5320u32 => 553, 5328u32 => 552, 5344u32 => 551, 5379u32 => 550, 5381u32 => 549,
-    5382u32 => 548, 5385u32 => 547, 5386u32 => 546, 5388u32 => 545, 5393u32 => 544, 5394u32 => 543, 5396u32 => 542, 5400u32 => 541, 5409u32 => 540, 5410u32 => 539,
-    5412u32 => 538, 5416u32 => 537, 5424u32 => 536, 5441u32 => 535, 5442u32 => 534, 5444u32 => 533, 5448u32 => 532, 5456u32 => 531, 5472u32 => 530, 5505u32 => 529,
-    5506u32 => 528, 5508u32 => 527, 5512u32 => 526, 5520u32 => 525, 5536u32 => 524, 5568u32 => 523, 5635u32 => 522, 5637u32 => 521, 5638u32 => 520, 5641u32 => 519,
-    5642u32 => 518, 5644u32 => 517, 5649u32 => 516, 5650u32 => 515, 5652u32 => 514, 5656u32 => 513, 5665u32 => 512, 5666u32 => 511, 5668u32 => 510, 5672u32 => 509,
-    5680u32 => 508, 5697u32 => 507, 5698u32 => 506, 5700u32 => 505, 5704u32 => 504, 5712u32 => 503, 5728u32 => 502, 5761u32 => 501, 5762u32 => 500, 5764u32 => 499,
-    5768u32 => 498, 5776u32 => 497, 5792u32 => 496, 5824u32 => 495, 5889u32 => 494, 5890u32 => 493, 5892u32 => 492, 5896u32 => 491, 5904u32 => 490, 5920u32 => 489,
-    5952u32 => 488, 6016u32 => 487, 6151u32 => 486, 6155u32 => 485, 6157u32 => 484, 6158u32 => 483, 6163u32 => 482, 6165u32 => 481, 6166u32 => 480, 6169u32 => 479,
-    6170u32 => 478, 6172u32 => 477, 6179u32 => 476, 6181u32 => 475, 6182u32 => 474, 6185u32 => 473, 6186u32 => 472, 6188u32 => 471, 6193u32 => 470, 6194u32 => 469,
-    6196u32 => 468, 6200u32 => 467, 6211u32 => 466, 6213u32 => 465, 6214u32 => 464, 6217u32 => 463, 6218u32 => 462, 6220u32 => 461, 6225u32 => 460, 6226u32 => 459,
-    6228u32 => 458, 6232u32 => 457, 6241u32 => 456, 6242u32 => 455, 6244u32 => 454, 6248u32 => 453, 6256u32 => 452, 6275u32 => 451, 6277u32 => 450, 6278u32 => 449,
-    6281u32 => 448, 6282u32 => 447, 6284u32 => 446, 6289u32 => 445, 6290u32 => 444, 6292u32 => 443, 6296u32 => 442, 6305u32 => 441, 6306u32 => 440, 6308u32 => 439,
-    6312u32 => 438, 6320u32 => 437, 6337u32 => 436, 6338u32 => 435, 6340u32 => 434, 6344u32 => 433, 6352u32 => 432, 6368u32 => 431, 6403u32 => 430, 6405u32 => 429,
-    6406u32 => 428, 6409u32 => 427, 6410u32 => 426, 6412u32 => 425, 6417u32 => 424, 6418u32 => 423, 6420u32 => 422, 6424u32 => 421, 6433u32 => 420, 6434u32 => 419,
-    6436u32 => 418, 6440u32 => 417, 6448u32 => 416, 6465u32 => 415, 6466u32 => 414, 6468u32 => 413, 6472u32 => 412, 6480u32 => 411, 6496u32 => 410, 6529u32 => 409,
-    6530u32 => 408, 6532u32 => 407, 6536u32 => 406, 6544u32 => 405, 6560u32 => 404, 6592u32 => 403, 6659u32 => 402, 6661u32 => 401, 6662u32 => 400, 6665u32 => 399,
-    6666u32 => 398, 6668u32 => 397, 6673u32 => 396, 6674u32 => 395, 6676u32 => 394, 6680u32 => 393, 6689u32 => 392, 6690u32 => 391, 6692u32 => 390, 6696u32 => 389,
-    6704u32 => 388, 6721u32 => 387, 6722u32 => 386, 6724u32 => 385, 6728u32 => 384, 6736u32 => 383, 6752u32 => 382, 6785u32 => 381, 6786u32 => 380, 6788u32 => 379,
-    6792u32 => 378, 6800u32 => 377, 6816u32 => 376, 6848u32 => 375, 6913u32 => 374, 6914u32 => 373, 6916u32 => 372, 6920u32 => 371, 6928u32 => 370, 6944u32 => 369,
-    6976u32 => 368, 7040u32 => 367, 7171u32 => 366, 7173u32 => 365, 7174u32 => 364, 7177u32 => 363, 7178u32 => 362, 7180u32 => 361, 7185u32 => 360, 7186u32 => 359,
-    7188u32 => 358, 7192u32 => 357, 7201u32 => 356, 7202u32 => 355, 7204u32 => 354, 7208u32 => 353, 7216u32 => 352, 7233u32 => 351, 7234u32 => 350, 7236u32 => 349,
-    7240u32 => 348, 7248u32 => 347, 7264u32 => 346, 7297u32 => 345, 7298u32 => 344, 7300u32 => 343, 7304u32 => 342, 7312u32 => 341, 7328u32 => 340, 7360u32 => 339,
-    7425u32 => 338, 7426u32 => 337, 7428u32 => 336, 7432u32 => 335, 7440u32 => 334, 7456u32 => 333, 7488u32 => 332, 7552u32 => 331, 7681u32 => 330, 7682u32 => 329,
-    7684u32 => 328, 7688u32 => 327, 7696u32 => 326, 7712u32 => 325, 7744u32 => 324, 7808u32 => 323, 7936u32 => 1,
-};
-
-pub static UNIQUE5: phf::Map<u32, u16> = phf_map! {
-    31u32 => 1608, 47u32 => 7462, 55u32 => 7461, 59u32 => 7460, 61u32 => 7459, 62u32 => 1607, 79u32 => 7458, 87u32 => 7457, 91u32 => 7456, 93u32 => 7455,
-    94u32 => 7454, 103u32 => 7453, 107u32 => 7452, 109u32 => 7451, 110u32 => 7450, 115u32 => 7449, 117u32 => 7448, 118u32 => 7447, 121u32 => 7446, 122u32 => 7445,
-    124u32 => 1606, 143u32 => 7444, 151u32 => 7443, 155u32 => 7442, 157u32 => 7441, 158u32 => 7440, 167u32 => 7439, 171u32 => 7438, 173u32 => 7437, 174u32 => 7436,
-    179u32 => 7435, 181u32 => 7434, 182u32 => 7433, 185u32 => 7432, 186u32 => 7431, 188u32 => 7430, 199u32 => 7429, 203u32 => 7428, 205u32 => 7427, 206u32 => 7426,
-    211u32 => 7425, 213u32 => 7424, 214u32 => 7423, 217u32 => 7422, 218u32 => 7421, 220u32 => 7420, 227u32 => 7419, 229u32 => 7418, 230u32 => 7417, 233u32 => 7416,
-    234u32 => 7415, 236u32 => 7414, 241u32 => 7413, 242u32 => 7412, 244u32 => 7411, 248u32 => 1605, 271u32 => 7410, 279u32 => 7409, 283u32 => 7408, 285u32 => 7407,
-    286u32 => 7406, 295u32 => 7405, 299u32 => 7404, 301u32 => 7403, 302u32 => 7402, 307u32 => 7401, 309u32 => 7400, 310u32 => 7399, 313u32 => 7398, 314u32 => 7397,
-    316u32 => 7396, 327u32 => 7395, 331u32 => 7394, 333u32 => 7393, 334u32 => 7392, 339u32 => 7391, 341u32 => 7390, 342u32 => 7389, 345u32 => 7388, 346u32 => 7387,
-    348u32 => 7386, 355u32 => 7385, 357u32 => 7384, 358u32 => 7383, 361u32 => 7382, 362u32 => 7381, 364u32 => 7380, 369u32 => 7379, 370u32 => 7378, 372u32 => 7377,
-    376u32 => 7376, 391u32 => 7375, 395u32 => 7374, 397u32 => 7373, 398u32 => 7372, 403u32 => 7371, 405u32 => 7370, 406u32 => 7369, 409u32 => 7368, 410u32 => 7367,
-    412u32 => 7366, 419u32 => 7365, 421u32 => 7364, 422u32 => 7363, 425u32 => 7362, 426u32 => 7361, 428u32 => 7360, 433u32 => 7359, 434u32 => 7358, 436u32 => 7357,
-    440u32 => 7356, 451u32 => 7355, 453u32 => 7354, 454u32 => 7353, 457u32 => 7352, 458u32 => 7351, 460u32 => 7350, 465u32 => 7349, 466u32 => 7348, 468u32 => 7347,
-    472u32 => 7346, 481u32 => 7345, 482u32 => 7344, 484u32 => 7343, 488u32 => 7342, 496u32 => 1604, 527u32 => 7341, 535u32 => 7340, 539u32 => 7339, 541u32 => 7338,
-    542u32 => 7337, 551u32 => 7336, 555u32 => 7335, 557u32 => 7334, 558u32 => 7333, 563u32 => 7332, 565u32 => 7331, 566u32 => 7330, 569u32 => 7329, 570u32 => 7328,
-    572u32 => 7327, 583u32 => 7326, 587u32 => 7325, 589u32 => 7324, 590u32 => 7323, 595u32 => 7322, 597u32 => 7321, 598u32 => 7320, 601u32 => 7319, 602u32 => 7318,
-    604u32 => 7317, 611u32 => 7316, 613u32 => 7315, 614u32 => 7314, 617u32 => 7313, 618u32 => 7312, 620u32 => 7311, 625u32 => 7310, 626u32 => 7309, 628u32 => 7308,
-    632u32 => 7307, 647u32 => 7306, 651u32 => 7305, 653u32 => 7304, 654u32 => 7303, 659u32 => 7302, 661u32 => 7301, 662u32 => 7300, 665u32 => 7299, 666u32 => 7298,
-    668u32 => 7297, 675u32 => 7296, 677u32 => 7295, 678u32 => 7294, 681u32 => 7293, 682u32 => 7292, 684u32 => 7291, 689u32 => 7290, 690u32 => 7289, 692u32 => 7288,
-    696u32 => 7287, 707u32 => 7286, 709u32 => 7285, 710u32 => 7284, 713u32 => 7283, 714u32 => 7282, 716u32 => 7281, 721u32 => 7280, 722u32 => 7279, 724u32 => 7278,
-    728u32 => 7277, 737u32 => 7276, 738u32 => 7275, 740u32 => 7274, 744u32 => 7273, 752u32 => 7272, 775u32 => 7271, 779u32 => 7270, 781u32 => 7269, 782u32 => 7268,
-    787u32 => 7267, 789u32 => 7266, 790u32 => 7265, 793u32 => 7264, 794u32 => 7263, 796u32 => 7262, 803u32 => 7261, 805u32 => 7260, 806u32 => 7259, 809u32 => 7258,
-    810u32 => 7257, 812u32 => 7256, 817u32 => 7255, 818u32 => 7254, 820u32 => 7253, 824u32 => 7252, 835u32 => 7251, 837u32 => 7250, 838u32 => 7249, 841u32 => 7248,
-    842u32 => 7247, 844u32 => 7246, 849u32 => 7245, 850u32 => 7244, 852u32 => 7243, 856u32 => 7242, 865u32 => 7241, 866u32 => 7240, 868u32 => 7239, 872u32 => 7238,
-    880u32 => 7237, 899u32 => 7236, 901u32 => 7235, 902u32 => 7234, 905u32 => 7233, 906u32 => 7232, 908u32 => 7231, 913u32 => 7230, 914u32 => 7229, 916u32 => 7228,
-    920u32 => 7227, 929u32 => 7226, 930u32 => 7225, 932u32 => 7224, 936u32 => 7223, 944u32 => 7222, 961u32 => 7221, 962u32 => 7220, 964u32 => 7219, 968u32 => 7218,
-    976u32 => 7217, 992u32 => 1603, 1039u32 => 7216, 1047u32 => 7215, 1051u32 => 7214, 1053u32 => 7213, 1054u32 => 7212, 1063u32 => 7211, 1067u32 => 7210, 1069u32 => 7209,
-    1070u32 => 7208, 1075u32 => 7207, 1077u32 => 7206, 1078u32 => 7205, 1081u32 => 7204, 1082u32 => 7203, 1084u32 => 7202, 1095u32 => 7201, 1099u32 => 7200, 1101u32 => 7199,
-    1102u32 => 7198, 1107u32 => 7197, 1109u32 => 7196, 1110u32 => 7195, 1113u32 => 7194, 1114u32 => 7193, 1116u32 => 7192, 1123u32 => 7191, 1125u32 => 7190, 1126u32 => 7189,
-    1129u32 => 7188, 1130u32 => 7187, 1132u32 => 7186, 1137u32 => 7185, 1138u32 => 7184, 1140u32 => 7183, 1144u32 => 7182, 1159u32 => 7181, 1163u32 => 7180, 1165u32 => 7179,
-    1166u32 => 7178, 1171u32 => 7177, 1173u32 => 7176, 1174u32 => 7175, 1177u32 => 7174, 1178u32 => 7173, 1180u32 => 7172, 1187u32 => 7171, 1189u32 => 7170, 1190u32 => 7169,
-    1193u32 => 7168, 1194u32 => 7167, 1196u32 => 7166, 1201u32 => 7165, 1202u32 => 7164, 1204u32 => 7163, 1208u32 => 7162, 1219u32 => 7161, 1221u32 => 7160, 1222u32 => 7159,
-    1225u32 => 7158, 1226u32 => 7157, 1228u32 => 7156, 1233u32 => 7155, 1234u32 => 7154, 1236u32 => 7153, 1240u32 => 7152, 1249u32 => 7151, 1250u32 => 7150, 1252u32 => 7149,
-    1256u32 => 7148, 1264u32 => 7147, 1287u32 => 7146, 1291u32 => 7145, 1293u32 => 7144, 1294u32 => 7143, 1299u32 => 7142, 1301u32 => 7141, 1302u32 => 7140, 1305u32 => 7139,
-    1306u32 => 7138, 1308u32 => 7137, 1315u32 => 7136, 1317u32 => 7135, 1318u32 => 7134, 1321u32 => 7133, 1322u32 => 7132, 1324u32 => 7131, 1329u32 => 7130, 1330u32 => 7129,
-    1332u32 => 7128, 1336u32 => 7127, 1347u32 => 7126, 1349u32 => 7125, 1350u32 => 7124, 1353u32 => 7123, 1354u32 => 7122, 1356u32 => 7121, 1361u32 => 7120, 1362u32 => 7119,
-    1364u32 => 7118, 1368u32 => 7117, 1377u32 => 7116, 1378u32 => 7115, 1380u32 => 7114, 1384u32 => 7113, 1392u32 => 7112, 1411u32 => 7111, 1413u32 => 7110, 1414u32 => 7109,
-    1417u32 => 7108, 1418u32 => 7107, 1420u32 => 7106, 1425u32 => 7105, 1426u32 => 7104, 1428u32 => 7103, 1432u32 => 7102, 1441u32 => 7101, 1442u32 => 7100, 1444u32 => 7099,
-    1448u32 => 7098, 1456u32 => 7097, 1473u32 => 7096, 1474u32 => 7095, 1476u32 => 7094, 1480u32 => 7093, 1488u32 => 7092, 1504u32 => 7091, 1543u32 => 7090, 1547u32 => 7089,
-    1549u32 => 7088, 1550u32 => 7087, 1555u32 => 7086, 1557u32 => 7085, 1558u32 => 7084, 1561u32 => 7083, 1562u32 => 7082, 1564u32 => 7081, 1571u32 => 7080, 1573u32 => 7079,
-    1574u32 => 7078, 1577u32 => 7077, 1578u32 => 7076, 1580u32 => 7075, 1585u32 => 7074, 1586u32 => 7073, 1588u32 => 7072, 1592u32 => 7071, 1603u32 => 7070, 1605u32 => 7069,
-    1606u32 => 7068, 1609u32 => 7067, 1610u32 => 7066, 1612u32 => 7065, 1617u32 => 7064, 1618u32 => 7063, 1620u32 => 7062, 1624u32 => 7061, 1633u32 => 7060, 1634u32 => 7059,
-    1636u32 => 7058, 1640u32 => 7057, 1648u32 => 7056, 1667u32 => 7055, 1669u32 => 7054, 1670u32 => 7053, 1673u32 => 7052, 1674u32 => 7051, 1676u32 => 7050, 1681u32 => 7049,
-    1682u32 => 7048, 1684u32 => 7047, 1688u32 => 7046, 1697u32 => 7045, 1698u32 => 7044, 1700u32 => 7043, 1704u32 => 7042, 1712u32 => 7041, 1729u32 => 7040, 1730u32 => 7039,
-    1732u32 => 7038, 1736u32 => 7037, 1744u32 => 7036, 1760u32 => 7035, 1795u32 => 7034, 1797u32 => 7033, 1798u32 => 7032, 1801u32 => 7031, 1802u32 => 7030, 1804u32 => 7029,
-    1809u32 => 7028, 1810u32 => 7027, 1812u32 => 7026, 1816u32 => 7025, 1825u32 => 7024, 1826u32 => 7023, 1828u32 => 7022, 1832u32 => 7021, 1840u32 => 7020, 1857u32 => 7019,
-    1858u32 => 7018, 1860u32 => 7017, 1864u32 => 7016, 1872u32 => 7015, 1888u32 => 7014, 1921u32 => 7013, 1922u32 => 7012, 1924u32 => 7011, 1928u32 => 7010, 1936u32 => 7009,
-    1952u32 => 7008, 1984u32 => 1602, 2063u32 => 7007, 2071u32 => 7006, 2075u32 => 7005, 2077u32 => 7004, 2078u32 => 7003, 2087u32 => 7002, 2091u32 => 7001, 2093u32 => 7000,
-    2094u32 => 6999, 2099u32 => 6998, 2101u32 => 6997, 2102u32 => 6996, 2105u32 => 6995, 2106u32 => 6994, 2108u32 => 6993, 2119u32 => 6992, 2123u32 => 6991, 2125u32 => 6990,
-    2126u32 => 6989, 2131u32 => 6988, 2133u32 => 6987, 2134u32 => 6986, 2137u32 => 6985, 2138u32 => 6984, 2140u32 => 6983, 2147u32 => 6982, 2149u32 => 6981, 2150u32 => 6980,
-    2153u32 => 6979, 2154u32 => 6978, 2156u32 => 6977, 2161u32 => 6976, 2162u32 => 6975, 2164u32 => 6974, 2168u32 => 6973, 2183u32 => 6972, 2187u32 => 6971, 2189u32 => 6970,
-    2190u32 => 6969, 2195u32 => 6968, 2197u32 => 6967, 2198u32 => 6966, 2201u32 => 6965, 2202u32 => 6964, 2204u32 => 6963, 2211u32 => 6962, 2213u32 => 6961, 2214u32 => 6960,
-    2217u32 => 6959, 2218u32 => 6958, 2220u32 => 6957, 2225u32 => 6956, 2226u32 => 6955, 2228u32 => 6954, 2232u32 => 6953, 2243u32 => 6952, 2245u32 => 6951, 2246u32 => 6950,
-    2249u32 => 6949, 2250u32 => 6948, 2252u32 => 6947, 2257u32 => 6946, 2258u32 => 6945, 2260u32 => 6944, 2264u32 => 6943, 2273u32 => 6942, 2274u32 => 6941, 2276u32 => 6940,
-    2280u32 => 6939, 2288u32 => 6938, 2311u32 => 6937, 2315u32 => 6936, 2317u32 => 6935, 2318u32 => 6934, 2323u32 => 6933, 2325u32 => 6932, 2326u32 => 6931, 2329u32 => 6930,
-    2330u32 => 6929, 2332u32 => 6928, 2339u32 => 6927, 2341u32 => 6926, 2342u32 => 6925, 2345u32 => 6924, 2346u32 => 6923, 2348u32 => 6922, 2353u32 => 6921, 2354u32 => 6920,
-    2356u32 => 6919, 2360u32 => 6918, 2371u32 => 6917, 2373u32 => 6916, 2374u32 => 6915, 2377u32 => 6914, 2378u32 => 6913, 2380u32 => 6912, 2385u32 => 6911, 2386u32 => 6910,
-    2388u32 => 6909, 2392u32 => 6908, 2401u32 => 6907, 2402u32 => 6906, 2404u32 => 6905, 2408u32 => 6904, 2416u32 => 6903, 2435u32 => 6902, 2437u32 => 6901, 2438u32 => 6900,
-    2441u32 => 6899, 2442u32 => 6898, 2444u32 => 6897, 2449u32 => 6896, 2450u32 => 6895, 2452u32 => 6894, 2456u32 => 6893, 2465u32 => 6892, 2466u32 => 6891, 2468u32 => 6890,
-    2472u32 => 6889, 2480u32 => 6888, 2497u32 => 6887, 2498u32 => 6886, 2500u32 => 6885, 2504u32 => 6884, 2512u32 => 6883, 2528u32 => 6882, 2567u32 => 6881, 2571u32 => 6880,
-    2573u32 => 6879, 2574u32 => 6878, 2579u32 => 6877, 2581u32 => 6876, 2582u32 => 6875, 2585u32 => 6874, 2586u32 => 6873, 2588u32 => 6872, 2595u32 => 6871, 2597u32 => 6870,
-    2598u32 => 6869, 2601u32 => 6868, 2602u32 => 6867, 2604u32 => 6866, 2609u32 => 6865, 2610u32 => 6864, 2612u32 => 6863, 2616u32 => 6862, 2627u32 => 6861, 2629u32 => 6860,
-    2630u32 => 6859, 2633u32 => 6858, 2634u32 => 6857, 2636u32 => 6856, 2641u32 => 6855, 2642u32 => 6854, 2644u32 => 6853, 2648u32 => 6852, 2657u32 => 6851, 2658u32 => 6850,
-    2660u32 => 6849, 2664u32 => 6848, 2672u32 => 6847, 2691u32 => 6846, 2693u32 => 6845, 2694u32 => 6844, 2697u32 => 6843, 2698u32 => 6842, 2700u32 => 6841, 2705u32 => 6840,
-    2706u32 => 6839, 2708u32 => 6838, 2712u32 => 6837, 2721u32 => 6836, 2722u32 => 6835, 2724u32 => 6834, 2728u32 => 6833, 2736u32 => 6832, 2753u32 => 6831, 2754u32 => 6830,
-    2756u32 => 6829, 2760u32 => 6828, 2768u32 => 6827, 2784u32 => 6826, 2819u32 => 6825, 2821u32 => 6824, 2822u32 => 6823, 2825u32 => 6822, 2826u32 => 6821, 2828u32 => 6820,
-    2833u32 => 6819, 2834u32 => 6818, 2836u32 => 6817, 2840u32 => 6816, 2849u32 => 6815, 2850u32 => 6814, 2852u32 => 6813, 2856u32 => 6812, 2864u32 => 6811, 2881u32 => 6810,
-    2882u32 => 6809, 2884u32 => 6808, 2888u32 => 6807, 2896u32 => 6806, 2912u32 => 6805, 2945u32 => 6804, 2946u32 => 6803, 2948u32 => 6802, 2952u32 => 6801, 2960u32 => 6800,
-    2976u32 => 6799, 3008u32 => 6798, 3079u32 => 6797, 3083u32 => 6796, 3085u32 => 6795, 3086u32 => 6794, 3091u32 => 6793, 3093u32 => 6792, 3094u32 => 6791, 3097u32 => 6790,
-    3098u32 => 6789, 3100u32 => 6788, 3107u32 => 6787, 3109u32 => 6786, 3110u32 => 6785, 3113u32 => 6784, 3114u32 => 6783, 3116u32 => 6782, 3121u32 => 6781, 3122u32 => 6780,
-    3124u32 => 6779, 3128u32 => 6778, 3139u32 => 6777, 3141u32 => 6776, 3142u32 => 6775, 3145u32 => 6774, 3146u32 => 6773, 3148u32 => 6772, 3153u32 => 6771, 3154u32 => 6770,
-    3156u32 => 6769, 3160u32 => 6768, 3169u32 => 6767, 3170u32 => 6766, 3172u32 => 6765, 3176u32 => 6764, 3184u32 => 6763, 3203u32 => 6762, 3205u32 => 6761, 3206u32 => 6760,
-    3209u32 => 6759, 3210u32 => 6758, 3212u32 => 6757, 3217u32 => 6756, 3218u32 => 6755, 3220u32 => 6754, 3224u32 => 6753, 3233u32 => 6752, 3234u32 => 6751, 3236u32 => 6750,
-    3240u32 => 6749, 3248u32 => 6748, 3265u32 => 6747, 3266u32 => 6746, 3268u32 => 6745, 3272u32 => 6744, 3280u32 => 6743, 3296u32 => 6742, 3331u32 => 6741, 3333u32 => 6740,
-    3334u32 => 6739, 3337u32 => 6738, 3338u32 => 6737, 3340u32 => 6736, 3345u32 => 6735, 3346u32 => 6734, 3348u32 => 6733, 3352u32 => 6732, 3361u32 => 6731, 3362u32 => 6730,
-    3364u32 => 6729, 3368u32 => 6728, 3376u32 => 6727, 3393u32 => 6726, 3394u32 => 6725, 3396u32 => 6724, 3400u32 => 6723, 3408u32 => 6722, 3424u32 => 6721, 3457u32 => 6720,
-    3458u32 => 6719, 3460u32 => 6718, 3464u32 => 6717, 3472u32 => 6716, 3488u32 => 6715, 3520u32 => 6714, 3587u32 => 6713, 3589u32 => 6712, 3590u32 => 6711, 3593u32 => 6710,
-    3594u32 => 6709, 3596u32 => 6708, 3601u32 => 6707, 3602u32 => 6706, 3604u32 => 6705, 3608u32 => 6704, 3617u32 => 6703, 3618u32 => 6702, 3620u32 => 6701, 3624u32 => 6700,
-    3632u32 => 6699, 3649u32 => 6698, 3650u32 => 6697, 3652u32 => 6696, 3656u32 => 6695, 3664u32 => 6694, 3680u32 => 6693, 3713u32 => 6692, 3714u32 => 6691, 3716u32 => 6690,
-    3720u32 => 6689, 3728u32 => 6688, 3744u32 => 6687, 3776u32 => 6686, 3841u32 => 6685, 3842u32 => 6684, 3844u32 => 6683, 3848u32 => 6682, 3856u32 => 6681, 3872u32 => 6680,
-    3904u32 => 6679, 3968u32 => 1601, 4111u32 => 1609, 4119u32 => 6678, 4123u32 => 6677, 4125u32 => 6676, 4126u32 => 6675, 4135u32 => 6674, 4139u32 => 6673, 4141u32 => 6672,
-    4142u32 => 6671, 4147u32 => 6670, 4149u32 => 6669, 4150u32 => 6668, 4153u32 => 6667, 4154u32 => 6666, 4156u32 => 6665, 4167u32 => 6664, 4171u32 => 6663, 4173u32 => 6662,
-    4174u32 => 6661, 4179u32 => 6660, 4181u32 => 6659, 4182u32 => 6658, 4185u32 => 6657, 4186u32 => 6656, 4188u32 => 6655, 4195u32 => 6654, 4197u32 => 6653, 4198u32 => 6652,
-    4201u32 => 6651, 4202u32 => 6650, 4204u32 => 6649, 4209u32 => 6648, 4210u32 => 6647, 4212u32 => 6646, 4216u32 => 6645, 4231u32 => 6644, 4235u32 => 6643, 4237u32 => 6642,
-    4238u32 => 6641, 4243u32 => 6640, 4245u32 => 6639, 4246u32 => 6638, 4249u32 => 6637, 4250u32 => 6636, 4252u32 => 6635, 4259u32 => 6634, 4261u32 => 6633, 4262u32 => 6632,
-    4265u32 => 6631, 4266u32 => 6630, 4268u32 => 6629, 4273u32 => 6628, 4274u32 => 6627, 4276u32 => 6626, 4280u32 => 6625, 4291u32 => 6624, 4293u32 => 6623, 4294u32 => 6622,
-    4297u32 => 6621, 4298u32 => 6620, 4300u32 => 6619, 4305u32 => 6618, 4306u32 => 6617, 4308u32 => 6616, 4312u32 => 6615, 4321u32 => 6614, 4322u32 => 6613, 4324u32 => 6612,
-    4328u32 => 6611, 4336u32 => 6610, 4359u32 => 6609, 4363u32 => 6608, 4365u32 => 6607, 4366u32 => 6606, 4371u32 => 6605, 4373u32 => 6604, 4374u32 => 6603, 4377u32 => 6602,
-    4378u32 => 6601, 4380u32 => 6600, 4387u32 => 6599, 4389u32 => 6598, 4390u32 => 6597, 4393u32 => 6596, 4394u32 => 6595, 4396u32 => 6594, 4401u32 => 6593, 4402u32 => 6592,
-    4404u32 => 6591, 4408u32 => 6590, 4419u32 => 6589, 4421u32 => 6588, 4422u32 => 6587, 4425u32 => 6586, 4426u32 => 6585, 4428u32 => 6584, 4433u32 => 6583, 4434u32 => 6582,
-    4436u32 => 6581, 4440u32 => 6580, 4449u32 => 6579, 4450u32 => 6578, 4452u32 => 6577, 4456u32 => 6576, 4464u32 => 6575, 4483u32 => 6574, 4485u32 => 6573, 4486u32 => 6572,
-    4489u32 => 6571, 4490u32 => 6570, 4492u32 => 6569, 4497u32 => 6568, 4498u32 => 6567, 4500u32 => 6566, 4504u32 => 6565, 4513u32 => 6564, 4514u32 => 6563, 4516u32 => 6562,
-    4520u32 => 6561, 4528u32 => 6560, 4545u32 => 6559, 4546u32 => 6558, 4548u32 => 6557, 4552u32 => 6556, 4560u32 => 6555, 4576u32 => 6554, 4615u32 => 6553, 4619u32 => 6552,
-    4621u32 => 6551, 4622u32 => 6550, 4627u32 => 6549, 4629u32 => 6548, 4630u32 => 6547, 4633u32 => 6546, 4634u32 => 6545, 4636u32 => 6544, 4643u32 => 6543, 4645u32 => 6542,
-    4646u32 => 6541, 4649u32 => 6540, 4650u32 => 6539, 4652u32 => 6538, 4657u32 => 6537, 4658u32 => 6536, 4660u32 => 6535, 4664u32 => 6534, 4675u32 => 6533, 4677u32 => 6532,
-    4678u32 => 6531, 4681u32 => 6530, 4682u32 => 6529, 4684u32 => 6528, 4689u32 => 6527, 4690u32 => 6526, 4692u32 => 6525, 4696u32 => 6524, 4705u32 => 6523, 4706u32 => 6522,
-    4708u32 => 6521, 4712u32 => 6520, 4720u32 => 6519, 4739u32 => 6518, 4741u32 => 6517, 4742u32 => 6516, 4745u32 => 6515, 4746u32 => 6514, 4748u32 => 6513, 4753u32 => 6512,
-    4754u32 => 6511, 4756u32 => 6510, 4760u32 => 6509, 4769u32 => 6508, 4770u32 => 6507, 4772u32 => 6506, 4776u32 => 6505, 4784u32 => 6504, 4801u32 => 6503, 4802u32 => 6502,
-    4804u32 => 6501, 4808u32 => 6500, 4816u32 => 6499, 4832u32 => 6498, 4867u32 => 6497, 4869u32 => 6496, 4870u32 => 6495, 4873u32 => 6494, 4874u32 => 6493, 4876u32 => 6492,
-    4881u32 => 6491, 4882u32 => 6490, 4884u32 => 6489, 4888u32 => 6488, 4897u32 => 6487, 4898u32 => 6486, 4900u32 => 6485, 4904u32 => 6484, 4912u32 => 6483, 4929u32 => 6482,
-    4930u32 => 6481, 4932u32 => 6480, 4936u32 => 6479, 4944u32 => 6478, 4960u32 => 6477, 4993u32 => 6476, 4994u32 => 6475, 4996u32 => 6474, 5000u32 => 6473, 5008u32 => 6472,
-    5024u32 => 6471, 5056u32 => 6470, 5127u32 => 6469, 5131u32 => 6468, 5133u32 => 6467, 5134u32 => 6466, 5139u32 => 6465, 5141u32 => 6464, 5142u32 => 6463, 5145u32 => 6462,
-    5146u32 => 6461, 5148u32 => 6460, 5155u32 => 6459, 5157u32 => 6458, 5158u32 => 6457, 5161u32 => 6456, 5162u32 => 6455, 5164u32 => 6454, 5169u32 => 6453, 5170u32 => 6452,
-    5172u32 => 6451, 5176u32 => 6450, 5187u32 => 6449, 5189u32 => 6448, 5190u32 => 6447, 5193u32 => 6446, 5194u32 => 6445, 5196u32 => 6444, 5201u32 => 6443, 5202u32 => 6442,
-    5204u32 => 6441, 5208u32 => 6440, 5217u32 => 6439, 5218u32 => 6438, 5220u32 => 6437, 5224u32 => 6436, 5232u32 => 6435, 5251u32 => 6434, 5253u32 => 6433, 5254u32 => 6432,
-    5257u32 => 6431, 5258u32 => 6430, 5260u32 => 6429, 5265u32 => 6428, 5266u32 => 6427, 5268u32 => 6426, 5272u32 => 6425, 5281u32 => 6424, 5282u32 => 6423, 5284u32 => 6422,
-    5288u32 => 6421, 5296u32 => 6420, 5313u32 => 6419, 5314u32 => 6418, 5316u32 => 6417, 5320u32 => 6416, 5328u32 => 6415, 5344u32 => 6414, 5379u32 => 6413, 5381u32 => 6412,
-    5382u32 => 6411, 5385u32 => 6410, 5386u32 => 6409, 5388u32 => 6408, 5393u32 => 6407, 5394u32 => 6406, 5396u32 => 6405, 5400u32 => 6404, 5409u32 => 6403, 5410u32 => 6402,
-    5412u32 => 6401, 5416u32 => 6400, 5424u32 => 6399, 5441u32 => 6398, 5442u32 => 6397, 5444u32 => 6396, 5448u32 => 6395, 5456u32 => 6394, 5472u32 => 6393, 5505u32 => 6392,
-    5506u32 => 6391, 5508u32 => 6390, 5512u32 => 6389, 5520u32 => 6388, 5536u32 => 6387, 5568u32 => 6386, 5635u32 => 6385, 5637u32 => 6384, 5638u32 => 6383, 5641u32 => 6382,
-    5642u32 => 6381, 5644u32 => 6380, 5649u32 => 6379, 5650u32 => 6378, 5652u32 => 6377, 5656u32 => 6376, 5665u32 => 6375, 5666u32 => 6374, 5668u32 => 6373, 5672u32 => 6372,
-    5680u32 => 6371, 5697u32 => 6370, 5698u32 => 6369, 5700u32 => 6368, 5704u32 => 6367, 5712u32 => 6366, 5728u32 => 6365, 5761u32 => 6364, 5762u32 => 6363, 5764u32 => 6362,
-    5768u32 => 6361, 5776u32 => 6360, 5792u32 => 6359, 5824u32 => 6358, 5889u32 => 6357, 5890u32 => 6356, 5892u32 => 6355, 5896u32 => 6354, 5904u32 => 6353, 5920u32 => 6352,
-    5952u32 => 6351, 6016u32 => 6350, 6151u32 => 6349, 6155u32 => 6348, 6157u32 => 6347, 6158u32 => 6346, 6163u32 => 6345, 6165u32 => 6344, 6166u32 => 6343, 6169u32 => 6342,
-    6170u32 => 6341, 6172u32 => 6340, 6179u32 => 6339, 6181u32 => 6338, 6182u32 => 6337, 6185u32 => 6336, 6186u32 => 6335, 6188u32 => 6334, 6193u32 => 6333, 6194u32 => 6332,
-    6196u32 => 6331, 6200u32 => 6330, 6211u32 => 6329, 6213u32 => 6328, 6214u32 => 6327, 6217u32 => 6326, 6218u32 => 6325, 6220u32 => 6324, 6225u32 => 6323, 6226u32 => 6322,
-    6228u32 => 6321, 6232u32 => 6320, 6241u32 => 6319, 6242u32 => 6318, 6244u32 => 6317, 6248u32 => 6316, 6256u32 => 6315, 6275u32 => 6314, 6277u32 => 6313, 6278u32 => 6312,
-    6281u32 => 6311, 6282u32 => 6310, 6284u32 => 6309, 6289u32 => 6308, 6290u32 => 6307, 6292u32 => 6306, 6296u32 => 6305, 6305u32 => 6304, 6306u32 => 6303, 6308u32 => 6302,
-    6312u32 => 6301, 6320u32 => 6300, 6337u32 => 6299, 6338u32 => 6298, 6340u32 => 6297, 6344u32 => 6296, 6352u32 => 6295, 6368u32 => 6294, 6403u32 => 6293, 6405u32 => 6292,
-    6406u32 => 6291, 6409u32 => 6290, 6410u32 => 6289, 6412u32 => 6288, 6417u32 => 6287, 6418u32 => 6286, 6420u32 => 6285, 6424u32 => 6284, 6433u32 => 6283, 6434u32 => 6282,
-    6436u32 => 6281, 6440u32 => 6280, 6448u32 => 6279, 6465u32 => 6278, 6466u32 => 6277, 6468u32 => 6276, 6472u32 => 6275, 6480u32 => 6274, 6496u32 => 6273, 6529u32 => 6272,
-    6530u32 => 6271, 6532u32 => 6270, 6536u32 => 6269, 6544u32 => 6268, 6560u32 => 6267, 6592u32 => 6266, 6659u32 => 6265, 6661u32 => 6264, 6662u32 => 6263, 6665u32 => 6262,
-    6666u32 => 6261, 6668u32 => 6260, 6673u32 => 6259, 6674u32 => 6258, 6676u32 => 6257, 6680u32 => 6256, 6689u32 => 6255, 6690u32 => 6254, 6692u32 => 6253, 6696u32 => 6252,
-    6704u32 => 6251, 6721u32 => 6250, 6722u32 => 6249, 6724u32 => 6248, 6728u32 => 6247, 6736u32 => 6246, 6752u32 => 6245, 6785u32 => 6244, 6786u32 => 6243, 6788u32 => 6242,
-    6792u32 => 6241, 6800u32 => 6240, 6816u32 => 6239, 6848u32 => 6238, 6913u32 => 6237, 6914u32 => 6236, 6916u32 => 6235, 6920u32 => 6234, 6928u32 => 6233, 6944u32 => 6232,
-    6976u32 => 6231, 7040u32 => 6230, 7171u32 => 6229, 7173u32 => 6228, 7174u32 => 6227, 7177u32 => 6226, 7178u32 => 6225, 7180u32 => 6224, 7185u32 => 6223, 7186u32 => 6222,
-    7188u32 => 6221, 7192u32 => 6220, 7201u32 => 6219, 7202u32 => 6218, 7204u32 => 6217, 7208u32 => 6216, 7216u32 => 6215, 7233u32 => 6214, 7234u32 => 6213, 7236u32 => 6212,
-    7240u32 => 6211, 7248u32 => 6210, 7264u32 => 6209, 7297u32 => 6208, 7298u32 => 6207, 7300u32 => 6206, 7304u32 => 6205, 7312u32 => 6204, 7328u32 => 6203, 7360u32 => 6202,
-    7425u32 => 6201, 7426u32 => 6200, 7428u32 => 6199, 7432u32 => 6198, 7440u32 => 6197, 7456u32 => 6196, 7488u32 => 6195, 7552u32 => 6194, 7681u32 => 6193, 7682u32 => 6192,
-    7684u32 => 6191, 7688u32 => 6190, 7696u32 => 6189, 7712u32 => 6188, 7744u32 => 6187, 7808u32 => 6186, 7936u32 => 1600,
-};
-
-pub static HASH_ADJUST: [u16; 512] = [
-    0, 5628, 7017, 1298, 2918, 2442, 8070, 6383, 6383, 7425, 2442, 5628, 8044, 7425, 3155, 6383,
-    2918, 7452, 1533, 6849, 5586, 7452, 7452, 1533, 2209, 6029, 2794, 3509, 7992, 7733, 7452, 131,
-    6029, 4491, 1814, 7452, 6110, 3155, 7077, 6675, 532, 1334, 7555, 5325, 3056, 1403, 1403, 3969,
-    4491, 1403, 7592, 522, 8070, 1403, 0, 1905, 3584, 2918, 922, 3304, 6675, 0, 7622, 7017, 3210,
-    2139, 1403, 5225, 0, 3969, 7992, 5743, 5499, 5499, 5345, 7452, 522, 305, 3056, 7017, 7017,
-    2139, 1338, 3056, 7452, 1403, 6799, 3204, 3290, 4099, 1814, 2191, 4099, 5743, 1570, 1334, 7363,
-    1905, 0, 6799, 4400, 1480, 6029, 1905, 0, 7525, 2028, 2794, 131, 7646, 3155, 4986, 1858, 2442,
-    7992, 1607, 3584, 4986, 706, 6029, 5345, 7622, 6322, 5196, 1905, 6847, 218, 1785, 0, 4099,
-    2981, 6849, 4751, 3950, 7733, 3056, 5499, 4055, 6849, 1533, 131, 5196, 2918, 3879, 5325, 2794,
-    6029, 0, 0, 322, 7452, 6178, 2918, 2320, 6675, 3056, 6675, 1533, 6029, 1428, 2280, 2171, 6788,
-    7452, 3325, 107, 4262, 311, 5562, 7857, 6110, 2139, 4942, 4600, 1905, 0, 3083, 5345, 7452,
-    6675, 0, 6112, 4099, 7017, 1338, 6799, 2918, 1232, 3584, 522, 6029, 5325, 1403, 6759, 6849,
-    508, 6675, 2987, 7745, 6870, 896, 7452, 1232, 4400, 12, 2981, 3850, 4491, 6849, 0, 6675, 747,
-    4491, 7525, 6675, 7452, 7992, 6921, 7323, 6849, 3056, 1199, 2139, 6029, 6029, 190, 4351, 7891,
-    4400, 7134, 1533, 1194, 3950, 6675, 5345, 6383, 7622, 131, 1905, 2883, 6383, 1533, 5345, 2794,
-    4303, 1403, 0, 1338, 2794, 992, 4871, 6383, 4099, 2794, 3889, 6184, 3304, 1905, 6383, 3950,
-    3056, 522, 1810, 3975, 7622, 7452, 522, 6799, 5866, 7084, 7622, 6528, 2798, 7452, 1810, 7907,
-    642, 5345, 1905, 6849, 6675, 7745, 2918, 4751, 3229, 2139, 6029, 5207, 6601, 2139, 7452, 5890,
-    1428, 5628, 7622, 2139, 3146, 2400, 578, 941, 7672, 1814, 3210, 1533, 4491, 12, 2918, 1900,
-    7425, 2794, 2987, 3465, 1377, 3822, 3969, 3210, 859, 5499, 6878, 1377, 3056, 4027, 8065, 8065,
-    5207, 4400, 4303, 3210, 3210, 0, 6675, 357, 5628, 5512, 1905, 3452, 1403, 7646, 859, 6788,
-    3210, 2139, 378, 5663, 7733, 870, 0, 4491, 4813, 2110, 578, 2139, 3056, 4099, 1905, 1298, 4672,
-    2191, 3950, 5499, 3969, 4974, 6323, 6029, 7414, 6383, 0, 4974, 3210, 795, 4099, 131, 5345,
-    5345, 6576, 1810, 1621, 4400, 2918, 1905, 2442, 2679, 6322, 7452, 2110, 1403, 6383, 2653, 5132,
-    6856, 7841, 2794, 6110, 2028, 6675, 7425, 6999, 7441, 6029, 183, 6675, 4400, 859, 1403, 2794,
-    5985, 5345, 1533, 322, 4400, 1227, 5890, 4474, 4491, 3574, 8166, 6849, 7086, 5345, 5345, 5459,
-    3584, 6675, 3969, 7579, 8044, 2295, 2577, 1480, 5743, 3304, 5499, 330, 4303, 6863, 3822, 4600,
-    4751, 5628, 3822, 2918, 6675, 2400, 6663, 1403, 6849, 6029, 3145, 6110, 3210, 747, 3229, 3056,
-    2918, 7733, 330, 4055, 7322, 5628, 2987, 3056, 1905, 2903, 669, 5325, 2845, 4099, 5225, 6283,
-    4099, 5000, 642, 4055, 5345, 8034, 2918, 1041, 5769, 7051, 1538, 2918, 3366, 608, 4303, 3921,
-    0, 2918, 1905, 218, 6687, 5963, 859, 3083, 2987, 896, 5056, 1905, 2918, 4415, 7966, 7646, 2883,
-    5628, 7017, 8029, 6528, 4474, 6322, 5562, 6669, 4610, 7006,
-];
-
-pub static HASH_VALUES: [u16; 8192] = [
-    148, 2934, 166, 5107, 4628, 166, 166, 166, 166, 3033, 166, 4692, 166, 5571, 2225, 166, 5340,
-    3423, 166, 3191, 1752, 166, 5212, 166, 166, 3520, 166, 166, 166, 1867, 166, 3313, 166, 3461,
-    166, 166, 3174, 1737, 5010, 5008, 166, 4344, 2868, 3877, 166, 4089, 166, 5041, 4748, 4073,
-    4066, 5298, 3502, 1812, 166, 5309, 166, 233, 3493, 166, 166, 3728, 5236, 4252, 4010, 2149, 166,
-    164, 4580, 3039, 4804, 3874, 166, 6170, 2812, 166, 4334, 166, 166, 166, 166, 166, 166, 1862,
-    224, 2131, 6081, 166, 2710, 166, 166, 166, 4765, 166, 1964, 5060, 166, 1897, 166, 3987, 166,
-    166, 5566, 2021, 166, 45, 166, 166, 3283, 3932, 166, 166, 3519, 166, 166, 291, 166, 166, 5132,
-    2800, 166, 166, 166, 5531, 4054, 166, 3509, 166, 166, 4908, 3028, 1756, 1910, 4671, 2729, 5224,
-    166, 121, 3327, 3317, 166, 181, 2371, 5541, 166, 1787, 2666, 5134, 5698, 166, 5480, 3870, 166,
-    3823, 166, 3165, 5343, 5123, 5089, 166, 2422, 3724, 166, 2735, 1953, 5724, 4444, 4871, 166,
-    166, 5001, 5512, 3133, 5171, 166, 2216, 166, 4877, 4542, 166, 166, 166, 5270, 166, 166, 166,
-    1922, 69, 3547, 166, 166, 166, 166, 166, 231, 4547, 5155, 3357, 3464, 166, 72, 3332, 166, 4392,
-    5971, 3896, 4451, 3173, 2569, 166, 4466, 2518, 1698, 2850, 5349, 166, 166, 4457, 5062, 166,
-    2202, 1650, 2191, 166, 1950, 2583, 166, 5293, 2032, 5893, 166, 3994, 5392, 3878, 96, 166, 166,
-    3195, 166, 4001, 1900, 2513, 6027, 166, 166, 166, 166, 5407, 166, 166, 2332, 5125, 5891, 3096,
-    3172, 166, 166, 3065, 166, 166, 4535, 166, 166, 166, 4553, 3131, 3693, 166, 2255, 2613, 166,
-    166, 166, 166, 2866, 166, 166, 166, 2940, 5333, 3199, 166, 2628, 4312, 166, 166, 1794, 4681,
-    2058, 3606, 166, 166, 3542, 2166, 4696, 2520, 166, 4739, 166, 2563, 166, 166, 3681, 166, 166,
-    166, 4127, 1967, 2972, 166, 5227, 166, 166, 5551, 4255, 56, 166, 5553, 3219, 4367, 166, 3218,
-    4749, 2886, 3695, 3711, 2228, 166, 166, 166, 2268, 5054, 3749, 4825, 166, 4933, 4992, 4530,
-    166, 4892, 3400, 166, 197, 166, 6078, 166, 166, 3971, 166, 166, 5357, 1852, 3377, 166, 5196,
-    3740, 5320, 166, 166, 3099, 166, 4562, 6061, 3294, 166, 166, 166, 166, 3266, 3627, 2567, 166,
-    228, 2773, 166, 166, 53, 1833, 2401, 124, 166, 4272, 3922, 5959, 2903, 3923, 166, 6155, 166,
-    166, 166, 166, 216, 166, 5247, 166, 5591, 166, 166, 82, 87, 4526, 166, 166, 5439, 166, 4935,
-    166, 3187, 1869, 166, 1764, 5500, 6023, 3356, 166, 3350, 2457, 2455, 166, 1637, 166, 3342, 166,
-    166, 3355, 5154, 166, 276, 166, 166, 166, 3371, 5969, 166, 1665, 166, 166, 166, 166, 166, 166,
-    166, 4092, 1712, 3122, 5086, 166, 166, 4906, 166, 2591, 166, 166, 166, 1894, 2997, 166, 4476,
-    4384, 166, 4747, 4109, 2655, 166, 5978, 1636, 4898, 166, 166, 166, 166, 166, 166, 166, 5207,
-    166, 166, 3712, 3876, 91, 5876, 3786, 5998, 166, 166, 166, 4391, 166, 166, 2832, 2220, 4435,
-    166, 166, 5796, 3156, 6112, 166, 1643, 1821, 3129, 166, 4200, 166, 5857, 166, 166, 2351, 5902,
-    1855, 5043, 166, 3167, 5191, 3996, 5718, 4876, 3071, 2965, 5735, 5930, 6149, 2345, 3297, 3822,
-    166, 166, 307, 6019, 1859, 2981, 4914, 3320, 6165, 2328, 140, 2372, 308, 166, 2280, 5081, 166,
-    3275, 166, 159, 2399, 2327, 5489, 4690, 6059, 4492, 4269, 6058, 166, 19, 166, 3323, 5708, 128,
-    4812, 2949, 166, 166, 2890, 2630, 5237, 166, 256, 3673, 4621, 5380, 166, 3353, 166, 1651, 2573,
-    1635, 4011, 3429, 3370, 3720, 166, 166, 6108, 3848, 5104, 2851, 1998, 166, 166, 5106, 20, 166,
-    2633, 166, 166, 166, 166, 5662, 125, 3651, 1731, 4702, 166, 3197, 166, 2947, 3046, 4196, 2185,
-    6100, 166, 2602, 2908, 2487, 166, 5232, 166, 4028, 5919, 166, 2680, 3608, 3252, 166, 4899, 166,
-    166, 166, 166, 2529, 166, 166, 166, 166, 166, 2534, 166, 2299, 4076, 166, 3643, 166, 3921, 166,
-    166, 166, 1939, 2124, 1829, 2436, 3892, 166, 3481, 271, 5307, 1697, 166, 166, 5098, 2906, 5545,
-    166, 5980, 3203, 166, 1903, 4626, 4674, 6118, 6097, 5926, 4136, 1677, 3232, 4720, 166, 166,
-    166, 229, 2012, 3620, 166, 3798, 166, 166, 2609, 3489, 3809, 166, 166, 166, 166, 166, 166, 166,
-    5826, 166, 166, 166, 4903, 166, 166, 166, 166, 6168, 166, 5052, 5044, 5644, 2375, 2677, 4012,
-    3062, 5831, 4752, 166, 4125, 2610, 2062, 3238, 292, 2533, 5872, 51, 166, 1947, 4225, 166, 2288,
-    4845, 166, 5788, 166, 5717, 166, 166, 5549, 5619, 166, 4165, 166, 2721, 2311, 5501, 4416, 4383,
-    166, 166, 3068, 5499, 5936, 166, 4204, 4766, 4688, 1870, 5220, 166, 166, 166, 166, 237, 2523,
-    6039, 3061, 2793, 3998, 166, 2545, 2309, 3144, 3679, 3969, 166, 166, 166, 4379, 3574, 205,
-    2808, 5822, 166, 166, 2188, 4823, 4990, 5561, 5711, 166, 5627, 6034, 5253, 3783, 5047, 4405,
-    166, 59, 1755, 3178, 318, 166, 4710, 2933, 3409, 6062, 2821, 166, 6099, 166, 4178, 166, 166,
-    4122, 36, 4779, 166, 166, 4323, 3073, 5410, 2101, 166, 166, 44, 5690, 166, 3265, 166, 5222,
-    5909, 1838, 166, 4755, 2215, 166, 4082, 166, 166, 3210, 5140, 3124, 5238, 166, 5913, 2321, 166,
-    2416, 5976, 3918, 5078, 4218, 5703, 4897, 6011, 5685, 2214, 166, 166, 6180, 5175, 1715, 166,
-    166, 3760, 4497, 1808, 4826, 166, 2540, 166, 166, 5513, 4971, 5915, 166, 166, 2525, 166, 4480,
-    42, 232, 2412, 2797, 3229, 5263, 2852, 5543, 2126, 3562, 166, 2872, 4695, 5985, 5136, 2714,
-    4262, 5473, 166, 4160, 4347, 166, 166, 166, 166, 5271, 166, 166, 5108, 166, 166, 166, 166,
-    5437, 4875, 3963, 4362, 5820, 5559, 4890, 4728, 166, 166, 2692, 166, 4870, 3591, 5472, 166,
-    2690, 166, 5854, 3817, 166, 280, 166, 166, 113, 4128, 3396, 166, 4264, 5058, 2283, 166, 2281,
-    4916, 5671, 166, 2708, 166, 166, 4589, 166, 166, 4689, 166, 1686, 166, 166, 166, 166, 166,
-    1774, 166, 166, 166, 5651, 3777, 2234, 166, 3864, 18, 3589, 4592, 4777, 166, 166, 5254, 4245,
-    166, 166, 166, 4368, 5172, 3522, 166, 4306, 153, 5230, 166, 5598, 5420, 311, 2414, 4159, 2985,
-    5137, 166, 2179, 1801, 166, 4595, 2083, 2020, 166, 3602, 2170, 4259, 3048, 166, 166, 4193,
-    2350, 166, 166, 2702, 166, 4521, 166, 166, 2496, 166, 4593, 2006, 166, 166, 2292, 4135, 166,
-    6069, 4623, 166, 166, 4827, 3995, 4291, 3243, 166, 166, 166, 5622, 166, 3539, 166, 166, 4915,
-    4373, 2479, 3775, 6008, 5838, 4321, 1612, 5530, 166, 3773, 4267, 4086, 3081, 2261, 166, 166,
-    4785, 4641, 5292, 166, 4820, 5612, 5556, 166, 166, 166, 4396, 6084, 3414, 166, 3331, 2380,
-    5921, 4315, 2340, 166, 5511, 166, 4713, 3754, 2912, 2553, 166, 3468, 5388, 166, 1932, 3540,
-    5834, 166, 166, 3186, 5258, 166, 4107, 166, 166, 166, 166, 166, 166, 166, 166, 2108, 12, 2368,
-    2789, 166, 166, 4148, 1878, 166, 166, 2324, 4179, 2945, 2531, 166, 166, 166, 4485, 3765, 2308,
-    166, 2754, 166, 6102, 166, 1921, 260, 2241, 166, 2592, 166, 166, 166, 4964, 166, 3055, 5261,
-    4943, 2916, 166, 201, 5728, 166, 5759, 4314, 4730, 6024, 166, 4926, 4762, 1834, 2055, 166, 40,
-    166, 5416, 166, 3722, 2360, 1928, 166, 4889, 4590, 5550, 3498, 166, 6003, 2029, 4106, 4346,
-    3758, 166, 2753, 103, 1891, 5067, 166, 3398, 2079, 5784, 3074, 3787, 166, 166, 3936, 166, 5766,
-    166, 4847, 3928, 5119, 166, 5181, 4602, 2605, 5712, 4523, 166, 166, 4717, 166, 2227, 2181, 166,
-    4678, 166, 166, 4901, 166, 4980, 166, 166, 166, 166, 5806, 2894, 5631, 4995, 2608, 166, 166,
-    166, 3917, 166, 3417, 166, 2795, 1655, 3189, 3364, 166, 4839, 3510, 4212, 5641, 6091, 138, 166,
-    166, 3343, 4620, 2722, 4566, 166, 3518, 3424, 166, 166, 1653, 166, 5057, 166, 5375, 4833, 166,
-    4273, 4348, 166, 166, 166, 4912, 166, 3662, 166, 4281, 166, 5169, 166, 5883, 2737, 2572, 4685,
-    4068, 166, 4214, 166, 166, 2409, 166, 166, 4571, 166, 5624, 5722, 5949, 166, 3675, 166, 166,
-    5109, 3428, 166, 166, 5446, 166, 3290, 166, 3309, 166, 166, 4776, 166, 166, 166, 166, 166, 166,
-    5617, 2860, 166, 166, 166, 166, 3629, 1741, 166, 166, 183, 4973, 3047, 2854, 75, 2035, 3652,
-    2159, 166, 4150, 6037, 3225, 4519, 1902, 2678, 2413, 1961, 166, 166, 166, 166, 4972, 1847, 166,
-    5636, 4017, 166, 3345, 166, 4520, 166, 2861, 166, 3092, 6060, 157, 2542, 2298, 4496, 166, 2607,
-    6110, 5707, 2314, 166, 166, 273, 166, 5952, 166, 4957, 322, 6065, 2272, 6140, 2438, 3458, 3287,
-    166, 166, 166, 166, 2684, 288, 3354, 166, 166, 3983, 1702, 166, 166, 166, 2393, 2435, 4202,
-    3308, 5805, 5085, 166, 166, 1938, 166, 166, 2171, 5892, 2337, 166, 4648, 3116, 2486, 4363,
-    3567, 166, 166, 2822, 2041, 166, 4703, 3956, 5192, 166, 3975, 5720, 3647, 2134, 5932, 166, 166,
-    5160, 263, 166, 166, 166, 4549, 166, 166, 1701, 3086, 166, 166, 4737, 166, 2252, 166, 170, 166,
-    166, 166, 2301, 5478, 166, 166, 5979, 3007, 166, 166, 166, 4104, 166, 2469, 2700, 166, 4998,
-    3376, 166, 1840, 166, 166, 4470, 166, 5235, 3930, 166, 166, 166, 6031, 166, 166, 166, 3827,
-    4700, 166, 166, 166, 166, 166, 166, 4103, 3976, 166, 166, 166, 166, 5027, 4322, 5130, 166,
-    4741, 2132, 4118, 3080, 4137, 166, 6179, 166, 166, 166, 166, 166, 6120, 4188, 166, 2251, 166,
-    3253, 166, 4887, 166, 4293, 5241, 166, 166, 166, 166, 166, 166, 5076, 166, 166, 4177, 166, 221,
-    166, 2757, 5377, 166, 43, 166, 166, 3180, 5540, 166, 213, 4541, 166, 166, 166, 166, 166, 1641,
-    166, 4578, 4639, 166, 166, 1683, 2139, 1689, 5249, 5773, 5226, 166, 2820, 166, 5516, 5045, 166,
-    4896, 5657, 5189, 166, 5770, 2725, 5148, 166, 166, 166, 2929, 166, 3479, 166, 166, 4564, 3752,
-    4305, 4232, 166, 5906, 1779, 166, 2709, 4941, 4342, 166, 4882, 166, 4277, 2322, 166, 4879,
-    1610, 3038, 166, 3762, 2054, 5652, 166, 4524, 3820, 4806, 166, 166, 104, 3416, 4869, 4243,
-    4854, 166, 4114, 166, 2121, 166, 3463, 3556, 166, 4795, 166, 2118, 3920, 166, 166, 4667, 5046,
-    166, 166, 2088, 4360, 5787, 2198, 4233, 5552, 3970, 3523, 2037, 5791, 166, 166, 4299, 2336,
-    166, 166, 166, 4173, 4588, 3626, 5187, 166, 3363, 4611, 294, 4962, 5243, 2719, 6022, 4976,
-    3559, 166, 2662, 5779, 6151, 166, 3527, 166, 5404, 6132, 1839, 166, 3090, 166, 2253, 166, 5441,
-    5518, 6049, 166, 166, 6136, 3026, 3474, 5960, 166, 3937, 4105, 166, 2348, 2039, 4738, 166,
-    5233, 3882, 3840, 166, 278, 190, 166, 5751, 4313, 166, 3855, 166, 166, 6171, 166, 166, 5381,
-    3941, 166, 166, 166, 166, 3334, 166, 2038, 6088, 166, 1918, 5037, 2325, 2378, 4894, 3514, 3715,
-    5168, 166, 166, 4083, 2873, 166, 166, 166, 2693, 166, 3543, 166, 2577, 3013, 166, 166, 4594,
-    2622, 166, 166, 166, 3401, 166, 166, 5447, 5328, 5547, 6133, 2335, 3739, 166, 166, 166, 166,
-    5614, 3492, 3610, 3466, 166, 5336, 4354, 166, 4662, 166, 166, 4283, 166, 166, 303, 5904, 166,
-    2717, 166, 166, 2276, 5564, 2386, 5661, 2040, 166, 1630, 4652, 166, 4840, 166, 110, 5329, 3979,
-    5734, 2550, 166, 166, 6007, 5999, 2978, 4771, 5360, 166, 4023, 166, 166, 5920, 4065, 166, 3880,
-    166, 5422, 1813, 166, 6166, 73, 166, 166, 3669, 5762, 5077, 166, 2953, 85, 166, 3517, 166, 116,
-    166, 2738, 3710, 166, 1634, 166, 166, 166, 2290, 3001, 166, 166, 3037, 2400, 3410, 166, 1791,
-    4231, 166, 3546, 5009, 5299, 2807, 166, 166, 1675, 1619, 2374, 3093, 5302, 3278, 2330, 5301,
-    2343, 2307, 3274, 5017, 2265, 3700, 2465, 166, 139, 4292, 166, 5056, 3952, 166, 4528, 2388,
-    1886, 166, 166, 3016, 3698, 5881, 166, 2379, 3223, 166, 166, 3847, 2407, 5493, 3183, 3307, 166,
-    265, 166, 2421, 6161, 2057, 5363, 3863, 2474, 166, 166, 5427, 166, 2140, 2955, 166, 3070, 4237,
-    5018, 5988, 5570, 275, 4862, 2357, 166, 195, 166, 2593, 6047, 166, 2878, 166, 166, 2781, 3004,
-    4180, 166, 5593, 166, 5973, 2544, 5064, 166, 4324, 4701, 166, 3084, 166, 166, 5372, 4725, 166,
-    5650, 166, 166, 2786, 166, 3781, 3583, 3682, 1850, 4420, 3296, 5173, 4461, 166, 166, 166, 2984,
-    166, 93, 166, 166, 4336, 5943, 2922, 3300, 166, 4843, 166, 166, 166, 166, 2094, 166, 2939, 166,
-    4656, 166, 5146, 166, 166, 166, 166, 2104, 3977, 4660, 5312, 166, 1865, 166, 5487, 5558, 3380,
-    166, 1957, 3162, 3281, 166, 3588, 3268, 2099, 166, 166, 2319, 4913, 4187, 5503, 5782, 150, 166,
-    52, 5450, 166, 166, 166, 2941, 5877, 166, 4031, 5393, 166, 3931, 4166, 3135, 3445, 166, 5053,
-    5430, 4836, 166, 5315, 3389, 4636, 166, 166, 3441, 166, 166, 3767, 2961, 166, 4761, 4604, 3179,
-    166, 166, 4751, 2148, 2015, 166, 123, 5013, 166, 2936, 166, 2063, 166, 5823, 166, 5096, 166,
-    166, 4198, 166, 166, 166, 3845, 166, 166, 238, 166, 2703, 3541, 166, 4813, 166, 4477, 2349,
-    4197, 5996, 3324, 4789, 3063, 166, 166, 5504, 5273, 2805, 13, 166, 5601, 5402, 4119, 5206, 166,
-    166, 4251, 3704, 4176, 1963, 2882, 166, 202, 3125, 3318, 112, 166, 3362, 4835, 3420, 3974,
-    5099, 166, 4433, 166, 166, 166, 1766, 2663, 166, 166, 4683, 166, 166, 5485, 47, 5101, 5341,
-    5765, 3390, 1648, 4341, 3945, 6045, 1645, 166, 5578, 2594, 166, 166, 3772, 166, 166, 3196,
-    3603, 166, 5399, 166, 5075, 166, 5911, 4632, 4781, 5313, 270, 166, 2346, 166, 166, 166, 1986,
-    166, 166, 4958, 166, 166, 166, 4048, 166, 3076, 166, 166, 4891, 166, 166, 57, 166, 220, 166,
-    166, 166, 4117, 166, 166, 166, 166, 5194, 2658, 166, 166, 2942, 6071, 4182, 166, 2976, 5816,
-    166, 166, 166, 166, 3985, 4211, 2514, 166, 166, 166, 2504, 3446, 1711, 166, 166, 2107, 5190,
-    166, 34, 166, 3912, 5382, 3003, 166, 166, 166, 2999, 2404, 4734, 4455, 2087, 166, 2405, 156,
-    166, 2830, 3303, 296, 3295, 2067, 4268, 166, 166, 5642, 166, 166, 1901, 166, 5133, 166, 166,
-    166, 166, 3176, 2973, 4677, 166, 166, 6164, 3000, 2396, 2734, 5697, 5989, 166, 2823, 5265,
-    5852, 166, 166, 2623, 2625, 2287, 4844, 1758, 166, 166, 166, 166, 166, 6073, 166, 5379, 2389,
-    5279, 2444, 5515, 166, 4038, 166, 4948, 5640, 166, 166, 3572, 4258, 166, 166, 166, 5204, 166,
-    4603, 5797, 166, 166, 166, 1725, 4600, 166, 166, 5498, 166, 4152, 166, 172, 4758, 166, 2598,
-    2489, 2076, 4366, 2568, 166, 4352, 3782, 166, 166, 3059, 3946, 5138, 5727, 4484, 5694, 166,
-    3796, 166, 166, 166, 166, 5334, 1778, 2245, 166, 4517, 4419, 2250, 182, 5856, 166, 2835, 4495,
-    1858, 2033, 6014, 6086, 3211, 166, 166, 154, 2145, 166, 129, 3661, 2661, 5860, 6143, 2640,
-    3890, 6160, 166, 166, 2747, 166, 166, 2291, 282, 2476, 166, 166, 3825, 166, 1925, 166, 4489,
-    166, 166, 166, 4034, 166, 166, 166, 166, 166, 166, 122, 4708, 4919, 2373, 2453, 5419, 5954,
-    297, 5290, 166, 1978, 166, 4932, 3501, 166, 3085, 3386, 166, 5405, 4512, 166, 3209, 5740, 4020,
-    5495, 5815, 314, 166, 3190, 4824, 166, 166, 3448, 207, 1623, 6096, 5878, 166, 1836, 166, 166,
-    2728, 166, 5278, 3419, 3012, 5618, 5266, 3078, 166, 166, 2244, 166, 4569, 6068, 166, 3336, 166,
-    5677, 6052, 5079, 166, 5453, 5245, 5799, 166, 1982, 166, 5958, 4619, 5821, 166, 5285, 284,
-    1631, 5710, 6070, 5365, 2189, 3242, 166, 2752, 5483, 5297, 6150, 5522, 166, 1815, 166, 166,
-    166, 5801, 166, 166, 5398, 166, 166, 166, 2967, 2515, 3169, 166, 166, 2562, 166, 1617, 2069,
-    166, 166, 6154, 166, 3721, 166, 5327, 166, 166, 166, 5592, 166, 166, 2286, 1716, 3903, 166,
-    2395, 286, 3587, 6146, 3286, 4186, 5882, 5894, 5737, 6032, 5879, 2761, 4829, 3788, 166, 166,
-    3233, 5356, 5693, 166, 2429, 2449, 141, 3444, 5186, 166, 166, 3477, 4080, 4584, 166, 166, 3670,
-    1851, 3824, 4337, 3886, 2792, 166, 5867, 166, 166, 3557, 3147, 166, 166, 2200, 166, 2505, 166,
-    4310, 4865, 5656, 5992, 5672, 166, 5199, 135, 3023, 2994, 4472, 166, 166, 166, 2019, 4319,
-    3472, 166, 166, 166, 29, 206, 3944, 3027, 5804, 4731, 5449, 166, 2825, 3310, 166, 6172, 5202,
-    166, 2516, 3644, 4557, 166, 166, 166, 166, 2671, 4427, 3432, 3276, 5584, 5536, 4645, 3202, 166,
-    2612, 166, 4249, 2425, 3259, 4622, 166, 2411, 4303, 4206, 166, 166, 166, 3734, 6063, 118, 166,
-    166, 3641, 166, 166, 166, 4937, 1871, 3421, 2208, 166, 166, 166, 166, 4881, 166, 166, 166, 166,
-    3298, 166, 61, 166, 166, 166, 3293, 6145, 71, 3619, 166, 166, 3383, 1624, 320, 2187, 4113, 166,
-    166, 166, 166, 166, 5080, 2344, 5625, 2358, 1621, 4230, 5579, 5359, 295, 4248, 5267, 3883,
-    6124, 187, 5112, 2122, 166, 166, 166, 5142, 6004, 166, 5322, 6175, 3639, 3182, 4425, 166, 175,
-    166, 166, 166, 5778, 3939, 3484, 166, 166, 5832, 5248, 5935, 4467, 5858, 166, 5038, 166, 166,
-    3102, 166, 4880, 166, 166, 166, 166, 3418, 1666, 5338, 3680, 5291, 4441, 3385, 166, 5733, 4503,
-    2774, 166, 2631, 4153, 166, 2000, 166, 166, 5345, 166, 166, 4298, 1804, 4707, 166, 1613, 1952,
-    2111, 166, 166, 166, 166, 166, 2897, 166, 166, 4044, 166, 166, 166, 166, 2863, 5475, 166, 166,
-    166, 1704, 166, 3609, 2782, 2018, 166, 5361, 166, 3694, 3733, 166, 2785, 1969, 166, 166, 2834,
-    1868, 3779, 1877, 60, 166, 4143, 3902, 166, 4361, 3188, 2498, 6009, 166, 115, 166, 3138, 166,
-    4575, 6080, 133, 2030, 166, 166, 166, 2306, 2136, 3043, 3447, 2142, 166, 3799, 1646, 5269,
-    3640, 166, 2674, 5502, 166, 5467, 166, 5069, 166, 166, 4654, 4581, 5274, 5036, 4364, 166, 3115,
-    166, 2128, 4544, 5433, 2086, 2584, 4413, 166, 166, 5385, 166, 234, 166, 1625, 166, 166, 166,
-    5139, 2511, 4974, 2766, 166, 166, 166, 2095, 3990, 217, 166, 2988, 4061, 166, 209, 4883, 166,
-    166, 166, 166, 166, 4326, 166, 5465, 2859, 166, 2887, 166, 2231, 166, 1658, 166, 2246, 166,
-    1844, 166, 166, 3087, 2871, 3872, 1660, 48, 166, 166, 3622, 166, 1709, 166, 166, 6177, 6173,
-    166, 3569, 166, 166, 166, 241, 3660, 3631, 166, 166, 5319, 5141, 174, 166, 166, 4412, 166,
-    5145, 166, 1919, 166, 5276, 166, 2385, 166, 1618, 166, 166, 2501, 166, 166, 1734, 5966, 3145,
-    166, 1690, 4025, 1664, 4559, 2433, 2392, 3552, 4006, 1896, 166, 166, 2546, 4450, 5396, 4221,
-    4046, 166, 166, 2642, 166, 4448, 166, 2784, 3480, 4807, 166, 166, 3534, 166, 166, 5272, 166,
-    166, 2831, 4263, 166, 166, 166, 166, 4414, 5628, 3486, 166, 3748, 166, 4598, 3719, 3598, 3611,
-    166, 4792, 5059, 4110, 166, 2656, 166, 166, 84, 5429, 166, 166, 166, 281, 1955, 166, 166, 166,
-    3616, 4997, 166, 166, 166, 166, 3230, 166, 166, 166, 166, 166, 166, 77, 166, 166, 166, 1800,
-    166, 4236, 166, 166, 166, 166, 166, 5757, 2530, 1662, 166, 4607, 1659, 166, 1685, 3341, 166,
-    1699, 4058, 3407, 1854, 4417, 3034, 166, 166, 166, 166, 5568, 166, 3206, 166, 5529, 166, 166,
-    166, 2116, 3487, 144, 166, 166, 166, 5523, 5373, 5321, 166, 6064, 2921, 166, 1696, 2473, 166,
-    166, 3716, 5689, 166, 4608, 3879, 166, 166, 166, 2156, 166, 4358, 2446, 166, 3958, 166, 5520,
-    4340, 4848, 166, 3285, 166, 2665, 166, 3459, 1905, 5115, 68, 5730, 166, 3127, 5029, 4370, 166,
-    3753, 166, 3674, 6025, 4490, 166, 4183, 166, 94, 166, 166, 4051, 3766, 3140, 4907, 3857, 166,
-    166, 4596, 166, 3888, 3040, 2507, 5643, 166, 166, 4311, 2618, 5582, 166, 166, 3678, 166, 1988,
-    166, 166, 4464, 166, 166, 166, 166, 4278, 3677, 2173, 5256, 166, 166, 5162, 166, 5178, 1644,
-    5094, 166, 2557, 5506, 166, 166, 166, 4927, 5348, 1797, 166, 166, 39, 166, 3866, 3655, 236,
-    5403, 2175, 3361, 166, 1976, 5993, 226, 166, 4643, 166, 5339, 4098, 2653, 4969, 166, 3346,
-    4984, 4635, 166, 166, 166, 166, 4981, 188, 166, 166, 28, 4088, 166, 166, 166, 25, 3663, 2696,
-    166, 4679, 5114, 5802, 166, 166, 166, 166, 166, 3810, 5749, 166, 1673, 4276, 166, 3756, 4184,
-    166, 5630, 166, 166, 166, 4531, 212, 5663, 166, 166, 2746, 166, 5386, 3618, 3594, 1887, 166,
-    166, 5443, 166, 1726, 4094, 5065, 4756, 166, 166, 5308, 5225, 2081, 166, 166, 3064, 166, 166,
-    1981, 3637, 4355, 1626, 166, 166, 4686, 166, 5793, 180, 5066, 2938, 3819, 4904, 3601, 166, 166,
-    2495, 5025, 5768, 2621, 4650, 3041, 166, 5897, 3633, 166, 166, 4375, 166, 5714, 1667, 3273,
-    3950, 1668, 166, 5855, 166, 2364, 166, 1881, 166, 2646, 5460, 166, 2770, 4951, 5414, 166, 4442,
-    2113, 5726, 298, 5934, 2053, 166, 166, 4053, 166, 166, 4514, 4697, 166, 166, 5198, 2707, 166,
-    5605, 166, 166, 5218, 2596, 166, 2110, 166, 1806, 2160, 166, 166, 2212, 166, 3636, 166, 166,
-    4377, 4021, 3707, 4502, 166, 4195, 166, 166, 166, 4108, 3725, 3676, 166, 2084, 166, 166, 166,
-    166, 4216, 166, 166, 6156, 166, 2896, 166, 166, 166, 166, 166, 166, 3826, 2870, 3793, 166, 166,
-    5927, 166, 2759, 166, 4613, 2297, 5638, 166, 2842, 5031, 4793, 5184, 166, 166, 2008, 166, 257,
-    2881, 117, 6051, 3044, 4079, 2833, 166, 6117, 166, 3236, 5469, 166, 166, 2874, 6076, 166, 1799,
-    80, 41, 166, 1864, 166, 5709, 1611, 5026, 5176, 168, 3269, 4081, 166, 166, 1970, 4550, 166,
-    4250, 4101, 4565, 5950, 5845, 97, 4064, 166, 5394, 4374, 4343, 166, 166, 4658, 3248, 166, 208,
-    1735, 4047, 2843, 166, 166, 166, 166, 2794, 166, 166, 5844, 166, 166, 3094, 2177, 5436, 3646,
-    166, 3564, 4682, 166, 5948, 5835, 162, 2059, 5151, 2034, 1926, 5941, 5903, 5177, 166, 166, 166,
-    4801, 3439, 1780, 166, 166, 3280, 3434, 166, 166, 4498, 5565, 4043, 166, 4432, 4722, 3959, 166,
-    3746, 166, 166, 177, 166, 166, 2748, 166, 4483, 166, 166, 4144, 166, 166, 166, 166, 2066, 2915,
-    166, 2049, 2130, 4684, 166, 49, 3506, 5391, 166, 2590, 6103, 1714, 2410, 3053, 3837, 4301, 166,
-    3255, 2644, 166, 166, 4014, 166, 2475, 4788, 2876, 166, 166, 166, 166, 166, 166, 4140, 166,
-    166, 321, 166, 1966, 166, 166, 2855, 3111, 3800, 166, 4446, 2551, 166, 166, 166, 2824, 166,
-    166, 166, 2164, 3010, 2226, 166, 4857, 166, 2582, 5118, 4582, 5917, 166, 166, 3338, 3482, 3328,
-    166, 4817, 166, 5371, 3830, 166, 3009, 1633, 3329, 4052, 166, 3701, 4983, 4500, 4487, 4878,
-    166, 166, 5482, 3544, 166, 3057, 2026, 4398, 2847, 3532, 3262, 3399, 166, 166, 166, 4478, 4167,
-    166, 3411, 2599, 5362, 166, 2711, 166, 166, 166, 166, 3452, 2522, 5586, 5548, 3279, 2538, 166,
-    166, 166, 4161, 166, 2123, 166, 166, 2660, 166, 166, 1706, 166, 15, 3537, 5051, 5869, 166,
-    3025, 166, 4447, 3744, 120, 166, 166, 166, 204, 2810, 166, 5124, 2376, 5306, 166, 166, 4493,
-    166, 166, 166, 5289, 6046, 166, 2762, 2541, 1857, 2467, 5163, 166, 166, 166, 166, 5830, 166,
-    2172, 3359, 166, 2928, 166, 166, 166, 6129, 166, 5445, 166, 166, 5924, 6144, 166, 102, 166,
-    166, 1678, 166, 4491, 5705, 166, 1753, 166, 3873, 5725, 4145, 1909, 166, 2155, 166, 166, 1848,
-    3315, 1874, 166, 4945, 2524, 166, 3263, 2362, 1785, 166, 166, 166, 152, 2102, 5723, 5131, 5754,
-    4032, 4029, 166, 4295, 3391, 166, 166, 166, 5282, 1747, 3159, 2235, 5583, 1786, 3630, 6111,
-    2974, 4797, 3623, 166, 2071, 4929, 166, 2603, 3964, 3378, 166, 166, 2654, 151, 3940, 4527,
-    4518, 166, 2430, 1884, 3812, 166, 2867, 166, 166, 166, 2756, 5418, 166, 2354, 4606, 166, 2153,
-    166, 4855, 166, 166, 1720, 166, 3213, 3926, 166, 5158, 4349, 166, 4828, 166, 166, 2031, 166,
-    2300, 166, 166, 166, 2211, 4954, 3121, 4754, 2485, 166, 166, 166, 3593, 166, 2718, 5317, 2765,
-    5120, 166, 2527, 166, 1994, 5947, 166, 166, 166, 6085, 2302, 100, 79, 2982, 3705, 2180, 2043,
-    166, 1872, 1671, 166, 3729, 166, 4944, 3665, 2217, 2119, 166, 5615, 166, 1620, 166, 166, 166,
-    166, 35, 3913, 2760, 166, 3688, 3672, 4042, 166, 166, 5117, 4227, 166, 4445, 2458, 3803, 4554,
-    4988, 166, 166, 3141, 3491, 166, 166, 166, 166, 5095, 4668, 5567, 166, 166, 2885, 1790, 2996,
-    166, 166, 166, 166, 3737, 166, 2470, 166, 166, 4339, 166, 166, 166, 4920, 166, 166, 3697, 5471,
-    166, 166, 3538, 4558, 3467, 5262, 5609, 3858, 166, 166, 5007, 2780, 2791, 2236, 5668, 3134,
-    166, 166, 5776, 3470, 3291, 166, 2532, 166, 166, 166, 3805, 264, 166, 3227, 166, 166, 166,
-    2334, 166, 5087, 101, 166, 3634, 58, 2813, 166, 166, 166, 3222, 4704, 4488, 4508, 5459, 2117,
-    5873, 166, 1828, 166, 166, 166, 166, 166, 2105, 166, 5613, 5761, 2920, 3098, 166, 166, 3277,
-    166, 166, 166, 166, 83, 166, 166, 166, 3967, 166, 5574, 166, 4985, 30, 3426, 166, 179, 3014,
-    4015, 246, 2556, 4449, 3723, 5611, 3436, 166, 4240, 3642, 166, 4536, 2048, 5810, 166, 1971,
-    166, 5557, 5323, 5022, 191, 5492, 166, 4837, 4426, 2537, 2271, 3177, 5674, 166, 2796, 1995,
-    166, 3906, 166, 4403, 3862, 4716, 2406, 3948, 4670, 4309, 166, 2575, 5358, 2951, 166, 3666,
-    3612, 5577, 4579, 4743, 166, 6072, 6036, 4563, 2586, 166, 5836, 166, 166, 5752, 166, 3563, 166,
-    2909, 3251, 92, 166, 4711, 4149, 166, 166, 3052, 5122, 2904, 2635, 1990, 166, 166, 166, 166,
-    166, 166, 166, 166, 4213, 166, 3103, 3142, 2683, 6105, 2209, 3175, 4215, 166, 166, 166, 166,
-    166, 166, 166, 5303, 4075, 5374, 166, 4174, 4154, 1895, 4538, 2764, 166, 5817, 6113, 4033, 166,
-    6090, 166, 2990, 166, 3164, 166, 166, 166, 247, 166, 6083, 3412, 166, 5738, 166, 3599, 166,
-    1904, 2162, 2547, 3960, 166, 166, 3154, 55, 166, 5991, 4921, 2879, 166, 166, 5347, 166, 166,
-    166, 2712, 4787, 166, 1908, 166, 166, 166, 3184, 166, 166, 166, 4572, 3846, 3657, 166, 166,
-    5481, 166, 166, 3397, 1856, 4978, 166, 3900, 3570, 3802, 166, 166, 2075, 4408, 166, 6079, 2313,
-    166, 166, 5756, 166, 166, 2070, 166, 166, 3137, 166, 166, 3686, 166, 166, 166, 166, 67, 5019,
-    166, 1742, 166, 5354, 166, 5149, 166, 2931, 4946, 6006, 166, 166, 2865, 4902, 3029, 1722, 3449,
-    166, 1987, 166, 62, 5626, 166, 166, 166, 2670, 1657, 5599, 3056, 166, 3791, 5020, 166, 1979,
-    4437, 1899, 166, 166, 196, 2636, 166, 143, 3475, 4317, 2512, 2415, 5033, 5024, 2112, 2864,
-    3551, 166, 1688, 33, 4585, 3648, 4399, 166, 166, 166, 166, 166, 1824, 166, 166, 166, 166, 166,
-    166, 4513, 166, 2478, 4407, 166, 166, 2492, 4130, 4318, 2980, 5746, 166, 2606, 4063, 4123, 166,
-    255, 166, 166, 4680, 166, 3586, 5975, 3935, 166, 5528, 166, 3158, 166, 166, 2614, 5035, 166,
-    3488, 3214, 166, 166, 166, 5413, 3713, 166, 5875, 4329, 5250, 166, 166, 3741, 166, 54, 1885,
-    3839, 166, 4924, 166, 166, 166, 4158, 166, 166, 2152, 1661, 166, 166, 4327, 166, 3933, 166,
-    5666, 166, 166, 2580, 166, 3404, 4111, 2862, 4438, 166, 166, 4072, 166, 166, 3938, 2958, 4302,
-    166, 3851, 166, 268, 166, 166, 1975, 222, 3204, 3438, 4616, 166, 4275, 3101, 2648, 3989, 5215,
-    166, 4229, 166, 5440, 166, 5093, 2639, 166, 166, 4439, 166, 2316, 4239, 166, 166, 166, 166,
-    166, 1817, 4486, 166, 3272, 166, 166, 4085, 2078, 2902, 166, 166, 166, 4381, 1853, 3054, 166,
-    166, 5005, 2669, 166, 2856, 2706, 166, 166, 166, 4185, 166, 1748, 166, 166, 166, 5771, 166,
-    166, 3915, 166, 166, 2205, 6122, 166, 166, 1632, 5400, 166, 2477, 4740, 166, 166, 166, 1802,
-    166, 2472, 3953, 166, 1849, 2604, 3780, 2560, 4786, 2566, 3576, 166, 4768, 166, 1951, 251,
-    5068, 166, 166, 166, 2619, 166, 166, 166, 5432, 166, 166, 5260, 5758, 3908, 166, 4141, 166,
-    5777, 166, 166, 166, 166, 166, 3961, 5143, 166, 3889, 3747, 3743, 166, 2818, 166, 166, 166,
-    3867, 166, 166, 3742, 4763, 2948, 5533, 166, 3966, 3555, 3843, 3503, 6005, 166, 4687, 2790,
-    4479, 5828, 3769, 5688, 166, 166, 166, 166, 3109, 166, 166, 166, 166, 4574, 81, 166, 166, 4576,
-    3369, 166, 166, 166, 4207, 166, 5072, 2210, 166, 184, 166, 4673, 166, 166, 166, 166, 166, 166,
-    1628, 3590, 1916, 4784, 4970, 166, 1832, 166, 166, 3584, 3384, 166, 166, 2880, 1783, 166, 166,
-    166, 166, 6115, 6121, 2157, 5428, 5859, 4861, 5635, 4331, 5839, 4223, 313, 166, 166, 6152,
-    2168, 166, 4112, 6089, 6012, 166, 5294, 3207, 166, 166, 4884, 166, 4655, 166, 166, 166, 1743,
-    166, 4077, 166, 4631, 166, 166, 2957, 1945, 4936, 166, 166, 5389, 166, 166, 5955, 166, 166,
-    1639, 2207, 4129, 166, 3582, 5560, 6147, 3088, 166, 166, 4529, 5259, 3118, 166, 3106, 2853,
-    166, 1845, 5660, 166, 3325, 3973, 2461, 2163, 166, 3083, 4190, 166, 166, 5505, 166, 166, 3226,
-    5507, 109, 6141, 3991, 166, 4939, 166, 166, 5889, 3986, 166, 3664, 4353, 2056, 166, 5071, 166,
-    166, 4376, 166, 1958, 2028, 166, 166, 1793, 166, 5252, 3536, 166, 166, 3525, 3580, 166, 166,
-    166, 1782, 5174, 2011, 1826, 3352, 3231, 166, 166, 4986, 2068, 2801, 166, 2500, 166, 5061, 166,
-    2263, 2632, 1993, 166, 2715, 4424, 166, 166, 6042, 4661, 166, 5074, 5479, 4822, 166, 166, 166,
-    166, 5600, 5853, 166, 1907, 166, 166, 166, 3808, 166, 5997, 5032, 4605, 166, 1732, 166, 166,
-    166, 3015, 5454, 166, 166, 166, 3806, 5444, 2238, 1946, 166, 166, 3221, 4922, 166, 6092, 166,
-    166, 4007, 166, 3425, 4282, 2571, 166, 1749, 166, 166, 38, 4744, 4900, 4257, 214, 5687, 166,
-    2490, 2979, 2924, 166, 4714, 219, 5344, 3836, 3302, 78, 1984, 2986, 2960, 166, 2869, 3507,
-    3335, 4967, 2892, 2723, 4849, 5070, 166, 166, 4629, 3815, 166, 4453, 4760, 166, 3224, 130, 166,
-    166, 166, 166, 166, 3408, 2494, 2691, 166, 4325, 2932, 5165, 5573, 166, 4769, 166, 5411, 5637,
-    2050, 166, 166, 2305, 166, 166, 4834, 24, 4693, 3554, 2491, 1738, 166, 166, 166, 23, 2758,
-    3072, 2564, 4800, 5537, 3545, 4133, 166, 166, 166, 5982, 166, 203, 166, 166, 290, 185, 166,
-    3774, 1929, 3379, 166, 166, 166, 166, 3002, 166, 3738, 166, 166, 3344, 4942, 5353, 2777, 2839,
-    4712, 1830, 2664, 166, 5884, 3516, 166, 5494, 4169, 2391, 3319, 166, 166, 5918, 2597, 166,
-    4821, 2787, 5719, 166, 166, 166, 1687, 6148, 3257, 254, 166, 5180, 6153, 5964, 306, 166, 6123,
-    166, 5208, 166, 3163, 5938, 1736, 166, 2502, 4910, 166, 166, 2549, 166, 2900, 3632, 3270, 166,
-    2082, 5953, 166, 107, 5750, 166, 166, 166, 5527, 1751, 4168, 2950, 166, 2659, 166, 4189, 1943,
-    2595, 166, 4191, 166, 166, 166, 166, 2998, 2296, 5221, 3617, 166, 5435, 2451, 2009, 3005, 2242,
-    3768, 3658, 166, 166, 166, 166, 166, 2481, 2256, 166, 166, 4074, 166, 3120, 166, 4409, 1759,
-    166, 166, 1679, 3659, 3499, 5219, 4501, 3082, 2047, 166, 166, 166, 4560, 2768, 5251, 166, 166,
-    166, 2437, 3993, 3215, 2447, 166, 166, 166, 2993, 4963, 166, 3045, 166, 166, 166, 166, 166,
-    166, 166, 5521, 166, 166, 4868, 166, 3895, 166, 6131, 3949, 3306, 3785, 166, 166, 4895, 4831,
-    166, 1772, 166, 166, 5928, 166, 2137, 4805, 2462, 310, 2667, 3561, 166, 166, 2312, 4931, 5255,
-    166, 166, 166, 5670, 166, 2285, 166, 4672, 5310, 166, 2103, 2174, 166, 166, 166, 166, 5417,
-    166, 4726, 4203, 166, 166, 166, 5581, 166, 5665, 166, 166, 5747, 166, 166, 2509, 1973, 2749,
-    5463, 166, 166, 4567, 5014, 166, 3322, 3051, 166, 4090, 166, 3709, 3887, 3478, 166, 166, 166,
-    166, 3565, 3934, 166, 32, 166, 166, 166, 2239, 166, 3947, 3849, 166, 2022, 166, 2169, 166,
-    4691, 98, 166, 3804, 4155, 1640, 4002, 166, 2138, 1739, 3730, 5970, 2274, 4873, 3119, 166,
-    4925, 3577, 3699, 4049, 3982, 166, 5161, 1744, 166, 166, 166, 5704, 4979, 2686, 5383, 5744,
-    2289, 166, 166, 166, 3927, 2539, 166, 166, 166, 2585, 166, 4723, 3755, 4509, 166, 4961, 2194,
-    2535, 166, 176, 166, 4494, 166, 4171, 166, 266, 166, 3454, 5369, 166, 166, 5899, 5284, 166,
-    3607, 3566, 5514, 166, 1843, 166, 3997, 4599, 2743, 166, 2857, 2497, 2751, 166, 166, 166, 3511,
-    5742, 166, 166, 166, 4504, 166, 166, 166, 5082, 4401, 166, 166, 5431, 166, 166, 1949, 4539,
-    166, 166, 4852, 166, 166, 3457, 166, 3433, 4669, 166, 1692, 2454, 3258, 6159, 166, 166, 166,
-    166, 166, 2788, 4350, 3249, 3816, 4893, 166, 4846, 166, 4993, 1708, 4138, 166, 2895, 2891, 166,
-    1860, 166, 2480, 1927, 3853, 166, 166, 166, 5100, 166, 3143, 5159, 166, 4286, 5182, 5246, 4975,
-    166, 2905, 166, 4917, 5102, 2044, 6016, 5673, 2005, 5090, 166, 4634, 3333, 166, 5702, 3413,
-    1762, 6094, 4284, 4431, 2641, 166, 4463, 5691, 166, 166, 3442, 3473, 4192, 2046, 166, 3838,
-    166, 3217, 3349, 166, 2243, 166, 3490, 166, 166, 166, 5922, 166, 166, 166, 4885, 1798, 2884,
-    2750, 5004, 2741, 166, 166, 5649, 166, 4410, 166, 166, 3382, 166, 166, 1913, 1703, 5532, 3770,
-    166, 5116, 2645, 2634, 4357, 5901, 166, 166, 5538, 166, 166, 166, 6028, 166, 166, 5840, 4102,
-    2704, 2091, 5287, 166, 4757, 2282, 166, 2650, 3528, 64, 253, 3732, 166, 166, 166, 166, 166,
-    3465, 166, 166, 166, 5848, 3110, 111, 166, 166, 3403, 2926, 6030, 3366, 1948, 4430, 5509, 3250,
-    3972, 2587, 3579, 166, 6048, 250, 5275, 4242, 2615, 3112, 3558, 166, 166, 2342, 166, 5157,
-    1917, 2733, 5647, 1934, 5675, 166, 3981, 2923, 5213, 5326, 37, 166, 5288, 3069, 166, 1923,
-    5755, 166, 166, 166, 1888, 166, 6041, 5895, 5376, 3727, 3901, 166, 5589, 166, 166, 4609, 166,
-    166, 166, 4706, 166, 4482, 1622, 166, 171, 166, 166, 4646, 4151, 2755, 4614, 166, 2072, 5409,
-    4469, 1647, 4434, 4633, 1915, 166, 3615, 4808, 166, 3388, 166, 5280, 2731, 166, 166, 2417, 166,
-    14, 166, 4533, 5126, 166, 2778, 3022, 166, 166, 166, 4830, 4764, 166, 166, 166, 4982, 166,
-    4265, 166, 2466, 5678, 147, 1883, 166, 166, 166, 114, 4000, 2427, 3597, 166, 4853, 5981, 166,
-    2023, 2519, 166, 1937, 2221, 4676, 166, 4522, 5716, 166, 2432, 5731, 166, 6020, 6163, 4351,
-    2442, 4380, 166, 4390, 1882, 6139, 4246, 262, 166, 1676, 5781, 2352, 1956, 200, 166, 166, 5800,
-    6184, 166, 2355, 149, 5962, 5524, 4238, 166, 5150, 166, 5888, 2423, 166, 5739, 3192, 4142, 166,
-    166, 166, 3201, 161, 4460, 2459, 158, 166, 166, 166, 166, 2689, 166, 166, 166, 166, 1889, 166,
-    166, 3374, 166, 70, 166, 2772, 166, 2995, 166, 2384, 4989, 166, 3299, 166, 166, 166, 166, 3614,
-    3645, 3415, 3160, 1727, 3735, 5201, 1693, 3531, 166, 166, 1776, 3871, 166, 166, 166, 166, 86,
-    3553, 166, 166, 166, 3392, 166, 166, 2232, 166, 4977, 2333, 3394, 2875, 2027, 5736, 166, 1719,
-    166, 4952, 2061, 2150, 5526, 166, 4637, 166, 4333, 166, 166, 4733, 4809, 3911, 166, 3460, 166,
-    5355, 3126, 4181, 4436, 300, 166, 3841, 166, 4770, 126, 5654, 166, 166, 166, 1730, 166, 166,
-    166, 5610, 166, 6002, 2197, 3807, 6109, 166, 166, 166, 166, 166, 5395, 4004, 166, 46, 166, 166,
-    2570, 4736, 5318, 4247, 166, 166, 166, 2293, 3031, 4591, 166, 245, 166, 5510, 1616, 3117, 4163,
-    166, 166, 4759, 3462, 4819, 4947, 166, 3128, 5946, 2278, 2969, 166, 166, 5183, 166, 166, 1729,
-    173, 2448, 166, 230, 2971, 166, 166, 5397, 166, 4093, 3348, 1866, 4280, 166, 6067, 3794, 166,
-    166, 166, 4729, 166, 3456, 166, 2394, 166, 4953, 166, 166, 2258, 4863, 166, 166, 4060, 166,
-    5468, 305, 166, 6134, 166, 166, 2326, 166, 3453, 2167, 2845, 166, 166, 166, 5597, 166, 166,
-    166, 166, 5462, 2809, 5994, 2899, 166, 166, 166, 5153, 166, 166, 1638, 166, 166, 4938, 3795,
-    166, 3842, 166, 166, 166, 2769, 3194, 166, 4745, 5508, 5604, 3910, 166, 166, 4147, 3239, 166,
-    166, 3548, 3859, 2092, 166, 2705, 166, 166, 3625, 4131, 166, 3513, 166, 166, 2987, 4555, 3107,
-    166, 166, 166, 166, 5713, 4698, 3079, 166, 5342, 166, 166, 2673, 2517, 2745, 1795, 166, 166,
-    166, 166, 166, 166, 2463, 166, 166, 2445, 5425, 6138, 166, 2687, 3254, 5871, 166, 2387, 4300,
-    166, 166, 3529, 1996, 166, 2369, 3818, 6126, 1615, 2643, 65, 4297, 166, 5324, 3311, 3852, 166,
-    3868, 4199, 3978, 166, 166, 166, 5466, 166, 166, 244, 166, 5929, 6157, 2390, 5639, 2267, 2073,
-    4610, 5774, 2521, 4556, 166, 4545, 4307, 2426, 2450, 166, 5783, 4968, 6176, 4156, 166, 166,
-    4126, 3549, 166, 3581, 5701, 3234, 166, 4013, 1879, 166, 6104, 5874, 166, 166, 3485, 4279,
-    2528, 5576, 166, 3992, 166, 3980, 4934, 166, 2176, 4228, 5164, 3784, 1933, 4120, 5055, 166,
-    166, 5015, 166, 166, 166, 2310, 1754, 166, 6087, 166, 166, 4548, 5268, 2930, 166, 3656, 166,
-    3042, 5229, 166, 4016, 2195, 166, 166, 166, 199, 1745, 3717, 166, 166, 74, 2668, 252, 4124,
-    4657, 5223, 166, 2186, 3628, 166, 166, 166, 4222, 3114, 2841, 5103, 3171, 5135, 166, 166, 2273,
-    166, 3899, 5332, 5842, 3575, 2579, 2431, 2464, 2229, 3604, 4561, 2977, 2815, 166, 3916, 166,
-    5825, 166, 1694, 166, 4030, 166, 5841, 166, 3881, 1831, 166, 5525, 3011, 166, 5535, 5217, 316,
-    4116, 166, 166, 2204, 166, 3136, 3650, 166, 5813, 1875, 4511, 4475, 166, 1999, 166, 2277, 166,
-    3024, 5484, 5546, 166, 3988, 5676, 166, 2213, 2264, 5214, 166, 4940, 5974, 166, 4750, 6077,
-    166, 1652, 3148, 166, 166, 166, 166, 2554, 166, 6167, 5257, 5300, 166, 166, 166, 166, 5408,
-    166, 166, 3402, 2141, 166, 4663, 5633, 3312, 166, 2814, 4930, 1959, 166, 166, 166, 3861, 166,
-    166, 302, 2624, 166, 166, 166, 1629, 1724, 166, 3909, 5281, 166, 2001, 4395, 5352, 4428, 2694,
-    4850, 166, 166, 5242, 5910, 166, 166, 166, 166, 166, 3212, 166, 2045, 166, 166, 166, 166, 166,
-    166, 3017, 4960, 4456, 166, 5616, 6093, 2151, 166, 166, 166, 315, 3381, 166, 166, 166, 4330,
-    166, 6158, 4721, 6075, 166, 166, 166, 4543, 2303, 166, 166, 3301, 166, 5000, 3929, 2543, 3437,
-    166, 166, 166, 3422, 166, 5987, 5729, 2428, 166, 4035, 5588, 3714, 3834, 5264, 5743, 166, 3305,
-    4886, 6107, 5156, 166, 166, 166, 166, 166, 1672, 5849, 5827, 5049, 6101, 2178, 2420, 3289, 166,
-    166, 4274, 6017, 2257, 166, 4172, 3451, 2367, 2382, 166, 2964, 4918, 3241, 2347, 6082, 99,
-    2383, 166, 4454, 163, 2460, 165, 304, 1818, 5580, 166, 312, 5790, 293, 5794, 5519, 5083, 3360,
-    5748, 166, 3750, 5034, 166, 166, 166, 1863, 3168, 166, 166, 166, 5111, 166, 166, 166, 166,
-    2183, 4510, 166, 166, 3495, 4382, 4235, 4462, 166, 4056, 5885, 17, 5028, 1614, 6038, 166, 2488,
-    5632, 3089, 166, 1940, 66, 4039, 3999, 235, 166, 166, 3829, 3954, 166, 2365, 269, 166, 166,
-    166, 166, 166, 166, 4418, 1796, 4709, 2004, 166, 3596, 5786, 166, 2819, 4624, 3152, 2968, 2838,
-    166, 5575, 1767, 5603, 166, 4386, 5890, 166, 1768, 4201, 3560, 166, 166, 166, 2184, 2262, 2966,
-    2716, 1765, 2611, 2983, 166, 4164, 4084, 142, 5314, 166, 166, 4071, 166, 2578, 2849, 3600, 166,
-    166, 166, 166, 5401, 4814, 3431, 166, 5088, 5084, 198, 166, 3578, 3764, 166, 2097, 166, 166,
-    5390, 4443, 166, 3166, 166, 4816, 166, 166, 166, 166, 3130, 5963, 1788, 2129, 1837, 4100, 6128,
-    166, 4586, 5945, 4772, 166, 5741, 3151, 3247, 5645, 4507, 5833, 3904, 6013, 2506, 3050, 4175,
-    1705, 3019, 166, 5942, 166, 2418, 3430, 2230, 5745, 166, 2093, 166, 166, 166, 166, 4666, 3246,
-    192, 2010, 4003, 3533, 5851, 166, 3621, 3684, 3066, 166, 166, 166, 5073, 3856, 166, 166, 2224,
-    166, 2637, 4270, 166, 166, 5679, 166, 5792, 5850, 166, 2589, 3060, 2196, 3476, 3150, 2025, 166,
-    166, 166, 2657, 166, 3685, 3790, 5587, 2817, 3692, 166, 166, 166, 2359, 2260, 5896, 2158, 119,
-    2816, 5753, 166, 2739, 5772, 166, 2919, 2147, 1985, 4271, 4838, 4991, 166, 166, 166, 5244, 166,
-    319, 166, 166, 2779, 4732, 4994, 5424, 166, 166, 3968, 3049, 3393, 4473, 4959, 5967, 5864,
-    5170, 4209, 166, 4810, 4815, 4205, 2339, 5023, 2279, 5050, 166, 5837, 132, 166, 166, 166, 2247,
-    21, 4775, 166, 166, 5286, 166, 4170, 4099, 4803, 5767, 166, 166, 166, 5811, 2240, 5699, 2499,
-    166, 4802, 166, 5785, 166, 166, 166, 3181, 3435, 166, 3339, 166, 5669, 3865, 2249, 5002, 166,
-    4694, 5461, 4753, 166, 3157, 166, 1960, 166, 166, 166, 2440, 166, 5818, 5534, 2439, 1717, 166,
-    3789, 2959, 166, 2943, 166, 2576, 166, 2002, 2007, 1819, 3256, 4402, 5311, 3832, 160, 166, 166,
-    2803, 166, 3264, 166, 5863, 166, 2017, 166, 2798, 166, 166, 166, 166, 5607, 4965, 166, 166,
-    166, 4537, 4378, 5944, 3494, 5457, 5602, 1942, 5900, 5780, 4411, 5147, 166, 4966, 2115, 155,
-    2827, 1980, 5063, 166, 285, 5912, 3304, 2963, 5179, 3220, 166, 166, 166, 2190, 3708, 5476,
-    1944, 2366, 3893, 166, 166, 166, 3759, 166, 5434, 2740, 1707, 4244, 5426, 166, 166, 166, 3155,
-    166, 4285, 166, 166, 166, 166, 5721, 166, 3833, 6001, 301, 166, 166, 2574, 186, 2724, 166,
-    1873, 3667, 166, 5216, 166, 2935, 2100, 4987, 166, 2284, 166, 166, 2911, 3828, 4009, 166, 2065,
-    166, 5496, 6130, 5563, 4387, 166, 3771, 3469, 2989, 2222, 4577, 3965, 4296, 2975, 3813, 3240,
-    166, 4780, 4481, 3387, 2338, 166, 6183, 166, 166, 166, 166, 166, 2675, 1761, 2600, 5167, 3170,
-    4773, 2165, 5166, 166, 2223, 4642, 166, 166, 4540, 166, 166, 166, 3897, 166, 2483, 1809, 5477,
-    3844, 4067, 2508, 2275, 166, 166, 166, 166, 166, 3497, 5458, 166, 249, 2956, 166, 4651, 166,
-    283, 166, 166, 4955, 4062, 2315, 2304, 3261, 2361, 4791, 4389, 1997, 166, 3455, 166, 166, 166,
-    166, 166, 166, 4746, 5695, 5296, 105, 1841, 3368, 166, 166, 166, 5228, 166, 3496, 4423, 2024,
-    3907, 4774, 166, 166, 166, 166, 166, 2294, 2193, 166, 166, 166, 166, 166, 166, 166, 166, 4393,
-    166, 166, 2127, 166, 4573, 166, 5350, 166, 5016, 3372, 166, 5653, 166, 5972, 4719, 166, 166,
-    166, 166, 166, 5370, 166, 6142, 166, 166, 3691, 2828, 166, 2601, 166, 2937, 2060, 3654, 3097,
-    2341, 5325, 4568, 4096, 2776, 166, 2946, 166, 166, 166, 5843, 1777, 5295, 2837, 4261, 4397,
-    5006, 5808, 4866, 166, 1713, 5732, 2954, 166, 166, 27, 166, 4308, 5629, 2652, 2434, 4474, 166,
-    4928, 166, 4727, 3811, 166, 166, 5234, 166, 6010, 166, 4911, 166, 4570, 166, 6000, 3450, 5304,
-    3919, 166, 166, 4008, 3942, 166, 272, 2363, 2064, 3595, 3505, 166, 166, 3957, 1695, 2452, 4659,
-    166, 1792, 166, 131, 5968, 166, 3731, 3905, 4115, 166, 166, 2468, 166, 2727, 166, 3526, 4724,
-    166, 4388, 3149, 5539, 5092, 4440, 6162, 166, 166, 193, 4429, 2493, 166, 166, 3683, 166, 6029,
-    166, 277, 166, 166, 166, 5240, 2408, 166, 309, 2561, 210, 166, 5200, 166, 166, 166, 1930, 5692,
-    2697, 166, 166, 166, 3330, 5331, 3860, 166, 166, 4335, 166, 50, 3605, 4289, 1763, 166, 166,
-    166, 166, 3521, 166, 166, 166, 3668, 166, 166, 166, 166, 166, 3271, 1656, 166, 166, 4782, 166,
-    2962, 166, 5907, 166, 3245, 3375, 2944, 5933, 166, 166, 5406, 5655, 3139, 5423, 166, 4359,
-    5231, 2548, 166, 3831, 2858, 5488, 166, 5824, 166, 166, 166, 3885, 4372, 166, 166, 4024, 166,
-    4811, 2970, 166, 4219, 211, 166, 3471, 166, 166, 166, 166, 3854, 166, 3358, 2877, 166, 166,
-    5205, 2804, 166, 166, 166, 4452, 166, 166, 166, 166, 3776, 166, 166, 3075, 4208, 166, 5623,
-    1974, 166, 2647, 166, 3235, 166, 166, 166, 5211, 166, 166, 4304, 2206, 166, 4157, 2182, 166,
-    1816, 2626, 166, 2893, 2248, 166, 166, 166, 166, 1983, 5648, 166, 194, 166, 2106, 4328, 166,
-    4742, 166, 166, 5572, 2329, 3314, 166, 6181, 166, 166, 26, 166, 6026, 166, 166, 2114, 1669,
-    4735, 166, 166, 4256, 166, 1861, 166, 5470, 2317, 166, 4404, 2482, 166, 5305, 4415, 5986, 4949,
-    5412, 166, 1728, 166, 1898, 166, 166, 4909, 1989, 166, 166, 166, 2836, 2051, 274, 166, 2799,
-    166, 5865, 1663, 4705, 5121, 2555, 166, 4316, 4287, 1880, 1825, 166, 3689, 166, 1733, 5012,
-    166, 166, 2237, 4471, 1682, 2910, 166, 5366, 166, 166, 166, 166, 4532, 166, 2802, 166, 166,
-    166, 4057, 2471, 166, 2889, 166, 166, 4026, 5682, 3091, 166, 1977, 166, 2901, 6137, 5658, 88,
-    2318, 1965, 166, 5914, 166, 166, 4468, 1822, 166, 6050, 5956, 2201, 166, 4644, 2918, 166, 3703,
-    166, 166, 3524, 4220, 2913, 4210, 166, 166, 2090, 166, 1906, 1911, 166, 166, 3671, 2370, 166,
-    2552, 166, 3763, 2259, 1924, 166, 5940, 166, 166, 166, 3185, 3821, 4069, 261, 2381, 3244, 166,
-    166, 5715, 166, 2052, 5905, 166, 2403, 166, 3030, 2199, 166, 3550, 166, 166, 1846, 166, 166,
-    95, 166, 289, 3208, 2559, 5195, 5091, 1654, 166, 1781, 1892, 166, 4516, 2629, 166, 1700, 3067,
-    166, 166, 166, 2080, 1680, 166, 166, 166, 5700, 166, 1820, 5491, 166, 4226, 166, 166, 166, 166,
-    4653, 166, 3508, 227, 5364, 166, 2098, 166, 299, 166, 5795, 166, 166, 166, 166, 3690, 4134,
-    5517, 4534, 5042, 4874, 5798, 4234, 166, 166, 166, 166, 3702, 166, 166, 3638, 3108, 3850, 166,
-    166, 166, 16, 166, 1775, 166, 4022, 166, 223, 4095, 166, 5127, 4266, 166, 189, 166, 166, 5203,
-    166, 1805, 3884, 3778, 166, 166, 2146, 4818, 166, 2848, 3440, 4506, 5886, 3006, 218, 166, 2377,
-    166, 4091, 5925, 166, 4320, 166, 2701, 3036, 166, 166, 166, 4715, 166, 3801, 166, 3161, 166,
-    2077, 166, 4254, 3032, 243, 1814, 166, 166, 166, 166, 166, 166, 166, 166, 1835, 166, 4394, 166,
-    5769, 4923, 166, 2917, 166, 166, 178, 166, 166, 1723, 166, 5887, 166, 4956, 2952, 166, 4665,
-    3925, 3443, 3123, 166, 166, 166, 166, 166, 166, 5144, 166, 4288, 2074, 2192, 5442, 6043, 1746,
-    2016, 5995, 2203, 166, 5686, 5659, 3193, 166, 4055, 166, 166, 2233, 3571, 5809, 5984, 2323,
-    166, 166, 1740, 89, 4356, 6053, 6106, 3282, 4796, 166, 6116, 6056, 2353, 2829, 166, 5807, 2042,
-    166, 166, 166, 1670, 5937, 4465, 5646, 166, 5562, 3008, 166, 2419, 3736, 166, 4132, 169, 166,
-    166, 166, 2402, 166, 166, 1968, 2398, 166, 1684, 1827, 4551, 2679, 3875, 166, 5585, 3835, 2295,
-    166, 1991, 1803, 2992, 166, 166, 5847, 2649, 166, 76, 5415, 166, 2269, 2397, 5387, 5337, 4422,
-    166, 2672, 4832, 4617, 166, 166, 166, 166, 4552, 166, 4612, 1750, 166, 1931, 166, 1691, 2424,
-    4194, 6018, 166, 166, 4458, 4856, 166, 2089, 3814, 166, 2844, 166, 3592, 166, 4867, 5128, 166,
-    2685, 166, 166, 2616, 1972, 2617, 3943, 4664, 166, 4999, 166, 166, 145, 3635, 166, 166, 4851,
-    166, 3483, 5039, 166, 3649, 3924, 166, 166, 166, 3105, 4260, 166, 6098, 166, 3568, 267, 2456,
-    3653, 2096, 166, 166, 166, 3512, 166, 3405, 166, 3504, 166, 166, 166, 4005, 2144, 1769, 166,
-    5474, 1920, 5554, 215, 2443, 3351, 166, 5961, 166, 166, 166, 166, 242, 2331, 166, 166, 5931,
-    166, 166, 5862, 166, 1710, 166, 166, 166, 3321, 166, 4139, 166, 166, 3515, 2732, 2510, 5544,
-    166, 166, 2783, 166, 166, 166, 4018, 4649, 5789, 166, 166, 166, 166, 166, 2726, 6074, 166, 166,
-    166, 5684, 166, 166, 3395, 166, 3100, 166, 5763, 3757, 1992, 166, 3198, 2003, 166, 166, 4675,
-    166, 1893, 5621, 166, 2270, 166, 166, 166, 5421, 5590, 5664, 4045, 166, 3687, 4406, 2699, 1811,
-    167, 4036, 5384, 166, 166, 4601, 1823, 4041, 239, 1954, 166, 146, 166, 166, 3077, 5152, 5814,
-    1649, 5681, 166, 5868, 166, 166, 3792, 4860, 166, 5335, 5110, 1718, 166, 166, 166, 166, 3718,
-    3365, 2826, 166, 166, 5021, 4783, 166, 5569, 5812, 166, 166, 1876, 166, 3260, 166, 1789, 5667,
-    4224, 166, 166, 4385, 166, 166, 2620, 166, 4162, 2883, 2143, 5497, 166, 166, 5316, 5680, 166,
-    166, 248, 4050, 166, 6021, 166, 2898, 4618, 166, 166, 166, 166, 166, 5368, 166, 5378, 1842,
-    1914, 3696, 3962, 166, 4345, 2581, 1773, 2109, 166, 4371, 166, 166, 3761, 5277, 5870, 3146,
-    166, 166, 166, 5764, 127, 3058, 4059, 4718, 166, 5097, 5040, 5351, 3205, 166, 166, 4996, 2991,
-    2014, 166, 5846, 2558, 2688, 5595, 4027, 3347, 2125, 5696, 5608, 166, 166, 3228, 3745, 5775,
-    166, 1757, 4647, 166, 5977, 3020, 166, 240, 2565, 166, 4459, 166, 3367, 166, 166, 166, 3104,
-    166, 166, 166, 166, 166, 166, 259, 5486, 2846, 166, 166, 166, 4778, 2713, 166, 3955, 5683,
-    2682, 2914, 5898, 166, 166, 166, 4400, 317, 166, 5185, 3021, 5983, 4332, 3891, 166, 3095, 5003,
-    166, 166, 166, 5367, 166, 279, 1784, 4019, 2736, 4905, 2651, 5346, 166, 4841, 166, 5606, 166,
-    166, 2806, 166, 5239, 166, 166, 3237, 5490, 166, 225, 166, 166, 2254, 166, 2742, 4587, 22, 166,
-    166, 166, 5555, 166, 108, 2927, 2218, 166, 2120, 166, 5452, 4087, 4369, 166, 166, 166, 166,
-    166, 4583, 4338, 6035, 2840, 4365, 3624, 11, 1770, 166, 4630, 166, 3216, 166, 166, 166, 4638,
-    4699, 3535, 2536, 4627, 166, 166, 5760, 1935, 166, 166, 5210, 166, 2219, 2484, 4597, 5193,
-    4799, 3706, 166, 166, 166, 166, 3337, 3113, 5951, 4294, 166, 4040, 3200, 4217, 5861, 2767,
-    3530, 4499, 2775, 4121, 134, 5939, 5880, 5908, 3869, 166, 166, 3316, 6095, 2441, 3288, 166,
-    3751, 4794, 166, 166, 5803, 6169, 2356, 6182, 6135, 6127, 166, 3018, 166, 1674, 166, 166, 4097,
-    166, 5923, 287, 5965, 5129, 166, 4078, 166, 166, 6114, 6015, 5990, 3573, 166, 4146, 2681, 90,
-    6055, 4864, 166, 166, 6119, 3284, 6054, 5456, 5113, 6125, 166, 6057, 166, 3292, 166, 166, 166,
-    166, 166, 6185, 5105, 1760, 166, 166, 166, 2720, 166, 2695, 5448, 166, 1936, 166, 1807, 3406,
-    166, 166, 2161, 1642, 166, 5030, 166, 2036, 5451, 3427, 166, 166, 166, 166, 3797, 166, 1627,
-    166, 4515, 166, 166, 166, 4241, 166, 166, 166, 2771, 166, 31, 5197, 2638, 3035, 166, 166, 3914,
-    166, 166, 4546, 166, 166, 166, 4253, 3500, 166, 166, 2526, 166, 2698, 166, 3726, 2744, 137,
-    166, 166, 2676, 166, 5594, 166, 166, 166, 4842, 166, 63, 2888, 3585, 4798, 166, 5011, 166,
-    5634, 5464, 166, 166, 5620, 3894, 4070, 166, 2730, 166, 166, 1810, 2503, 5957, 1721, 6066,
-    5188, 166, 166, 1890, 4505, 1771, 5455, 166, 3132, 3984, 166, 166, 2811, 1962, 166, 166, 4872,
-    106, 3898, 3267, 166, 2085, 166, 4950, 6040, 4525, 6044, 5866, 3613, 2907, 4615, 2135, 258,
-    166, 1681, 1941, 4888, 166, 4859, 6178, 6174, 4858, 5209, 1912, 3340, 166, 4640, 5706, 166,
-    2763, 3153, 3951, 166, 5542, 5596, 5819, 5330, 5048, 4037, 166, 6033, 4625, 3326, 2013, 5283,
-    136, 3373, 2154, 166, 166, 166, 4421, 166, 5438, 2627, 2266, 2320, 166, 2588, 4790, 4290, 166,
-    4767, 5829, 2925, 5916, 2133, 166,
-];
@@ -0,0 +1,293 @@
+//! Derives the equivalence classes of five-card hands and the lookup tables that rank them
+//!
+//! This module is shared with the build script, which uses it to generate the shipped tables, so
+//! it must only depend on the standard library.
+
+/// The prime of each value, from Two to Ace
+const PRIMES: [u32; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// The adjustments of Paul Senzee's perfect hash, which spreads the 4888 products of five-card
+/// hands with a repeated value across 8192 slots without any collisions
+pub static HASH_ADJUST: [u16; 512] = [
+    0, 5628, 7017, 1298, 2918, 2442, 8070, 6383, 6383, 7425, 2442, 5628, 8044, 7425, 3155, 6383,
+    2918, 7452, 1533, 6849, 5586, 7452, 7452, 1533, 2209, 6029, 2794, 3509, 7992, 7733, 7452, 131,
+    6029, 4491, 1814, 7452, 6110, 3155, 7077, 6675, 532, 1334, 7555, 5325, 3056, 1403, 1403, 3969,
+    4491, 1403, 7592, 522, 8070, 1403, 0, 1905, 3584, 2918, 922, 3304, 6675, 0, 7622, 7017, 3210,
+    2139, 1403, 5225, 0, 3969, 7992, 5743, 5499, 5499, 5345, 7452, 522, 305, 3056, 7017, 7017,
+    2139, 1338, 3056, 7452, 1403, 6799, 3204, 3290, 4099, 1814, 2191, 4099, 5743, 1570, 1334, 7363,
+    1905, 0, 6799, 4400, 1480, 6029, 1905, 0, 7525, 2028, 2794, 131, 7646, 3155, 4986, 1858, 2442,
+    7992, 1607, 3584, 4986, 706, 6029, 5345, 7622, 6322, 5196, 1905, 6847, 218, 1785, 0, 4099,
+    2981, 6849, 4751, 3950, 7733, 3056, 5499, 4055, 6849, 1533, 131, 5196, 2918, 3879, 5325, 2794,
+    6029, 0, 0, 322, 7452, 6178, 2918, 2320, 6675, 3056, 6675, 1533, 6029, 1428, 2280, 2171, 6788,
+    7452, 3325, 107, 4262, 311, 5562, 7857, 6110, 2139, 4942, 4600, 1905, 0, 3083, 5345, 7452,
+    6675, 0, 6112, 4099, 7017, 1338, 6799, 2918, 1232, 3584, 522, 6029, 5325, 1403, 6759, 6849,
+    508, 6675, 2987, 7745, 6870, 896, 7452, 1232, 4400, 12, 2981, 3850, 4491, 6849, 0, 6675, 747,
+    4491, 7525, 6675, 7452, 7992, 6921, 7323, 6849, 3056, 1199, 2139, 6029, 6029, 190, 4351, 7891,
+    4400, 7134, 1533, 1194, 3950, 6675, 5345, 6383, 7622, 131, 1905, 2883, 6383, 1533, 5345, 2794,
+    4303, 1403, 0, 1338, 2794, 992, 4871, 6383, 4099, 2794, 3889, 6184, 3304, 1905, 6383, 3950,
+    3056, 522, 1810, 3975, 7622, 7452, 522, 6799, 5866, 7084, 7622, 6528, 2798, 7452, 1810, 7907,
+    642, 5345, 1905, 6849, 6675, 7745, 2918, 4751, 3229, 2139, 6029, 5207, 6601, 2139, 7452, 5890,
+    1428, 5628, 7622, 2139, 3146, 2400, 578, 941, 7672, 1814, 3210, 1533, 4491, 12, 2918, 1900,
+    7425, 2794, 2987, 3465, 1377, 3822, 3969, 3210, 859, 5499, 6878, 1377, 3056, 4027, 8065, 8065,
+    5207, 4400, 4303, 3210, 3210, 0, 6675, 357, 5628, 5512, 1905, 3452, 1403, 7646, 859, 6788,
+    3210, 2139, 378, 5663, 7733, 870, 0, 4491, 4813, 2110, 578, 2139, 3056, 4099, 1905, 1298, 4672,
+    2191, 3950, 5499, 3969, 4974, 6323, 6029, 7414, 6383, 0, 4974, 3210, 795, 4099, 131, 5345,
+    5345, 6576, 1810, 1621, 4400, 2918, 1905, 2442, 2679, 6322, 7452, 2110, 1403, 6383, 2653, 5132,
+    6856, 7841, 2794, 6110, 2028, 6675, 7425, 6999, 7441, 6029, 183, 6675, 4400, 859, 1403, 2794,
+    5985, 5345, 1533, 322, 4400, 1227, 5890, 4474, 4491, 3574, 8166, 6849, 7086, 5345, 5345, 5459,
+    3584, 6675, 3969, 7579, 8044, 2295, 2577, 1480, 5743, 3304, 5499, 330, 4303, 6863, 3822, 4600,
+    4751, 5628, 3822, 2918, 6675, 2400, 6663, 1403, 6849, 6029, 3145, 6110, 3210, 747, 3229, 3056,
+    2918, 7733, 330, 4055, 7322, 5628, 2987, 3056, 1905, 2903, 669, 5325, 2845, 4099, 5225, 6283,
+    4099, 5000, 642, 4055, 5345, 8034, 2918, 1041, 5769, 7051, 1538, 2918, 3366, 608, 4303, 3921,
+    0, 2918, 1905, 218, 6687, 5963, 859, 3083, 2987, 896, 5056, 1905, 2918, 4415, 7966, 7646, 2883,
+    5628, 7017, 8029, 6528, 4474, 6322, 5562, 6669, 4610, 7006,
+];
+
+/// A category of five-card hand
+///
+/// The variants share their names with `HandClass`, but their strength relative to one another is
+/// decided by the `Rules` that the tables are generated for.
+#[allow(missing_docs)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    HighCard,
+    Pair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+}
+
+/// The rules that decide which five-card hands exist and how they rank against one another
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    order: [Category; 9],
+    lowest_value: u8,
+}
+
+impl Rules {
+    /// Creates a set of rules from the order of the categories, strongest first, and the lowest
+    /// value within the deck
+    ///
+    /// `lowest_value` uses the numerical representation of `Value`, where 0 is a Two and 12 is an
+    /// Ace, and the deck holds every value from it up to the Ace. The Ace also plays below the
+    /// lowest value to make the smallest straight, like the wheel in a standard deck. `None` will
+    /// return if a category appears more than once or if the deck has fewer than five values.
+    pub fn new(order: [Category; 9], lowest_value: u8) -> Option<Self> {
+        let repeats = (0..order.len()).any(|i| order[i + 1..].contains(&order[i]));
+        if repeats || lowest_value > 8 {
+            return None;
+        }
+        Some(Self {
+            order,
+            lowest_value,
+        })
+    }
+
+    /// The rules of a standard 52-card deck
+    pub fn standard() -> Self {
+        Self {
+            order: [
+                Category::StraightFlush,
+                Category::FourOfAKind,
+                Category::FullHouse,
+                Category::Flush,
+                Category::Straight,
+                Category::ThreeOfAKind,
+                Category::TwoPair,
+                Category::Pair,
+                Category::HighCard,
+            ],
+            lowest_value: 0,
+        }
+    }
+
+    /// The rules of short deck hold'em, which removes the Twos through Fives from the deck
+    ///
+    /// A flush beats a full house and three of a kind beats a straight, as played in the Triton
+    /// series. A-6-7-8-9 is the smallest straight.
+    pub fn short_deck() -> Self {
+        Self {
+            order: [
+                Category::StraightFlush,
+                Category::FourOfAKind,
+                Category::Flush,
+                Category::FullHouse,
+                Category::ThreeOfAKind,
+                Category::Straight,
+                Category::TwoPair,
+                Category::Pair,
+                Category::HighCard,
+            ],
+            lowest_value: 4,
+        }
+    }
+
+    /// Returns the order of the categories, strongest first
+    pub fn order(&self) -> &[Category; 9] {
+        &self.order
+    }
+
+    /// Returns the numerical representation of the lowest value within the deck
+    pub fn lowest_value(&self) -> u8 {
+        self.lowest_value
+    }
+}
+
+/// The lookup tables that rank every five-card hand under a set of rules
+///
+/// Every table holds the equivalence class of a hand, numbered from 1 for the strongest hand up
+/// to `classes` for the weakest, and 0 for any entry that no hand maps to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tables {
+    /// The class of every flush, indexed by the bits of its five values
+    pub flushes: Vec<u16>,
+    /// The class of every straight and high card hand, indexed by the bits of its five values
+    pub unique5: Vec<u16>,
+    /// The class of every hand with a repeated value, indexed by `perfect_hash` of the product of
+    /// the primes of its values
+    pub hash_values: Vec<u16>,
+    /// The number of equivalence classes
+    pub classes: u16,
+}
+
+/// Generates the lookup tables for a set of rules
+///
+/// The equivalence classes are enumerated from strongest to weakest, where hands within a
+/// category are compared value by value from their most repeated values down to their kickers.
+/// Generating the tables for `Rules::standard()` gives the 7462 classes of the tables shipped with
+/// the crate, which can be used to verify them.
+pub fn generate(rules: &Rules) -> Tables {
+    let lowest = rules.lowest_value as usize;
+    let values = || (lowest..13).rev();
+
+    // the bits of every straight, strongest first, ending with the Ace playing low
+    let mut straights: Vec<usize> = (lowest..=8).rev().map(|low| 0x1f << low).collect();
+    let wheel = 0x1000 | 0xf << lowest;
+    if !straights.contains(&wheel) {
+        straights.push(wheel);
+    }
+    // the bits of every set of five distinct values that is not a straight, strongest first
+    let high_cards: Vec<usize> = (0..0x1f01usize)
+        .rev()
+        .filter(|bits| bits >> lowest << lowest == *bits)
+        .filter(|bits| bits.count_ones() == 5 && !straights.contains(bits))
+        .collect();
+
+    let mut tables = Tables {
+        flushes: vec![0; 0x1f01],
+        unique5: vec![0; 0x1f01],
+        hash_values: vec![0; 8192],
+        classes: 0,
+    };
+    let Tables {
+        flushes,
+        unique5,
+        hash_values,
+        classes,
+    } = &mut tables;
+    let mut next_class = || {
+        *classes += 1;
+        *classes
+    };
+    let mut insert_product = |product: u32, class: u16| {
+        let slot = &mut hash_values[perfect_hash(product)];
+        assert_eq!(*slot, 0, "Perfect hash collision for product {product}");
+        *slot = class;
+    };
+
+    for category in rules.order {
+        match category {
+            Category::StraightFlush => {
+                for &bits in straights.iter() {
+                    flushes[bits] = next_class();
+                }
+            }
+            Category::FourOfAKind => {
+                for quads in values() {
+                    for kicker in values().filter(|&val| val != quads) {
+                        insert_product(PRIMES[quads].pow(4) * PRIMES[kicker], next_class());
+                    }
+                }
+            }
+            Category::FullHouse => {
+                for trips in values() {
+                    for pair in values().filter(|&val| val != trips) {
+                        let product = PRIMES[trips].pow(3) * PRIMES[pair].pow(2);
+                        insert_product(product, next_class());
+                    }
+                }
+            }
+            Category::Flush => {
+                for &bits in high_cards.iter() {
+                    flushes[bits] = next_class();
+                }
+            }
+            Category::Straight => {
+                for &bits in straights.iter() {
+                    unique5[bits] = next_class();
+                }
+            }
+            Category::ThreeOfAKind => {
+                for trips in values() {
+                    for high in values().filter(|&val| val != trips) {
+                        for low in (lowest..high).rev().filter(|&val| val != trips) {
+                            let product = PRIMES[trips].pow(3) * PRIMES[high] * PRIMES[low];
+                            insert_product(product, next_class());
+                        }
+                    }
+                }
+            }
+            Category::TwoPair => {
+                for high in values() {
+                    for low in (lowest..high).rev() {
+                        for kicker in values().filter(|&val| val != high && val != low) {
+                            let product = PRIMES[high].pow(2) * PRIMES[low].pow(2) * PRIMES[kicker];
+                            insert_product(product, next_class());
+                        }
+                    }
+                }
+            }
+            Category::Pair => {
+                for pair in values() {
+                    for first in values().filter(|&val| val != pair) {
+                        for second in (lowest..first).rev().filter(|&val| val != pair) {
+                            for third in (lowest..second).rev().filter(|&val| val != pair) {
+                                let product = PRIMES[pair].pow(2)
+                                    * PRIMES[first]
+                                    * PRIMES[second]
+                                    * PRIMES[third];
+                                insert_product(product, next_class());
+                            }
+                        }
+                    }
+                }
+            }
+            Category::HighCard => {
+                for &bits in high_cards.iter() {
+                    unique5[bits] = next_class();
+                }
+            }
+        }
+    }
+
+    tables
+}
+
+/// Returns the index into the hash values table for the product of the primes of the values of a
+/// five-card hand with a repeated value
+///
+/// This is Paul Senzee's perfect hash, so any other product may share its index with a hand.
+pub fn perfect_hash(product: u32) -> usize {
+    let mut query = product.wrapping_add(0xe91aaa35);
+    query ^= query >> 16;
+    query = query.wrapping_add(query << 8);
+    query ^= query >> 4;
+    let b = (query >> 8) & 0x1ff;
+    let a = query.wrapping_add(query << 2) >> 19;
+    (a ^ HASH_ADJUST[b as usize] as u32) as usize
+}
@@ -0,0 +1,109 @@
+//! The lookup tables used by the high evaluator, and the algorithm that generates them
+//!
+//! The shipped tables rank every five-card hand of a standard deck from 1 for a royal flush to
+//! 7462 for 7-5-4-3-2 offsuit, following the Cactus-Kev evaluator. They are generated by the build
+//! script with `generate`, which can also be called directly to verify them or to build tables for
+//! other rule sets, such as short deck hold'em.
+//!
+//! Other tables are built upon first use, and `preload()` can build them ahead of time.
+//!
+//! ```rust
+//! use playing_cards::{core::Card, poker::tables::{self, Rules}};
+//!
+//! let standard = tables::generate(&Rules::standard());
+//! assert_eq!(standard.classes, 7462);
+//! assert_eq!(standard.flushes, tables::FLUSHES);
+//!
+//! // a flush beats a full house with fewer cards in the deck
+//! let short_deck = tables::generate(&Rules::short_deck());
+//! let flush = short_deck.class_of(&Card::vec_from_str("AhJh9h7h6h").unwrap());
+//! let full_house = short_deck.class_of(&Card::vec_from_str("AsAcAdKhKs").unwrap());
+//! assert!(flush < full_house);
+//! ```
+
+mod generate;
+mod preload;
+
+use crate::core::Card;
+
+pub use generate::{generate, perfect_hash, Category, Rules, Tables, HASH_ADJUST};
+pub use preload::{preload, LazyTable};
+
+include!(concat!(env!("OUT_DIR"), "/tables.rs"));
+
+impl Tables {
+    /// Returns the equivalence class of a five-card hand
+    ///
+    /// `None` will return if there are not exactly five cards, or if the hand cannot be made under
+    /// the rules that the tables were generated for, such as a hand with duplicate cards or a card
+    /// below the lowest value of the deck.
+    pub fn class_of(&self, cards: &[Card]) -> Option<u16> {
+        let patterns: [u32; 5] = cards
+            .iter()
+            .map(|card| card.calculate_bit_pattern())
+            .collect::<Vec<_>>()
+            .try_into()
+            .ok()?;
+        if cards.iter().any(Card::is_joker) || (0..5).any(|i| cards[i + 1..].contains(&cards[i])) {
+            return None;
+        }
+
+        let bits = (patterns.iter().fold(0, |acc, pattern| acc | pattern) >> 16) as usize;
+        let class = if patterns.iter().fold(0xf000, |acc, pattern| acc & pattern) != 0 {
+            self.flushes.get(bits)
+        } else if bits.count_ones() == 5 {
+            self.unique5.get(bits)
+        } else {
+            let product = patterns.iter().map(|pattern| pattern & 0xff).product();
+            self.hash_values.get(perfect_hash(product))
+        };
+        class.copied().filter(|&class| class != 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_tables() {
+        let tables = generate(&Rules::standard());
+
+        assert_eq!(tables.classes, 7462);
+        assert_eq!(tables.flushes, FLUSHES);
+        assert_eq!(tables.unique5, UNIQUE5);
+        assert_eq!(tables.hash_values, HASH_VALUES);
+
+        let class_of = |hand: &str| tables.class_of(&Card::vec_from_str(hand).unwrap());
+        assert_eq!(class_of("AsKsQsJsTs"), Some(1));
+        assert_eq!(class_of("5h4c3d2s7h"), Some(7462));
+        assert_eq!(class_of("AsAcAdAhKs"), Some(11));
+        assert_eq!(class_of("AsAcAdAhKs"), class_of("AsAcAdAhKd"));
+        assert_eq!(class_of("AsAsAdAhKs"), None);
+        assert_eq!(class_of("AsAcAdAh"), None);
+    }
+
+    #[test]
+    fn short_deck_tables() {
+        let tables = generate(&Rules::short_deck());
+        assert_eq!(tables.classes, 1404);
+
+        let class_of = |hand: &str| tables.class_of(&Card::vec_from_str(hand).unwrap());
+        assert_eq!(class_of("AsKsQsJsTs"), Some(1));
+        assert_eq!(class_of("9h8c7d6sJh"), Some(1404));
+        assert!(class_of("9h8h7h6hAh") < class_of("AsAcAdAhKs"));
+        assert!(class_of("AhJh9h7h6h") < class_of("AsAcAdKhKs"));
+        assert!(class_of("6s6c6dKhQs") < class_of("Ah9c8d7h6s"));
+        assert!(class_of("Ah9c8d7h6s") < class_of("AsAcKdKh6s"));
+        assert_eq!(class_of("5h4c3d2sAh"), None);
+    }
+
+    #[test]
+    fn invalid_rules() {
+        let mut order = *Rules::standard().order();
+        assert!(Rules::new(order, 8).is_some());
+        assert!(Rules::new(order, 9).is_none());
+        order[0] = Category::HighCard;
+        assert!(Rules::new(order, 0).is_none());
+    }
+}
@@ -0,0 +1,108 @@
+use std::time::{Duration, Instant};
+
+use crate::poker::boards;
+use crate::poker::evaluators::high_evaluator;
+
+/// A table that is built upon first use rather than generated with the crate
+///
+/// Each table is built the first time that something needs it, which puts its setup cost on
+/// whatever happens to need it first, such as the first evaluated hand. Loading the tables ahead
+/// of time moves that cost to a point of the caller's choosing, such as the startup of a game
+/// server. Leaving them alone keeps deferring them, which avoids paying for tables that are never
+/// used.
+///
+/// Only tables of a fixed size are listed. The preflop equities of `equity::preflop_equity()` and
+/// `equity::preflop_win_probability()` are also cached upon first use, but one matchup at a time,
+/// since calculating every matchup up front would take minutes.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LazyTable {
+    /// The tables that look up 5 to 7 card high hands directly, used by every high evaluation
+    HighHands,
+    /// The card values of every high hand strength, used to describe high and 2-7 ranks
+    HandValues,
+    /// The number of 5, 6, and 7 card hands at or below each high hand strength, used for
+    /// percentiles and runout distributions
+    HandCounts,
+    /// The canonical flops returned by `boards::canonical_flops()`
+    CanonicalFlops,
+}
+
+impl LazyTable {
+    /// Every table that can be loaded ahead of time
+    pub const ALL: [LazyTable; 4] = [
+        Self::HighHands,
+        Self::HandValues,
+        Self::HandCounts,
+        Self::CanonicalFlops,
+    ];
+
+    /// Builds the table if it has not been built yet
+    ///
+    /// Returns how long the call took, which is close to zero if the table was already built. If
+    /// another thread is building the table at the same time, this waits for it to finish.
+    pub fn load(self) -> Duration {
+        let start = Instant::now();
+        match self {
+            Self::HighHands => {
+                high_evaluator::direct_tables();
+            }
+            Self::HandValues => {
+                high_evaluator::hand_values();
+            }
+            Self::HandCounts => {
+                for card_count in 5..=7 {
+                    high_evaluator::cumulative_hand_counts(card_count);
+                }
+            }
+            Self::CanonicalFlops => {
+                boards::canonical_flops();
+            }
+        }
+        start.elapsed()
+    }
+}
+
+/// Builds every table in `LazyTable::ALL`
+///
+/// Returns how long each table took to build, in the order of `LazyTable::ALL`. Tables that were
+/// already built take close to no time, so calling this more than once is cheap. Individual
+/// tables can be built with `LazyTable::load()` instead. The preflop equity caches are not
+/// included; see `LazyTable`.
+///
+/// ```rust
+/// use playing_cards::poker::tables::{self, LazyTable};
+///
+/// let loaded = tables::preload();
+/// for (table, duration) in loaded {
+///     println!("Built {:?} in {:?}", table, duration);
+/// }
+///
+/// assert_eq!(loaded.map(|(table, _)| table), LazyTable::ALL);
+/// ```
+pub fn preload() -> [(LazyTable, Duration); 4] {
+    LazyTable::ALL.map(|table| (table, table.load()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Card;
+    use crate::poker::ranks::HighRank;
+
+    #[test]
+    fn preloaded_tables() {
+        let loaded = preload();
+        assert_eq!(loaded.map(|(table, _)| table), LazyTable::ALL);
+
+        let rank = high_evaluator::evaluate_hand(&Card::vec_from_str("AsAhKdQc2s").unwrap());
+        assert_eq!(rank.unwrap().to_string(), "Pair of Aces");
+        assert!(
+            HighRank::from_strength(7462)
+                .unwrap()
+                .percentile(7)
+                .unwrap()
+                > 99.9
+        );
+    }
+}